@@ -208,11 +208,31 @@ where
     Ok(())
 }
 
+// Reads a message that this process wrote, optionally skipping bounds checks.
+fn read_own_message<C>(
+    compression: &C,
+    mut bytes: &[u8],
+    trusted: bool,
+) -> ::capnp::Result<message::Reader<::capnp::serialize::OwnedSegments>>
+where
+    C: Serialize,
+{
+    let options = message::ReaderOptions::default();
+    let reader = compression.read_message(&mut bytes, options)?;
+    if trusted {
+        // Safe because the message was written by a message::Builder.
+        Ok(unsafe { message::Reader::new_trusted(reader.into_segments(), options) })
+    } else {
+        Ok(reader)
+    }
+}
+
 fn pass_by_bytes<C, S, T>(
     testcase: T,
     mut reuse: S,
     compression: C,
     iters: u64,
+    trusted: bool,
 ) -> ::capnp::Result<()>
 where
     C: Serialize,
@@ -240,9 +260,7 @@ where
                 compression.write_message(&mut writer, &message_req)?;
             }
 
-            let mut request_bytes1: &[u8] = &request_bytes;
-            let message_reader =
-                compression.read_message(&mut request_bytes1, Default::default())?;
+            let message_reader = read_own_message(&compression, &request_bytes, trusted)?;
 
             let request_reader = message_reader.get_root()?;
             testcase.handle_request(request_reader, response)?;
@@ -253,8 +271,7 @@ where
             compression.write_message(&mut writer, &message_res)?;
         }
 
-        let mut response_bytes1: &[u8] = &response_bytes;
-        let message_reader = compression.read_message(&mut response_bytes1, Default::default())?;
+        let message_reader = read_own_message(&compression, &response_bytes, trusted)?;
 
         let response_reader = message_reader.get_root()?;
         testcase.check_response(response_reader, expected)?;
@@ -374,6 +391,7 @@ where
 pub enum Mode {
     Object,
     Bytes,
+    TrustedBytes,
     Client,
     Server,
     Pipe,
@@ -384,6 +402,7 @@ impl Mode {
         match s {
             "object" => Ok(Self::Object),
             "bytes" => Ok(Self::Bytes),
+            "trusted-bytes" => Ok(Self::TrustedBytes),
             "client" => Ok(Self::Client),
             "server" => Ok(Self::Server),
             "pipe" => Ok(Self::Pipe),
@@ -406,7 +425,8 @@ where
 {
     match mode {
         Mode::Object => pass_by_object(testcase, reuse, iters),
-        Mode::Bytes => pass_by_bytes(testcase, reuse, compression, iters),
+        Mode::Bytes => pass_by_bytes(testcase, reuse, compression, iters, false),
+        Mode::TrustedBytes => pass_by_bytes(testcase, reuse, compression, iters, true),
         Mode::Client => sync_client(testcase, reuse, compression, iters),
         Mode::Server => {
             let input: ::std::fs::File = unsafe { ::std::os::unix::io::FromRawFd::from_raw_fd(1) };
@@ -54,7 +54,7 @@ fn run_case(executable: &str, case: &str, scratch_options: &[&str], iteration_co
         run_one(executable, case, "object", scratch, "none", iteration_count);
     }
 
-    for mode in &["bytes", "pipe"] {
+    for mode in &["bytes", "trusted-bytes", "pipe"] {
         for compression in &["none", "packed"] {
             for scratch in scratch_options {
                 run_one(
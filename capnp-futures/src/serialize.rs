@@ -192,67 +192,70 @@ where
 }
 
 /// Writes the provided message to `writer`. Does not call `flush()`.
+///
+/// The segment table and the segments are written with vectored writes, so that writers
+/// which support them, like sockets, can send the whole message without copying it.
 pub async fn write_message<W, M>(mut writer: W, message: M) -> Result<()>
 where
     W: AsyncWrite + Unpin,
     M: AsOutputSegments,
 {
     let segments = message.as_output_segments();
-    write_segment_table(&mut writer, &segments[..]).await?;
-    write_segments(writer, &segments[..]).await?;
+    let table = segment_table(&segments[..]);
+    let mut bufs = Vec::with_capacity(segments.len() + 1);
+    bufs.push(&table[..]);
+    bufs.extend_from_slice(&segments[..]);
+    write_all_vectored(&mut writer, &bufs).await?;
     Ok(())
 }
 
-async fn write_segment_table<W>(mut write: W, segments: &[&[u8]]) -> ::std::io::Result<()>
-where
-    W: AsyncWrite + Unpin,
-{
-    let mut buf: [u8; 8] = [0; 8];
+/// Constructs the segment table for `segments`.
+fn segment_table(segments: &[&[u8]]) -> Vec<u8> {
     let segment_count = segments.len();
-
-    // write the first Word, which contains segment_count and the 1st segment length
+    let mut buf = vec![0; (segment_count / 2 + 1) * 8];
     buf[0..4].copy_from_slice(&(segment_count as u32 - 1).to_le_bytes());
-    buf[4..8].copy_from_slice(&((segments[0].len() / 8) as u32).to_le_bytes());
-    write.write_all(&buf).await?;
-
-    if segment_count > 1 {
-        if segment_count < 4 {
-            for idx in 1..segment_count {
-                buf[(idx - 1) * 4..idx * 4]
-                    .copy_from_slice(&((segments[idx].len() / 8) as u32).to_le_bytes());
-            }
-            if segment_count == 2 {
-                for value in &mut buf[4..8] {
-                    *value = 0;
-                }
-            }
-            write.write_all(&buf).await?;
-        } else {
-            let mut buf = vec![0; (segment_count & !1) * 4];
-            for idx in 1..segment_count {
-                buf[(idx - 1) * 4..idx * 4]
-                    .copy_from_slice(&((segments[idx].len() / 8) as u32).to_le_bytes());
-            }
-            if segment_count % 2 == 0 {
-                for idx in (buf.len() - 4)..(buf.len()) {
-                    buf[idx] = 0
-                }
-            }
-            write.write_all(&buf).await?;
-        }
+    for (idx, segment) in segments.iter().enumerate() {
+        buf[4 + idx * 4..8 + idx * 4].copy_from_slice(&((segment.len() / 8) as u32).to_le_bytes());
     }
-    Ok(())
+    buf
 }
 
-/// Writes segments to `write`.
-async fn write_segments<W>(mut write: W, segments: &[&[u8]]) -> Result<()>
+// The number of buffers to pass to a single `write_vectored()` call.
+const MAX_IO_SLICES: usize = 64;
+
+/// Writes all of `bufs` to `write`, one after the other. If `write` does not support
+/// vectored writes, each call to `write_vectored()` writes only the first buffer.
+async fn write_all_vectored<W>(write: &mut W, mut bufs: &[&[u8]]) -> std::io::Result<()>
 where
     W: AsyncWrite + Unpin,
 {
-    for segment in segments {
-        write.write_all(segment).await?;
+    let mut skip = 0; // Bytes of bufs[0] that have been written already.
+    let mut slices = Vec::with_capacity(bufs.len().min(MAX_IO_SLICES));
+    loop {
+        while let Some(first) = bufs.first() {
+            if skip < first.len() {
+                break;
+            }
+            skip -= first.len();
+            bufs = &bufs[1..];
+        }
+        if bufs.is_empty() {
+            return Ok(());
+        }
+        slices.clear();
+        slices.push(std::io::IoSlice::new(&bufs[0][skip..]));
+        slices.extend(
+            bufs[1..bufs.len().min(MAX_IO_SLICES)]
+                .iter()
+                .map(|buf| std::io::IoSlice::new(buf)),
+        );
+        match write.write_vectored(&slices).await {
+            Ok(0) => return Err(std::io::ErrorKind::WriteZero.into()),
+            Ok(n) => skip += n,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
     }
-    Ok(())
 }
 
 #[cfg(test)]
@@ -424,11 +427,7 @@ pub mod test {
     }
 
     fn construct_segment_table(segments: &[&[u8]]) -> Vec<u8> {
-        let mut exec = futures::executor::LocalPool::new();
-        let mut buf = vec![];
-        exec.run_until(super::write_segment_table(&mut buf, segments))
-            .unwrap();
-        buf
+        super::segment_table(segments)
     }
 
     #[test]
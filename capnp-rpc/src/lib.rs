@@ -356,6 +356,26 @@ where
     )))
 }
 
+/// Creates a new local RPC client for the interface described by `schema`, out of an object
+/// that implements the dynamic server trait. The server lives as long as any client refers to it,
+/// so its schema must be `'static`; to serve a schema from a `SchemaLoader`, the loader must live
+/// for the rest of the program, for example by leaking it with `Box::leak()`. Clients of schemas
+/// that only live for a while can be made with `dynamic_capability::Client::new()`.
+pub fn new_dynamic_client<S>(
+    schema: capnp::schema::InterfaceSchema<'static>,
+    s: S,
+) -> capnp::dynamic_capability::Client<'static>
+where
+    S: capnp::dynamic_capability::Server<'static> + 'static,
+{
+    capnp::dynamic_capability::Client::new(
+        Box::new(local::Client::new(
+            capnp::dynamic_capability::ServerDispatch::new(schema, s),
+        )),
+        schema,
+    )
+}
+
 /// Allows a server to recognize its own capabilities when passed back to it, and obtain the
 /// underlying Server objects associated with them. Holds only weak references to Server objects
 /// allowing Server objects to be dropped when dropped by the remote client. Call the `gc` method
@@ -383,10 +383,9 @@ fn remote_exception_to_error(exception: exception::Reader) -> Error {
     let reason_str = reason
         .to_str()
         .unwrap_or("<malformed utf-8 in error reason>");
-    Error {
-        extra: format!("remote exception: {reason_str}"),
-        kind,
-    }
+    let mut error = Error::from_kind(kind);
+    error.extra = format!("remote exception: {reason_str}");
+    error
 }
 
 pub struct ConnectionErrorHandler<VatId>
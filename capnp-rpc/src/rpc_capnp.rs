@@ -11,12 +11,15 @@ pub mod message {
     #[derive(Copy, Clone)]
     pub struct Owned(());
     impl ::capnp::introspect::Introspect for Owned {
-        fn introspect() -> ::capnp::introspect::Type {
-            ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+        fn introspect() -> ::capnp::introspect::Type<'static> {
+            ::capnp::introspect::TypeVariant::Struct(
+                ::capnp::introspect::RawBrandedStructSchema {
+                    generic: &_private::RAW_SCHEMA,
+                    field_types: _private::get_field_types,
+                    annotation_types: _private::get_annotation_types,
+                }
+                .into(),
+            )
             .into()
         }
     }
@@ -211,43 +214,50 @@ pub mod message {
                     ::capnp::traits::FromPointerReader::get_from_pointer(
                         &self.reader.get_pointer_field(0),
                         ::core::option::Option::None,
-                    ),
+                    )
+                    .map_err(|e| e.with_field(_private::TYPE_ID, "unimplemented")),
                 )),
                 1 => ::core::result::Result::Ok(Abort(
                     ::capnp::traits::FromPointerReader::get_from_pointer(
                         &self.reader.get_pointer_field(0),
                         ::core::option::Option::None,
-                    ),
+                    )
+                    .map_err(|e| e.with_field(_private::TYPE_ID, "abort")),
                 )),
                 2 => ::core::result::Result::Ok(Call(
                     ::capnp::traits::FromPointerReader::get_from_pointer(
                         &self.reader.get_pointer_field(0),
                         ::core::option::Option::None,
-                    ),
+                    )
+                    .map_err(|e| e.with_field(_private::TYPE_ID, "call")),
                 )),
                 3 => ::core::result::Result::Ok(Return(
                     ::capnp::traits::FromPointerReader::get_from_pointer(
                         &self.reader.get_pointer_field(0),
                         ::core::option::Option::None,
-                    ),
+                    )
+                    .map_err(|e| e.with_field(_private::TYPE_ID, "return")),
                 )),
                 4 => ::core::result::Result::Ok(Finish(
                     ::capnp::traits::FromPointerReader::get_from_pointer(
                         &self.reader.get_pointer_field(0),
                         ::core::option::Option::None,
-                    ),
+                    )
+                    .map_err(|e| e.with_field(_private::TYPE_ID, "finish")),
                 )),
                 5 => ::core::result::Result::Ok(Resolve(
                     ::capnp::traits::FromPointerReader::get_from_pointer(
                         &self.reader.get_pointer_field(0),
                         ::core::option::Option::None,
-                    ),
+                    )
+                    .map_err(|e| e.with_field(_private::TYPE_ID, "resolve")),
                 )),
                 6 => ::core::result::Result::Ok(Release(
                     ::capnp::traits::FromPointerReader::get_from_pointer(
                         &self.reader.get_pointer_field(0),
                         ::core::option::Option::None,
-                    ),
+                    )
+                    .map_err(|e| e.with_field(_private::TYPE_ID, "release")),
                 )),
                 7 => ::core::result::Result::Ok(ObsoleteSave(::capnp::any_pointer::Reader::new(
                     self.reader.get_pointer_field(0),
@@ -256,7 +266,8 @@ pub mod message {
                     ::capnp::traits::FromPointerReader::get_from_pointer(
                         &self.reader.get_pointer_field(0),
                         ::core::option::Option::None,
-                    ),
+                    )
+                    .map_err(|e| e.with_field(_private::TYPE_ID, "bootstrap")),
                 )),
                 9 => ::core::result::Result::Ok(ObsoleteDelete(::capnp::any_pointer::Reader::new(
                     self.reader.get_pointer_field(0),
@@ -265,25 +276,29 @@ pub mod message {
                     ::capnp::traits::FromPointerReader::get_from_pointer(
                         &self.reader.get_pointer_field(0),
                         ::core::option::Option::None,
-                    ),
+                    )
+                    .map_err(|e| e.with_field(_private::TYPE_ID, "provide")),
                 )),
                 11 => ::core::result::Result::Ok(Accept(
                     ::capnp::traits::FromPointerReader::get_from_pointer(
                         &self.reader.get_pointer_field(0),
                         ::core::option::Option::None,
-                    ),
+                    )
+                    .map_err(|e| e.with_field(_private::TYPE_ID, "accept")),
                 )),
                 12 => ::core::result::Result::Ok(Join(
                     ::capnp::traits::FromPointerReader::get_from_pointer(
                         &self.reader.get_pointer_field(0),
                         ::core::option::Option::None,
-                    ),
+                    )
+                    .map_err(|e| e.with_field(_private::TYPE_ID, "join")),
                 )),
                 13 => ::core::result::Result::Ok(Disembargo(
                     ::capnp::traits::FromPointerReader::get_from_pointer(
                         &self.reader.get_pointer_field(0),
                         ::core::option::Option::None,
-                    ),
+                    )
+                    .map_err(|e| e.with_field(_private::TYPE_ID, "disembargo")),
                 )),
                 x => ::core::result::Result::Err(::capnp::NotInSchema(x)),
             }
@@ -394,6 +409,40 @@ pub mod message {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_unimplemented(
+            self,
+        ) -> ::capnp::Result<crate::rpc_capnp::message::Builder<'a>> {
+            self.builder.set_data_field::<u16>(0, 0);
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[inline]
+        pub fn disown_unimplemented(
+            &mut self,
+        ) -> ::capnp::Result<::capnp::orphan::Orphan<crate::rpc_capnp::message::Owned>> {
+            if self.builder.get_data_field::<u16>(0) != 0 {
+                let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::Failed);
+                ::core::write!(
+                    error,
+                    "cannot disown unimplemented, which is not the active member of its union"
+                );
+                return ::core::result::Result::Err(error);
+            }
+            ::core::result::Result::Ok(::capnp::orphan::Orphan::disown(
+                self.builder.reborrow().get_pointer_field(0),
+            ))
+        }
+        #[inline]
+        pub fn adopt_unimplemented(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::message::Owned>,
+        ) -> ::capnp::Result<()> {
+            self.builder.set_data_field::<u16>(0, 0);
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
         pub fn has_unimplemented(&self) -> bool {
             if self.builder.get_data_field::<u16>(0) != 0 {
                 return false;
@@ -418,6 +467,38 @@ pub mod message {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_abort(self) -> ::capnp::Result<crate::rpc_capnp::exception::Builder<'a>> {
+            self.builder.set_data_field::<u16>(0, 1);
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[inline]
+        pub fn disown_abort(
+            &mut self,
+        ) -> ::capnp::Result<::capnp::orphan::Orphan<crate::rpc_capnp::exception::Owned>> {
+            if self.builder.get_data_field::<u16>(0) != 1 {
+                let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::Failed);
+                ::core::write!(
+                    error,
+                    "cannot disown abort, which is not the active member of its union"
+                );
+                return ::core::result::Result::Err(error);
+            }
+            ::core::result::Result::Ok(::capnp::orphan::Orphan::disown(
+                self.builder.reborrow().get_pointer_field(0),
+            ))
+        }
+        #[inline]
+        pub fn adopt_abort(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::exception::Owned>,
+        ) -> ::capnp::Result<()> {
+            self.builder.set_data_field::<u16>(0, 1);
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
         pub fn has_abort(&self) -> bool {
             if self.builder.get_data_field::<u16>(0) != 1 {
                 return false;
@@ -442,6 +523,38 @@ pub mod message {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_call(self) -> ::capnp::Result<crate::rpc_capnp::call::Builder<'a>> {
+            self.builder.set_data_field::<u16>(0, 2);
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[inline]
+        pub fn disown_call(
+            &mut self,
+        ) -> ::capnp::Result<::capnp::orphan::Orphan<crate::rpc_capnp::call::Owned>> {
+            if self.builder.get_data_field::<u16>(0) != 2 {
+                let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::Failed);
+                ::core::write!(
+                    error,
+                    "cannot disown call, which is not the active member of its union"
+                );
+                return ::core::result::Result::Err(error);
+            }
+            ::core::result::Result::Ok(::capnp::orphan::Orphan::disown(
+                self.builder.reborrow().get_pointer_field(0),
+            ))
+        }
+        #[inline]
+        pub fn adopt_call(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::call::Owned>,
+        ) -> ::capnp::Result<()> {
+            self.builder.set_data_field::<u16>(0, 2);
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
         pub fn has_call(&self) -> bool {
             if self.builder.get_data_field::<u16>(0) != 2 {
                 return false;
@@ -466,6 +579,38 @@ pub mod message {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_return(self) -> ::capnp::Result<crate::rpc_capnp::return_::Builder<'a>> {
+            self.builder.set_data_field::<u16>(0, 3);
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[inline]
+        pub fn disown_return(
+            &mut self,
+        ) -> ::capnp::Result<::capnp::orphan::Orphan<crate::rpc_capnp::return_::Owned>> {
+            if self.builder.get_data_field::<u16>(0) != 3 {
+                let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::Failed);
+                ::core::write!(
+                    error,
+                    "cannot disown return, which is not the active member of its union"
+                );
+                return ::core::result::Result::Err(error);
+            }
+            ::core::result::Result::Ok(::capnp::orphan::Orphan::disown(
+                self.builder.reborrow().get_pointer_field(0),
+            ))
+        }
+        #[inline]
+        pub fn adopt_return(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::return_::Owned>,
+        ) -> ::capnp::Result<()> {
+            self.builder.set_data_field::<u16>(0, 3);
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
         pub fn has_return(&self) -> bool {
             if self.builder.get_data_field::<u16>(0) != 3 {
                 return false;
@@ -490,6 +635,38 @@ pub mod message {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_finish(self) -> ::capnp::Result<crate::rpc_capnp::finish::Builder<'a>> {
+            self.builder.set_data_field::<u16>(0, 4);
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[inline]
+        pub fn disown_finish(
+            &mut self,
+        ) -> ::capnp::Result<::capnp::orphan::Orphan<crate::rpc_capnp::finish::Owned>> {
+            if self.builder.get_data_field::<u16>(0) != 4 {
+                let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::Failed);
+                ::core::write!(
+                    error,
+                    "cannot disown finish, which is not the active member of its union"
+                );
+                return ::core::result::Result::Err(error);
+            }
+            ::core::result::Result::Ok(::capnp::orphan::Orphan::disown(
+                self.builder.reborrow().get_pointer_field(0),
+            ))
+        }
+        #[inline]
+        pub fn adopt_finish(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::finish::Owned>,
+        ) -> ::capnp::Result<()> {
+            self.builder.set_data_field::<u16>(0, 4);
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
         pub fn has_finish(&self) -> bool {
             if self.builder.get_data_field::<u16>(0) != 4 {
                 return false;
@@ -514,6 +691,38 @@ pub mod message {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_resolve(self) -> ::capnp::Result<crate::rpc_capnp::resolve::Builder<'a>> {
+            self.builder.set_data_field::<u16>(0, 5);
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[inline]
+        pub fn disown_resolve(
+            &mut self,
+        ) -> ::capnp::Result<::capnp::orphan::Orphan<crate::rpc_capnp::resolve::Owned>> {
+            if self.builder.get_data_field::<u16>(0) != 5 {
+                let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::Failed);
+                ::core::write!(
+                    error,
+                    "cannot disown resolve, which is not the active member of its union"
+                );
+                return ::core::result::Result::Err(error);
+            }
+            ::core::result::Result::Ok(::capnp::orphan::Orphan::disown(
+                self.builder.reborrow().get_pointer_field(0),
+            ))
+        }
+        #[inline]
+        pub fn adopt_resolve(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::resolve::Owned>,
+        ) -> ::capnp::Result<()> {
+            self.builder.set_data_field::<u16>(0, 5);
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
         pub fn has_resolve(&self) -> bool {
             if self.builder.get_data_field::<u16>(0) != 5 {
                 return false;
@@ -538,6 +747,38 @@ pub mod message {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_release(self) -> ::capnp::Result<crate::rpc_capnp::release::Builder<'a>> {
+            self.builder.set_data_field::<u16>(0, 6);
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[inline]
+        pub fn disown_release(
+            &mut self,
+        ) -> ::capnp::Result<::capnp::orphan::Orphan<crate::rpc_capnp::release::Owned>> {
+            if self.builder.get_data_field::<u16>(0) != 6 {
+                let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::Failed);
+                ::core::write!(
+                    error,
+                    "cannot disown release, which is not the active member of its union"
+                );
+                return ::core::result::Result::Err(error);
+            }
+            ::core::result::Result::Ok(::capnp::orphan::Orphan::disown(
+                self.builder.reborrow().get_pointer_field(0),
+            ))
+        }
+        #[inline]
+        pub fn adopt_release(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::release::Owned>,
+        ) -> ::capnp::Result<()> {
+            self.builder.set_data_field::<u16>(0, 6);
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
         pub fn has_release(&self) -> bool {
             if self.builder.get_data_field::<u16>(0) != 6 {
                 return false;
@@ -552,6 +793,30 @@ pub mod message {
             result
         }
         #[inline]
+        pub fn disown_obsolete_save(
+            &mut self,
+        ) -> ::capnp::Result<::capnp::orphan::Orphan<::capnp::any_pointer::Owned>> {
+            if self.builder.get_data_field::<u16>(0) != 7 {
+                let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::Failed);
+                ::core::write!(
+                    error,
+                    "cannot disown obsoleteSave, which is not the active member of its union"
+                );
+                return ::core::result::Result::Err(error);
+            }
+            ::core::result::Result::Ok(::capnp::orphan::Orphan::disown(
+                self.builder.reborrow().get_pointer_field(0),
+            ))
+        }
+        #[inline]
+        pub fn adopt_obsolete_save(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<::capnp::any_pointer::Owned>,
+        ) -> ::capnp::Result<()> {
+            self.builder.set_data_field::<u16>(0, 7);
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
         pub fn has_obsolete_save(&self) -> bool {
             if self.builder.get_data_field::<u16>(0) != 7 {
                 return false;
@@ -576,6 +841,40 @@ pub mod message {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_bootstrap(
+            self,
+        ) -> ::capnp::Result<crate::rpc_capnp::bootstrap::Builder<'a>> {
+            self.builder.set_data_field::<u16>(0, 8);
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[inline]
+        pub fn disown_bootstrap(
+            &mut self,
+        ) -> ::capnp::Result<::capnp::orphan::Orphan<crate::rpc_capnp::bootstrap::Owned>> {
+            if self.builder.get_data_field::<u16>(0) != 8 {
+                let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::Failed);
+                ::core::write!(
+                    error,
+                    "cannot disown bootstrap, which is not the active member of its union"
+                );
+                return ::core::result::Result::Err(error);
+            }
+            ::core::result::Result::Ok(::capnp::orphan::Orphan::disown(
+                self.builder.reborrow().get_pointer_field(0),
+            ))
+        }
+        #[inline]
+        pub fn adopt_bootstrap(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::bootstrap::Owned>,
+        ) -> ::capnp::Result<()> {
+            self.builder.set_data_field::<u16>(0, 8);
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
         pub fn has_bootstrap(&self) -> bool {
             if self.builder.get_data_field::<u16>(0) != 8 {
                 return false;
@@ -590,6 +889,30 @@ pub mod message {
             result
         }
         #[inline]
+        pub fn disown_obsolete_delete(
+            &mut self,
+        ) -> ::capnp::Result<::capnp::orphan::Orphan<::capnp::any_pointer::Owned>> {
+            if self.builder.get_data_field::<u16>(0) != 9 {
+                let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::Failed);
+                ::core::write!(
+                    error,
+                    "cannot disown obsoleteDelete, which is not the active member of its union"
+                );
+                return ::core::result::Result::Err(error);
+            }
+            ::core::result::Result::Ok(::capnp::orphan::Orphan::disown(
+                self.builder.reborrow().get_pointer_field(0),
+            ))
+        }
+        #[inline]
+        pub fn adopt_obsolete_delete(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<::capnp::any_pointer::Owned>,
+        ) -> ::capnp::Result<()> {
+            self.builder.set_data_field::<u16>(0, 9);
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
         pub fn has_obsolete_delete(&self) -> bool {
             if self.builder.get_data_field::<u16>(0) != 9 {
                 return false;
@@ -614,6 +937,38 @@ pub mod message {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_provide(self) -> ::capnp::Result<crate::rpc_capnp::provide::Builder<'a>> {
+            self.builder.set_data_field::<u16>(0, 10);
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[inline]
+        pub fn disown_provide(
+            &mut self,
+        ) -> ::capnp::Result<::capnp::orphan::Orphan<crate::rpc_capnp::provide::Owned>> {
+            if self.builder.get_data_field::<u16>(0) != 10 {
+                let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::Failed);
+                ::core::write!(
+                    error,
+                    "cannot disown provide, which is not the active member of its union"
+                );
+                return ::core::result::Result::Err(error);
+            }
+            ::core::result::Result::Ok(::capnp::orphan::Orphan::disown(
+                self.builder.reborrow().get_pointer_field(0),
+            ))
+        }
+        #[inline]
+        pub fn adopt_provide(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::provide::Owned>,
+        ) -> ::capnp::Result<()> {
+            self.builder.set_data_field::<u16>(0, 10);
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
         pub fn has_provide(&self) -> bool {
             if self.builder.get_data_field::<u16>(0) != 10 {
                 return false;
@@ -638,6 +993,38 @@ pub mod message {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_accept(self) -> ::capnp::Result<crate::rpc_capnp::accept::Builder<'a>> {
+            self.builder.set_data_field::<u16>(0, 11);
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[inline]
+        pub fn disown_accept(
+            &mut self,
+        ) -> ::capnp::Result<::capnp::orphan::Orphan<crate::rpc_capnp::accept::Owned>> {
+            if self.builder.get_data_field::<u16>(0) != 11 {
+                let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::Failed);
+                ::core::write!(
+                    error,
+                    "cannot disown accept, which is not the active member of its union"
+                );
+                return ::core::result::Result::Err(error);
+            }
+            ::core::result::Result::Ok(::capnp::orphan::Orphan::disown(
+                self.builder.reborrow().get_pointer_field(0),
+            ))
+        }
+        #[inline]
+        pub fn adopt_accept(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::accept::Owned>,
+        ) -> ::capnp::Result<()> {
+            self.builder.set_data_field::<u16>(0, 11);
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
         pub fn has_accept(&self) -> bool {
             if self.builder.get_data_field::<u16>(0) != 11 {
                 return false;
@@ -662,6 +1049,38 @@ pub mod message {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_join(self) -> ::capnp::Result<crate::rpc_capnp::join::Builder<'a>> {
+            self.builder.set_data_field::<u16>(0, 12);
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[inline]
+        pub fn disown_join(
+            &mut self,
+        ) -> ::capnp::Result<::capnp::orphan::Orphan<crate::rpc_capnp::join::Owned>> {
+            if self.builder.get_data_field::<u16>(0) != 12 {
+                let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::Failed);
+                ::core::write!(
+                    error,
+                    "cannot disown join, which is not the active member of its union"
+                );
+                return ::core::result::Result::Err(error);
+            }
+            ::core::result::Result::Ok(::capnp::orphan::Orphan::disown(
+                self.builder.reborrow().get_pointer_field(0),
+            ))
+        }
+        #[inline]
+        pub fn adopt_join(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::join::Owned>,
+        ) -> ::capnp::Result<()> {
+            self.builder.set_data_field::<u16>(0, 12);
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
         pub fn has_join(&self) -> bool {
             if self.builder.get_data_field::<u16>(0) != 12 {
                 return false;
@@ -686,6 +1105,40 @@ pub mod message {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_disembargo(
+            self,
+        ) -> ::capnp::Result<crate::rpc_capnp::disembargo::Builder<'a>> {
+            self.builder.set_data_field::<u16>(0, 13);
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[inline]
+        pub fn disown_disembargo(
+            &mut self,
+        ) -> ::capnp::Result<::capnp::orphan::Orphan<crate::rpc_capnp::disembargo::Owned>> {
+            if self.builder.get_data_field::<u16>(0) != 13 {
+                let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::Failed);
+                ::core::write!(
+                    error,
+                    "cannot disown disembargo, which is not the active member of its union"
+                );
+                return ::core::result::Result::Err(error);
+            }
+            ::core::result::Result::Ok(::capnp::orphan::Orphan::disown(
+                self.builder.reborrow().get_pointer_field(0),
+            ))
+        }
+        #[inline]
+        pub fn adopt_disembargo(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::disembargo::Owned>,
+        ) -> ::capnp::Result<()> {
+            self.builder.set_data_field::<u16>(0, 13);
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
         pub fn has_disembargo(&self) -> bool {
             if self.builder.get_data_field::<u16>(0) != 13 {
                 return false;
@@ -1024,7 +1477,7 @@ pub mod message {
             ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
             ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
         ];
-        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type<'static> {
             match index {
         0 => <crate::rpc_capnp::message::Owned as ::capnp::introspect::Introspect>::introspect(),
         1 => <crate::rpc_capnp::exception::Owned as ::capnp::introspect::Introspect>::introspect(),
@@ -1046,7 +1499,7 @@ pub mod message {
         pub fn get_annotation_types(
             child_index: Option<u16>,
             index: u32,
-        ) -> ::capnp::introspect::Type {
+        ) -> ::capnp::introspect::Type<'static> {
             panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
         }
         pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema =
@@ -1116,12 +1569,15 @@ pub mod bootstrap {
     #[derive(Copy, Clone)]
     pub struct Owned(());
     impl ::capnp::introspect::Introspect for Owned {
-        fn introspect() -> ::capnp::introspect::Type {
-            ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+        fn introspect() -> ::capnp::introspect::Type<'static> {
+            ::capnp::introspect::TypeVariant::Struct(
+                ::capnp::introspect::RawBrandedStructSchema {
+                    generic: &_private::RAW_SCHEMA,
+                    field_types: _private::get_field_types,
+                    annotation_types: _private::get_annotation_types,
+                }
+                .into(),
+            )
             .into()
         }
     }
@@ -1330,6 +1786,19 @@ pub mod bootstrap {
             result
         }
         #[inline]
+        pub fn disown_deprecated_object_id(
+            &mut self,
+        ) -> ::capnp::orphan::Orphan<::capnp::any_pointer::Owned> {
+            ::capnp::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
+        pub fn adopt_deprecated_object_id(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<::capnp::any_pointer::Owned>,
+        ) -> ::capnp::Result<()> {
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
         pub fn has_deprecated_object_id(&self) -> bool {
             !self.builder.is_pointer_field_null(0)
         }
@@ -1403,7 +1872,7 @@ pub mod bootstrap {
             ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
             ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
         ];
-        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type<'static> {
             match index {
                 0 => <u32 as ::capnp::introspect::Introspect>::introspect(),
                 1 => <::capnp::any_pointer::Owned as ::capnp::introspect::Introspect>::introspect(),
@@ -1413,7 +1882,7 @@ pub mod bootstrap {
         pub fn get_annotation_types(
             child_index: Option<u16>,
             index: u32,
-        ) -> ::capnp::introspect::Type {
+        ) -> ::capnp::introspect::Type<'static> {
             panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
         }
         pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema =
@@ -1434,12 +1903,15 @@ pub mod call {
     #[derive(Copy, Clone)]
     pub struct Owned(());
     impl ::capnp::introspect::Introspect for Owned {
-        fn introspect() -> ::capnp::introspect::Type {
-            ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+        fn introspect() -> ::capnp::introspect::Type<'static> {
+            ::capnp::introspect::TypeVariant::Struct(
+                ::capnp::introspect::RawBrandedStructSchema {
+                    generic: &_private::RAW_SCHEMA,
+                    field_types: _private::get_field_types,
+                    annotation_types: _private::get_annotation_types,
+                }
+                .into(),
+            )
             .into()
         }
     }
@@ -1539,6 +2011,7 @@ pub mod call {
                 &self.reader.get_pointer_field(0),
                 ::core::option::Option::None,
             )
+            .map_err(|e| e.with_field(_private::TYPE_ID, "target"))
         }
         #[inline]
         pub fn has_target(&self) -> bool {
@@ -1558,6 +2031,7 @@ pub mod call {
                 &self.reader.get_pointer_field(1),
                 ::core::option::Option::None,
             )
+            .map_err(|e| e.with_field(_private::TYPE_ID, "params"))
         }
         #[inline]
         pub fn has_params(&self) -> bool {
@@ -1698,6 +2172,28 @@ pub mod call {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_target(
+            self,
+        ) -> ::capnp::Result<crate::rpc_capnp::message_target::Builder<'a>> {
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[inline]
+        pub fn disown_target(
+            &mut self,
+        ) -> ::capnp::orphan::Orphan<crate::rpc_capnp::message_target::Owned> {
+            ::capnp::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
+        pub fn adopt_target(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::message_target::Owned>,
+        ) -> ::capnp::Result<()> {
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
         pub fn has_target(&self) -> bool {
             !self.builder.is_pointer_field_null(0)
         }
@@ -1740,6 +2236,26 @@ pub mod call {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(1), 0)
         }
         #[inline]
+        pub fn try_init_params(self) -> ::capnp::Result<crate::rpc_capnp::payload::Builder<'a>> {
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(1),
+                0,
+            )
+        }
+        #[inline]
+        pub fn disown_params(
+            &mut self,
+        ) -> ::capnp::orphan::Orphan<crate::rpc_capnp::payload::Owned> {
+            ::capnp::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(1))
+        }
+        #[inline]
+        pub fn adopt_params(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::payload::Owned>,
+        ) -> ::capnp::Result<()> {
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(1))
+        }
+        #[inline]
         pub fn has_params(&self) -> bool {
             !self.builder.is_pointer_field_null(1)
         }
@@ -1959,7 +2475,7 @@ pub mod call {
             ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
             ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
         ];
-        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type<'static> {
             match index {
         0 => <u32 as ::capnp::introspect::Introspect>::introspect(),
         1 => <crate::rpc_capnp::message_target::Owned as ::capnp::introspect::Introspect>::introspect(),
@@ -1976,7 +2492,7 @@ pub mod call {
         pub fn get_annotation_types(
             child_index: Option<u16>,
             index: u32,
-        ) -> ::capnp::introspect::Type {
+        ) -> ::capnp::introspect::Type<'static> {
             panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
         }
         pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema =
@@ -1998,13 +2514,14 @@ pub mod call {
         #[derive(Copy, Clone)]
         pub struct Owned(());
         impl ::capnp::introspect::Introspect for Owned {
-            fn introspect() -> ::capnp::introspect::Type {
+            fn introspect() -> ::capnp::introspect::Type<'static> {
                 ::capnp::introspect::TypeVariant::Struct(
                     ::capnp::introspect::RawBrandedStructSchema {
                         generic: &_private::RAW_SCHEMA,
                         field_types: _private::get_field_types,
                         annotation_types: _private::get_annotation_types,
-                    },
+                    }
+                    .into(),
                 )
                 .into()
             }
@@ -2225,6 +2742,30 @@ pub mod call {
                 result
             }
             #[inline]
+            pub fn disown_third_party(
+                &mut self,
+            ) -> ::capnp::Result<::capnp::orphan::Orphan<::capnp::any_pointer::Owned>> {
+                if self.builder.get_data_field::<u16>(3) != 2 {
+                    let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::Failed);
+                    ::core::write!(
+                        error,
+                        "cannot disown thirdParty, which is not the active member of its union"
+                    );
+                    return ::core::result::Result::Err(error);
+                }
+                ::core::result::Result::Ok(::capnp::orphan::Orphan::disown(
+                    self.builder.reborrow().get_pointer_field(2),
+                ))
+            }
+            #[inline]
+            pub fn adopt_third_party(
+                &mut self,
+                orphan: ::capnp::orphan::Orphan<::capnp::any_pointer::Owned>,
+            ) -> ::capnp::Result<()> {
+                self.builder.set_data_field::<u16>(3, 2);
+                orphan.adopt_into(self.builder.reborrow().get_pointer_field(2))
+            }
+            #[inline]
             pub fn has_third_party(&self) -> bool {
                 if self.builder.get_data_field::<u16>(3) != 2 {
                     return false;
@@ -2322,7 +2863,7 @@ pub mod call {
                 ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
                 ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
             ];
-            pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+            pub fn get_field_types(index: u16) -> ::capnp::introspect::Type<'static> {
                 match index {
                     0 => <() as ::capnp::introspect::Introspect>::introspect(),
                     1 => <() as ::capnp::introspect::Introspect>::introspect(),
@@ -2336,7 +2877,7 @@ pub mod call {
             pub fn get_annotation_types(
                 child_index: Option<u16>,
                 index: u32,
-            ) -> ::capnp::introspect::Type {
+            ) -> ::capnp::introspect::Type<'static> {
                 panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
             }
             pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema =
@@ -2370,12 +2911,15 @@ pub mod return_ {
     #[derive(Copy, Clone)]
     pub struct Owned(());
     impl ::capnp::introspect::Introspect for Owned {
-        fn introspect() -> ::capnp::introspect::Type {
-            ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+        fn introspect() -> ::capnp::introspect::Type<'static> {
+            ::capnp::introspect::TypeVariant::Struct(
+                ::capnp::introspect::RawBrandedStructSchema {
+                    generic: &_private::RAW_SCHEMA,
+                    field_types: _private::get_field_types,
+                    annotation_types: _private::get_annotation_types,
+                }
+                .into(),
+            )
             .into()
         }
     }
@@ -2505,13 +3049,15 @@ pub mod return_ {
                     ::capnp::traits::FromPointerReader::get_from_pointer(
                         &self.reader.get_pointer_field(0),
                         ::core::option::Option::None,
-                    ),
+                    )
+                    .map_err(|e| e.with_field(_private::TYPE_ID, "results")),
                 )),
                 1 => ::core::result::Result::Ok(Exception(
                     ::capnp::traits::FromPointerReader::get_from_pointer(
                         &self.reader.get_pointer_field(0),
                         ::core::option::Option::None,
-                    ),
+                    )
+                    .map_err(|e| e.with_field(_private::TYPE_ID, "exception")),
                 )),
                 2 => ::core::result::Result::Ok(Canceled(())),
                 3 => ::core::result::Result::Ok(ResultsSentElsewhere(())),
@@ -2646,6 +3192,38 @@ pub mod return_ {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_results(self) -> ::capnp::Result<crate::rpc_capnp::payload::Builder<'a>> {
+            self.builder.set_data_field::<u16>(3, 0);
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[inline]
+        pub fn disown_results(
+            &mut self,
+        ) -> ::capnp::Result<::capnp::orphan::Orphan<crate::rpc_capnp::payload::Owned>> {
+            if self.builder.get_data_field::<u16>(3) != 0 {
+                let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::Failed);
+                ::core::write!(
+                    error,
+                    "cannot disown results, which is not the active member of its union"
+                );
+                return ::core::result::Result::Err(error);
+            }
+            ::core::result::Result::Ok(::capnp::orphan::Orphan::disown(
+                self.builder.reborrow().get_pointer_field(0),
+            ))
+        }
+        #[inline]
+        pub fn adopt_results(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::payload::Owned>,
+        ) -> ::capnp::Result<()> {
+            self.builder.set_data_field::<u16>(3, 0);
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
         pub fn has_results(&self) -> bool {
             if self.builder.get_data_field::<u16>(3) != 0 {
                 return false;
@@ -2670,6 +3248,40 @@ pub mod return_ {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_exception(
+            self,
+        ) -> ::capnp::Result<crate::rpc_capnp::exception::Builder<'a>> {
+            self.builder.set_data_field::<u16>(3, 1);
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[inline]
+        pub fn disown_exception(
+            &mut self,
+        ) -> ::capnp::Result<::capnp::orphan::Orphan<crate::rpc_capnp::exception::Owned>> {
+            if self.builder.get_data_field::<u16>(3) != 1 {
+                let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::Failed);
+                ::core::write!(
+                    error,
+                    "cannot disown exception, which is not the active member of its union"
+                );
+                return ::core::result::Result::Err(error);
+            }
+            ::core::result::Result::Ok(::capnp::orphan::Orphan::disown(
+                self.builder.reborrow().get_pointer_field(0),
+            ))
+        }
+        #[inline]
+        pub fn adopt_exception(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::exception::Owned>,
+        ) -> ::capnp::Result<()> {
+            self.builder.set_data_field::<u16>(3, 1);
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
         pub fn has_exception(&self) -> bool {
             if self.builder.get_data_field::<u16>(3) != 1 {
                 return false;
@@ -2697,6 +3309,27 @@ pub mod return_ {
             result
         }
         #[inline]
+        pub fn disown_accept_from_third_party(
+            &mut self,
+        ) -> ::capnp::Result<::capnp::orphan::Orphan<::capnp::any_pointer::Owned>> {
+            if self.builder.get_data_field::<u16>(3) != 5 {
+                let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::Failed);
+                ::core::write!(error, "cannot disown acceptFromThirdParty, which is not the active member of its union");
+                return ::core::result::Result::Err(error);
+            }
+            ::core::result::Result::Ok(::capnp::orphan::Orphan::disown(
+                self.builder.reborrow().get_pointer_field(0),
+            ))
+        }
+        #[inline]
+        pub fn adopt_accept_from_third_party(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<::capnp::any_pointer::Owned>,
+        ) -> ::capnp::Result<()> {
+            self.builder.set_data_field::<u16>(3, 5);
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
         pub fn has_accept_from_third_party(&self) -> bool {
             if self.builder.get_data_field::<u16>(3) != 5 {
                 return false;
@@ -2917,7 +3550,7 @@ pub mod return_ {
             ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
             ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
         ];
-        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type<'static> {
             match index {
         0 => <u32 as ::capnp::introspect::Introspect>::introspect(),
         1 => <bool as ::capnp::introspect::Introspect>::introspect(),
@@ -2934,7 +3567,7 @@ pub mod return_ {
         pub fn get_annotation_types(
             child_index: Option<u16>,
             index: u32,
-        ) -> ::capnp::introspect::Type {
+        ) -> ::capnp::introspect::Type<'static> {
             panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
         }
         pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema =
@@ -2973,12 +3606,15 @@ pub mod finish {
     #[derive(Copy, Clone)]
     pub struct Owned(());
     impl ::capnp::introspect::Introspect for Owned {
-        fn introspect() -> ::capnp::introspect::Type {
-            ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+        fn introspect() -> ::capnp::introspect::Type<'static> {
+            ::capnp::introspect::TypeVariant::Struct(
+                ::capnp::introspect::RawBrandedStructSchema {
+                    generic: &_private::RAW_SCHEMA,
+                    field_types: _private::get_field_types,
+                    annotation_types: _private::get_annotation_types,
+                }
+                .into(),
+            )
             .into()
         }
     }
@@ -3277,7 +3913,7 @@ pub mod finish {
             ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
             ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
         ];
-        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type<'static> {
             match index {
                 0 => <u32 as ::capnp::introspect::Introspect>::introspect(),
                 1 => <bool as ::capnp::introspect::Introspect>::introspect(),
@@ -3288,7 +3924,7 @@ pub mod finish {
         pub fn get_annotation_types(
             child_index: Option<u16>,
             index: u32,
-        ) -> ::capnp::introspect::Type {
+        ) -> ::capnp::introspect::Type<'static> {
             panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
         }
         pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema =
@@ -3311,12 +3947,15 @@ pub mod resolve {
     #[derive(Copy, Clone)]
     pub struct Owned(());
     impl ::capnp::introspect::Introspect for Owned {
-        fn introspect() -> ::capnp::introspect::Type {
-            ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+        fn introspect() -> ::capnp::introspect::Type<'static> {
+            ::capnp::introspect::TypeVariant::Struct(
+                ::capnp::introspect::RawBrandedStructSchema {
+                    generic: &_private::RAW_SCHEMA,
+                    field_types: _private::get_field_types,
+                    annotation_types: _private::get_annotation_types,
+                }
+                .into(),
+            )
             .into()
         }
     }
@@ -3431,13 +4070,15 @@ pub mod resolve {
                     ::capnp::traits::FromPointerReader::get_from_pointer(
                         &self.reader.get_pointer_field(0),
                         ::core::option::Option::None,
-                    ),
+                    )
+                    .map_err(|e| e.with_field(_private::TYPE_ID, "cap")),
                 )),
                 1 => ::core::result::Result::Ok(Exception(
                     ::capnp::traits::FromPointerReader::get_from_pointer(
                         &self.reader.get_pointer_field(0),
                         ::core::option::Option::None,
-                    ),
+                    )
+                    .map_err(|e| e.with_field(_private::TYPE_ID, "exception")),
                 )),
                 x => ::core::result::Result::Err(::capnp::NotInSchema(x)),
             }
@@ -3556,6 +4197,41 @@ pub mod resolve {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_cap(
+            self,
+        ) -> ::capnp::Result<crate::rpc_capnp::cap_descriptor::Builder<'a>> {
+            self.builder.set_data_field::<u16>(2, 0);
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[inline]
+        pub fn disown_cap(
+            &mut self,
+        ) -> ::capnp::Result<::capnp::orphan::Orphan<crate::rpc_capnp::cap_descriptor::Owned>>
+        {
+            if self.builder.get_data_field::<u16>(2) != 0 {
+                let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::Failed);
+                ::core::write!(
+                    error,
+                    "cannot disown cap, which is not the active member of its union"
+                );
+                return ::core::result::Result::Err(error);
+            }
+            ::core::result::Result::Ok(::capnp::orphan::Orphan::disown(
+                self.builder.reborrow().get_pointer_field(0),
+            ))
+        }
+        #[inline]
+        pub fn adopt_cap(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::cap_descriptor::Owned>,
+        ) -> ::capnp::Result<()> {
+            self.builder.set_data_field::<u16>(2, 0);
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
         pub fn has_cap(&self) -> bool {
             if self.builder.get_data_field::<u16>(2) != 0 {
                 return false;
@@ -3580,6 +4256,40 @@ pub mod resolve {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_exception(
+            self,
+        ) -> ::capnp::Result<crate::rpc_capnp::exception::Builder<'a>> {
+            self.builder.set_data_field::<u16>(2, 1);
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[inline]
+        pub fn disown_exception(
+            &mut self,
+        ) -> ::capnp::Result<::capnp::orphan::Orphan<crate::rpc_capnp::exception::Owned>> {
+            if self.builder.get_data_field::<u16>(2) != 1 {
+                let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::Failed);
+                ::core::write!(
+                    error,
+                    "cannot disown exception, which is not the active member of its union"
+                );
+                return ::core::result::Result::Err(error);
+            }
+            ::core::result::Result::Ok(::capnp::orphan::Orphan::disown(
+                self.builder.reborrow().get_pointer_field(0),
+            ))
+        }
+        #[inline]
+        pub fn adopt_exception(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::exception::Owned>,
+        ) -> ::capnp::Result<()> {
+            self.builder.set_data_field::<u16>(2, 1);
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
         pub fn has_exception(&self) -> bool {
             if self.builder.get_data_field::<u16>(2) != 1 {
                 return false;
@@ -3684,7 +4394,7 @@ pub mod resolve {
             ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
             ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
         ];
-        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type<'static> {
             match index {
         0 => <u32 as ::capnp::introspect::Introspect>::introspect(),
         1 => <crate::rpc_capnp::cap_descriptor::Owned as ::capnp::introspect::Introspect>::introspect(),
@@ -3695,7 +4405,7 @@ pub mod resolve {
         pub fn get_annotation_types(
             child_index: Option<u16>,
             index: u32,
-        ) -> ::capnp::introspect::Type {
+        ) -> ::capnp::introspect::Type<'static> {
             panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
         }
         pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema =
@@ -3728,12 +4438,15 @@ pub mod release {
     #[derive(Copy, Clone)]
     pub struct Owned(());
     impl ::capnp::introspect::Introspect for Owned {
-        fn introspect() -> ::capnp::introspect::Type {
-            ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+        fn introspect() -> ::capnp::introspect::Type<'static> {
+            ::capnp::introspect::TypeVariant::Struct(
+                ::capnp::introspect::RawBrandedStructSchema {
+                    generic: &_private::RAW_SCHEMA,
+                    field_types: _private::get_field_types,
+                    annotation_types: _private::get_annotation_types,
+                }
+                .into(),
+            )
             .into()
         }
     }
@@ -3999,7 +4712,7 @@ pub mod release {
             ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
             ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
         ];
-        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type<'static> {
             match index {
                 0 => <u32 as ::capnp::introspect::Introspect>::introspect(),
                 1 => <u32 as ::capnp::introspect::Introspect>::introspect(),
@@ -4009,7 +4722,7 @@ pub mod release {
         pub fn get_annotation_types(
             child_index: Option<u16>,
             index: u32,
-        ) -> ::capnp::introspect::Type {
+        ) -> ::capnp::introspect::Type<'static> {
             panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
         }
         pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema =
@@ -4030,12 +4743,15 @@ pub mod disembargo {
     #[derive(Copy, Clone)]
     pub struct Owned(());
     impl ::capnp::introspect::Introspect for Owned {
-        fn introspect() -> ::capnp::introspect::Type {
-            ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+        fn introspect() -> ::capnp::introspect::Type<'static> {
+            ::capnp::introspect::TypeVariant::Struct(
+                ::capnp::introspect::RawBrandedStructSchema {
+                    generic: &_private::RAW_SCHEMA,
+                    field_types: _private::get_field_types,
+                    annotation_types: _private::get_annotation_types,
+                }
+                .into(),
+            )
             .into()
         }
     }
@@ -4131,6 +4847,7 @@ pub mod disembargo {
                 &self.reader.get_pointer_field(0),
                 ::core::option::Option::None,
             )
+            .map_err(|e| e.with_field(_private::TYPE_ID, "target"))
         }
         #[inline]
         pub fn has_target(&self) -> bool {
@@ -4247,8 +4964,30 @@ pub mod disembargo {
             )
         }
         #[inline]
-        pub fn init_target(self) -> crate::rpc_capnp::message_target::Builder<'a> {
-            ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+        pub fn init_target(self) -> crate::rpc_capnp::message_target::Builder<'a> {
+            ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+        }
+        #[inline]
+        pub fn try_init_target(
+            self,
+        ) -> ::capnp::Result<crate::rpc_capnp::message_target::Builder<'a>> {
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[inline]
+        pub fn disown_target(
+            &mut self,
+        ) -> ::capnp::orphan::Orphan<crate::rpc_capnp::message_target::Owned> {
+            ::capnp::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
+        pub fn adopt_target(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::message_target::Owned>,
+        ) -> ::capnp::Result<()> {
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
         }
         #[inline]
         pub fn has_target(&self) -> bool {
@@ -4327,7 +5066,7 @@ pub mod disembargo {
             ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
             ::capnp::word(99, 111, 110, 116, 101, 120, 116, 0),
         ];
-        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type<'static> {
             match index {
         0 => <crate::rpc_capnp::message_target::Owned as ::capnp::introspect::Introspect>::introspect(),
         1 => <crate::rpc_capnp::disembargo::context::Owned as ::capnp::introspect::Introspect>::introspect(),
@@ -4337,7 +5076,7 @@ pub mod disembargo {
         pub fn get_annotation_types(
             child_index: Option<u16>,
             index: u32,
-        ) -> ::capnp::introspect::Type {
+        ) -> ::capnp::introspect::Type<'static> {
             panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
         }
         pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema =
@@ -4359,13 +5098,14 @@ pub mod disembargo {
         #[derive(Copy, Clone)]
         pub struct Owned(());
         impl ::capnp::introspect::Introspect for Owned {
-            fn introspect() -> ::capnp::introspect::Type {
+            fn introspect() -> ::capnp::introspect::Type<'static> {
                 ::capnp::introspect::TypeVariant::Struct(
                     ::capnp::introspect::RawBrandedStructSchema {
                         generic: &_private::RAW_SCHEMA,
                         field_types: _private::get_field_types,
                         annotation_types: _private::get_annotation_types,
-                    },
+                    }
+                    .into(),
                 )
                 .into()
             }
@@ -4694,7 +5434,7 @@ pub mod disembargo {
                 ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
                 ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
             ];
-            pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+            pub fn get_field_types(index: u16) -> ::capnp::introspect::Type<'static> {
                 match index {
                     0 => <u32 as ::capnp::introspect::Introspect>::introspect(),
                     1 => <u32 as ::capnp::introspect::Introspect>::introspect(),
@@ -4706,7 +5446,7 @@ pub mod disembargo {
             pub fn get_annotation_types(
                 child_index: Option<u16>,
                 index: u32,
-            ) -> ::capnp::introspect::Type {
+            ) -> ::capnp::introspect::Type<'static> {
                 panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
             }
             pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema =
@@ -4736,12 +5476,15 @@ pub mod provide {
     #[derive(Copy, Clone)]
     pub struct Owned(());
     impl ::capnp::introspect::Introspect for Owned {
-        fn introspect() -> ::capnp::introspect::Type {
-            ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+        fn introspect() -> ::capnp::introspect::Type<'static> {
+            ::capnp::introspect::TypeVariant::Struct(
+                ::capnp::introspect::RawBrandedStructSchema {
+                    generic: &_private::RAW_SCHEMA,
+                    field_types: _private::get_field_types,
+                    annotation_types: _private::get_annotation_types,
+                }
+                .into(),
+            )
             .into()
         }
     }
@@ -4841,6 +5584,7 @@ pub mod provide {
                 &self.reader.get_pointer_field(0),
                 ::core::option::Option::None,
             )
+            .map_err(|e| e.with_field(_private::TYPE_ID, "target"))
         }
         #[inline]
         pub fn has_target(&self) -> bool {
@@ -4973,6 +5717,28 @@ pub mod provide {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_target(
+            self,
+        ) -> ::capnp::Result<crate::rpc_capnp::message_target::Builder<'a>> {
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[inline]
+        pub fn disown_target(
+            &mut self,
+        ) -> ::capnp::orphan::Orphan<crate::rpc_capnp::message_target::Owned> {
+            ::capnp::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
+        pub fn adopt_target(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::message_target::Owned>,
+        ) -> ::capnp::Result<()> {
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
         pub fn has_target(&self) -> bool {
             !self.builder.is_pointer_field_null(0)
         }
@@ -4987,6 +5753,17 @@ pub mod provide {
             result
         }
         #[inline]
+        pub fn disown_recipient(&mut self) -> ::capnp::orphan::Orphan<::capnp::any_pointer::Owned> {
+            ::capnp::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(1))
+        }
+        #[inline]
+        pub fn adopt_recipient(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<::capnp::any_pointer::Owned>,
+        ) -> ::capnp::Result<()> {
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(1))
+        }
+        #[inline]
         pub fn has_recipient(&self) -> bool {
             !self.builder.is_pointer_field_null(1)
         }
@@ -5077,7 +5854,7 @@ pub mod provide {
             ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
             ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
         ];
-        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type<'static> {
             match index {
         0 => <u32 as ::capnp::introspect::Introspect>::introspect(),
         1 => <crate::rpc_capnp::message_target::Owned as ::capnp::introspect::Introspect>::introspect(),
@@ -5088,7 +5865,7 @@ pub mod provide {
         pub fn get_annotation_types(
             child_index: Option<u16>,
             index: u32,
-        ) -> ::capnp::introspect::Type {
+        ) -> ::capnp::introspect::Type<'static> {
             panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
         }
         pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema =
@@ -5109,12 +5886,15 @@ pub mod accept {
     #[derive(Copy, Clone)]
     pub struct Owned(());
     impl ::capnp::introspect::Introspect for Owned {
-        fn introspect() -> ::capnp::introspect::Type {
-            ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+        fn introspect() -> ::capnp::introspect::Type<'static> {
+            ::capnp::introspect::TypeVariant::Struct(
+                ::capnp::introspect::RawBrandedStructSchema {
+                    generic: &_private::RAW_SCHEMA,
+                    field_types: _private::get_field_types,
+                    annotation_types: _private::get_annotation_types,
+                }
+                .into(),
+            )
             .into()
         }
     }
@@ -5327,6 +6107,17 @@ pub mod accept {
             result
         }
         #[inline]
+        pub fn disown_provision(&mut self) -> ::capnp::orphan::Orphan<::capnp::any_pointer::Owned> {
+            ::capnp::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
+        pub fn adopt_provision(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<::capnp::any_pointer::Owned>,
+        ) -> ::capnp::Result<()> {
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
         pub fn has_provision(&self) -> bool {
             !self.builder.is_pointer_field_null(0)
         }
@@ -5422,7 +6213,7 @@ pub mod accept {
             ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
             ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
         ];
-        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type<'static> {
             match index {
                 0 => <u32 as ::capnp::introspect::Introspect>::introspect(),
                 1 => <::capnp::any_pointer::Owned as ::capnp::introspect::Introspect>::introspect(),
@@ -5433,7 +6224,7 @@ pub mod accept {
         pub fn get_annotation_types(
             child_index: Option<u16>,
             index: u32,
-        ) -> ::capnp::introspect::Type {
+        ) -> ::capnp::introspect::Type<'static> {
             panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
         }
         pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema =
@@ -5454,12 +6245,15 @@ pub mod join {
     #[derive(Copy, Clone)]
     pub struct Owned(());
     impl ::capnp::introspect::Introspect for Owned {
-        fn introspect() -> ::capnp::introspect::Type {
-            ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+        fn introspect() -> ::capnp::introspect::Type<'static> {
+            ::capnp::introspect::TypeVariant::Struct(
+                ::capnp::introspect::RawBrandedStructSchema {
+                    generic: &_private::RAW_SCHEMA,
+                    field_types: _private::get_field_types,
+                    annotation_types: _private::get_annotation_types,
+                }
+                .into(),
+            )
             .into()
         }
     }
@@ -5559,6 +6353,7 @@ pub mod join {
                 &self.reader.get_pointer_field(0),
                 ::core::option::Option::None,
             )
+            .map_err(|e| e.with_field(_private::TYPE_ID, "target"))
         }
         #[inline]
         pub fn has_target(&self) -> bool {
@@ -5691,6 +6486,28 @@ pub mod join {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_target(
+            self,
+        ) -> ::capnp::Result<crate::rpc_capnp::message_target::Builder<'a>> {
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[inline]
+        pub fn disown_target(
+            &mut self,
+        ) -> ::capnp::orphan::Orphan<crate::rpc_capnp::message_target::Owned> {
+            ::capnp::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
+        pub fn adopt_target(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::message_target::Owned>,
+        ) -> ::capnp::Result<()> {
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
         pub fn has_target(&self) -> bool {
             !self.builder.is_pointer_field_null(0)
         }
@@ -5705,6 +6522,17 @@ pub mod join {
             result
         }
         #[inline]
+        pub fn disown_key_part(&mut self) -> ::capnp::orphan::Orphan<::capnp::any_pointer::Owned> {
+            ::capnp::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(1))
+        }
+        #[inline]
+        pub fn adopt_key_part(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<::capnp::any_pointer::Owned>,
+        ) -> ::capnp::Result<()> {
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(1))
+        }
+        #[inline]
         pub fn has_key_part(&self) -> bool {
             !self.builder.is_pointer_field_null(1)
         }
@@ -5793,7 +6621,7 @@ pub mod join {
             ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
             ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
         ];
-        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type<'static> {
             match index {
         0 => <u32 as ::capnp::introspect::Introspect>::introspect(),
         1 => <crate::rpc_capnp::message_target::Owned as ::capnp::introspect::Introspect>::introspect(),
@@ -5804,7 +6632,7 @@ pub mod join {
         pub fn get_annotation_types(
             child_index: Option<u16>,
             index: u32,
-        ) -> ::capnp::introspect::Type {
+        ) -> ::capnp::introspect::Type<'static> {
             panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
         }
         pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema =
@@ -5827,12 +6655,15 @@ pub mod message_target {
     #[derive(Copy, Clone)]
     pub struct Owned(());
     impl ::capnp::introspect::Introspect for Owned {
-        fn introspect() -> ::capnp::introspect::Type {
-            ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+        fn introspect() -> ::capnp::introspect::Type<'static> {
+            ::capnp::introspect::TypeVariant::Struct(
+                ::capnp::introspect::RawBrandedStructSchema {
+                    generic: &_private::RAW_SCHEMA,
+                    field_types: _private::get_field_types,
+                    annotation_types: _private::get_annotation_types,
+                }
+                .into(),
+            )
             .into()
         }
     }
@@ -5937,7 +6768,8 @@ pub mod message_target {
                     ::capnp::traits::FromPointerReader::get_from_pointer(
                         &self.reader.get_pointer_field(0),
                         ::core::option::Option::None,
-                    ),
+                    )
+                    .map_err(|e| e.with_field(_private::TYPE_ID, "promisedAnswer")),
                 )),
                 x => ::core::result::Result::Err(::capnp::NotInSchema(x)),
             }
@@ -6053,6 +6885,41 @@ pub mod message_target {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_promised_answer(
+            self,
+        ) -> ::capnp::Result<crate::rpc_capnp::promised_answer::Builder<'a>> {
+            self.builder.set_data_field::<u16>(2, 1);
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[inline]
+        pub fn disown_promised_answer(
+            &mut self,
+        ) -> ::capnp::Result<::capnp::orphan::Orphan<crate::rpc_capnp::promised_answer::Owned>>
+        {
+            if self.builder.get_data_field::<u16>(2) != 1 {
+                let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::Failed);
+                ::core::write!(
+                    error,
+                    "cannot disown promisedAnswer, which is not the active member of its union"
+                );
+                return ::core::result::Result::Err(error);
+            }
+            ::core::result::Result::Ok(::capnp::orphan::Orphan::disown(
+                self.builder.reborrow().get_pointer_field(0),
+            ))
+        }
+        #[inline]
+        pub fn adopt_promised_answer(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::promised_answer::Owned>,
+        ) -> ::capnp::Result<()> {
+            self.builder.set_data_field::<u16>(2, 1);
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
         pub fn has_promised_answer(&self) -> bool {
             if self.builder.get_data_field::<u16>(2) != 1 {
                 return false;
@@ -6137,7 +7004,7 @@ pub mod message_target {
             ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
             ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
         ];
-        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type<'static> {
             match index {
         0 => <u32 as ::capnp::introspect::Introspect>::introspect(),
         1 => <crate::rpc_capnp::promised_answer::Owned as ::capnp::introspect::Introspect>::introspect(),
@@ -6147,7 +7014,7 @@ pub mod message_target {
         pub fn get_annotation_types(
             child_index: Option<u16>,
             index: u32,
-        ) -> ::capnp::introspect::Type {
+        ) -> ::capnp::introspect::Type<'static> {
             panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
         }
         pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema =
@@ -6176,12 +7043,15 @@ pub mod payload {
     #[derive(Copy, Clone)]
     pub struct Owned(());
     impl ::capnp::introspect::Introspect for Owned {
-        fn introspect() -> ::capnp::introspect::Type {
-            ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+        fn introspect() -> ::capnp::introspect::Type<'static> {
+            ::capnp::introspect::TypeVariant::Struct(
+                ::capnp::introspect::RawBrandedStructSchema {
+                    generic: &_private::RAW_SCHEMA,
+                    field_types: _private::get_field_types,
+                    annotation_types: _private::get_annotation_types,
+                }
+                .into(),
+            )
             .into()
         }
     }
@@ -6289,6 +7159,7 @@ pub mod payload {
                 &self.reader.get_pointer_field(1),
                 ::core::option::Option::None,
             )
+            .map_err(|e| e.with_field(_private::TYPE_ID, "capTable"))
         }
         #[inline]
         pub fn has_cap_table(&self) -> bool {
@@ -6393,6 +7264,17 @@ pub mod payload {
             result
         }
         #[inline]
+        pub fn disown_content(&mut self) -> ::capnp::orphan::Orphan<::capnp::any_pointer::Owned> {
+            ::capnp::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
+        pub fn adopt_content(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<::capnp::any_pointer::Owned>,
+        ) -> ::capnp::Result<()> {
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
         pub fn has_content(&self) -> bool {
             !self.builder.is_pointer_field_null(0)
         }
@@ -6429,6 +7311,35 @@ pub mod payload {
             )
         }
         #[inline]
+        pub fn try_init_cap_table(
+            self,
+            size: u32,
+        ) -> ::capnp::Result<
+            ::capnp::struct_list::Builder<'a, crate::rpc_capnp::cap_descriptor::Owned>,
+        > {
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(1),
+                size,
+            )
+        }
+        #[inline]
+        pub fn disown_cap_table(
+            &mut self,
+        ) -> ::capnp::orphan::Orphan<
+            ::capnp::struct_list::Owned<crate::rpc_capnp::cap_descriptor::Owned>,
+        > {
+            ::capnp::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(1))
+        }
+        #[inline]
+        pub fn adopt_cap_table(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<
+                ::capnp::struct_list::Owned<crate::rpc_capnp::cap_descriptor::Owned>,
+            >,
+        ) -> ::capnp::Result<()> {
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(1))
+        }
+        #[inline]
         pub fn has_cap_table(&self) -> bool {
             !self.builder.is_pointer_field_null(1)
         }
@@ -6504,7 +7415,7 @@ pub mod payload {
             ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
             ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
         ];
-        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type<'static> {
             match index {
         0 => <::capnp::any_pointer::Owned as ::capnp::introspect::Introspect>::introspect(),
         1 => <::capnp::struct_list::Owned<crate::rpc_capnp::cap_descriptor::Owned> as ::capnp::introspect::Introspect>::introspect(),
@@ -6514,7 +7425,7 @@ pub mod payload {
         pub fn get_annotation_types(
             child_index: Option<u16>,
             index: u32,
-        ) -> ::capnp::introspect::Type {
+        ) -> ::capnp::introspect::Type<'static> {
             panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
         }
         pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema =
@@ -6539,12 +7450,15 @@ pub mod cap_descriptor {
     #[derive(Copy, Clone)]
     pub struct Owned(());
     impl ::capnp::introspect::Introspect for Owned {
-        fn introspect() -> ::capnp::introspect::Type {
-            ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+        fn introspect() -> ::capnp::introspect::Type<'static> {
+            ::capnp::introspect::TypeVariant::Struct(
+                ::capnp::introspect::RawBrandedStructSchema {
+                    generic: &_private::RAW_SCHEMA,
+                    field_types: _private::get_field_types,
+                    annotation_types: _private::get_annotation_types,
+                }
+                .into(),
+            )
             .into()
         }
     }
@@ -6667,13 +7581,15 @@ pub mod cap_descriptor {
                     ::capnp::traits::FromPointerReader::get_from_pointer(
                         &self.reader.get_pointer_field(0),
                         ::core::option::Option::None,
-                    ),
+                    )
+                    .map_err(|e| e.with_field(_private::TYPE_ID, "receiverAnswer")),
                 )),
                 5 => ::core::result::Result::Ok(ThirdPartyHosted(
                     ::capnp::traits::FromPointerReader::get_from_pointer(
                         &self.reader.get_pointer_field(0),
                         ::core::option::Option::None,
-                    ),
+                    )
+                    .map_err(|e| e.with_field(_private::TYPE_ID, "thirdPartyHosted")),
                 )),
                 x => ::core::result::Result::Err(::capnp::NotInSchema(x)),
             }
@@ -6803,6 +7719,41 @@ pub mod cap_descriptor {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_receiver_answer(
+            self,
+        ) -> ::capnp::Result<crate::rpc_capnp::promised_answer::Builder<'a>> {
+            self.builder.set_data_field::<u16>(0, 4);
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[inline]
+        pub fn disown_receiver_answer(
+            &mut self,
+        ) -> ::capnp::Result<::capnp::orphan::Orphan<crate::rpc_capnp::promised_answer::Owned>>
+        {
+            if self.builder.get_data_field::<u16>(0) != 4 {
+                let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::Failed);
+                ::core::write!(
+                    error,
+                    "cannot disown receiverAnswer, which is not the active member of its union"
+                );
+                return ::core::result::Result::Err(error);
+            }
+            ::core::result::Result::Ok(::capnp::orphan::Orphan::disown(
+                self.builder.reborrow().get_pointer_field(0),
+            ))
+        }
+        #[inline]
+        pub fn adopt_receiver_answer(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::promised_answer::Owned>,
+        ) -> ::capnp::Result<()> {
+            self.builder.set_data_field::<u16>(0, 4);
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
         pub fn has_receiver_answer(&self) -> bool {
             if self.builder.get_data_field::<u16>(0) != 4 {
                 return false;
@@ -6829,6 +7780,42 @@ pub mod cap_descriptor {
             ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_third_party_hosted(
+            self,
+        ) -> ::capnp::Result<crate::rpc_capnp::third_party_cap_descriptor::Builder<'a>> {
+            self.builder.set_data_field::<u16>(0, 5);
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[inline]
+        pub fn disown_third_party_hosted(
+            &mut self,
+        ) -> ::capnp::Result<
+            ::capnp::orphan::Orphan<crate::rpc_capnp::third_party_cap_descriptor::Owned>,
+        > {
+            if self.builder.get_data_field::<u16>(0) != 5 {
+                let mut error = ::capnp::Error::from_kind(::capnp::ErrorKind::Failed);
+                ::core::write!(
+                    error,
+                    "cannot disown thirdPartyHosted, which is not the active member of its union"
+                );
+                return ::core::result::Result::Err(error);
+            }
+            ::core::result::Result::Ok(::capnp::orphan::Orphan::disown(
+                self.builder.reborrow().get_pointer_field(0),
+            ))
+        }
+        #[inline]
+        pub fn adopt_third_party_hosted(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<crate::rpc_capnp::third_party_cap_descriptor::Owned>,
+        ) -> ::capnp::Result<()> {
+            self.builder.set_data_field::<u16>(0, 5);
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
         pub fn has_third_party_hosted(&self) -> bool {
             if self.builder.get_data_field::<u16>(0) != 5 {
                 return false;
@@ -7016,7 +8003,7 @@ pub mod cap_descriptor {
             ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
             ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
         ];
-        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type<'static> {
             match index {
         0 => <() as ::capnp::introspect::Introspect>::introspect(),
         1 => <u32 as ::capnp::introspect::Introspect>::introspect(),
@@ -7031,7 +8018,7 @@ pub mod cap_descriptor {
         pub fn get_annotation_types(
             child_index: Option<u16>,
             index: u32,
-        ) -> ::capnp::introspect::Type {
+        ) -> ::capnp::introspect::Type<'static> {
             panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
         }
         pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema =
@@ -7068,12 +8055,15 @@ pub mod promised_answer {
     #[derive(Copy, Clone)]
     pub struct Owned(());
     impl ::capnp::introspect::Introspect for Owned {
-        fn introspect() -> ::capnp::introspect::Type {
-            ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+        fn introspect() -> ::capnp::introspect::Type<'static> {
+            ::capnp::introspect::TypeVariant::Struct(
+                ::capnp::introspect::RawBrandedStructSchema {
+                    generic: &_private::RAW_SCHEMA,
+                    field_types: _private::get_field_types,
+                    annotation_types: _private::get_annotation_types,
+                }
+                .into(),
+            )
             .into()
         }
     }
@@ -7177,6 +8167,7 @@ pub mod promised_answer {
                 &self.reader.get_pointer_field(0),
                 ::core::option::Option::None,
             )
+            .map_err(|e| e.with_field(_private::TYPE_ID, "transform"))
         }
         #[inline]
         pub fn has_transform(&self) -> bool {
@@ -7312,6 +8303,35 @@ pub mod promised_answer {
             )
         }
         #[inline]
+        pub fn try_init_transform(
+            self,
+            size: u32,
+        ) -> ::capnp::Result<
+            ::capnp::struct_list::Builder<'a, crate::rpc_capnp::promised_answer::op::Owned>,
+        > {
+            ::capnp::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                size,
+            )
+        }
+        #[inline]
+        pub fn disown_transform(
+            &mut self,
+        ) -> ::capnp::orphan::Orphan<
+            ::capnp::struct_list::Owned<crate::rpc_capnp::promised_answer::op::Owned>,
+        > {
+            ::capnp::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
+        pub fn adopt_transform(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<
+                ::capnp::struct_list::Owned<crate::rpc_capnp::promised_answer::op::Owned>,
+            >,
+        ) -> ::capnp::Result<()> {
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
         pub fn has_transform(&self) -> bool {
             !self.builder.is_pointer_field_null(0)
         }
@@ -7388,7 +8408,7 @@ pub mod promised_answer {
             ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
             ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
         ];
-        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type<'static> {
             match index {
         0 => <u32 as ::capnp::introspect::Introspect>::introspect(),
         1 => <::capnp::struct_list::Owned<crate::rpc_capnp::promised_answer::op::Owned> as ::capnp::introspect::Introspect>::introspect(),
@@ -7398,7 +8418,7 @@ pub mod promised_answer {
         pub fn get_annotation_types(
             child_index: Option<u16>,
             index: u32,
-        ) -> ::capnp::introspect::Type {
+        ) -> ::capnp::introspect::Type<'static> {
             panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
         }
         pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema =
@@ -7420,13 +8440,14 @@ pub mod promised_answer {
         #[derive(Copy, Clone)]
         pub struct Owned(());
         impl ::capnp::introspect::Introspect for Owned {
-            fn introspect() -> ::capnp::introspect::Type {
+            fn introspect() -> ::capnp::introspect::Type<'static> {
                 ::capnp::introspect::TypeVariant::Struct(
                     ::capnp::introspect::RawBrandedStructSchema {
                         generic: &_private::RAW_SCHEMA,
                         field_types: _private::get_field_types,
                         annotation_types: _private::get_annotation_types,
-                    },
+                    }
+                    .into(),
                 )
                 .into()
             }
@@ -7706,7 +8727,7 @@ pub mod promised_answer {
                 ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
                 ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
             ];
-            pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+            pub fn get_field_types(index: u16) -> ::capnp::introspect::Type<'static> {
                 match index {
                     0 => <() as ::capnp::introspect::Introspect>::introspect(),
                     1 => <u16 as ::capnp::introspect::Introspect>::introspect(),
@@ -7716,7 +8737,7 @@ pub mod promised_answer {
             pub fn get_annotation_types(
                 child_index: Option<u16>,
                 index: u32,
-            ) -> ::capnp::introspect::Type {
+            ) -> ::capnp::introspect::Type<'static> {
                 panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
             }
             pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema =
@@ -7744,12 +8765,15 @@ pub mod third_party_cap_descriptor {
     #[derive(Copy, Clone)]
     pub struct Owned(());
     impl ::capnp::introspect::Introspect for Owned {
-        fn introspect() -> ::capnp::introspect::Type {
-            ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+        fn introspect() -> ::capnp::introspect::Type<'static> {
+            ::capnp::introspect::TypeVariant::Struct(
+                ::capnp::introspect::RawBrandedStructSchema {
+                    generic: &_private::RAW_SCHEMA,
+                    field_types: _private::get_field_types,
+                    annotation_types: _private::get_annotation_types,
+                }
+                .into(),
+            )
             .into()
         }
     }
@@ -7950,6 +8974,17 @@ pub mod third_party_cap_descriptor {
             result
         }
         #[inline]
+        pub fn disown_id(&mut self) -> ::capnp::orphan::Orphan<::capnp::any_pointer::Owned> {
+            ::capnp::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
+        pub fn adopt_id(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<::capnp::any_pointer::Owned>,
+        ) -> ::capnp::Result<()> {
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
         pub fn has_id(&self) -> bool {
             !self.builder.is_pointer_field_null(0)
         }
@@ -8030,7 +9065,7 @@ pub mod third_party_cap_descriptor {
             ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
             ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
         ];
-        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type<'static> {
             match index {
                 0 => <::capnp::any_pointer::Owned as ::capnp::introspect::Introspect>::introspect(),
                 1 => <u32 as ::capnp::introspect::Introspect>::introspect(),
@@ -8040,7 +9075,7 @@ pub mod third_party_cap_descriptor {
         pub fn get_annotation_types(
             child_index: Option<u16>,
             index: u32,
-        ) -> ::capnp::introspect::Type {
+        ) -> ::capnp::introspect::Type<'static> {
             panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
         }
         pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema =
@@ -8061,12 +9096,15 @@ pub mod exception {
     #[derive(Copy, Clone)]
     pub struct Owned(());
     impl ::capnp::introspect::Introspect for Owned {
-        fn introspect() -> ::capnp::introspect::Type {
-            ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+        fn introspect() -> ::capnp::introspect::Type<'static> {
+            ::capnp::introspect::TypeVariant::Struct(
+                ::capnp::introspect::RawBrandedStructSchema {
+                    generic: &_private::RAW_SCHEMA,
+                    field_types: _private::get_field_types,
+                    annotation_types: _private::get_annotation_types,
+                }
+                .into(),
+            )
             .into()
         }
     }
@@ -8162,6 +9200,7 @@ pub mod exception {
                 &self.reader.get_pointer_field(0),
                 ::core::option::Option::None,
             )
+            .map_err(|e| e.with_field(_private::TYPE_ID, "reason"))
         }
         #[inline]
         pub fn has_reason(&self) -> bool {
@@ -8188,6 +9227,7 @@ pub mod exception {
                 &self.reader.get_pointer_field(1),
                 ::core::option::Option::None,
             )
+            .map_err(|e| e.with_field(_private::TYPE_ID, "trace"))
         }
         #[inline]
         pub fn has_trace(&self) -> bool {
@@ -8301,10 +9341,36 @@ pub mod exception {
             .unwrap()
         }
         #[inline]
+        pub fn try_set_reason(
+            &mut self,
+            value: impl ::capnp::traits::SetterInput<::capnp::text::Owned>,
+        ) -> ::capnp::Result<()> {
+            ::capnp::traits::SetterInput::set_pointer_builder(
+                self.builder.reborrow().get_pointer_field(0),
+                value,
+                false,
+            )
+        }
+        #[inline]
         pub fn init_reason(self, size: u32) -> ::capnp::text::Builder<'a> {
             self.builder.get_pointer_field(0).init_text(size)
         }
         #[inline]
+        pub fn try_init_reason(self, size: u32) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+            self.builder.get_pointer_field(0).try_init_text(size)
+        }
+        #[inline]
+        pub fn disown_reason(&mut self) -> ::capnp::orphan::Orphan<::capnp::text::Owned> {
+            ::capnp::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
+        pub fn adopt_reason(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<::capnp::text::Owned>,
+        ) -> ::capnp::Result<()> {
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
         pub fn has_reason(&self) -> bool {
             !self.builder.is_pointer_field_null(0)
         }
@@ -8355,10 +9421,36 @@ pub mod exception {
             .unwrap()
         }
         #[inline]
+        pub fn try_set_trace(
+            &mut self,
+            value: impl ::capnp::traits::SetterInput<::capnp::text::Owned>,
+        ) -> ::capnp::Result<()> {
+            ::capnp::traits::SetterInput::set_pointer_builder(
+                self.builder.reborrow().get_pointer_field(1),
+                value,
+                false,
+            )
+        }
+        #[inline]
         pub fn init_trace(self, size: u32) -> ::capnp::text::Builder<'a> {
             self.builder.get_pointer_field(1).init_text(size)
         }
         #[inline]
+        pub fn try_init_trace(self, size: u32) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+            self.builder.get_pointer_field(1).try_init_text(size)
+        }
+        #[inline]
+        pub fn disown_trace(&mut self) -> ::capnp::orphan::Orphan<::capnp::text::Owned> {
+            ::capnp::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(1))
+        }
+        #[inline]
+        pub fn adopt_trace(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<::capnp::text::Owned>,
+        ) -> ::capnp::Result<()> {
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(1))
+        }
+        #[inline]
         pub fn has_trace(&self) -> bool {
             !self.builder.is_pointer_field_null(1)
         }
@@ -8477,7 +9569,7 @@ pub mod exception {
             ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
             ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
         ];
-        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type<'static> {
             match index {
         0 => <::capnp::text::Owned as ::capnp::introspect::Introspect>::introspect(),
         1 => <bool as ::capnp::introspect::Introspect>::introspect(),
@@ -8490,7 +9582,7 @@ pub mod exception {
         pub fn get_annotation_types(
             child_index: Option<u16>,
             index: u32,
-        ) -> ::capnp::introspect::Type {
+        ) -> ::capnp::introspect::Type<'static> {
             panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
         }
         pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema =
@@ -8516,11 +9608,14 @@ pub mod exception {
     }
 
     impl ::capnp::introspect::Introspect for Type {
-        fn introspect() -> ::capnp::introspect::Type {
-            ::capnp::introspect::TypeVariant::Enum(::capnp::introspect::RawEnumSchema {
-                encoded_node: &type_::ENCODED_NODE,
-                annotation_types: type_::get_annotation_types,
-            })
+        fn introspect() -> ::capnp::introspect::Type<'static> {
+            ::capnp::introspect::TypeVariant::Enum(
+                ::capnp::introspect::RawEnumSchema {
+                    encoded_node: &type_::ENCODED_NODE,
+                    annotation_types: type_::get_annotation_types,
+                }
+                .into(),
+            )
             .into()
         }
     }
@@ -8603,7 +9698,7 @@ pub mod exception {
         pub fn get_annotation_types(
             child_index: Option<u16>,
             index: u32,
-        ) -> ::capnp::introspect::Type {
+        ) -> ::capnp::introspect::Type<'static> {
             panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
         }
     }
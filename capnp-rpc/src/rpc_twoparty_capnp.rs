@@ -10,11 +10,14 @@ pub enum Side {
 }
 
 impl ::capnp::introspect::Introspect for Side {
-    fn introspect() -> ::capnp::introspect::Type {
-        ::capnp::introspect::TypeVariant::Enum(::capnp::introspect::RawEnumSchema {
-            encoded_node: &side::ENCODED_NODE,
-            annotation_types: side::get_annotation_types,
-        })
+    fn introspect() -> ::capnp::introspect::Type<'static> {
+        ::capnp::introspect::TypeVariant::Enum(
+            ::capnp::introspect::RawEnumSchema {
+                encoded_node: &side::ENCODED_NODE,
+                annotation_types: side::get_annotation_types,
+            }
+            .into(),
+        )
         .into()
     }
 }
@@ -80,7 +83,10 @@ mod side {
         ::capnp::word(115, 101, 114, 118, 101, 114, 0, 0),
         ::capnp::word(99, 108, 105, 101, 110, 116, 0, 0),
     ];
-    pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> ::capnp::introspect::Type {
+    pub fn get_annotation_types(
+        child_index: Option<u16>,
+        index: u32,
+    ) -> ::capnp::introspect::Type<'static> {
         panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
     }
 }
@@ -89,12 +95,15 @@ pub mod vat_id {
     #[derive(Copy, Clone)]
     pub struct Owned(());
     impl ::capnp::introspect::Introspect for Owned {
-        fn introspect() -> ::capnp::introspect::Type {
-            ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+        fn introspect() -> ::capnp::introspect::Type<'static> {
+            ::capnp::introspect::TypeVariant::Struct(
+                ::capnp::introspect::RawBrandedStructSchema {
+                    generic: &_private::RAW_SCHEMA,
+                    field_types: _private::get_field_types,
+                    annotation_types: _private::get_annotation_types,
+                }
+                .into(),
+            )
             .into()
         }
     }
@@ -337,7 +346,7 @@ pub mod vat_id {
             ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
             ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
         ];
-        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type<'static> {
             match index {
                 0 => {
                     <crate::rpc_twoparty_capnp::Side as ::capnp::introspect::Introspect>::introspect(
@@ -349,7 +358,7 @@ pub mod vat_id {
         pub fn get_annotation_types(
             child_index: Option<u16>,
             index: u32,
-        ) -> ::capnp::introspect::Type {
+        ) -> ::capnp::introspect::Type<'static> {
             panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
         }
         pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema =
@@ -370,12 +379,15 @@ pub mod provision_id {
     #[derive(Copy, Clone)]
     pub struct Owned(());
     impl ::capnp::introspect::Introspect for Owned {
-        fn introspect() -> ::capnp::introspect::Type {
-            ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+        fn introspect() -> ::capnp::introspect::Type<'static> {
+            ::capnp::introspect::TypeVariant::Struct(
+                ::capnp::introspect::RawBrandedStructSchema {
+                    generic: &_private::RAW_SCHEMA,
+                    field_types: _private::get_field_types,
+                    annotation_types: _private::get_annotation_types,
+                }
+                .into(),
+            )
             .into()
         }
     }
@@ -614,7 +626,7 @@ pub mod provision_id {
             ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
             ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
         ];
-        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type<'static> {
             match index {
                 0 => <u32 as ::capnp::introspect::Introspect>::introspect(),
                 _ => panic!("invalid field index {}", index),
@@ -623,7 +635,7 @@ pub mod provision_id {
         pub fn get_annotation_types(
             child_index: Option<u16>,
             index: u32,
-        ) -> ::capnp::introspect::Type {
+        ) -> ::capnp::introspect::Type<'static> {
             panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
         }
         pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema =
@@ -644,12 +656,15 @@ pub mod recipient_id {
     #[derive(Copy, Clone)]
     pub struct Owned(());
     impl ::capnp::introspect::Introspect for Owned {
-        fn introspect() -> ::capnp::introspect::Type {
-            ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+        fn introspect() -> ::capnp::introspect::Type<'static> {
+            ::capnp::introspect::TypeVariant::Struct(
+                ::capnp::introspect::RawBrandedStructSchema {
+                    generic: &_private::RAW_SCHEMA,
+                    field_types: _private::get_field_types,
+                    annotation_types: _private::get_annotation_types,
+                }
+                .into(),
+            )
             .into()
         }
     }
@@ -860,13 +875,13 @@ pub mod recipient_id {
             ::capnp::word(105, 101, 110, 116, 73, 100, 0, 0),
             ::capnp::word(0, 0, 0, 0, 1, 0, 1, 0),
         ];
-        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type<'static> {
             panic!("invalid field index {}", index)
         }
         pub fn get_annotation_types(
             child_index: Option<u16>,
             index: u32,
-        ) -> ::capnp::introspect::Type {
+        ) -> ::capnp::introspect::Type<'static> {
             panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
         }
         pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema =
@@ -887,12 +902,15 @@ pub mod third_party_cap_id {
     #[derive(Copy, Clone)]
     pub struct Owned(());
     impl ::capnp::introspect::Introspect for Owned {
-        fn introspect() -> ::capnp::introspect::Type {
-            ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+        fn introspect() -> ::capnp::introspect::Type<'static> {
+            ::capnp::introspect::TypeVariant::Struct(
+                ::capnp::introspect::RawBrandedStructSchema {
+                    generic: &_private::RAW_SCHEMA,
+                    field_types: _private::get_field_types,
+                    annotation_types: _private::get_annotation_types,
+                }
+                .into(),
+            )
             .into()
         }
     }
@@ -1104,13 +1122,13 @@ pub mod third_party_cap_id {
             ::capnp::word(73, 100, 0, 0, 0, 0, 0, 0),
             ::capnp::word(0, 0, 0, 0, 1, 0, 1, 0),
         ];
-        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type<'static> {
             panic!("invalid field index {}", index)
         }
         pub fn get_annotation_types(
             child_index: Option<u16>,
             index: u32,
-        ) -> ::capnp::introspect::Type {
+        ) -> ::capnp::introspect::Type<'static> {
             panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
         }
         pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema =
@@ -1131,12 +1149,15 @@ pub mod join_key_part {
     #[derive(Copy, Clone)]
     pub struct Owned(());
     impl ::capnp::introspect::Introspect for Owned {
-        fn introspect() -> ::capnp::introspect::Type {
-            ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+        fn introspect() -> ::capnp::introspect::Type<'static> {
+            ::capnp::introspect::TypeVariant::Struct(
+                ::capnp::introspect::RawBrandedStructSchema {
+                    generic: &_private::RAW_SCHEMA,
+                    field_types: _private::get_field_types,
+                    annotation_types: _private::get_annotation_types,
+                }
+                .into(),
+            )
             .into()
         }
     }
@@ -1430,7 +1451,7 @@ pub mod join_key_part {
             ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
             ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
         ];
-        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type<'static> {
             match index {
                 0 => <u32 as ::capnp::introspect::Introspect>::introspect(),
                 1 => <u16 as ::capnp::introspect::Introspect>::introspect(),
@@ -1441,7 +1462,7 @@ pub mod join_key_part {
         pub fn get_annotation_types(
             child_index: Option<u16>,
             index: u32,
-        ) -> ::capnp::introspect::Type {
+        ) -> ::capnp::introspect::Type<'static> {
             panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
         }
         pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema =
@@ -1462,12 +1483,15 @@ pub mod join_result {
     #[derive(Copy, Clone)]
     pub struct Owned(());
     impl ::capnp::introspect::Introspect for Owned {
-        fn introspect() -> ::capnp::introspect::Type {
-            ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+        fn introspect() -> ::capnp::introspect::Type<'static> {
+            ::capnp::introspect::TypeVariant::Struct(
+                ::capnp::introspect::RawBrandedStructSchema {
+                    generic: &_private::RAW_SCHEMA,
+                    field_types: _private::get_field_types,
+                    annotation_types: _private::get_annotation_types,
+                }
+                .into(),
+            )
             .into()
         }
     }
@@ -1688,6 +1712,17 @@ pub mod join_result {
             result
         }
         #[inline]
+        pub fn disown_cap(&mut self) -> ::capnp::orphan::Orphan<::capnp::any_pointer::Owned> {
+            ::capnp::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
+        pub fn adopt_cap(
+            &mut self,
+            orphan: ::capnp::orphan::Orphan<::capnp::any_pointer::Owned>,
+        ) -> ::capnp::Result<()> {
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
         pub fn has_cap(&self) -> bool {
             !self.builder.is_pointer_field_null(0)
         }
@@ -1775,7 +1810,7 @@ pub mod join_result {
             ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
             ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
         ];
-        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+        pub fn get_field_types(index: u16) -> ::capnp::introspect::Type<'static> {
             match index {
                 0 => <u32 as ::capnp::introspect::Introspect>::introspect(),
                 1 => <bool as ::capnp::introspect::Introspect>::introspect(),
@@ -1786,7 +1821,7 @@ pub mod join_result {
         pub fn get_annotation_types(
             child_index: Option<u16>,
             index: u32,
-        ) -> ::capnp::introspect::Type {
+        ) -> ::capnp::introspect::Type<'static> {
             panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
         }
         pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema =
@@ -1061,3 +1061,163 @@ fn capability_server_set_rpc() {
         Ok(())
     })
 }
+
+#[test]
+fn dynamic_rpc_calls() {
+    use capnp::dynamic_capability;
+    rpc_top_level(|_spawner, client| async move {
+        let client = dynamic_capability::Client::from_typed(client)?;
+        let response = client.new_request_named("testInterface")?.send();
+        let cap = response.pipeline.get_named("cap")?.as_client()?;
+        assert!(cap
+            .get_schema()
+            .get_proto()
+            .get_display_name()?
+            .to_str()?
+            .ends_with("TestInterface"));
+
+        let mut request = cap.new_request_named("foo")?;
+        request.get().set_named("i", 123u32.into())?;
+        request.get().set_named("j", true.into())?;
+        let response = request.send().promise.await?;
+        let x: capnp::text::Reader = response.get()?.get_named("x")?.downcast();
+        assert_eq!(x, "foo");
+
+        assert!(cap.new_request_named("nonexistent").is_err());
+        Ok(())
+    });
+}
+
+/// Collects the nodes of `ty` and of every type reachable from it, including the params and
+/// results of interface methods.
+fn collect_nodes(
+    ty: capnp::introspect::Type<'static>,
+    seen: &mut std::collections::HashSet<u64>,
+    nodes: &mut Vec<capnp::schema_capnp::node::Reader<'static>>,
+) {
+    use capnp::introspect::TypeVariant;
+    match ty.which() {
+        TypeVariant::Struct(st) => collect_struct_nodes(st.into(), seen, nodes),
+        TypeVariant::Enum(en) => {
+            let schema: capnp::schema::EnumSchema = en.into();
+            if seen.insert(schema.get_proto().get_id()) {
+                nodes.push(schema.get_proto());
+            }
+        }
+        TypeVariant::Capability(iface) => collect_interface_nodes(iface.into(), seen, nodes),
+        TypeVariant::List(element) => collect_nodes(element, seen, nodes),
+        _ => (),
+    }
+}
+
+fn collect_struct_nodes(
+    schema: capnp::schema::StructSchema<'static>,
+    seen: &mut std::collections::HashSet<u64>,
+    nodes: &mut Vec<capnp::schema_capnp::node::Reader<'static>>,
+) {
+    if seen.insert(schema.get_proto().get_id()) {
+        nodes.push(schema.get_proto());
+        for field in schema.get_fields().unwrap() {
+            collect_nodes(field.get_type(), seen, nodes);
+        }
+    }
+}
+
+fn collect_interface_nodes(
+    schema: capnp::schema::InterfaceSchema<'static>,
+    seen: &mut std::collections::HashSet<u64>,
+    nodes: &mut Vec<capnp::schema_capnp::node::Reader<'static>>,
+) {
+    if seen.insert(schema.get_proto().get_id()) {
+        nodes.push(schema.get_proto());
+        for superclass in schema.get_superclasses().unwrap() {
+            collect_interface_nodes(superclass, seen, nodes);
+        }
+        for method in schema.get_methods().unwrap() {
+            collect_struct_nodes(method.get_param_type(), seen, nodes);
+            collect_struct_nodes(method.get_result_type(), seen, nodes);
+        }
+    }
+}
+
+fn interface_id(ty: capnp::introspect::Type<'static>) -> u64 {
+    let capnp::introspect::TypeVariant::Capability(iface) = ty.which() else {
+        panic!("expected an interface type");
+    };
+    capnp::schema::InterfaceSchema::from(iface)
+        .get_proto()
+        .get_id()
+}
+
+#[test]
+fn dynamic_rpc_calls_with_loaded_schema() {
+    use capnp::introspect::Introspect;
+    use capnp::schema_loader::SchemaLoader;
+    rpc_top_level(|_spawner, client| async move {
+        let mut nodes = Vec::new();
+        collect_nodes(
+            test_capnp::bootstrap::Client::introspect(),
+            &mut std::collections::HashSet::new(),
+            &mut nodes,
+        );
+        let loader = SchemaLoader::new();
+        loader.load_all(nodes)?;
+        let bootstrap_id = interface_id(test_capnp::bootstrap::Client::introspect());
+
+        // Schemas from the loader only live as long as the loader.
+        let client = capnp::dynamic_capability::Client::new(
+            client.into_client_hook(),
+            loader.get_interface(bootstrap_id)?,
+        );
+        let response = client.new_request_named("testInterface")?.send();
+        let cap = response.pipeline.get_named("cap")?.as_client()?;
+        assert_eq!(
+            cap.get_schema().get_proto().get_id(),
+            interface_id(test_capnp::test_interface::Client::introspect())
+        );
+
+        let mut request = cap.new_request_named("foo")?;
+        request.get().set_named("i", 123u32.into())?;
+        request.get().set_named("j", true.into())?;
+        let response = request.send().promise.await?;
+        let x: capnp::text::Reader = response.get()?.get_named("x")?.downcast();
+        assert_eq!(x, "foo");
+        Ok(())
+    });
+}
+
+struct DynamicTestInterface;
+
+impl<'s> capnp::dynamic_capability::Server<'s> for DynamicTestInterface {
+    fn call(
+        &mut self,
+        method: capnp::schema::Method<'s>,
+        params: capnp::dynamic_capability::Params<'s>,
+        mut results: capnp::dynamic_capability::Results<'s>,
+    ) -> Promise<(), Error> {
+        if capnp_rpc::pry!(method.get_proto().get_name()) != "foo" {
+            return Promise::err(Error::unimplemented("not foo".to_string()));
+        }
+        let i: u32 = capnp_rpc::pry!(capnp_rpc::pry!(params.get()).get_named("i")).downcast();
+        capnp_rpc::pry!(capnp_rpc::pry!(results.get()).set_named("x", format!("foo{i}")[..].into()));
+        Promise::ok(())
+    }
+}
+
+#[test]
+fn dynamic_server() {
+    use capnp::introspect::{Introspect, TypeVariant};
+    let TypeVariant::Capability(schema) = test_capnp::test_interface::Client::introspect().which()
+    else {
+        panic!("expected an interface type");
+    };
+    let client: test_capnp::test_interface::Client =
+        capnp_rpc::new_dynamic_client(schema.into(), DynamicTestInterface).downcast();
+
+    let mut request = client.foo_request();
+    request.get().set_i(7);
+    let response = futures::executor::block_on(request.send().promise).unwrap();
+    assert_eq!(response.get().unwrap().get_x().unwrap(), "foo7");
+
+    assert!(futures::executor::block_on(client.bar_request().send().promise).is_err());
+}
@@ -38,7 +38,7 @@ impl crate::traits::Owned for Owned {
 }
 
 impl crate::introspect::Introspect for Owned {
-    fn introspect() -> crate::introspect::Type {
+    fn introspect() -> crate::introspect::Type<'static> {
         crate::introspect::TypeVariant::AnyPointer.into()
     }
 }
@@ -77,6 +77,17 @@ impl<'a> Reader<'a> {
         Ok(FromClientHook::new(self.reader.get_capability()?))
     }
 
+    /// Interprets the target as a struct with the given schema.
+    pub fn get_as_dynamic(
+        &self,
+        schema: crate::schema::StructSchema<'a>,
+    ) -> Result<crate::dynamic_struct::Reader<'a>> {
+        Ok(crate::dynamic_struct::Reader::new(
+            self.reader.get_struct(None)?,
+            schema,
+        ))
+    }
+
     //# Used by RPC system to implement pipelining. Applications
     //# generally shouldn't use this directly.
     #[cfg(feature = "alloc")]
@@ -166,6 +177,42 @@ impl<'a> Builder<'a> {
         FromPointerBuilder::init_pointer(self.builder, size)
     }
 
+    /// Like `init_as()`, but returns an error instead of panicking if the message's
+    /// allocator cannot provide the space.
+    pub fn try_init_as<T: FromPointerBuilder<'a>>(self) -> Result<T> {
+        FromPointerBuilder::try_init_pointer(self.builder, 0)
+    }
+
+    /// Like `initn_as()`, but returns an error instead of panicking if the message's
+    /// allocator cannot provide the space.
+    pub fn try_initn_as<T: FromPointerBuilder<'a>>(self, size: u32) -> Result<T> {
+        FromPointerBuilder::try_init_pointer(self.builder, size)
+    }
+
+    /// Interprets the target as a struct with the given schema.
+    pub fn get_as_dynamic(
+        self,
+        schema: crate::schema::StructSchema<'a>,
+    ) -> Result<crate::dynamic_struct::Builder<'a>> {
+        let size = crate::dynamic_struct::struct_size_from_schema(schema)?;
+        Ok(crate::dynamic_struct::Builder::new(
+            self.builder.get_struct(size, None)?,
+            schema,
+        ))
+    }
+
+    /// Initializes the target as a struct with the given schema.
+    pub fn init_as_dynamic(
+        self,
+        schema: crate::schema::StructSchema<'a>,
+    ) -> Result<crate::dynamic_struct::Builder<'a>> {
+        let size = crate::dynamic_struct::struct_size_from_schema(schema)?;
+        Ok(crate::dynamic_struct::Builder::new(
+            self.builder.try_init_struct(size)?,
+            schema,
+        ))
+    }
+
     pub fn set_as<T: crate::traits::Owned>(&mut self, value: impl SetterInput<T>) -> Result<()> {
         SetterInput::set_pointer_builder(self.builder.reborrow(), value, false)
     }
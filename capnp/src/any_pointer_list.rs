@@ -36,7 +36,7 @@ impl crate::traits::Owned for Owned {
 }
 
 impl crate::introspect::Introspect for Owned {
-    fn introspect() -> crate::introspect::Type {
+    fn introspect() -> crate::introspect::Type<'static> {
         crate::introspect::Type::list_of(crate::introspect::TypeVariant::AnyPointer.into())
     }
 }
@@ -158,6 +158,11 @@ impl<'a> FromPointerBuilder<'a> for Builder<'a> {
             builder: builder.init_list(Pointer, size),
         }
     }
+    fn try_init_pointer(builder: PointerBuilder<'a>, size: u32) -> Result<Builder<'a>> {
+        Ok(Builder {
+            builder: builder.try_init_list(Pointer, size)?,
+        })
+    }
 
     fn get_from_pointer(
         builder: PointerBuilder<'a>,
@@ -0,0 +1,118 @@
+//! Equality and hashing of structs by their [canonical
+//! form](https://capnproto.org/encoding.html#canonicalization).
+//!
+//! Two struct readers are canonically equal when they would canonicalize to the same
+//! bytes. This makes the comparison independent of how each message happens to be laid
+//! out: segment boundaries, far pointers, padding, and data or pointer sections that are
+//! longer than necessary all disappear. Scalar fields that are explicitly set to their
+//! default compare equal to fields that are absent because the struct was written with an
+//! older schema. Pointer fields are compared by content, so a null pointer is not equal
+//! to a pointer to an empty struct or list.
+//!
+//! [`CanonicalEq`] is implemented for generated struct readers and for
+//! [`dynamic_struct::Reader`](crate::dynamic_struct::Reader). To use readers as map keys,
+//! wrap them in [`Canonical`], which canonicalizes once up front and then implements
+//! [`Eq`] and [`Hash`].
+//!
+//! Only content is compared, not type. Readers of two different struct types can be
+//! canonically equal, which matters when comparing dynamic readers.
+
+use alloc::vec::Vec;
+use core::hash::{Hash, Hasher};
+
+use crate::message::{self, HeapAllocator};
+use crate::private::layout::{PointerBuilder, StructReader};
+use crate::traits::{IntoInternalStructReader, SetterInput};
+use crate::{any_pointer, Result, Word};
+
+/// Comparison and hashing by canonical form. See the [module docs](self).
+pub trait CanonicalEq<'a>: IntoInternalStructReader<'a> + Copy {
+    /// Returns the canonical encoding of this struct, as a single-segment message.
+    fn canonical_words(self) -> Result<Vec<Word>> {
+        canonical_words(self.into_internal_struct_reader())
+    }
+
+    /// Checks whether `self` and `other` have the same canonical form.
+    fn canonical_eq(self, other: Self) -> Result<bool> {
+        Ok(self.canonical_words()? == other.canonical_words()?)
+    }
+
+    /// Feeds the canonical form of `self` into `state`. Readers that are
+    /// [canonically equal](CanonicalEq::canonical_eq) hash the same.
+    fn canonical_hash<H: Hasher>(self, state: &mut H) -> Result<()> {
+        Word::words_to_bytes(&self.canonical_words()?).hash(state);
+        Ok(())
+    }
+}
+
+impl<'a, T: IntoInternalStructReader<'a> + Copy> CanonicalEq<'a> for T {}
+
+/// A struct reader that implements [`Eq`] and [`Hash`] by canonical form.
+///
+/// The canonical form is computed once, when the `Canonical` is created,
+/// so that comparisons and hashing afterwards cannot fail.
+#[derive(Clone)]
+pub struct Canonical<T> {
+    value: T,
+    words: Vec<Word>,
+}
+
+impl<'a, T: CanonicalEq<'a>> Canonical<T> {
+    pub fn new(value: T) -> Result<Self> {
+        Ok(Self {
+            words: value.canonical_words()?,
+            value,
+        })
+    }
+
+    pub fn get(&self) -> T {
+        self.value
+    }
+
+    /// The canonical encoding of the value.
+    pub fn as_words(&self) -> &[Word] {
+        &self.words
+    }
+}
+
+impl<T> PartialEq for Canonical<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.words == other.words
+    }
+}
+
+impl<T> Eq for Canonical<T> {}
+
+impl<T> Hash for Canonical<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Word::words_to_bytes(&self.words).hash(state)
+    }
+}
+
+impl<T: core::fmt::Debug> core::fmt::Debug for Canonical<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+struct CanonicalInput<'a>(StructReader<'a>);
+
+impl<'a> SetterInput<any_pointer::Owned> for CanonicalInput<'a> {
+    fn set_pointer_builder(
+        mut pointer: PointerBuilder<'_>,
+        value: Self,
+        canonicalize: bool,
+    ) -> Result<()> {
+        pointer.set_struct(&value.0, canonicalize)
+    }
+}
+
+fn canonical_words(reader: StructReader) -> Result<Vec<Word>> {
+    let size = reader.total_size()?.word_count + 1;
+    let mut message = message::Builder::new(HeapAllocator::new().first_segment_words(size as u32));
+    message.set_root_canonical::<any_pointer::Owned>(CanonicalInput(reader))?;
+    let output = message.get_segments_for_output()[0];
+    let mut words = Word::allocate_zeroed_vec(output.len() / crate::private::units::BYTES_PER_WORD);
+    Word::words_to_bytes_mut(&mut words).copy_from_slice(output);
+    Ok(words)
+}
@@ -290,6 +290,17 @@ pub trait FromClientHook {
     {
         FromClientHook::new(self.into_client_hook())
     }
+
+    /// Describes the interface that this client type implements, e.g. for the elements
+    /// of a `capability_list::Owned<Self>`. Generated clients return their interface.
+    /// Other implementations are described as an untyped capability,
+    /// see [`Type::untyped_capability()`](crate::introspect::Type::untyped_capability).
+    fn introspect_interface() -> crate::introspect::Type<'static>
+    where
+        Self: Sized,
+    {
+        crate::introspect::Type::untyped_capability()
+    }
 }
 
 /// An untyped client.
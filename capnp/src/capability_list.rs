@@ -41,8 +41,8 @@ impl<T> crate::introspect::Introspect for Owned<T>
 where
     T: FromClientHook,
 {
-    fn introspect() -> crate::introspect::Type {
-        crate::introspect::Type::list_of(crate::introspect::TypeVariant::Capability.into())
+    fn introspect() -> crate::introspect::Type<'static> {
+        crate::introspect::Type::list_of(T::introspect_interface())
     }
 }
 
@@ -125,7 +125,10 @@ where
     pub fn get(self, index: u32) -> Result<T> {
         assert!(index < self.len());
         Ok(FromClientHook::new(
-            self.reader.get_pointer_element(index).get_capability()?,
+            self.reader
+                .get_pointer_element(index)
+                .get_capability()
+                .map_err(|e| e.with_index(index))?,
         ))
     }
 
@@ -137,7 +140,8 @@ where
                 self.reader
                     .get_pointer_element(index)
                     .get_capability()
-                    .map(FromClientHook::new),
+                    .map(FromClientHook::new)
+                    .map_err(|e| e.with_index(index)),
             )
         } else {
             None
@@ -212,6 +216,12 @@ where
             builder: builder.init_list(Pointer, size),
         }
     }
+    fn try_init_pointer(builder: PointerBuilder<'a>, size: u32) -> Result<Builder<'a, T>> {
+        Ok(Builder {
+            marker: PhantomData,
+            builder: builder.try_init_list(Pointer, size)?,
+        })
+    }
     fn get_from_pointer(
         builder: PointerBuilder<'a>,
         default: Option<&'a [crate::Word]>,
@@ -282,7 +292,7 @@ impl<'a, T: FromClientHook> From<Reader<'a, T>> for crate::dynamic_value::Reader
     fn from(t: Reader<'a, T>) -> crate::dynamic_value::Reader<'a> {
         crate::dynamic_value::Reader::List(crate::dynamic_list::Reader::new(
             t.reader,
-            crate::introspect::TypeVariant::Capability.into(),
+            T::introspect_interface(),
         ))
     }
 }
@@ -291,7 +301,7 @@ impl<'a, T: FromClientHook> From<Builder<'a, T>> for crate::dynamic_value::Build
     fn from(t: Builder<'a, T>) -> crate::dynamic_value::Builder<'a> {
         crate::dynamic_value::Builder::List(crate::dynamic_list::Builder::new(
             t.builder,
-            crate::introspect::TypeVariant::Capability.into(),
+            T::introspect_interface(),
         ))
     }
 }
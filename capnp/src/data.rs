@@ -22,7 +22,7 @@
 //! Sequence of bytes.
 
 use crate::private::layout::{PointerBuilder, PointerReader};
-use crate::Result;
+use crate::{Error, ErrorKind, Result};
 
 #[derive(Copy, Clone)]
 pub struct Owned(());
@@ -33,7 +33,7 @@ impl crate::traits::Owned for Owned {
 }
 
 impl crate::introspect::Introspect for Owned {
-    fn introspect() -> crate::introspect::Type {
+    fn introspect() -> crate::introspect::Type<'static> {
         crate::introspect::TypeVariant::Data.into()
     }
 }
@@ -63,6 +63,9 @@ impl<'a> crate::traits::FromPointerBuilder<'a> for Builder<'a> {
     fn init_pointer(builder: PointerBuilder<'a>, size: u32) -> Builder<'a> {
         builder.init_data(size)
     }
+    fn try_init_pointer(builder: PointerBuilder<'a>, size: u32) -> Result<Builder<'a>> {
+        builder.try_init_data(size)
+    }
     fn get_from_pointer(
         builder: PointerBuilder<'a>,
         default: Option<&'a [crate::Word]>,
@@ -71,14 +74,155 @@ impl<'a> crate::traits::FromPointerBuilder<'a> for Builder<'a> {
     }
 }
 
+/// A data builder that can grow after it has been created, unlike [`Builder`], whose length
+/// is fixed when it is initialized. Space is managed as for
+/// [`text::GrowableBuilder`](crate::text::GrowableBuilder).
+///
+/// A `GrowableBuilder` for a field is usually obtained from
+/// [`Orphanage::get_growable_data()`](crate::orphan::Orphanage::get_growable_data) and the
+/// orphan then adopted into the field.
+pub struct GrowableBuilder<'a> {
+    pointer: PointerBuilder<'a>,
+
+    /// Start of the data.
+    ptr: *mut u8,
+
+    len: u32,
+
+    /// Words allocated for the data.
+    capacity: u32,
+}
+
+impl<'a> GrowableBuilder<'a> {
+    /// Creates a builder for the data that `pointer` points to, setting it to empty data
+    /// if it is null.
+    pub fn new(mut pointer: PointerBuilder<'a>) -> Result<Self> {
+        let len = pointer.reborrow().get_data(None)?.len() as u32;
+        let mut capacity = 0;
+        let ptr = pointer.resize_byte_list(len, &mut capacity)?;
+        Ok(Self {
+            pointer,
+            ptr,
+            len,
+            capacity,
+        })
+    }
+
+    fn set_len(&mut self, len: u32) -> Result<()> {
+        self.ptr = self.pointer.resize_byte_list(len, &mut self.capacity)?;
+        self.len = len;
+        Ok(())
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The number of bytes the data can hold without being moved.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.capacity as usize * crate::private::units::BYTES_PER_WORD
+    }
+
+    /// The length after appending `additional` bytes, or an error if the data would not fit
+    /// in a list, which holds fewer than 2^29 bytes.
+    fn grown_len(&self, additional: usize) -> Result<u32> {
+        u32::try_from(additional)
+            .ok()
+            .and_then(|additional| self.len.checked_add(additional))
+            .filter(|len| *len < 1 << 29)
+            .ok_or_else(|| {
+                let mut error = Error::from_kind(ErrorKind::Failed);
+                write!(
+                    error,
+                    "data cannot grow by {additional} bytes past 2^29 bytes"
+                );
+                error
+            })
+    }
+
+    /// Makes sure that at least `additional` more bytes can be appended without moving the data.
+    pub fn reserve(&mut self, additional: usize) -> Result<()> {
+        let len = self.len;
+        self.set_len(self.grown_len(additional)?)?;
+        self.set_len(len)
+    }
+
+    /// Appends `bytes` to the end of the data.
+    pub fn extend_from_slice(&mut self, bytes: &[u8]) -> Result<()> {
+        let start = self.len as usize;
+        self.set_len(self.grown_len(bytes.len())?)?;
+        self.as_bytes_mut()[start..].copy_from_slice(bytes);
+        Ok(())
+    }
+
+    /// Appends a single byte to the end of the data.
+    pub fn push(&mut self, byte: u8) -> Result<()> {
+        self.extend_from_slice(&[byte])
+    }
+
+    /// Shortens the data to `len` bytes, zeroing the rest. Does nothing if the data is
+    /// already shorter. The allocated space stays the same.
+    pub fn truncate(&mut self, len: usize) -> Result<()> {
+        if len < self.len() {
+            self.set_len(len as u32)?;
+        }
+        Ok(())
+    }
+
+    /// Sets the data to empty, zeroing its bytes.
+    pub fn clear(&mut self) -> Result<()> {
+        self.truncate(0)
+    }
+
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        unsafe { core::slice::from_raw_parts(self.ptr, self.len as usize) }
+    }
+
+    #[inline]
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        unsafe { core::slice::from_raw_parts_mut(self.ptr, self.len as usize) }
+    }
+
+    #[inline]
+    pub fn into_reader(self) -> Reader<'a> {
+        unsafe { core::slice::from_raw_parts(self.ptr, self.len as usize) }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> std::io::Write for GrowableBuilder<'a> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.extend_from_slice(buf)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a> core::fmt::Debug for GrowableBuilder<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.as_bytes().fmt(f)
+    }
+}
+
 impl<'a> crate::traits::SetterInput<Owned> for Reader<'a> {
     fn set_pointer_builder<'b>(
         mut pointer: PointerBuilder<'b>,
         value: Reader<'a>,
         _canonicalize: bool,
     ) -> Result<()> {
-        pointer.set_data(value);
-        Ok(())
+        pointer.try_set_data(value)
     }
 }
 
@@ -34,7 +34,7 @@ impl crate::traits::Owned for Owned {
 }
 
 impl crate::introspect::Introspect for Owned {
-    fn introspect() -> crate::introspect::Type {
+    fn introspect() -> crate::introspect::Type<'static> {
         crate::introspect::Type::list_of(crate::introspect::TypeVariant::Data.into())
     }
 }
@@ -91,14 +91,22 @@ impl<'a> Reader<'a> {
     /// greater than or equal to `len()`.
     pub fn get(self, index: u32) -> Result<crate::data::Reader<'a>> {
         assert!(index < self.len());
-        self.reader.get_pointer_element(index).get_data(None)
+        self.reader
+            .get_pointer_element(index)
+            .get_data(None)
+            .map_err(|e| e.with_index(index))
     }
 
     /// Gets the `data::Reader` at position `index`. Returns `None` if `index`
     /// is greater than or equal to `len()`.
     pub fn try_get(self, index: u32) -> Option<Result<crate::data::Reader<'a>>> {
         if index < self.len() {
-            Some(self.reader.get_pointer_element(index).get_data(None))
+            Some(
+                self.reader
+                    .get_pointer_element(index)
+                    .get_data(None)
+                    .map_err(|e| e.with_index(index)),
+            )
         } else {
             None
         }
@@ -155,6 +163,11 @@ impl<'a> FromPointerBuilder<'a> for Builder<'a> {
             builder: builder.init_list(Pointer, size),
         }
     }
+    fn try_init_pointer(builder: PointerBuilder<'a>, size: u32) -> Result<Builder<'a>> {
+        Ok(Builder {
+            builder: builder.try_init_list(Pointer, size)?,
+        })
+    }
 
     fn get_from_pointer(
         builder: PointerBuilder<'a>,
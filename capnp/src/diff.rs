@@ -0,0 +1,911 @@
+//! Structural differences between messages, driven by dynamic reflection.
+//!
+//! [`diff()`] compares two structs of the same type and produces a [`Patch`]: a list of
+//! [`Change`]s, each addressed by the [`Path`] of the field or list element it affects.
+//! [`apply()`] replays a patch on a builder, so that a builder holding the first struct
+//! ends up equal to the second one.
+//!
+//! Changes are meant to be applied in order. Each one assumes the changes before it have
+//! already been made, so list indices refer to positions in the list as it is at that point.
+//! A struct, list, or union member that comes into existence starts out with default
+//! values and is filled in by the changes that follow.
+//!
+//! `AnyPointer` and capability fields cannot be compared. Setting one to null is
+//! supported, but any other change results in an error.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::introspect::{Type, TypeVariant};
+use crate::private::layout;
+use crate::schema::{Field, StructSchema};
+use crate::schema_capnp::field;
+use crate::{any_pointer, dynamic_list, dynamic_struct, dynamic_value, message};
+use crate::{Error, ErrorKind, Result};
+
+/// Largest number of element pairs to consider when matching up the elements of two lists.
+/// Beyond this, differing elements are compared position by position.
+const MAX_LIST_MATCHING_PAIRS: usize = 1 << 20;
+
+/// One step of a [`Path`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PathElement {
+    /// A field of a struct or group, by name.
+    Field(String),
+
+    /// An element of a list.
+    Index(u32),
+}
+
+/// The location of a field or list element, starting from the root struct.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Path(pub Vec<PathElement>);
+
+impl fmt::Display for Path {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, element) in self.0.iter().enumerate() {
+            match element {
+                PathElement::Field(name) if i == 0 => write!(f, "{name}")?,
+                PathElement::Field(name) => write!(f, ".{name}")?,
+                PathElement::Index(index) => write!(f, "[{index}]")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A value that fits in a single [`Change`]: anything but a struct or a list.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Void,
+    Bool(bool),
+    Int8(i8),
+    Int16(i16),
+    Int32(i32),
+    Int64(i64),
+    UInt8(u8),
+    UInt16(u16),
+    UInt32(u32),
+    UInt64(u64),
+    Float32(f32),
+    Float64(f64),
+
+    /// The numeric value of an enumerant.
+    Enum(u16),
+    Text(String),
+    Data(Vec<u8>),
+}
+
+impl Value {
+    fn from_reader(value: dynamic_value::Reader) -> Result<Self> {
+        Ok(match value {
+            dynamic_value::Reader::Void => Self::Void,
+            dynamic_value::Reader::Bool(b) => Self::Bool(b),
+            dynamic_value::Reader::Int8(x) => Self::Int8(x),
+            dynamic_value::Reader::Int16(x) => Self::Int16(x),
+            dynamic_value::Reader::Int32(x) => Self::Int32(x),
+            dynamic_value::Reader::Int64(x) => Self::Int64(x),
+            dynamic_value::Reader::UInt8(x) => Self::UInt8(x),
+            dynamic_value::Reader::UInt16(x) => Self::UInt16(x),
+            dynamic_value::Reader::UInt32(x) => Self::UInt32(x),
+            dynamic_value::Reader::UInt64(x) => Self::UInt64(x),
+            dynamic_value::Reader::Float32(x) => Self::Float32(x),
+            dynamic_value::Reader::Float64(x) => Self::Float64(x),
+            dynamic_value::Reader::Enum(e) => Self::Enum(e.get_value()),
+            dynamic_value::Reader::Text(t) => Self::Text(t.to_string()?),
+            dynamic_value::Reader::Data(d) => Self::Data(d.to_vec()),
+            _ => return Err(Error::from_kind(ErrorKind::TypeMismatch)),
+        })
+    }
+
+    /// Views the value as a value of type `ty`.
+    fn as_reader<'a>(&'a self, ty: Type<'a>) -> Result<dynamic_value::Reader<'a>> {
+        Ok(match (self, ty.which()) {
+            (Self::Enum(value), TypeVariant::Enum(schema)) => {
+                dynamic_value::Enum::new(*value, schema.into()).into()
+            }
+            (Self::Enum(_), _) => return Err(Error::from_kind(ErrorKind::TypeMismatch)),
+            (Self::Void, _) => dynamic_value::Reader::Void,
+            (Self::Bool(b), _) => (*b).into(),
+            (Self::Int8(x), _) => (*x).into(),
+            (Self::Int16(x), _) => (*x).into(),
+            (Self::Int32(x), _) => (*x).into(),
+            (Self::Int64(x), _) => (*x).into(),
+            (Self::UInt8(x), _) => (*x).into(),
+            (Self::UInt16(x), _) => (*x).into(),
+            (Self::UInt32(x), _) => (*x).into(),
+            (Self::UInt64(x), _) => (*x).into(),
+            (Self::Float32(x), _) => (*x).into(),
+            (Self::Float64(x), _) => (*x).into(),
+            (Self::Text(t), _) => dynamic_value::Reader::Text(t.as_str().into()),
+            (Self::Data(d), _) => dynamic_value::Reader::Data(&d[..]),
+        })
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Void => write!(f, "void"),
+            Self::Bool(b) => write!(f, "{b}"),
+            Self::Int8(x) => write!(f, "{x}"),
+            Self::Int16(x) => write!(f, "{x}"),
+            Self::Int32(x) => write!(f, "{x}"),
+            Self::Int64(x) => write!(f, "{x}"),
+            Self::UInt8(x) => write!(f, "{x}"),
+            Self::UInt16(x) => write!(f, "{x}"),
+            Self::UInt32(x) => write!(f, "{x}"),
+            Self::UInt64(x) => write!(f, "{x}"),
+            Self::Float32(x) => write!(f, "{x}"),
+            Self::Float64(x) => write!(f, "{x}"),
+            Self::Enum(x) => write!(f, "enumerant {x}"),
+            Self::Text(t) => write!(f, "{t:?}"),
+            Self::Data(d) => write!(f, "{d:?}"),
+        }
+    }
+}
+
+/// What a [`Change`] does.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ChangeKind {
+    /// The union member at the path became the active one, with its default value.
+    /// `from` is the name of the member that was active before, if it is known.
+    SwitchUnion { from: Option<String> },
+
+    /// A non-struct, non-list value was set.
+    Set(Value),
+
+    /// A pointer was set to null.
+    Clear,
+
+    /// A null struct pointer was set to a struct with default field values.
+    InitStruct,
+
+    /// A null list pointer was set to a list with this many elements, all with default values.
+    InitList(u32),
+
+    /// An element with a default value was inserted into a list, at the index at the end of the path.
+    Insert,
+
+    /// The list element at the path was removed.
+    Remove,
+}
+
+/// A single change made by a [`Patch`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Change {
+    pub path: Path,
+    pub kind: ChangeKind,
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let path = &self.path;
+        match &self.kind {
+            ChangeKind::SwitchUnion { from: Some(from) } => {
+                write!(f, "switch union to {path} (from {from})")
+            }
+            ChangeKind::SwitchUnion { from: None } => write!(f, "switch union to {path}"),
+            ChangeKind::Set(value) => write!(f, "set {path} = {value}"),
+            ChangeKind::Clear => write!(f, "clear {path}"),
+            ChangeKind::InitStruct => write!(f, "init {path}"),
+            ChangeKind::InitList(len) => write!(f, "init {path} with {len} elements"),
+            ChangeKind::Insert => write!(f, "insert {path}"),
+            ChangeKind::Remove => write!(f, "remove {path}"),
+        }
+    }
+}
+
+/// The changes that turn one struct into another. See the [module docs](self).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Patch {
+    pub changes: Vec<Change>,
+}
+
+impl Patch {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+/// Writes one change per line.
+impl fmt::Display for Patch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{change}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Computes the changes that turn `a` into `b`. Both must be structs of the same type.
+pub fn diff<'a, 'b>(
+    a: impl Into<dynamic_value::Reader<'a>>,
+    b: impl Into<dynamic_value::Reader<'b>>,
+) -> Result<Patch> {
+    let (dynamic_value::Reader::Struct(a), dynamic_value::Reader::Struct(b)) = (a.into(), b.into())
+    else {
+        return Err(Error::from_kind(ErrorKind::NotAStruct));
+    };
+    if a.get_schema().raw != b.get_schema().raw {
+        return Err(Error::from_kind(ErrorKind::TypeMismatch));
+    }
+    let mut differ = Differ::default();
+    differ.diff_struct(a, b)?;
+    Ok(Patch {
+        changes: differ.changes,
+    })
+}
+
+/// Applies the changes of `patch`, in order, to the struct in `builder`.
+///
+/// Fails if a change does not fit the struct, for example because its path names a field
+/// that does not exist or a list index that is out of bounds. Changes before the failing
+/// one remain applied.
+pub fn apply<'a>(patch: &Patch, builder: impl Into<dynamic_value::Builder<'a>>) -> Result<()> {
+    let dynamic_value::Builder::Struct(builder) = builder.into() else {
+        return Err(Error::from_kind(ErrorKind::NotAStruct));
+    };
+    apply_changes(builder, &patch.changes)
+}
+
+// ----------------------------------------------------------------------------
+// Diffing
+
+#[derive(Default)]
+struct Differ {
+    path: Vec<PathElement>,
+    changes: Vec<Change>,
+}
+
+impl Differ {
+    fn emit(&mut self, kind: ChangeKind) {
+        self.changes.push(Change {
+            path: Path(self.path.clone()),
+            kind,
+        });
+    }
+
+    fn diff_struct(&mut self, a: dynamic_struct::Reader, b: dynamic_struct::Reader) -> Result<()> {
+        let (a_member, b_member) = (a.which()?, b.which()?);
+        for field in b.get_schema().get_fields()? {
+            if field.get_proto().get_discriminant_value() == field::NO_DISCRIMINANT {
+                self.diff_field(field, a, b)?;
+            } else if b_member.map(|f| f.get_index()) != Some(field.get_index()) {
+                continue;
+            } else if a_member.map(|f| f.get_index()) == Some(field.get_index()) {
+                self.diff_field(field, a, b)?;
+            } else {
+                self.path.push(field_name(field)?);
+                let from = match a_member {
+                    Some(f) => Some(f.get_proto().get_name()?.to_string()?),
+                    None => None,
+                };
+                self.emit(ChangeKind::SwitchUnion { from });
+                self.path.pop();
+                self.diff_field(field, default_struct(b.get_schema()), b)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn diff_field(
+        &mut self,
+        field: Field,
+        a: dynamic_struct::Reader,
+        b: dynamic_struct::Reader,
+    ) -> Result<()> {
+        self.path.push(field_name(field)?);
+        match field.get_proto().which()? {
+            field::Group(_) => {
+                let (dynamic_value::Reader::Struct(a), dynamic_value::Reader::Struct(b)) =
+                    (a.get(field)?, b.get(field)?)
+                else {
+                    return Err(Error::from_kind(ErrorKind::NotAStruct));
+                };
+                self.diff_struct(a, b)?;
+            }
+            field::Slot(_) => {
+                // `has()` is false for inactive union members, so only use it for pointers.
+                let ty = field.get_type();
+                let a = if !ty.is_pointer_type() || a.has(field)? {
+                    Some(a.get(field)?)
+                } else {
+                    None
+                };
+                let b = if !ty.is_pointer_type() || b.has(field)? {
+                    Some(b.get(field)?)
+                } else {
+                    None
+                };
+                self.diff_value(ty, a, b)?;
+            }
+        }
+        self.path.pop();
+        Ok(())
+    }
+
+    /// Diffs two values of type `ty`. `None` stands for a null pointer.
+    fn diff_value(
+        &mut self,
+        ty: Type,
+        a: Option<dynamic_value::Reader>,
+        b: Option<dynamic_value::Reader>,
+    ) -> Result<()> {
+        let Some(b) = b else {
+            if a.is_some() {
+                self.emit(ChangeKind::Clear);
+            }
+            return Ok(());
+        };
+        match b {
+            dynamic_value::Reader::Struct(b) => {
+                let a = match a {
+                    Some(dynamic_value::Reader::Struct(a)) => a,
+                    Some(_) => return Err(Error::from_kind(ErrorKind::TypeMismatch)),
+                    None => {
+                        self.emit(ChangeKind::InitStruct);
+                        default_struct(b.get_schema())
+                    }
+                };
+                self.diff_struct(a, b)
+            }
+            dynamic_value::Reader::List(b) => match a {
+                Some(dynamic_value::Reader::List(a)) => self.diff_list(a, b),
+                Some(_) => Err(Error::from_kind(ErrorKind::TypeMismatch)),
+                None => {
+                    self.emit(ChangeKind::InitList(b.len()));
+                    let element_type = b.element_type();
+                    for index in 0..b.len() {
+                        self.path.push(PathElement::Index(index));
+                        self.diff_value(
+                            element_type,
+                            default_element(element_type),
+                            list_element(b, index)?,
+                        )?;
+                        self.path.pop();
+                    }
+                    Ok(())
+                }
+            },
+            dynamic_value::Reader::AnyPointer(_) | dynamic_value::Reader::Capability(_) => {
+                Err(Error::unimplemented(alloc::format!(
+                    "cannot diff {} at {}",
+                    if let TypeVariant::AnyPointer = ty.which() {
+                        "AnyPointer values"
+                    } else {
+                        "capabilities"
+                    },
+                    Path(self.path.clone())
+                )))
+            }
+            b => {
+                if !matches!(a, Some(a) if same_scalar(a, b)?) {
+                    self.emit(ChangeKind::Set(Value::from_reader(b)?));
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn diff_list(&mut self, a: dynamic_list::Reader, b: dynamic_list::Reader) -> Result<()> {
+        let element_type = b.element_type();
+        let (a_len, b_len) = (a.len(), b.len());
+        let mut prefix = 0;
+        while prefix < a_len.min(b_len) && elements_equal(a, prefix, b, prefix)? {
+            prefix += 1;
+        }
+        let mut suffix = 0;
+        while suffix < a_len.min(b_len) - prefix
+            && elements_equal(a, a_len - suffix - 1, b, b_len - suffix - 1)?
+        {
+            suffix += 1;
+        }
+
+        // Walk through the remaining elements hunk by hunk, where each hunk is a run of
+        // elements of `a` that turn into a run of elements of `b`, followed by an
+        // element that is the same in both. `position` tracks where we are in the list
+        // as it looks after the changes emitted so far.
+        let matches = matching_elements(a, prefix..a_len - suffix, b, prefix..b_len - suffix)?;
+        let mut position = prefix;
+        let (mut i, mut j) = (prefix, prefix);
+        for (match_i, match_j) in matches
+            .into_iter()
+            .chain(core::iter::once((a_len - suffix, b_len - suffix)))
+        {
+            let (removed, inserted) = (match_i - i, match_j - j);
+            for _ in 0..removed.min(inserted) {
+                self.path.push(PathElement::Index(position));
+                self.diff_value(element_type, list_element(a, i)?, list_element(b, j)?)?;
+                self.path.pop();
+                (i, j, position) = (i + 1, j + 1, position + 1);
+            }
+            for _ in inserted..removed {
+                self.path.push(PathElement::Index(position));
+                self.emit(ChangeKind::Remove);
+                self.path.pop();
+                i += 1;
+            }
+            for _ in removed..inserted {
+                self.path.push(PathElement::Index(position));
+                self.emit(ChangeKind::Insert);
+                self.diff_value(
+                    element_type,
+                    default_element(element_type),
+                    list_element(b, j)?,
+                )?;
+                self.path.pop();
+                (j, position) = (j + 1, position + 1);
+            }
+            (i, j, position) = (i + 1, j + 1, position + 1);
+        }
+        Ok(())
+    }
+}
+
+fn field_name(field: Field) -> Result<PathElement> {
+    Ok(PathElement::Field(
+        field.get_proto().get_name()?.to_string()?,
+    ))
+}
+
+/// A struct whose fields all have their default values.
+fn default_struct(schema: StructSchema<'_>) -> dynamic_struct::Reader<'_> {
+    dynamic_struct::Reader::new(layout::StructReader::new_default(), schema)
+}
+
+/// The value of a freshly allocated list element of type `ty`. `None` stands for a null pointer.
+fn default_element(ty: Type<'_>) -> Option<dynamic_value::Reader<'_>> {
+    Some(match ty.which() {
+        TypeVariant::Void => dynamic_value::Reader::Void,
+        TypeVariant::Bool => false.into(),
+        TypeVariant::Int8 => 0i8.into(),
+        TypeVariant::Int16 => 0i16.into(),
+        TypeVariant::Int32 => 0i32.into(),
+        TypeVariant::Int64 => 0i64.into(),
+        TypeVariant::UInt8 => 0u8.into(),
+        TypeVariant::UInt16 => 0u16.into(),
+        TypeVariant::UInt32 => 0u32.into(),
+        TypeVariant::UInt64 => 0u64.into(),
+        TypeVariant::Float32 => 0f32.into(),
+        TypeVariant::Float64 => 0f64.into(),
+        TypeVariant::Enum(schema) => dynamic_value::Enum::new(0, schema.into()).into(),
+        TypeVariant::Struct(schema) => default_struct(schema.into()).into(),
+        TypeVariant::Text
+        | TypeVariant::Data
+        | TypeVariant::List(_)
+        | TypeVariant::AnyPointer
+        | TypeVariant::Capability(_) => return None,
+    })
+}
+
+/// Whether list elements of type `ty` are pointers. Struct lists store their elements inline.
+fn is_nullable_element(ty: Type) -> bool {
+    ty.is_pointer_type() && !matches!(ty.which(), TypeVariant::Struct(_))
+}
+
+/// Gets an element of `list`, or `None` if it is a null pointer.
+fn list_element(list: dynamic_list::Reader, index: u32) -> Result<Option<dynamic_value::Reader>> {
+    if is_nullable_element(list.element_type()) && list.reader.get_pointer_element(index).is_null()
+    {
+        Ok(None)
+    } else {
+        list.get(index).map(Some)
+    }
+}
+
+fn elements_equal(
+    a: dynamic_list::Reader,
+    i: u32,
+    b: dynamic_list::Reader,
+    j: u32,
+) -> Result<bool> {
+    values_equal(list_element(a, i)?, list_element(b, j)?)
+}
+
+/// Whether `diff_value()` would find no changes between `a` and `b`, computed
+/// without allocating. `None` stands for a null pointer.
+fn values_equal(
+    a: Option<dynamic_value::Reader>,
+    b: Option<dynamic_value::Reader>,
+) -> Result<bool> {
+    let (Some(a), Some(b)) = (a, b) else {
+        return Ok(a.is_none() && b.is_none());
+    };
+    match (a, b) {
+        (dynamic_value::Reader::Struct(a), dynamic_value::Reader::Struct(b)) => structs_equal(a, b),
+        (dynamic_value::Reader::List(a), dynamic_value::Reader::List(b)) => {
+            if a.len() != b.len() {
+                return Ok(false);
+            }
+            for index in 0..a.len() {
+                if !elements_equal(a, index, b, index)? {
+                    return Ok(false);
+                }
+            }
+            Ok(true)
+        }
+        (dynamic_value::Reader::AnyPointer(_), _) | (dynamic_value::Reader::Capability(_), _) => {
+            Err(Error::unimplemented(
+                "cannot diff AnyPointer values or capabilities".into(),
+            ))
+        }
+        (a, b) => same_scalar(a, b),
+    }
+}
+
+fn structs_equal(a: dynamic_struct::Reader, b: dynamic_struct::Reader) -> Result<bool> {
+    let (a_member, b_member) = (a.which()?, b.which()?);
+    if a_member.map(|f| f.get_index()) != b_member.map(|f| f.get_index()) {
+        return Ok(false);
+    }
+    for field in b.get_schema().get_fields()? {
+        if field.get_proto().get_discriminant_value() != field::NO_DISCRIMINANT
+            && b_member.map(|f| f.get_index()) != Some(field.get_index())
+        {
+            continue;
+        }
+        let equal = match field.get_proto().which()? {
+            field::Group(_) => match (a.get(field)?, b.get(field)?) {
+                (dynamic_value::Reader::Struct(a), dynamic_value::Reader::Struct(b)) => {
+                    structs_equal(a, b)?
+                }
+                _ => return Err(Error::from_kind(ErrorKind::NotAStruct)),
+            },
+            field::Slot(_) => {
+                let is_pointer = field.get_type().is_pointer_type();
+                let a = if !is_pointer || a.has(field)? {
+                    Some(a.get(field)?)
+                } else {
+                    None
+                };
+                let b = if !is_pointer || b.has(field)? {
+                    Some(b.get(field)?)
+                } else {
+                    None
+                };
+                values_equal(a, b)?
+            }
+        };
+        if !equal {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+/// Compares two non-pointer or blob values. Floats are compared bitwise, so that
+/// a NaN is equal to itself and distinct from other NaNs.
+fn same_scalar(a: dynamic_value::Reader, b: dynamic_value::Reader) -> Result<bool> {
+    Ok(match (a, b) {
+        (dynamic_value::Reader::Void, dynamic_value::Reader::Void) => true,
+        (dynamic_value::Reader::Bool(a), dynamic_value::Reader::Bool(b)) => a == b,
+        (dynamic_value::Reader::Int8(a), dynamic_value::Reader::Int8(b)) => a == b,
+        (dynamic_value::Reader::Int16(a), dynamic_value::Reader::Int16(b)) => a == b,
+        (dynamic_value::Reader::Int32(a), dynamic_value::Reader::Int32(b)) => a == b,
+        (dynamic_value::Reader::Int64(a), dynamic_value::Reader::Int64(b)) => a == b,
+        (dynamic_value::Reader::UInt8(a), dynamic_value::Reader::UInt8(b)) => a == b,
+        (dynamic_value::Reader::UInt16(a), dynamic_value::Reader::UInt16(b)) => a == b,
+        (dynamic_value::Reader::UInt32(a), dynamic_value::Reader::UInt32(b)) => a == b,
+        (dynamic_value::Reader::UInt64(a), dynamic_value::Reader::UInt64(b)) => a == b,
+        (dynamic_value::Reader::Float32(a), dynamic_value::Reader::Float32(b)) => {
+            a.to_bits() == b.to_bits()
+        }
+        (dynamic_value::Reader::Float64(a), dynamic_value::Reader::Float64(b)) => {
+            a.to_bits() == b.to_bits()
+        }
+        (dynamic_value::Reader::Enum(a), dynamic_value::Reader::Enum(b)) => {
+            a.get_value() == b.get_value()
+        }
+        (dynamic_value::Reader::Text(a), dynamic_value::Reader::Text(b)) => {
+            a.as_bytes() == b.as_bytes()
+        }
+        (dynamic_value::Reader::Data(a), dynamic_value::Reader::Data(b)) => a == b,
+        _ => return Err(Error::from_kind(ErrorKind::TypeMismatch)),
+    })
+}
+
+/// Finds a longest common subsequence of the elements of `a` and `b` in the given ranges,
+/// returned as pairs of indices of equal elements in increasing order.
+fn matching_elements(
+    a: dynamic_list::Reader,
+    a_range: core::ops::Range<u32>,
+    b: dynamic_list::Reader,
+    b_range: core::ops::Range<u32>,
+) -> Result<Vec<(u32, u32)>> {
+    let (n, m) = (a_range.len(), b_range.len());
+    if n == 0 || m == 0 || n.saturating_mul(m) > MAX_LIST_MATCHING_PAIRS {
+        return Ok(Vec::new());
+    }
+
+    // lengths[x * (m + 1) + y] is the length of the longest common subsequence
+    // of the elements of `a` from x on and the elements of `b` from y on.
+    let mut lengths = alloc::vec![0u32; (n + 1) * (m + 1)];
+    for x in (0..n).rev() {
+        for y in (0..m).rev() {
+            lengths[x * (m + 1) + y] =
+                if elements_equal(a, a_range.start + x as u32, b, b_range.start + y as u32)? {
+                    lengths[(x + 1) * (m + 1) + y + 1] + 1
+                } else {
+                    lengths[(x + 1) * (m + 1) + y].max(lengths[x * (m + 1) + y + 1])
+                };
+        }
+    }
+
+    let mut result = Vec::new();
+    let (mut x, mut y) = (0, 0);
+    while x < n && y < m {
+        let length = lengths[x * (m + 1) + y];
+        if length == lengths[(x + 1) * (m + 1) + y] {
+            x += 1;
+        } else if length == lengths[x * (m + 1) + y + 1] {
+            y += 1;
+        } else {
+            result.push((a_range.start + x as u32, b_range.start + y as u32));
+            x += 1;
+            y += 1;
+        }
+    }
+    Ok(result)
+}
+
+// ----------------------------------------------------------------------------
+// Applying
+
+/// A struct or list that contains the target of a change.
+enum Container<'a> {
+    Struct(dynamic_struct::Builder<'a>),
+    List(dynamic_list::Builder<'a>),
+}
+
+fn invalid_path(path: &Path) -> Error {
+    Error::failed(alloc::format!("patch path {path} does not fit the message"))
+}
+
+fn apply_changes(mut root: dynamic_struct::Builder, changes: &[Change]) -> Result<()> {
+    let mut rest = changes;
+    while let Some(change) = rest.first() {
+        match list_edit(change) {
+            Some(list_path) => {
+                // Every insertion and removal in a list means copying the list, so collect
+                // the edits of the list and the changes to its elements up to the next
+                // change elsewhere, and copy the list only once for all of them.
+                let len = rest
+                    .iter()
+                    .take_while(|c| {
+                        c.path.0.len() > list_path.len() && c.path.0.starts_with(list_path)
+                    })
+                    .count();
+                edit_list(root.reborrow(), list_path, &rest[..len], &change.path)?;
+                rest = &rest[len..];
+            }
+            None => {
+                apply_change(root.reborrow(), change)?;
+                rest = &rest[1..];
+            }
+        }
+    }
+    Ok(())
+}
+
+/// If `change` inserts or removes a list element, the path of the list.
+fn list_edit(change: &Change) -> Option<&[PathElement]> {
+    match (&change.kind, change.path.0.split_last()) {
+        (ChangeKind::Insert | ChangeKind::Remove, Some((PathElement::Index(_), list_path)))
+            if !list_path.is_empty() =>
+        {
+            Some(list_path)
+        }
+        _ => None,
+    }
+}
+
+fn apply_change(root: dynamic_struct::Builder, change: &Change) -> Result<()> {
+    let path = &change.path;
+    let Some((last, parents)) = path.0.split_last() else {
+        return Err(invalid_path(path));
+    };
+    match (&change.kind, last) {
+        (ChangeKind::Insert | ChangeKind::Remove, _) => Err(invalid_path(path)),
+        (kind, last) => match (navigate(root, parents, path)?, last) {
+            (Container::Struct(mut st), PathElement::Field(name)) => {
+                let field = st.get_schema().get_field_by_name(name)?;
+                match kind {
+                    ChangeKind::SwitchUnion { .. } | ChangeKind::Clear => st.clear(field),
+                    ChangeKind::Set(value) => st.set(field, value.as_reader(field.get_type())?),
+                    ChangeKind::InitStruct => st.init(field).map(drop),
+                    ChangeKind::InitList(len) => st.initn(field, *len).map(drop),
+                    ChangeKind::Insert | ChangeKind::Remove => unreachable!(),
+                }
+            }
+            (Container::List(mut list), &PathElement::Index(index)) if index < list.len() => {
+                match kind {
+                    ChangeKind::Set(value) => {
+                        let element_type = list.element_type();
+                        list.set(index, value.as_reader(element_type)?)
+                    }
+                    ChangeKind::Clear if is_nullable_element(list.element_type()) => {
+                        list.builder.reborrow().get_pointer_element(index).clear();
+                        Ok(())
+                    }
+                    ChangeKind::InitList(len) => list.init(index, *len).map(drop),
+                    _ => Err(invalid_path(path)),
+                }
+            }
+            _ => Err(invalid_path(path)),
+        },
+    }
+}
+
+/// Follows `elements` down from `root`, which must lead to a struct or a list.
+fn navigate<'a>(
+    root: dynamic_struct::Builder<'a>,
+    elements: &[PathElement],
+    path: &Path,
+) -> Result<Container<'a>> {
+    let mut container = Container::Struct(root);
+    for element in elements {
+        let value = match (container, element) {
+            (Container::Struct(st), PathElement::Field(name)) => st.get_named(name)?,
+            (Container::List(list), &PathElement::Index(index)) if index < list.len() => {
+                list.get(index)?
+            }
+            _ => return Err(invalid_path(path)),
+        };
+        container = match value {
+            dynamic_value::Builder::Struct(st) => Container::Struct(st),
+            dynamic_value::Builder::List(list) => Container::List(list),
+            _ => return Err(invalid_path(path)),
+        };
+    }
+    Ok(container)
+}
+
+/// Applies `changes`, which all lie within the list at `list_path` and start with the
+/// insertion or removal of an element, copying the list only once. Lists cannot change
+/// size in place, so the list is copied aside, and a new one is allocated with the
+/// elements that remain, in their new order. Changes to elements are made afterwards,
+/// at the positions where the elements end up.
+fn edit_list(
+    mut root: dynamic_struct::Builder,
+    list_path: &[PathElement],
+    changes: &[Change],
+    path: &Path,
+) -> Result<()> {
+    let (element, grandparents) = list_path.split_last().ok_or_else(|| invalid_path(path))?;
+    let container = navigate(root.reborrow(), grandparents, path)?;
+    let old_len = list_len(container, element, path)?;
+
+    // For each element of the edited list, its index in the old list, if any, and an ID
+    // by which changes to it are tracked.
+    let mut elements: Vec<(Option<u32>, usize)> =
+        (0..old_len).map(|i| (Some(i), i as usize)).collect();
+    let mut next_id = old_len as usize;
+    let mut element_changes = Vec::new();
+    for change in changes {
+        let PathElement::Index(index) = change.path.0[list_path.len()] else {
+            return Err(invalid_path(&change.path));
+        };
+        let index = index as usize;
+        let at_element = change.path.0.len() == list_path.len() + 1;
+        match change.kind {
+            ChangeKind::Insert if at_element && index <= elements.len() => {
+                elements.insert(index, (None, next_id));
+                next_id += 1;
+            }
+            ChangeKind::Remove if at_element && index < elements.len() => {
+                elements.remove(index);
+            }
+            ChangeKind::Insert | ChangeKind::Remove if at_element => {
+                return Err(invalid_path(&change.path))
+            }
+            _ if index < elements.len() => element_changes.push((elements[index].1, change)),
+            _ => return Err(invalid_path(&change.path)),
+        }
+    }
+
+    let container = navigate(root.reborrow(), grandparents, path)?;
+    rebuild_list(container, element, &elements, path)?;
+
+    let mut positions = alloc::vec![None; next_id];
+    for (position, &(_, id)) in elements.iter().enumerate() {
+        positions[id] = Some(position as u32);
+    }
+    // Changes to elements that were removed later on have no effect.
+    let element_changes: Vec<Change> = element_changes
+        .into_iter()
+        .filter_map(|(id, change)| {
+            let mut change = change.clone();
+            change.path.0[list_path.len()] = PathElement::Index(positions[id]?);
+            Some(change)
+        })
+        .collect();
+    apply_changes(root, &element_changes)
+}
+
+/// Gets the length of the list found at `element` in `container`.
+fn list_len(container: Container, element: &PathElement, path: &Path) -> Result<u32> {
+    let list = match (container, element) {
+        (Container::Struct(st), PathElement::Field(name)) => st.get_named(name)?,
+        (Container::List(outer), &PathElement::Index(index)) if index < outer.len() => {
+            outer.get(index)?
+        }
+        _ => return Err(invalid_path(path)),
+    };
+    let dynamic_value::Builder::List(list) = list else {
+        return Err(invalid_path(path));
+    };
+    Ok(list.len())
+}
+
+/// Replaces the list found at `element` in `container` with a list of `elements.len()`
+/// elements, where each element is a copy of the old element with the given index, or
+/// has its default value.
+fn rebuild_list(
+    container: Container,
+    element: &PathElement,
+    elements: &[(Option<u32>, usize)],
+    path: &Path,
+) -> Result<()> {
+    let (mut container, element_type) = match (container, element) {
+        (Container::Struct(st), PathElement::Field(name)) => {
+            let field = st.get_schema().get_field_by_name(name)?;
+            let TypeVariant::List(element_type) = field.get_type().which() else {
+                return Err(invalid_path(path));
+            };
+            (Container::Struct(st), element_type)
+        }
+        (Container::List(outer), PathElement::Index(_)) => {
+            let TypeVariant::List(element_type) = outer.element_type().which() else {
+                return Err(invalid_path(path));
+            };
+            (Container::List(outer), element_type)
+        }
+        _ => return Err(invalid_path(path)),
+    };
+
+    let mut scratch = message::Builder::new_default();
+    let old_list = {
+        let list = match (&mut container, element) {
+            (Container::Struct(st), PathElement::Field(name)) => st.reborrow().get_named(name)?,
+            (Container::List(outer), &PathElement::Index(outer_index)) => {
+                outer.reborrow().get(outer_index)?
+            }
+            _ => unreachable!(),
+        };
+        let dynamic_value::Builder::List(list) = list else {
+            unreachable!()
+        };
+        scratch
+            .get_root::<any_pointer::Builder>()?
+            .set_as(list.into_reader())?;
+        let root = scratch.get_root_as_reader::<any_pointer::Reader>()?;
+        dynamic_list::Reader::new(
+            root.reader
+                .get_list(element_type.expected_element_size(), None)?,
+            element_type,
+        )
+    };
+
+    let new_len = elements.len() as u32;
+    let new_list = match (container, element) {
+        (Container::Struct(st), PathElement::Field(name)) => st.initn_named(name, new_len)?,
+        (Container::List(outer), &PathElement::Index(outer_index)) => {
+            outer.init(outer_index, new_len)?
+        }
+        _ => unreachable!(),
+    };
+    let dynamic_value::Builder::List(mut new_list) = new_list else {
+        unreachable!()
+    };
+    for (new_index, &(old_index, _)) in elements.iter().enumerate() {
+        let Some(old_index) = old_index else {
+            continue;
+        };
+        if let Some(value) = list_element(old_list, old_index)? {
+            new_list.set(new_index as u32, value)?;
+        }
+    }
+    Ok(())
+}
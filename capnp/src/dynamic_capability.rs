@@ -0,0 +1,315 @@
+//! Dynamically-typed capabilities.
+//!
+//! A [`Client`] pairs a capability with an [`InterfaceSchema`], so that methods can be
+//! called by name and their params and results accessed through
+//! [`dynamic_struct`](crate::dynamic_struct), without generated code for the interface.
+//! On the other side, a [`Server`] receives calls together with the schema of the
+//! method being called. Wrap it in a [`ServerDispatch`] to get a
+//! [`capability::Server`](crate::capability::Server) that an RPC system can serve.
+//!
+//! Every type here borrows its schema for `'s`, so schemas from generated code (which are
+//! `'static`) and schemas from a [`SchemaLoader`](crate::schema_loader::SchemaLoader)
+//! (which borrow the loader) can both be used.
+
+use alloc::boxed::Box;
+use alloc::string::ToString;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use crate::any_pointer;
+use crate::capability::{self, FromClientHook, Promise};
+use crate::introspect::{Introspect, Type, TypeVariant};
+use crate::private::capability::{ClientHook, ParamsHook, RequestHook, ResponseHook, ResultsHook};
+use crate::schema::{InterfaceSchema, Method, StructSchema};
+use crate::schema_capnp::field;
+use crate::{dynamic_struct, Error, ErrorKind, MessageSize, Result};
+
+/// A capability client whose interface is described by a schema
+/// that is only known at run time.
+pub struct Client<'s> {
+    pub client: capability::Client,
+    schema: InterfaceSchema<'s>,
+}
+
+impl<'s> Client<'s> {
+    pub fn new(hook: Box<dyn ClientHook>, schema: InterfaceSchema<'s>) -> Self {
+        Self {
+            client: capability::Client::new(hook),
+            schema,
+        }
+    }
+
+    /// Wraps a client of a generated interface type.
+    pub fn from_typed<T: FromClientHook + Introspect>(client: T) -> Result<Self> {
+        let TypeVariant::Capability(schema) = T::introspect().which() else {
+            return Err(Error::from_kind(ErrorKind::TypeMismatch));
+        };
+        Ok(Self::new(client.into_client_hook(), schema.into()))
+    }
+
+    /// Converts to a client of a generated interface type. This always succeeds; calls
+    /// fail with "unimplemented" errors if the capability does not implement `T`'s interface.
+    pub fn downcast<T: FromClientHook>(self) -> T {
+        FromClientHook::new(self.client.hook)
+    }
+
+    pub fn get_schema(&self) -> InterfaceSchema<'s> {
+        self.schema
+    }
+
+    /// Starts a call to `method`, which must belong to this client's interface
+    /// or one of its superclasses.
+    pub fn new_request(
+        &self,
+        method: Method<'s>,
+        size_hint: Option<MessageSize>,
+    ) -> Result<Request<'s>> {
+        let interface = method.get_containing_interface();
+        if !self.schema.extends(interface)? {
+            let mut error = Error::from_kind(ErrorKind::MethodNotFound);
+            write!(
+                error,
+                "{} is not a method of {}",
+                method.get_proto().get_name()?.to_str()?,
+                self.schema.get_proto().get_display_name()?.to_str()?
+            );
+            return Err(error);
+        }
+        let typeless = self.client.hook.new_call(
+            interface.get_proto().get_id(),
+            method.get_ordinal(),
+            size_hint,
+        );
+        Ok(Request {
+            hook: typeless.hook,
+            method,
+        })
+    }
+
+    /// Starts a call to the method named `name`, which may be inherited from a superclass.
+    pub fn new_request_named(&self, name: &str) -> Result<Request<'s>> {
+        self.new_request(self.schema.get_method_by_name(name)?, None)
+    }
+
+    /// If the capability is actually only a promise, the returned promise resolves once the
+    /// capability itself has resolved to its final destination.
+    pub fn when_resolved(&self) -> Promise<(), Error> {
+        self.client.when_resolved()
+    }
+}
+
+impl<'s> Clone for Client<'s> {
+    fn clone(&self) -> Self {
+        Self {
+            client: capability::Client::new(self.client.hook.add_ref()),
+            schema: self.schema,
+        }
+    }
+}
+
+/// A method call that has not been sent yet.
+pub struct Request<'s> {
+    pub hook: Box<dyn RequestHook>,
+    method: Method<'s>,
+}
+
+impl<'s> Request<'s> {
+    pub fn get_method(&self) -> Method<'s> {
+        self.method
+    }
+
+    /// Gets the params, to be filled in before sending.
+    pub fn get(&mut self) -> dynamic_struct::Builder<'_> {
+        self.hook
+            .get()
+            .get_as_dynamic(self.method.get_param_type())
+            .unwrap()
+    }
+
+    pub fn send(self) -> RemotePromise<'s> {
+        let schema = self.method.get_result_type();
+        let capability::RemotePromise { promise, pipeline } = self.hook.send();
+        RemotePromise {
+            promise: ResponsePromise { promise, schema },
+            pipeline: Pipeline {
+                typeless: pipeline,
+                ty: TypeVariant::Struct(schema.raw).into(),
+            },
+        }
+    }
+}
+
+/// A promise for the result of a method call, along with a pipeline
+/// for making calls on capabilities in the result before it arrives.
+#[must_use]
+pub struct RemotePromise<'s> {
+    pub promise: ResponsePromise<'s>,
+    pub pipeline: Pipeline<'s>,
+}
+
+/// The response to a method call, once it arrives. Unlike a [`Promise`], this may borrow
+/// the schema of the response.
+#[must_use]
+pub struct ResponsePromise<'s> {
+    promise: Promise<capability::Response<any_pointer::Owned>, Error>,
+    schema: StructSchema<'s>,
+}
+
+impl<'s> Future for ResponsePromise<'s> {
+    type Output = Result<Response<'s>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let schema = this.schema;
+        Pin::new(&mut this.promise)
+            .poll(cx)
+            .map_ok(|response| Response {
+                hook: response.hook,
+                schema,
+            })
+    }
+}
+
+/// A response from a method call, as seen by the client.
+pub struct Response<'s> {
+    pub hook: Box<dyn ResponseHook>,
+    schema: StructSchema<'s>,
+}
+
+impl<'s> Response<'s> {
+    pub fn get(&self) -> Result<dynamic_struct::Reader<'_>> {
+        self.hook.get()?.get_as_dynamic(self.schema)
+    }
+}
+
+/// A promised struct or capability inside the results of a call that has not returned yet.
+pub struct Pipeline<'s> {
+    pub typeless: any_pointer::Pipeline,
+    ty: Type<'s>,
+}
+
+impl<'s> Pipeline<'s> {
+    /// Gets the pipeline for a struct, group, or capability field of this struct.
+    pub fn get_named(&self, name: &str) -> Result<Self> {
+        let TypeVariant::Struct(st) = self.ty.which() else {
+            return Err(Error::from_kind(ErrorKind::NotAStruct));
+        };
+        let field = StructSchema::from(st).get_field_by_name(name)?;
+        let ty = field.get_type();
+        let typeless = match field.get_proto().which()? {
+            field::Group(_) => self.typeless.noop(),
+            field::Slot(slot) => match ty.which() {
+                TypeVariant::Struct(_) | TypeVariant::Capability(_) => {
+                    self.typeless.get_pointer_field(slot.get_offset() as u16)
+                }
+                _ => {
+                    let mut error = Error::from_kind(ErrorKind::TypeMismatch);
+                    write!(error, "field {name} cannot be pipelined");
+                    return Err(error);
+                }
+            },
+        };
+        Ok(Self { typeless, ty })
+    }
+
+    /// Gets a client for the promised capability.
+    pub fn as_client(&self) -> Result<Client<'s>> {
+        let TypeVariant::Capability(schema) = self.ty.which() else {
+            return Err(Error::from_kind(ErrorKind::TypeMismatch));
+        };
+        Ok(Client::new(self.typeless.as_cap(), schema.into()))
+    }
+}
+
+/// The params of a call, as seen by a [`Server`].
+pub struct Params<'s> {
+    pub hook: Box<dyn ParamsHook>,
+    schema: StructSchema<'s>,
+}
+
+impl<'s> Params<'s> {
+    pub fn get(&self) -> Result<dynamic_struct::Reader<'_>> {
+        self.hook.get()?.get_as_dynamic(self.schema)
+    }
+}
+
+/// The results of a call, written in place by a [`Server`].
+pub struct Results<'s> {
+    pub hook: Box<dyn ResultsHook>,
+    schema: StructSchema<'s>,
+}
+
+impl<'s> Results<'s> {
+    pub fn get(&mut self) -> Result<dynamic_struct::Builder<'_>> {
+        self.hook.get()?.get_as_dynamic(self.schema)
+    }
+}
+
+/// A server whose interface is described by a schema that is only known at run time.
+pub trait Server<'s> {
+    /// Handles a call to `method`, which belongs to the served interface
+    /// or to one of its superclasses.
+    fn call(
+        &mut self,
+        method: Method<'s>,
+        params: Params<'s>,
+        results: Results<'s>,
+    ) -> Promise<(), Error>;
+}
+
+/// Dispatches calls to a dynamic [`Server`], looking up each method in `schema`.
+pub struct ServerDispatch<'s, S> {
+    schema: InterfaceSchema<'s>,
+    pub server: S,
+}
+
+impl<'s, S: Server<'s>> ServerDispatch<'s, S> {
+    pub fn new(schema: InterfaceSchema<'s>, server: S) -> Self {
+        Self { schema, server }
+    }
+
+    pub fn get_schema(&self) -> InterfaceSchema<'s> {
+        self.schema
+    }
+}
+
+impl<'s, S: Server<'s>> ::core::ops::Deref for ServerDispatch<'s, S> {
+    type Target = S;
+    fn deref(&self) -> &S {
+        &self.server
+    }
+}
+
+impl<'s, S: Server<'s>> ::core::ops::DerefMut for ServerDispatch<'s, S> {
+    fn deref_mut(&mut self) -> &mut S {
+        &mut self.server
+    }
+}
+
+impl<'s, S: Server<'s>> capability::Server for ServerDispatch<'s, S> {
+    fn dispatch_call(
+        &mut self,
+        interface_id: u64,
+        method_id: u16,
+        params: capability::Params<any_pointer::Owned>,
+        results: capability::Results<any_pointer::Owned>,
+    ) -> Promise<(), Error> {
+        let method = match self.schema.find_method_by_id(interface_id, method_id) {
+            Ok(Some(method)) => method,
+            Ok(None) => {
+                return Promise::err(Error::unimplemented("Method not implemented.".to_string()))
+            }
+            Err(e) => return Promise::err(e),
+        };
+        let params = Params {
+            hook: params.hook,
+            schema: method.get_param_type(),
+        };
+        let results = Results {
+            hook: results.hook,
+            schema: method.get_result_type(),
+        };
+        self.server.call(method, params, results)
+    }
+}
@@ -10,7 +10,7 @@ use crate::{Error, ErrorKind, Result};
 #[derive(Copy, Clone)]
 pub struct Reader<'a> {
     pub(crate) reader: layout::ListReader<'a>,
-    pub(crate) element_type: Type,
+    pub(crate) element_type: Type<'a>,
 }
 
 impl<'a> From<Reader<'a>> for dynamic_value::Reader<'a> {
@@ -20,7 +20,7 @@ impl<'a> From<Reader<'a>> for dynamic_value::Reader<'a> {
 }
 
 impl<'a> Reader<'a> {
-    pub(crate) fn new(reader: layout::ListReader<'a>, element_type: Type) -> Self {
+    pub(crate) fn new(reader: layout::ListReader<'a>, element_type: Type<'a>) -> Self {
         Self {
             reader,
             element_type,
@@ -35,7 +35,7 @@ impl<'a> Reader<'a> {
         self.len() == 0
     }
 
-    pub fn element_type(&self) -> Type {
+    pub fn element_type(&self) -> Type<'a> {
         self.element_type
     }
 
@@ -93,16 +93,23 @@ impl<'a> Reader<'a> {
             )
             .into()),
             TypeVariant::Text => Ok(dynamic_value::Reader::Text(
-                self.reader.get_pointer_element(index).get_text(None)?,
+                self.reader
+                    .get_pointer_element(index)
+                    .get_text(None)
+                    .map_err(|e| e.with_index(index))?,
             )),
             TypeVariant::Data => Ok(dynamic_value::Reader::Data(
-                self.reader.get_pointer_element(index).get_data(None)?,
+                self.reader
+                    .get_pointer_element(index)
+                    .get_data(None)
+                    .map_err(|e| e.with_index(index))?,
             )),
             TypeVariant::List(element_type) => Ok(Reader {
                 reader: self
                     .reader
                     .get_pointer_element(index)
-                    .get_list(element_type.expected_element_size(), None)?,
+                    .get_list(element_type.expected_element_size(), None)
+                    .map_err(|e| e.with_index(index))?,
                 element_type,
             }
             .into()),
@@ -115,7 +122,7 @@ impl<'a> Reader<'a> {
             TypeVariant::AnyPointer => {
                 Ok(crate::any_pointer::Reader::new(self.reader.get_pointer_element(index)).into())
             }
-            TypeVariant::Capability => {
+            TypeVariant::Capability(_) => {
                 Ok(dynamic_value::Reader::Capability(dynamic_value::Capability))
             }
         }
@@ -144,7 +151,7 @@ impl<'a> ::core::iter::IntoIterator for Reader<'a> {
 /// A mutable dynamically-typed list.
 pub struct Builder<'a> {
     pub(crate) builder: layout::ListBuilder<'a>,
-    pub(crate) element_type: Type,
+    pub(crate) element_type: Type<'a>,
 }
 
 impl<'a> From<Builder<'a>> for dynamic_value::Builder<'a> {
@@ -154,7 +161,7 @@ impl<'a> From<Builder<'a>> for dynamic_value::Builder<'a> {
 }
 
 impl<'a> Builder<'a> {
-    pub(crate) fn new(builder: layout::ListBuilder<'a>, element_type: Type) -> Self {
+    pub(crate) fn new(builder: layout::ListBuilder<'a>, element_type: Type<'a>) -> Self {
         Self {
             builder,
             element_type,
@@ -183,7 +190,7 @@ impl<'a> Builder<'a> {
         self.len() == 0
     }
 
-    pub fn element_type(&self) -> Type {
+    pub fn element_type(&self) -> Type<'a> {
         self.element_type
     }
 
@@ -253,7 +260,7 @@ impl<'a> Builder<'a> {
                 self.builder.get_pointer_element(index),
             )
             .into()),
-            TypeVariant::Capability => Ok(dynamic_value::Builder::Capability(
+            TypeVariant::Capability(_) => Ok(dynamic_value::Builder::Capability(
                 dynamic_value::Capability,
             )),
         }
@@ -311,20 +318,16 @@ impl<'a> Builder<'a> {
                 PrimitiveElement::set(&self.builder, index, e.get_value());
                 Ok(())
             }
-            (TypeVariant::Text, dynamic_value::Reader::Text(t)) => {
-                self.builder
-                    .reborrow()
-                    .get_pointer_element(index)
-                    .set_text(t);
-                Ok(())
-            }
-            (TypeVariant::Data, dynamic_value::Reader::Data(d)) => {
-                self.builder
-                    .reborrow()
-                    .get_pointer_element(index)
-                    .set_data(d);
-                Ok(())
-            }
+            (TypeVariant::Text, dynamic_value::Reader::Text(t)) => self
+                .builder
+                .reborrow()
+                .get_pointer_element(index)
+                .try_set_text(t),
+            (TypeVariant::Data, dynamic_value::Reader::Data(d)) => self
+                .builder
+                .reborrow()
+                .get_pointer_element(index)
+                .try_set_data(d),
             (TypeVariant::Struct(ss), dynamic_value::Reader::Struct(s)) => {
                 assert_eq!(ss, s.get_schema().raw);
                 self.builder
@@ -340,7 +343,7 @@ impl<'a> Builder<'a> {
             (TypeVariant::AnyPointer, _) => {
                 Err(Error::from_kind(ErrorKind::ListAnyPointerNotSupported))
             }
-            (TypeVariant::Capability, dynamic_value::Reader::Capability(_)) => {
+            (TypeVariant::Capability(_), dynamic_value::Reader::Capability(_)) => {
                 Err(Error::from_kind(ErrorKind::ListCapabilityNotSupported))
             }
             (_, _) => Err(Error::from_kind(ErrorKind::TypeMismatch)),
@@ -364,30 +367,32 @@ impl<'a> Builder<'a> {
             | TypeVariant::Float64
             | TypeVariant::Enum(_)
             | TypeVariant::Struct(_)
-            | TypeVariant::Capability => Err(Error::from_kind(ErrorKind::ExpectedAListOrBlob)),
+            | TypeVariant::Capability(_) => Err(Error::from_kind(ErrorKind::ExpectedAListOrBlob)),
             TypeVariant::Text => Ok(self
                 .builder
                 .get_pointer_element(index)
-                .init_text(size)
+                .try_init_text(size)?
                 .into()),
             TypeVariant::Data => Ok(self
                 .builder
                 .get_pointer_element(index)
-                .init_data(size)
+                .try_init_data(size)?
                 .into()),
             TypeVariant::List(inner_element_type) => match inner_element_type.which() {
                 TypeVariant::Struct(rbs) => Ok(Builder::new(
-                    self.builder.get_pointer_element(index).init_struct_list(
-                        size,
-                        crate::dynamic_struct::struct_size_from_schema(rbs.into())?,
-                    ),
+                    self.builder
+                        .get_pointer_element(index)
+                        .try_init_struct_list(
+                            size,
+                            crate::dynamic_struct::struct_size_from_schema(rbs.into())?,
+                        )?,
                     inner_element_type,
                 )
                 .into()),
                 _ => Ok(Builder::new(
                     self.builder
                         .get_pointer_element(index)
-                        .init_list(inner_element_type.expected_element_size(), size),
+                        .try_init_list(inner_element_type.expected_element_size(), size)?,
                     inner_element_type,
                 )
                 .into()),
@@ -1,16 +1,32 @@
 //! Dynamically-typed structs.
 
 use crate::introspect::TypeVariant;
+use crate::orphan::Orphan;
 use crate::private::layout;
 use crate::schema::{Field, StructSchema};
 use crate::schema_capnp::{field, node, value};
-use crate::{dynamic_list, dynamic_value};
+use crate::{any_pointer, dynamic_list, dynamic_value};
 use crate::{Error, ErrorKind, Result};
 
 fn has_discriminant_value(reader: field::Reader) -> bool {
     reader.get_discriminant_value() != field::NO_DISCRIMINANT
 }
 
+fn pointer_field_offset(field: Field) -> Result<usize> {
+    match (field.get_proto().which()?, field.get_type().which()) {
+        (
+            field::Slot(slot),
+            TypeVariant::Text
+            | TypeVariant::Data
+            | TypeVariant::Struct(_)
+            | TypeVariant::List(_)
+            | TypeVariant::AnyPointer
+            | TypeVariant::Capability(_),
+        ) => Ok(slot.get_offset() as usize),
+        _ => Err(Error::from_kind(ErrorKind::TypeMismatch)),
+    }
+}
+
 pub(crate) fn struct_size_from_schema(schema: StructSchema) -> Result<layout::StructSize> {
     if let node::Struct(s) = schema.proto.which()? {
         Ok(layout::StructSize {
@@ -26,7 +42,7 @@ pub(crate) fn struct_size_from_schema(schema: StructSchema) -> Result<layout::St
 #[derive(Clone, Copy)]
 pub struct Reader<'a> {
     pub(crate) reader: layout::StructReader<'a>,
-    schema: StructSchema,
+    schema: StructSchema<'a>,
 }
 
 impl<'a> From<Reader<'a>> for dynamic_value::Reader<'a> {
@@ -35,8 +51,12 @@ impl<'a> From<Reader<'a>> for dynamic_value::Reader<'a> {
     }
 }
 
+fn field_name(field: Field<'_>) -> Option<&str> {
+    field.get_proto().get_name().ok()?.to_str().ok()
+}
+
 impl<'a> Reader<'a> {
-    pub fn new(reader: layout::StructReader<'a>, schema: StructSchema) -> Self {
+    pub fn new(reader: layout::StructReader<'a>, schema: StructSchema<'a>) -> Self {
         Self { reader, schema }
     }
 
@@ -44,11 +64,19 @@ impl<'a> Reader<'a> {
         self.reader.total_size()
     }
 
-    pub fn get_schema(&self) -> StructSchema {
+    pub fn get_schema(&self) -> StructSchema<'a> {
         self.schema
     }
 
-    pub fn get(self, field: Field) -> Result<dynamic_value::Reader<'a>> {
+    pub fn get(self, field: Field<'a>) -> Result<dynamic_value::Reader<'a>> {
+        let type_id = self.schema.get_proto().get_id();
+        self.get_value(field).map_err(|e| match field_name(field) {
+            Some(name) => e.with_field_name(type_id, name),
+            None => e,
+        })
+    }
+
+    fn get_value(self, field: Field<'a>) -> Result<dynamic_value::Reader<'a>> {
         assert_eq!(self.schema.raw, field.parent.raw);
         let ty = field.get_type();
         match field.get_proto().which()? {
@@ -157,7 +185,7 @@ impl<'a> Reader<'a> {
                         };
                         Ok(dynamic_value::Reader::AnyPointer(a1))
                     }
-                    (TypeVariant::Capability, value::Interface(())) => {
+                    (TypeVariant::Capability(_), value::Interface(())) => {
                         Ok(dynamic_value::Reader::Capability(dynamic_value::Capability))
                     }
                     _ => Err(Error::from_kind(ErrorKind::FieldAndDefaultMismatch)),
@@ -180,7 +208,7 @@ impl<'a> Reader<'a> {
 
     /// If this struct has union fields, returns the one that is currently active.
     /// Otherwise, returns None.
-    pub fn which(&self) -> Result<Option<Field>> {
+    pub fn which(&self) -> Result<Option<Field<'a>>> {
         let node::Struct(st) = self.schema.get_proto().which()? else {
             return Err(Error::from_kind(ErrorKind::NotAStruct));
         };
@@ -237,7 +265,7 @@ impl<'a> Reader<'a> {
 /// A mutable dynamically-typed struct.
 pub struct Builder<'a> {
     builder: layout::StructBuilder<'a>,
-    schema: StructSchema,
+    schema: StructSchema<'a>,
 }
 
 impl<'a> From<Builder<'a>> for dynamic_value::Builder<'a> {
@@ -247,7 +275,7 @@ impl<'a> From<Builder<'a>> for dynamic_value::Builder<'a> {
 }
 
 impl<'a> Builder<'a> {
-    pub fn new(builder: layout::StructBuilder<'a>, schema: StructSchema) -> Self {
+    pub fn new(builder: layout::StructBuilder<'a>, schema: StructSchema<'a>) -> Self {
         Self { builder, schema }
     }
 
@@ -272,11 +300,11 @@ impl<'a> Builder<'a> {
         }
     }
 
-    pub fn get_schema(&self) -> StructSchema {
+    pub fn get_schema(&self) -> StructSchema<'a> {
         self.schema
     }
 
-    pub fn get(self, field: Field) -> Result<dynamic_value::Builder<'a>> {
+    pub fn get(self, field: Field<'a>) -> Result<dynamic_value::Builder<'a>> {
         assert_eq!(self.schema.raw, field.parent.raw);
         let ty = field.get_type();
         match field.get_proto().which()? {
@@ -336,7 +364,7 @@ impl<'a> Builder<'a> {
                             // If the type is a generic, then the default value
                             // is always an empty AnyPointer. Ignore that case.
                             if let value::Text(t) = dval {
-                                p.set_text(t?);
+                                p.try_set_text(t?)?;
                             }
                         }
                         Ok(dynamic_value::Builder::Text(p.get_text(None)?))
@@ -347,7 +375,7 @@ impl<'a> Builder<'a> {
                             // If the type is a generic, then the default value
                             // is always an empty AnyPointer. Ignore that case.
                             if let value::Data(d) = dval {
-                                p.set_data(d?);
+                                p.try_set_data(d?)?;
                             }
                         }
                         Ok(dynamic_value::Builder::Data(p.get_data(None)?))
@@ -389,7 +417,7 @@ impl<'a> Builder<'a> {
                         )
                         .into())
                     }
-                    (TypeVariant::Capability, value::Interface(())) => Ok(
+                    (TypeVariant::Capability(_), value::Interface(())) => Ok(
                         dynamic_value::Builder::Capability(dynamic_value::Capability),
                     ),
                     _ => Err(Error::from_kind(ErrorKind::FieldAndDefaultMismatch)),
@@ -410,7 +438,7 @@ impl<'a> Builder<'a> {
         self.get(field)
     }
 
-    pub fn which(&self) -> Result<Option<Field>> {
+    pub fn which(&self) -> Result<Option<Field<'a>>> {
         let node::Struct(st) = self.schema.get_proto().which()? else {
             return Err(Error::from_kind(ErrorKind::NotAStruct));
         };
@@ -504,13 +532,11 @@ impl<'a> Builder<'a> {
                     }
                     (TypeVariant::Text, dynamic_value::Reader::Text(tv), _) => {
                         let mut p = self.builder.reborrow().get_pointer_field(offset);
-                        p.set_text(tv);
-                        Ok(())
+                        p.try_set_text(tv)
                     }
                     (TypeVariant::Data, dynamic_value::Reader::Data(v), _) => {
                         let mut p = self.builder.reborrow().get_pointer_field(offset);
-                        p.set_data(v);
-                        Ok(())
+                        p.try_set_data(v)
                     }
                     (TypeVariant::List(_), dynamic_value::Reader::List(l), _) => {
                         let mut p = self.builder.reborrow().get_pointer_field(offset);
@@ -539,7 +565,7 @@ impl<'a> Builder<'a> {
                             )),
                         }
                     }
-                    (TypeVariant::Capability, _, _) => Err(Error::from_kind(
+                    (TypeVariant::Capability(_), _, _) => Err(Error::from_kind(
                         ErrorKind::SettingDynamicCapabilitiesIsUnsupported,
                     )),
                     _ => Err(Error::from_kind(ErrorKind::TypeMismatch)),
@@ -573,7 +599,7 @@ impl<'a> Builder<'a> {
         self.set(field, value)
     }
 
-    pub fn init(mut self, field: Field) -> Result<dynamic_value::Builder<'a>> {
+    pub fn init(mut self, field: Field<'a>) -> Result<dynamic_value::Builder<'a>> {
         assert_eq!(self.schema.raw, field.parent.raw);
         self.set_in_union(field)?;
         let ty = field.get_type();
@@ -586,7 +612,7 @@ impl<'a> Builder<'a> {
                         builder: self
                             .builder
                             .get_pointer_field(offset)
-                            .init_struct(struct_size_from_schema(ss.into())?),
+                            .try_init_struct(struct_size_from_schema(ss.into())?)?,
                     }
                     .into()),
                     TypeVariant::AnyPointer => {
@@ -614,7 +640,7 @@ impl<'a> Builder<'a> {
         self.init(field)
     }
 
-    pub fn initn(mut self, field: Field, size: u32) -> Result<dynamic_value::Builder<'a>> {
+    pub fn initn(mut self, field: Field<'a>, size: u32) -> Result<dynamic_value::Builder<'a>> {
         assert_eq!(self.schema.raw, field.parent.raw);
         self.set_in_union(field)?;
         let ty = field.get_type();
@@ -626,14 +652,14 @@ impl<'a> Builder<'a> {
                         TypeVariant::Struct(ss) => Ok(dynamic_list::Builder::new(
                             self.builder
                                 .get_pointer_field(offset)
-                                .init_struct_list(size, struct_size_from_schema(ss.into())?),
+                                .try_init_struct_list(size, struct_size_from_schema(ss.into())?)?,
                             element_type,
                         )
                         .into()),
                         _ => Ok(dynamic_list::Builder::new(
                             self.builder
                                 .get_pointer_field(offset)
-                                .init_list(element_type.expected_element_size(), size),
+                                .try_init_list(element_type.expected_element_size(), size)?,
                             element_type,
                         )
                         .into()),
@@ -641,12 +667,12 @@ impl<'a> Builder<'a> {
                     TypeVariant::Text => Ok(self
                         .builder
                         .get_pointer_field(offset)
-                        .init_text(size)
+                        .try_init_text(size)?
                         .into()),
                     TypeVariant::Data => Ok(self
                         .builder
                         .get_pointer_field(offset)
-                        .init_data(size)
+                        .try_init_data(size)?
                         .into()),
 
                     _ => Err(Error::from_kind(
@@ -729,7 +755,7 @@ impl<'a> Builder<'a> {
                     | TypeVariant::Struct(_)
                     | TypeVariant::List(_)
                     | TypeVariant::AnyPointer
-                    | TypeVariant::Capability => {
+                    | TypeVariant::Capability(_) => {
                         self.builder.reborrow().get_pointer_field(offset).clear();
                         Ok(())
                     }
@@ -761,6 +787,51 @@ impl<'a> Builder<'a> {
         self.clear(field)
     }
 
+    /// Detaches the value of a pointer field, leaving the field null.
+    /// Fails if `field` is a member of a union that is not currently active.
+    /// See [`orphan`](crate::orphan).
+    pub fn disown(&mut self, field: Field) -> Result<Orphan<any_pointer::Owned>> {
+        assert_eq!(self.schema.raw, field.parent.raw);
+        let offset = pointer_field_offset(field)?;
+        if has_discriminant_value(field.get_proto())
+            && self.which()?.map(|f| f.get_index()) != Some(field.get_index())
+        {
+            let mut error = Error::from_kind(ErrorKind::Failed);
+            write!(
+                error,
+                "cannot disown {}, which is not the active member of its union",
+                field.get_proto().get_name()?.to_str()?
+            );
+            return Err(error);
+        }
+        Ok(Orphan::disown(
+            self.builder.reborrow().get_pointer_field(offset),
+        ))
+    }
+
+    pub fn disown_named(&mut self, field_name: &str) -> Result<Orphan<any_pointer::Owned>> {
+        let field = self.schema.get_field_by_name(field_name)?;
+        self.disown(field)
+    }
+
+    /// Attaches `orphan`, which must belong to the same message, as the value of a
+    /// pointer field. The orphan's type is not checked against the field's type.
+    pub fn adopt(&mut self, field: Field, orphan: Orphan<any_pointer::Owned>) -> Result<()> {
+        assert_eq!(self.schema.raw, field.parent.raw);
+        let offset = pointer_field_offset(field)?;
+        self.set_in_union(field)?;
+        orphan.adopt_into(self.builder.reborrow().get_pointer_field(offset))
+    }
+
+    pub fn adopt_named(
+        &mut self,
+        field_name: &str,
+        orphan: Orphan<any_pointer::Owned>,
+    ) -> Result<()> {
+        let field = self.schema.get_field_by_name(field_name)?;
+        self.adopt(field, orphan)
+    }
+
     fn set_in_union(&mut self, field: Field) -> Result<()> {
         if has_discriminant_value(field.get_proto()) {
             let node::Struct(st) = self.schema.get_proto().which()? else {
@@ -785,6 +856,12 @@ impl<'a> crate::traits::SetterInput<crate::any_pointer::Owned> for Reader<'a> {
     }
 }
 
+impl<'a> crate::traits::IntoInternalStructReader<'a> for Reader<'a> {
+    fn into_internal_struct_reader(self) -> layout::StructReader<'a> {
+        self.reader
+    }
+}
+
 impl<'a> core::fmt::Debug for Reader<'a> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         core::fmt::Debug::fmt(
@@ -20,7 +20,7 @@ pub enum Reader<'a> {
     UInt64(u64),
     Float32(f32),
     Float64(f64),
-    Enum(Enum),
+    Enum(Enum<'a>),
     Text(crate::text::Reader<'a>),
     Data(crate::data::Reader<'a>),
     Struct(dynamic_struct::Reader<'a>),
@@ -30,7 +30,7 @@ pub enum Reader<'a> {
 }
 
 impl<'a> Reader<'a> {
-    pub fn new(value: value::Reader<'a>, ty: introspect::Type) -> Result<Self> {
+    pub fn new(value: value::Reader<'a>, ty: introspect::Type<'a>) -> Result<Self> {
         match (value.which()?, ty.which()) {
             (value::Void(()), _) => Ok(Reader::Void),
             (value::Bool(b), _) => Ok(Reader::Bool(b)),
@@ -57,7 +57,7 @@ impl<'a> Reader<'a> {
                     element_type,
                 )))
             }
-            (value::Interface(()), TypeVariant::Capability) => Ok(Capability.into()),
+            (value::Interface(()), TypeVariant::Capability(_)) => Ok(Capability.into()),
             (value::AnyPointer(a), TypeVariant::AnyPointer) => Ok(a.into()),
             _ => Err(crate::Error::from_kind(crate::ErrorKind::TypeMismatch)),
         }
@@ -131,7 +131,7 @@ downcast_reader_impl!(u32, UInt32, "u32");
 downcast_reader_impl!(u64, UInt64, "u64");
 downcast_reader_impl!(f32, Float32, "f32");
 downcast_reader_impl!(f64, Float64, "f64");
-downcast_reader_impl!(Enum, Enum, "enum");
+downcast_reader_impl!(Enum<'a>, Enum, "enum");
 downcast_reader_impl!(crate::text::Reader<'a>, Text, "text");
 downcast_reader_impl!(crate::data::Reader<'a>, Data, "data");
 downcast_reader_impl!(dynamic_list::Reader<'a>, List, "list");
@@ -152,7 +152,7 @@ pub enum Builder<'a> {
     UInt64(u64),
     Float32(f32),
     Float64(f64),
-    Enum(Enum),
+    Enum(Enum<'a>),
     Text(crate::text::Builder<'a>),
     Data(crate::data::Builder<'a>),
     Struct(dynamic_struct::Builder<'a>),
@@ -252,7 +252,7 @@ downcast_builder_impl!(u32, UInt32, "u32");
 downcast_builder_impl!(u64, UInt64, "u64");
 downcast_builder_impl!(f32, Float32, "f32");
 downcast_builder_impl!(f64, Float64, "f64");
-downcast_builder_impl!(Enum, Enum, "enum");
+downcast_builder_impl!(Enum<'a>, Enum, "enum");
 downcast_builder_impl!(crate::text::Builder<'a>, Text, "text");
 downcast_builder_impl!(crate::data::Builder<'a>, Data, "data");
 downcast_builder_impl!(dynamic_list::Builder<'a>, List, "list");
@@ -261,13 +261,13 @@ downcast_builder_impl!(crate::any_pointer::Builder<'a>, AnyPointer, "anypointer"
 
 /// A dynamically-typed enum value.
 #[derive(Clone, Copy)]
-pub struct Enum {
+pub struct Enum<'a> {
     value: u16,
-    schema: crate::schema::EnumSchema,
+    schema: crate::schema::EnumSchema<'a>,
 }
 
-impl Enum {
-    pub fn new(value: u16, schema: crate::schema::EnumSchema) -> Self {
+impl<'a> Enum<'a> {
+    pub fn new(value: u16, schema: crate::schema::EnumSchema<'a>) -> Self {
         Self { value, schema }
     }
 
@@ -277,7 +277,7 @@ impl Enum {
     }
 
     /// Gets the schema of this enumerant.
-    pub fn get_enumerant(self) -> crate::Result<Option<crate::schema::Enumerant>> {
+    pub fn get_enumerant(self) -> crate::Result<Option<crate::schema::Enumerant<'a>>> {
         let enumerants = self.schema.get_enumerants()?;
         if (self.value) < enumerants.len() {
             Ok(Some(enumerants.get(self.value)))
@@ -287,14 +287,14 @@ impl Enum {
     }
 }
 
-impl<'a> From<Enum> for Reader<'a> {
-    fn from(e: Enum) -> Reader<'a> {
+impl<'a> From<Enum<'a>> for Reader<'a> {
+    fn from(e: Enum<'a>) -> Reader<'a> {
         Reader::Enum(e)
     }
 }
 
-impl<'a> From<Enum> for Builder<'a> {
-    fn from(e: Enum) -> Builder<'a> {
+impl<'a> From<Enum<'a>> for Builder<'a> {
+    fn from(e: Enum<'a>) -> Builder<'a> {
         Builder::Enum(e)
     }
 }
@@ -38,7 +38,7 @@ impl<T> crate::introspect::Introspect for Owned<T>
 where
     T: crate::introspect::Introspect,
 {
-    fn introspect() -> crate::introspect::Type {
+    fn introspect() -> crate::introspect::Type<'static> {
         crate::introspect::Type::list_of(T::introspect())
     }
 }
@@ -156,6 +156,12 @@ impl<'a, T: TryFrom<u16, Error = NotInSchema>> FromPointerBuilder<'a> for Builde
             marker: PhantomData,
         }
     }
+    fn try_init_pointer(builder: PointerBuilder<'a>, size: u32) -> Result<Builder<'a, T>> {
+        Ok(Builder {
+            builder: builder.try_init_list(TwoBytes, size)?,
+            marker: PhantomData,
+        })
+    }
     fn get_from_pointer(
         builder: PointerBuilder<'a>,
         default: Option<&'a [crate::Word]>,
@@ -213,10 +219,10 @@ impl<'a, T: Copy + Into<u16>> crate::traits::SetterInput<Owned<T>> for &'a [T] {
         value: &'a [T],
         _canonicalize: bool,
     ) -> Result<()> {
-        let builder = pointer.init_list(
+        let builder = pointer.try_init_list(
             crate::private::layout::ElementSize::TwoBytes,
             value.len() as u32,
-        );
+        )?;
         for (idx, v) in value.iter().enumerate() {
             <u16 as PrimitiveElement>::set(&builder, idx as u32, (*v).into())
         }
@@ -0,0 +1,299 @@
+//! Field masks, which select a subset of the fields of a struct by path.
+//!
+//! A path names a field, a field of a struct or group inside it, and so on, joined by dots:
+//! `user.address.city`. A list field is followed by `[*]` to step into all of its elements:
+//! `items[*].price`. Selecting a struct, group, or list selects everything inside it.
+//!
+//! A [`FieldMask`] can copy the selected fields of a struct into a builder with
+//! [`project()`](FieldMask::project), or clear everything else in place with
+//! [`retain()`](FieldMask::retain). Union members that are not active are skipped,
+//! so selecting several members of a union keeps whichever one happens to be set.
+
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+
+use crate::introspect::TypeVariant;
+use crate::schema::Field;
+use crate::schema_capnp::field;
+use crate::{dynamic_list, dynamic_struct, dynamic_value};
+use crate::{Error, ErrorKind, Result};
+
+/// A set of paths selecting fields of a struct. See the [module docs](self).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FieldMask {
+    root: Node,
+}
+
+/// The part of a mask that applies to one value.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct Node {
+    /// The whole value is selected.
+    all: bool,
+
+    /// Selections within fields of a struct, by field name.
+    fields: BTreeMap<String, Node>,
+
+    /// Selection within each element of a list.
+    elements: Option<Box<Node>>,
+}
+
+impl Node {
+    fn select_all(&mut self) {
+        *self = Self {
+            all: true,
+            ..Self::default()
+        };
+    }
+}
+
+impl FieldMask {
+    /// Creates a mask that selects nothing.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a mask that selects each of `paths`.
+    pub fn from_paths<'a>(paths: impl IntoIterator<Item = &'a str>) -> Result<Self> {
+        let mut mask = Self::new();
+        for path in paths {
+            mask.add_path(path)?;
+        }
+        Ok(mask)
+    }
+
+    /// Adds `path` to the selection.
+    pub fn add_path(&mut self, path: &str) -> Result<()> {
+        let invalid = || Error::failed(alloc::format!("invalid field mask path {path:?}"));
+        let mut node = &mut self.root;
+        for segment in path.split('.') {
+            let (name, mut rest) = match segment.find('[') {
+                Some(i) => segment.split_at(i),
+                None => (segment, ""),
+            };
+            if name.is_empty() {
+                return Err(invalid());
+            }
+            if node.all {
+                return Ok(());
+            }
+            node = node.fields.entry(name.to_string()).or_default();
+            while !rest.is_empty() {
+                rest = rest.strip_prefix("[*]").ok_or_else(invalid)?;
+                if node.all {
+                    return Ok(());
+                }
+                node = node.elements.get_or_insert_with(Default::default);
+            }
+        }
+        node.select_all();
+        Ok(())
+    }
+
+    /// Returns `true` if the mask selects nothing.
+    pub fn is_empty(&self) -> bool {
+        !self.root.all && self.root.fields.is_empty()
+    }
+
+    /// Copies the selected fields of `source` into `target`, which is usually a freshly
+    /// initialized struct of the same type. Fields that are not selected are left untouched.
+    pub fn project<'a, 'b>(
+        &self,
+        source: impl Into<dynamic_value::Reader<'a>>,
+        target: impl Into<dynamic_value::Builder<'b>>,
+    ) -> Result<()> {
+        let (dynamic_value::Reader::Struct(source), dynamic_value::Builder::Struct(target)) =
+            (source.into(), target.into())
+        else {
+            return Err(Error::from_kind(ErrorKind::NotAStruct));
+        };
+        if source.get_schema().raw != target.get_schema().raw {
+            return Err(Error::from_kind(ErrorKind::TypeMismatch));
+        }
+        project_struct(&self.root, source, target)
+    }
+
+    /// Clears every field of `builder` that the mask does not select.
+    ///
+    /// If the mask does not fit the schema of `builder`, an error is returned
+    /// and some fields may already have been cleared.
+    pub fn retain<'a>(&self, builder: impl Into<dynamic_value::Builder<'a>>) -> Result<()> {
+        let dynamic_value::Builder::Struct(builder) = builder.into() else {
+            return Err(Error::from_kind(ErrorKind::NotAStruct));
+        };
+        retain_struct(&self.root, builder)
+    }
+}
+
+fn is_union_member(field: Field) -> bool {
+    field.get_proto().get_discriminant_value() != field::NO_DISCRIMINANT
+}
+
+fn not_a_container(name: &str) -> Error {
+    Error::failed(alloc::format!(
+        "field mask descends into field {name}, which is not a struct, group, or list"
+    ))
+}
+
+fn project_struct(
+    node: &Node,
+    source: dynamic_struct::Reader,
+    mut target: dynamic_struct::Builder,
+) -> Result<()> {
+    let schema = source.get_schema();
+    for (name, child) in &node.fields {
+        project_field(
+            child,
+            schema.get_field_by_name(name)?,
+            source,
+            target.reborrow(),
+        )?;
+    }
+    Ok(())
+}
+
+fn project_field<'a>(
+    node: &Node,
+    field: Field<'a>,
+    source: dynamic_struct::Reader,
+    mut target: dynamic_struct::Builder<'a>,
+) -> Result<()> {
+    if is_union_member(field) && source.which()?.map(|f| f.get_index()) != Some(field.get_index()) {
+        return Ok(());
+    }
+    let is_group = matches!(field.get_proto().which()?, field::Group(_));
+    if !is_group && field.get_type().is_pointer_type() && !source.has(field)? {
+        return Ok(());
+    }
+    if node.all {
+        return match source.get(field)? {
+            dynamic_value::Reader::AnyPointer(p) => {
+                let dynamic_value::Builder::AnyPointer(mut target) = target.init(field)? else {
+                    return Err(Error::from_kind(ErrorKind::TypeMismatch));
+                };
+                target.set_as(p)
+            }
+            value => target.set(field, value),
+        };
+    }
+    let name = field.get_proto().get_name()?.to_str()?;
+    match source.get(field)? {
+        dynamic_value::Reader::Struct(inner_source) if node.elements.is_none() => {
+            let inner_target = if is_group && !is_union_member(field) {
+                target.get(field)?
+            } else {
+                target.init(field)?
+            };
+            project_struct(node, inner_source, inner_target.downcast())
+        }
+        dynamic_value::Reader::List(list) if node.fields.is_empty() => {
+            let Some(elements) = &node.elements else {
+                return Err(not_a_container(name));
+            };
+            let target = target.initn(field, list.len())?.downcast();
+            project_list(elements, name, list, target)
+        }
+        _ => Err(not_a_container(name)),
+    }
+}
+
+fn project_list(
+    node: &Node,
+    name: &str,
+    source: dynamic_list::Reader,
+    mut target: dynamic_list::Builder,
+) -> Result<()> {
+    let nullable = matches!(
+        source.element_type().which(),
+        TypeVariant::Text | TypeVariant::Data | TypeVariant::List(_)
+    );
+    for index in 0..source.len() {
+        if node.all {
+            if !(nullable && source.reader.get_pointer_element(index).is_null()) {
+                target.set(index, source.get(index)?)?;
+            }
+            continue;
+        }
+        match source.get(index)? {
+            dynamic_value::Reader::Struct(element) if node.elements.is_none() => {
+                project_struct(node, element, target.reborrow().get(index)?.downcast())?
+            }
+            dynamic_value::Reader::List(element) if node.fields.is_empty() => {
+                let Some(elements) = &node.elements else {
+                    return Err(not_a_container(name));
+                };
+                if source.reader.get_pointer_element(index).is_null() {
+                    continue;
+                }
+                let inner_target = target.reborrow().init(index, element.len())?.downcast();
+                project_list(elements, name, element, inner_target)?;
+            }
+            _ => return Err(not_a_container(name)),
+        }
+    }
+    Ok(())
+}
+
+fn retain_struct(node: &Node, mut builder: dynamic_struct::Builder) -> Result<()> {
+    let schema = builder.get_schema();
+    for name in node.fields.keys() {
+        // Fail early on misspelled paths, rather than clearing everything.
+        schema.get_field_by_name(name)?;
+    }
+    let active = builder.which()?.map(|f| f.get_index());
+    for field in schema.get_fields()? {
+        if is_union_member(field) && active != Some(field.get_index()) {
+            continue;
+        }
+        let name = field.get_proto().get_name()?.to_str()?;
+        match node.fields.get(name) {
+            None => builder.clear(field)?,
+            Some(child) if child.all => (),
+            Some(child) => {
+                if field.get_type().is_pointer_type() && !builder.has(field)? {
+                    continue;
+                }
+                match builder.reborrow().get(field)? {
+                    dynamic_value::Builder::Struct(inner) if child.elements.is_none() => {
+                        retain_struct(child, inner)?
+                    }
+                    dynamic_value::Builder::List(list) if child.fields.is_empty() => {
+                        let Some(elements) = &child.elements else {
+                            return Err(not_a_container(name));
+                        };
+                        retain_list(elements, name, list)?
+                    }
+                    _ => return Err(not_a_container(name)),
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn retain_list(node: &Node, name: &str, mut list: dynamic_list::Builder) -> Result<()> {
+    if node.all {
+        return Ok(());
+    }
+    if !matches!(
+        list.element_type().which(),
+        TypeVariant::Struct(_) | TypeVariant::List(_)
+    ) {
+        return Err(not_a_container(name));
+    }
+    for index in 0..list.len() {
+        match list.reborrow().get(index)? {
+            dynamic_value::Builder::Struct(element) if node.elements.is_none() => {
+                retain_struct(node, element)?
+            }
+            dynamic_value::Builder::List(element) if node.fields.is_empty() => {
+                let Some(elements) = &node.elements else {
+                    return Err(not_a_container(name));
+                };
+                retain_list(elements, name, element)?
+            }
+            _ => return Err(not_a_container(name)),
+        }
+    }
+    Ok(())
+}
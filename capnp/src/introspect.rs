@@ -1,30 +1,36 @@
 //! Traits and types to support run-time type introspection, i.e. reflection.
 
 use crate::private::layout::ElementSize;
+#[cfg(all(feature = "alloc", feature = "std"))]
+use crate::schema_loader::LoadedSchema;
 
 /// A type that supports reflection. All types that can appear in a Cap'n Proto message
 /// implement this trait.
 pub trait Introspect {
     /// Retrieves a description of the type.
-    fn introspect() -> Type;
+    fn introspect() -> Type<'static>;
 }
 
 /// A description of a Cap'n Proto type. The representation is
 /// optimized to avoid heap allocation.
 ///
+/// The lifetime is that of the schemas that the type refers to. It is `'static` for
+/// types from generated code, and the lifetime of the
+/// [`SchemaLoader`](crate::schema_loader::SchemaLoader) for types that were loaded at run time.
+///
 /// To examine a `Type`, you should call the `which()` method.
 #[derive(Copy, Clone, PartialEq, Eq)]
-pub struct Type {
+pub struct Type<'a> {
     /// The type, minus any outer `List( )`.
-    base: BaseType,
+    base: BaseType<'a>,
 
     /// How many times `base` is wrapped in `List( )`.
     list_count: usize,
 }
 
-impl Type {
+impl<'a> Type<'a> {
     /// Constructs a new `Type` that is not a list.
-    fn new_base(base: BaseType) -> Self {
+    fn new_base(base: BaseType<'a>) -> Self {
         Self {
             base,
             list_count: 0,
@@ -32,13 +38,26 @@ impl Type {
     }
 
     /// Constructs a new `Type` that is a list wrapping some other `Type`.
-    pub fn list_of(mut element_type: Type) -> Self {
+    pub fn list_of(mut element_type: Type<'a>) -> Self {
         element_type.list_count += 1;
         element_type
     }
 
+    /// Constructs the type of a capability whose interface is not known statically. Its
+    /// schema describes an interface named `Capability` with no methods and no superclasses.
+    pub fn untyped_capability() -> Self {
+        Type::new_base(BaseType::Capability(InterfaceType::Raw(
+            RawBrandedInterfaceSchema {
+                generic: &UNTYPED_CAPABILITY_SCHEMA,
+                method_types: untyped_capability_method_types,
+                superclass_types: untyped_capability_superclass_types,
+                annotation_types: untyped_capability_annotation_types,
+            },
+        )))
+    }
+
     /// Unfolds a single layer of the `Type`, to allow for pattern matching.
-    pub fn which(&self) -> TypeVariant {
+    pub fn which(&self) -> TypeVariant<'a> {
         if self.list_count > 0 {
             TypeVariant::List(Type {
                 base: self.base,
@@ -63,7 +82,7 @@ impl Type {
                 BaseType::Enum(re) => TypeVariant::Enum(re),
                 BaseType::Struct(rs) => TypeVariant::Struct(rs),
                 BaseType::AnyPointer => TypeVariant::AnyPointer,
-                BaseType::Capability => TypeVariant::Capability,
+                BaseType::Capability(ri) => TypeVariant::Capability(ri),
             }
         }
     }
@@ -81,9 +100,10 @@ impl Type {
                 BaseType::Int16 | BaseType::UInt16 | BaseType::Enum(_) => ElementSize::TwoBytes,
                 BaseType::Int32 | BaseType::UInt32 | BaseType::Float32 => ElementSize::FourBytes,
                 BaseType::Int64 | BaseType::UInt64 | BaseType::Float64 => ElementSize::EightBytes,
-                BaseType::Text | BaseType::Data | BaseType::AnyPointer | BaseType::Capability => {
-                    ElementSize::Pointer
-                }
+                BaseType::Text
+                | BaseType::Data
+                | BaseType::AnyPointer
+                | BaseType::Capability(_) => ElementSize::Pointer,
                 BaseType::Struct(_) => ElementSize::InlineComposite,
             }
         }
@@ -100,7 +120,7 @@ impl Type {
                     | BaseType::Data
                     | BaseType::AnyPointer
                     | BaseType::Struct(_)
-                    | BaseType::Capability
+                    | BaseType::Capability(_)
             )
         }
     }
@@ -109,7 +129,7 @@ impl Type {
 #[derive(Copy, Clone, PartialEq, Eq)]
 /// A `Type` unfolded one level. Suitable for pattern matching. Can be trivially
 /// converted to `Type` via the `From`/`Into` traits.
-pub enum TypeVariant {
+pub enum TypeVariant<'a> {
     Void,
     Bool,
     Int8,
@@ -124,15 +144,15 @@ pub enum TypeVariant {
     Float64,
     Text,
     Data,
-    Struct(RawBrandedStructSchema),
+    Struct(StructType<'a>),
     AnyPointer,
-    Capability,
-    Enum(RawEnumSchema),
-    List(Type),
+    Capability(InterfaceType<'a>),
+    Enum(EnumType<'a>),
+    List(Type<'a>),
 }
 
-impl From<TypeVariant> for Type {
-    fn from(tv: TypeVariant) -> Type {
+impl<'a> From<TypeVariant<'a>> for Type<'a> {
+    fn from(tv: TypeVariant<'a>) -> Type<'a> {
         match tv {
             TypeVariant::Void => Type::new_base(BaseType::Void),
             TypeVariant::Bool => Type::new_base(BaseType::Bool),
@@ -150,7 +170,7 @@ impl From<TypeVariant> for Type {
             TypeVariant::Data => Type::new_base(BaseType::Data),
             TypeVariant::Struct(rbs) => Type::new_base(BaseType::Struct(rbs)),
             TypeVariant::AnyPointer => Type::new_base(BaseType::AnyPointer),
-            TypeVariant::Capability => Type::new_base(BaseType::Capability),
+            TypeVariant::Capability(ri) => Type::new_base(BaseType::Capability(ri)),
             TypeVariant::Enum(es) => Type::new_base(BaseType::Enum(es)),
             TypeVariant::List(list) => Type::list_of(list),
         }
@@ -159,7 +179,7 @@ impl From<TypeVariant> for Type {
 
 /// A Cap'n Proto type, excluding `List`.
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
-enum BaseType {
+enum BaseType<'a> {
     Void,
     Bool,
     Int8,
@@ -174,16 +194,16 @@ enum BaseType {
     Float64,
     Text,
     Data,
-    Struct(RawBrandedStructSchema),
+    Struct(StructType<'a>),
     AnyPointer,
-    Capability,
-    Enum(RawEnumSchema),
+    Capability(InterfaceType<'a>),
+    Enum(EnumType<'a>),
 }
 
 macro_rules! primitive_introspect(
     ($t:ty, $v:ident) => (
         impl Introspect for $t {
-            fn introspect() -> Type { Type::new_base(BaseType::$v) }
+            fn introspect() -> Type<'static> { Type::new_base(BaseType::$v) }
         }
     )
 );
@@ -227,11 +247,11 @@ pub struct RawBrandedStructSchema {
     pub generic: &'static RawStructSchema,
 
     /// Map from field index (not ordinal) to Type.
-    pub field_types: fn(u16) -> Type,
+    pub field_types: fn(u16) -> Type<'static>,
 
     /// Map from (maybe field index, annotation index) to the Type
     /// of the value held by that annotation.
-    pub annotation_types: fn(Option<u16>, u32) -> Type,
+    pub annotation_types: fn(Option<u16>, u32) -> Type<'static>,
 }
 
 impl core::cmp::PartialEq for RawBrandedStructSchema {
@@ -249,7 +269,7 @@ impl core::fmt::Debug for RawBrandedStructSchema {
         write!(
             f,
             "RawBrandedStructSchema({:?}, {:?})",
-            self.generic as *const _, self.field_types as *const fn(u16) -> Type
+            self.generic as *const _, self.field_types as *const fn(u16) -> Type<'static>
         )
     }
 }
@@ -265,7 +285,7 @@ pub struct RawEnumSchema {
 
     /// Map from (maybe enumerant index, annotation index) to the Type
     /// of the value held by that annotation.
-    pub annotation_types: fn(Option<u16>, u32) -> Type,
+    pub annotation_types: fn(Option<u16>, u32) -> Type<'static>,
 }
 
 impl core::cmp::PartialEq for RawEnumSchema {
@@ -281,3 +301,299 @@ impl core::fmt::Debug for RawEnumSchema {
         write!(f, "RawEnumSchema({:?})", self.encoded_node as *const _)
     }
 }
+
+/// Type information that gets included in the generated code for every
+/// user-defined Cap'n Proto interface.
+#[derive(Copy, Clone)]
+pub struct RawInterfaceSchema {
+    /// The Node (as defined in schema.capnp), as a single segment message.
+    pub encoded_node: &'static [crate::Word],
+
+    /// Indices (which are also ordinals) of methods, sorted by their respective names.
+    pub methods_by_name: &'static [u16],
+}
+
+/// The schema behind `Type::untyped_capability()`.
+static UNTYPED_CAPABILITY_SCHEMA: RawInterfaceSchema = RawInterfaceSchema {
+    encoded_node: &UNTYPED_CAPABILITY_NODE,
+    methods_by_name: &[],
+};
+
+static UNTYPED_CAPABILITY_NODE: [crate::Word; 12] = [
+    crate::word(0, 0, 0, 0, 2, 0, 5, 0),
+    crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+    crate::word(0, 0, 0, 0, 3, 0, 0, 0),
+    crate::word(17, 0, 0, 0, 90, 0, 0, 0),
+    crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+    crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+    crate::word(13, 0, 0, 0, 7, 0, 0, 0),
+    crate::word(13, 0, 0, 0, 7, 0, 0, 0),
+    crate::word(67, 97, 112, 97, 98, 105, 108, 105),
+    crate::word(116, 121, 0, 0, 0, 0, 0, 0),
+    crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+    crate::word(0, 0, 0, 0, 0, 0, 0, 0),
+];
+
+fn untyped_capability_method_types(ordinal: u16) -> (Type<'static>, Type<'static>) {
+    panic!("invalid method ordinal {ordinal}")
+}
+
+fn untyped_capability_superclass_types(index: u32) -> Type<'static> {
+    panic!("invalid superclass index {index}")
+}
+
+fn untyped_capability_annotation_types(child_index: Option<u16>, index: u32) -> Type<'static> {
+    panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
+}
+
+/// A RawInterfaceSchema with branding information, i.e. resolution of type parameters.
+/// To use one of this, you will usually want to convert it to a `schema::InterfaceSchema`,
+/// which can be done via `into()`.
+#[derive(Copy, Clone)]
+pub struct RawBrandedInterfaceSchema {
+    /// The unbranded base schema.
+    pub generic: &'static RawInterfaceSchema,
+
+    /// Map from method ordinal to the Types of the method's params and results structs.
+    pub method_types: fn(u16) -> (Type<'static>, Type<'static>),
+
+    /// Map from superclass index to the Type of the superclass.
+    pub superclass_types: fn(u32) -> Type<'static>,
+
+    /// Map from (maybe method ordinal, annotation index) to the Type
+    /// of the value held by that annotation.
+    pub annotation_types: fn(Option<u16>, u32) -> Type<'static>,
+}
+
+impl core::cmp::PartialEq for RawBrandedInterfaceSchema {
+    fn eq(&self, other: &Self) -> bool {
+        core::ptr::eq(self.generic, other.generic)
+            && core::ptr::eq(
+                self.method_types as *const (),
+                other.method_types as *const (),
+            )
+        // don't need to compare superclass_types or annotation_types.
+        // those fields are equal iff method_types is.
+    }
+}
+
+impl core::cmp::Eq for RawBrandedInterfaceSchema {}
+
+impl core::fmt::Debug for RawBrandedInterfaceSchema {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::result::Result<(), core::fmt::Error> {
+        write!(
+            f,
+            "RawBrandedInterfaceSchema({:?}, {:?})",
+            self.generic as *const _,
+            self.method_types as *const fn(u16) -> (Type<'static>, Type<'static>)
+        )
+    }
+}
+
+/// Stands in for `schema_loader::LoadedSchema` in builds without the schema loader.
+/// It has no values, so the `Loaded` variants below can never be constructed.
+#[cfg(not(all(feature = "alloc", feature = "std")))]
+#[doc(hidden)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct LoadedSchema<'a>(core::marker::PhantomData<&'a ()>, core::convert::Infallible);
+
+#[cfg(not(all(feature = "alloc", feature = "std")))]
+impl<'a> LoadedSchema<'a> {
+    fn encoded_node(&self) -> &'a [crate::Word] {
+        match self.1 {}
+    }
+
+    fn nonunion_members(&self) -> &'a [u16] {
+        match self.1 {}
+    }
+
+    fn members_by_discriminant(&self) -> &'a [u16] {
+        match self.1 {}
+    }
+
+    fn members_by_name(&self) -> &'a [u16] {
+        match self.1 {}
+    }
+
+    fn field_type(&self, _index: u16) -> Type<'a> {
+        match self.1 {}
+    }
+
+    fn method_types(&self, _ordinal: u16) -> (Type<'a>, Type<'a>) {
+        match self.1 {}
+    }
+
+    fn superclass_type(&self, _index: u32) -> Type<'a> {
+        match self.1 {}
+    }
+
+    fn annotation_type(&self, _child_index: Option<u16>, _index: u32) -> Type<'a> {
+        match self.1 {}
+    }
+}
+
+/// A struct type with its type parameters resolved. Usually this wraps a
+/// `RawBrandedStructSchema` from generated code, but it can also refer to a
+/// schema that was loaded at run time by a [`SchemaLoader`](crate::schema_loader::SchemaLoader).
+/// To use one of these, you will usually want to convert it to a `schema::StructSchema`,
+/// which can be done via `into()`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[non_exhaustive]
+pub enum StructType<'a> {
+    /// Schema emitted by the code generator.
+    Raw(RawBrandedStructSchema),
+
+    /// Schema loaded at run time.
+    Loaded(LoadedSchema<'a>),
+}
+
+impl<'a> StructType<'a> {
+    /// The Node (as defined in schema.capnp), as a single segment message.
+    pub(crate) fn encoded_node(&self) -> &'a [crate::Word] {
+        match self {
+            Self::Raw(raw) => raw.generic.encoded_node,
+            Self::Loaded(loaded) => loaded.encoded_node(),
+        }
+    }
+
+    /// Indices of fields that don't have a discriminant value.
+    pub(crate) fn nonunion_members(&self) -> &'a [u16] {
+        match self {
+            Self::Raw(raw) => raw.generic.nonunion_members,
+            Self::Loaded(loaded) => loaded.nonunion_members(),
+        }
+    }
+
+    /// Map from discriminant value to field index.
+    pub(crate) fn members_by_discriminant(&self) -> &'a [u16] {
+        match self {
+            Self::Raw(raw) => raw.generic.members_by_discriminant,
+            Self::Loaded(loaded) => loaded.members_by_discriminant(),
+        }
+    }
+
+    /// Indices of fields, sorted by their respective names.
+    pub(crate) fn members_by_name(&self) -> &'a [u16] {
+        match self {
+            Self::Raw(raw) => raw.generic.members_by_name,
+            Self::Loaded(loaded) => loaded.members_by_name(),
+        }
+    }
+
+    pub(crate) fn field_type(&self, index: u16) -> Type<'a> {
+        match self {
+            Self::Raw(raw) => (raw.field_types)(index),
+            Self::Loaded(loaded) => loaded.field_type(index),
+        }
+    }
+
+    pub(crate) fn annotation_type(&self, child_index: Option<u16>, index: u32) -> Type<'a> {
+        match self {
+            Self::Raw(raw) => (raw.annotation_types)(child_index, index),
+            Self::Loaded(loaded) => loaded.annotation_type(child_index, index),
+        }
+    }
+}
+
+impl From<RawBrandedStructSchema> for StructType<'_> {
+    fn from(raw: RawBrandedStructSchema) -> Self {
+        Self::Raw(raw)
+    }
+}
+
+/// An enum type. Usually this wraps a `RawEnumSchema` from generated code,
+/// but it can also refer to a schema that was loaded at run time by a
+/// [`SchemaLoader`](crate::schema_loader::SchemaLoader).
+/// To use one of these, you will usually want to convert it to a `schema::EnumSchema`,
+/// which can be done via `into()`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[non_exhaustive]
+pub enum EnumType<'a> {
+    /// Schema emitted by the code generator.
+    Raw(RawEnumSchema),
+
+    /// Schema loaded at run time.
+    Loaded(LoadedSchema<'a>),
+}
+
+impl<'a> EnumType<'a> {
+    /// The Node (as defined in schema.capnp), as a single segment message.
+    pub(crate) fn encoded_node(&self) -> &'a [crate::Word] {
+        match self {
+            Self::Raw(raw) => raw.encoded_node,
+            Self::Loaded(loaded) => loaded.encoded_node(),
+        }
+    }
+
+    pub(crate) fn annotation_type(&self, child_index: Option<u16>, index: u32) -> Type<'a> {
+        match self {
+            Self::Raw(raw) => (raw.annotation_types)(child_index, index),
+            Self::Loaded(loaded) => loaded.annotation_type(child_index, index),
+        }
+    }
+}
+
+impl From<RawEnumSchema> for EnumType<'_> {
+    fn from(raw: RawEnumSchema) -> Self {
+        Self::Raw(raw)
+    }
+}
+
+/// An interface type with its type parameters resolved. Usually this wraps a
+/// `RawBrandedInterfaceSchema` from generated code, but it can also refer to a
+/// schema that was loaded at run time by a [`SchemaLoader`](crate::schema_loader::SchemaLoader).
+/// To use one of these, you will usually want to convert it to a `schema::InterfaceSchema`,
+/// which can be done via `into()`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[non_exhaustive]
+pub enum InterfaceType<'a> {
+    /// Schema emitted by the code generator.
+    Raw(RawBrandedInterfaceSchema),
+
+    /// Schema loaded at run time.
+    Loaded(LoadedSchema<'a>),
+}
+
+impl<'a> InterfaceType<'a> {
+    /// The Node (as defined in schema.capnp), as a single segment message.
+    pub(crate) fn encoded_node(&self) -> &'a [crate::Word] {
+        match self {
+            Self::Raw(raw) => raw.generic.encoded_node,
+            Self::Loaded(loaded) => loaded.encoded_node(),
+        }
+    }
+
+    pub(crate) fn methods_by_name(&self) -> &'a [u16] {
+        match self {
+            Self::Raw(raw) => raw.generic.methods_by_name,
+            Self::Loaded(loaded) => loaded.members_by_name(),
+        }
+    }
+
+    pub(crate) fn method_types(&self, ordinal: u16) -> (Type<'a>, Type<'a>) {
+        match self {
+            Self::Raw(raw) => (raw.method_types)(ordinal),
+            Self::Loaded(loaded) => loaded.method_types(ordinal),
+        }
+    }
+
+    pub(crate) fn superclass_type(&self, index: u32) -> Type<'a> {
+        match self {
+            Self::Raw(raw) => (raw.superclass_types)(index),
+            Self::Loaded(loaded) => loaded.superclass_type(index),
+        }
+    }
+
+    pub(crate) fn annotation_type(&self, child_index: Option<u16>, index: u32) -> Type<'a> {
+        match self {
+            Self::Raw(raw) => (raw.annotation_types)(child_index, index),
+            Self::Loaded(loaded) => loaded.annotation_type(child_index, index),
+        }
+    }
+}
+
+impl From<RawBrandedInterfaceSchema> for InterfaceType<'_> {
+    fn from(raw: RawBrandedInterfaceSchema) -> Self {
+        Self::Raw(raw)
+    }
+}
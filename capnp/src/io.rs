@@ -40,6 +40,16 @@ pub trait BufRead: Read {
 /// A rough approximation of std::io::Write.
 pub trait Write {
     fn write_all(&mut self, buf: &[u8]) -> Result<()>;
+
+    /// Writes all of `bufs`, one after the other. Writers that can write several buffers
+    /// in one go should override this. The default implementation calls `write_all()` on
+    /// each buffer in turn.
+    fn write_all_vectored(&mut self, bufs: &[&[u8]]) -> Result<()> {
+        for buf in bufs {
+            self.write_all(buf)?;
+        }
+        Ok(())
+    }
 }
 
 /// Blanket impls for when `std` is enabled.
@@ -75,6 +85,9 @@ mod std_impls {
         }
     }
 
+    // The number of buffers to pass to a single `write_vectored()` call.
+    const MAX_IO_SLICES: usize = 64;
+
     impl<W> Write for W
     where
         W: std::io::Write,
@@ -83,6 +96,36 @@ mod std_impls {
             std::io::Write::write_all(self, buf)?;
             Ok(())
         }
+
+        fn write_all_vectored(&mut self, mut bufs: &[&[u8]]) -> Result<()> {
+            // Like the unstable std::io::Write::write_all_vectored(). For writers that
+            // do not support vectored writes, write_vectored() writes the first buffer only.
+            let mut skip = 0; // Bytes of bufs[0] that have been written already.
+            loop {
+                while let Some(first) = bufs.first() {
+                    if skip < first.len() {
+                        break;
+                    }
+                    skip -= first.len();
+                    bufs = &bufs[1..];
+                }
+                if bufs.is_empty() {
+                    return Ok(());
+                }
+                let mut slices = [std::io::IoSlice::new(&[]); MAX_IO_SLICES];
+                let count = bufs.len().min(MAX_IO_SLICES);
+                slices[0] = std::io::IoSlice::new(&bufs[0][skip..]);
+                for (slice, buf) in slices[1..count].iter_mut().zip(&bufs[1..]) {
+                    *slice = std::io::IoSlice::new(buf);
+                }
+                match std::io::Write::write_vectored(self, &slices[..count]) {
+                    Ok(0) => return Err(std::io::Error::from(std::io::ErrorKind::WriteZero).into()),
+                    Ok(n) => skip += n,
+                    Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+                    Err(e) => return Err(e.into()),
+                }
+            }
+        }
     }
 }
 
@@ -153,6 +196,10 @@ mod no_std_impls {
         fn write_all(&mut self, buf: &[u8]) -> Result<()> {
             (**self).write_all(buf)
         }
+
+        fn write_all_vectored(&mut self, bufs: &[&[u8]]) -> Result<()> {
+            (**self).write_all_vectored(bufs)
+        }
     }
 
     impl<'a> Read for &'a [u8] {
@@ -0,0 +1,1116 @@
+//! JSON encoding and decoding of Cap'n Proto messages, driven by dynamic reflection.
+//!
+//! The mapping follows the one used by the C++ implementation's `JsonCodec`:
+//!
+//!   * Structs and groups become JSON objects. Null pointer fields and inactive union
+//!     members are omitted. The active member of a union is always written.
+//!   * Enums are written as the name of the enumerant.
+//!   * `Int64` and `UInt64` values are written as strings, so that they survive
+//!     a round trip through JavaScript numbers. Either a string or a number is accepted
+//!     when decoding.
+//!   * Non-finite floating point values are written as `"NaN"`, `"Infinity"`, and `"-Infinity"`.
+//!   * `Data` is written as a base64 string. When decoding, an array of byte values is
+//!     also accepted.
+//!   * `Void` is written as `null`.
+//!
+//! The annotations from `json.capnp` are honored, identified by the IDs in this module:
+//! `$Json.name`, `$Json.flatten`, `$Json.discriminator`, `$Json.base64` and `$Json.hex`.
+//!
+//! `AnyPointer` and capability fields cannot be represented and result in an error.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::introspect::{Type, TypeVariant};
+use crate::schema::{AnnotationList, EnumSchema, Enumerant, Field, StructSchema};
+use crate::schema_capnp::field;
+use crate::{dynamic_list, dynamic_struct, dynamic_value};
+use crate::{Error, ErrorKind, Result};
+
+/// ID of the `$Json.name` annotation, which overrides the JSON name of a field or enumerant.
+pub const NAME_ANNOTATION_ID: u64 = 0xfa5b1fd61c2e7c3d;
+
+/// ID of the `$Json.flatten` annotation, which inlines the fields of a struct or group
+/// into its parent object.
+pub const FLATTEN_ANNOTATION_ID: u64 = 0x82d3e852af0336bf;
+
+/// ID of the `$Json.discriminator` annotation, which selects a union member
+/// by a separate tag field.
+pub const DISCRIMINATOR_ANNOTATION_ID: u64 = 0xcfa794e8d19a0162;
+
+/// ID of the `$Json.base64` annotation, which requests base64 encoding of a `Data` field.
+pub const BASE64_ANNOTATION_ID: u64 = 0xd7d879450a253e4b;
+
+/// ID of the `$Json.hex` annotation, which requests hexadecimal encoding of a `Data` field.
+pub const HEX_ANNOTATION_ID: u64 = 0xf061e22f0ae5c7b5;
+
+/// Maximum nesting depth of arrays and objects accepted by the parser.
+const MAX_NESTING_DEPTH: usize = 64;
+
+/// Encodes a value, usually a struct, as JSON text.
+pub fn to_json<'a>(value: impl Into<dynamic_value::Reader<'a>>) -> Result<String> {
+    let encoded = encode_value(value.into(), DataEncoding::Base64)?;
+    let mut out = String::new();
+    write_value(&encoded, &mut out);
+    Ok(out)
+}
+
+/// Decodes JSON text into `builder`, which is usually a freshly initialized struct.
+/// Object members that do not correspond to any field are ignored.
+pub fn from_json<'a>(json: &str, builder: impl Into<dynamic_value::Builder<'a>>) -> Result<()> {
+    let value = Parser::new(json).parse_document()?;
+    let dynamic_value::Builder::Struct(builder) = builder.into() else {
+        return Err(Error::from_kind(ErrorKind::NotAStruct));
+    };
+    let Value::Object(members) = &value else {
+        return Err(Error::failed("expected a JSON object".to_string()));
+    };
+    let entries: Vec<(&str, &Value)> = members.iter().map(|(k, v)| (k.as_str(), v)).collect();
+    decode_struct(&entries, builder, None)
+}
+
+/// A parsed JSON value. Numbers are kept in their textual form so that
+/// 64-bit integers do not lose precision.
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    Null,
+    Bool(bool),
+    Number(String),
+    String(String),
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+impl Value {
+    fn kind(&self) -> &'static str {
+        match self {
+            Value::Null => "null",
+            Value::Bool(_) => "boolean",
+            Value::Number(_) => "number",
+            Value::String(_) => "string",
+            Value::Array(_) => "array",
+            Value::Object(_) => "object",
+        }
+    }
+}
+
+fn type_error(expected: &str, found: &Value) -> Error {
+    let mut error = Error::from_kind(ErrorKind::TypeMismatch);
+    write!(error, "expected JSON {expected}, found {}", found.kind());
+    error
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum DataEncoding {
+    Base64,
+    Hex,
+}
+
+/// Options of a `$Json.discriminator` annotation.
+#[derive(Clone)]
+struct Discriminator {
+    name: Option<String>,
+    value_name: Option<String>,
+}
+
+/// The JSON-relevant annotations found on a field.
+struct FieldOptions {
+    name: String,
+    flatten_prefix: Option<String>,
+    data_encoding: DataEncoding,
+    discriminator: Option<Discriminator>,
+}
+
+fn annotation_text(annotations: AnnotationList, id: u64) -> Result<Option<String>> {
+    match annotations.find(id) {
+        None => Ok(None),
+        Some(annotation) => match annotation.get_value()? {
+            dynamic_value::Reader::Text(t) => Ok(Some(t.to_str()?.to_string())),
+            _ => Err(Error::from_kind(ErrorKind::TypeMismatch)),
+        },
+    }
+}
+
+fn optional_text_member(st: dynamic_struct::Reader, name: &str) -> Result<Option<String>> {
+    if st.has_named(name)? {
+        Ok(Some(
+            st.get_named(name)?
+                .downcast::<crate::text::Reader>()
+                .to_string()?,
+        ))
+    } else {
+        Ok(None)
+    }
+}
+
+fn discriminator_options(annotations: AnnotationList) -> Result<Option<Discriminator>> {
+    match annotations.find(DISCRIMINATOR_ANNOTATION_ID) {
+        None => Ok(None),
+        Some(annotation) => match annotation.get_value()? {
+            dynamic_value::Reader::Struct(st) => Ok(Some(Discriminator {
+                name: optional_text_member(st, "name")?,
+                value_name: optional_text_member(st, "valueName")?,
+            })),
+            _ => Ok(Some(Discriminator {
+                name: None,
+                value_name: None,
+            })),
+        },
+    }
+}
+
+fn field_options(field: Field) -> Result<FieldOptions> {
+    let annotations = field.get_annotations()?;
+    let name = match annotation_text(annotations, NAME_ANNOTATION_ID)? {
+        Some(name) => name,
+        None => field.get_proto().get_name()?.to_string()?,
+    };
+    let flatten_prefix = match annotations.find(FLATTEN_ANNOTATION_ID) {
+        None => None,
+        Some(annotation) => match annotation.get_value()? {
+            dynamic_value::Reader::Struct(st) => {
+                Some(optional_text_member(st, "prefix")?.unwrap_or_default())
+            }
+            _ => Some(String::new()),
+        },
+    };
+    let data_encoding = if annotations.find(HEX_ANNOTATION_ID).is_some() {
+        DataEncoding::Hex
+    } else {
+        DataEncoding::Base64
+    };
+
+    // A discriminator on a named union may be attached either to the group field
+    // or to the group's own node.
+    let mut discriminator = discriminator_options(annotations)?;
+    if discriminator.is_none() {
+        if let (field::Group(_), TypeVariant::Struct(group)) =
+            (field.get_proto().which()?, field.get_type().which())
+        {
+            discriminator = discriminator_options(StructSchema::from(group).get_annotations()?)?;
+        }
+    }
+    Ok(FieldOptions {
+        name,
+        flatten_prefix,
+        data_encoding,
+        discriminator,
+    })
+}
+
+fn is_union_member(field: Field) -> bool {
+    field.get_proto().get_discriminant_value() != field::NO_DISCRIMINANT
+}
+
+fn is_struct_like(field: Field) -> Result<bool> {
+    Ok(match field.get_proto().which()? {
+        field::Group(_) => true,
+        field::Slot(_) => matches!(field.get_type().which(), TypeVariant::Struct(_)),
+    })
+}
+
+/// Resolves the name of the discriminator field of a union. `union_name` is `None`
+/// for the unnamed union of a struct.
+fn discriminator_name(discriminator: &Discriminator, union_name: Option<&str>) -> Result<String> {
+    match (&discriminator.name, union_name) {
+        (Some(name), _) => Ok(name.clone()),
+        (None, Some(union_name)) => Ok(union_name.to_string()),
+        (None, None) => Err(Error::failed(
+            "$Json.discriminator on an unnamed union must specify a name".to_string(),
+        )),
+    }
+}
+
+fn enumerant_json_name(enumerant: Enumerant) -> Result<String> {
+    match annotation_text(enumerant.get_annotations()?, NAME_ANNOTATION_ID)? {
+        Some(name) => Ok(name),
+        None => Ok(enumerant.get_proto().get_name()?.to_string()?),
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Encoding
+
+fn encode_value(value: dynamic_value::Reader, data_encoding: DataEncoding) -> Result<Value> {
+    Ok(match value {
+        dynamic_value::Reader::Void => Value::Null,
+        dynamic_value::Reader::Bool(b) => Value::Bool(b),
+        dynamic_value::Reader::Int8(x) => Value::Number(x.to_string()),
+        dynamic_value::Reader::Int16(x) => Value::Number(x.to_string()),
+        dynamic_value::Reader::Int32(x) => Value::Number(x.to_string()),
+        dynamic_value::Reader::Int64(x) => Value::String(x.to_string()),
+        dynamic_value::Reader::UInt8(x) => Value::Number(x.to_string()),
+        dynamic_value::Reader::UInt16(x) => Value::Number(x.to_string()),
+        dynamic_value::Reader::UInt32(x) => Value::Number(x.to_string()),
+        dynamic_value::Reader::UInt64(x) => Value::String(x.to_string()),
+        dynamic_value::Reader::Float32(x) => encode_float(x as f64, x.to_string()),
+        dynamic_value::Reader::Float64(x) => encode_float(x, x.to_string()),
+        dynamic_value::Reader::Enum(e) => match e.get_enumerant()? {
+            Some(enumerant) => Value::String(enumerant_json_name(enumerant)?),
+            None => Value::Number(e.get_value().to_string()),
+        },
+        dynamic_value::Reader::Text(t) => Value::String(t.to_string()?),
+        dynamic_value::Reader::Data(d) => Value::String(match data_encoding {
+            DataEncoding::Base64 => base64_encode(d),
+            DataEncoding::Hex => hex_encode(d),
+        }),
+        dynamic_value::Reader::List(list) => {
+            let mut elements = Vec::with_capacity(list.len() as usize);
+            for element in list.iter() {
+                elements.push(encode_value(element?, data_encoding)?);
+            }
+            Value::Array(elements)
+        }
+        dynamic_value::Reader::Struct(st) => {
+            let mut members = Vec::new();
+            encode_struct(st, None, "", &mut members)?;
+            Value::Object(members)
+        }
+        dynamic_value::Reader::AnyPointer(_) => {
+            return Err(Error::unimplemented(
+                "AnyPointer values cannot be encoded as JSON".to_string(),
+            ))
+        }
+        dynamic_value::Reader::Capability(_) => {
+            return Err(Error::unimplemented(
+                "capabilities cannot be encoded as JSON".to_string(),
+            ))
+        }
+    })
+}
+
+fn encode_float(x: f64, finite: String) -> Value {
+    if x.is_nan() {
+        Value::String("NaN".to_string())
+    } else if x.is_infinite() {
+        Value::String(if x > 0.0 { "Infinity" } else { "-Infinity" }.to_string())
+    } else {
+        Value::Number(finite)
+    }
+}
+
+/// Appends the members of `st` to `members`, prefixing each name with `prefix`.
+/// `discriminator` comes from the field holding `st`, if any.
+fn encode_struct(
+    st: dynamic_struct::Reader,
+    discriminator: Option<Discriminator>,
+    prefix: &str,
+    members: &mut Vec<(String, Value)>,
+) -> Result<()> {
+    let schema = st.get_schema();
+    let discriminator = match discriminator {
+        Some(d) => Some(d),
+        None => discriminator_options(schema.get_annotations()?)?,
+    };
+    let active = st.which()?;
+    for field in schema.get_fields()? {
+        let union_member = is_union_member(field);
+        if union_member && active.map(|f| f.get_index()) != Some(field.get_index()) {
+            continue;
+        }
+        if !union_member && !st.has(field)? {
+            continue;
+        }
+        let options = field_options(field)?;
+        let mut key = options.name.clone();
+        if union_member {
+            if let Some(d) = &discriminator {
+                members.push((
+                    alloc::format!("{prefix}{}", discriminator_name(d, None)?),
+                    Value::String(options.name.clone()),
+                ));
+                if let TypeVariant::Void = field.get_type().which() {
+                    continue;
+                }
+                if let Some(value_name) = &d.value_name {
+                    key = value_name.clone();
+                }
+            }
+        }
+        let value = st.get(field)?;
+        match (&options.flatten_prefix, value) {
+            (Some(flatten_prefix), dynamic_value::Reader::Struct(inner)) => {
+                if st.has(field)? {
+                    let inner_prefix = alloc::format!("{prefix}{flatten_prefix}");
+                    encode_struct(inner, options.discriminator, &inner_prefix, members)?;
+                }
+            }
+            (_, dynamic_value::Reader::Struct(inner)) if st.has(field)? => {
+                let mut inner_members = Vec::new();
+                let inner_discriminator = match options.discriminator {
+                    Some(d) => Some(Discriminator {
+                        name: Some(discriminator_name(&d, Some(&options.name))?),
+                        value_name: d.value_name,
+                    }),
+                    None => None,
+                };
+                encode_struct(inner, inner_discriminator, "", &mut inner_members)?;
+                members.push((
+                    alloc::format!("{prefix}{key}"),
+                    Value::Object(inner_members),
+                ));
+            }
+            _ if field.get_type().is_pointer_type() && !st.has(field)? => {
+                members.push((alloc::format!("{prefix}{key}"), Value::Null));
+            }
+            (_, value) => {
+                members.push((
+                    alloc::format!("{prefix}{key}"),
+                    encode_value(value, options.data_encoding)?,
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+// ----------------------------------------------------------------------------
+// Decoding
+
+/// Fills `builder` from the object members in `entries`.
+/// `discriminator` comes from the field holding the struct, if any.
+fn decode_struct(
+    entries: &[(&str, &Value)],
+    mut builder: dynamic_struct::Builder,
+    discriminator: Option<Discriminator>,
+) -> Result<()> {
+    let schema = builder.get_schema();
+    let discriminator = match discriminator {
+        Some(d) => Some(d),
+        None => discriminator_options(schema.get_annotations()?)?,
+    };
+
+    // With a discriminator, the tag alone decides which union member is active.
+    let tag = match &discriminator {
+        Some(d) => {
+            let tag_name = discriminator_name(d, None)?;
+            match lookup(entries, &tag_name) {
+                Some(Value::String(tag)) => Some(tag.as_str()),
+                Some(other) => return Err(type_error("string", other)),
+                None => None,
+            }
+        }
+        None => None,
+    };
+
+    for field in schema.get_fields()? {
+        let options = field_options(field)?;
+        let mut key = options.name.as_str();
+        if is_union_member(field) {
+            if let Some(d) = &discriminator {
+                if tag != Some(options.name.as_str()) {
+                    continue;
+                }
+                if let Some(value_name) = &d.value_name {
+                    key = value_name;
+                }
+                if options.flatten_prefix.is_none() && lookup(entries, key).is_none() {
+                    // The tag selects the member even when no value is present.
+                    builder.clear(field)?;
+                    continue;
+                }
+            }
+        }
+
+        if let Some(flatten_prefix) = &options.flatten_prefix {
+            let inner: Vec<(&str, &Value)> = entries
+                .iter()
+                .filter_map(|(k, v)| k.strip_prefix(flatten_prefix.as_str()).map(|k| (k, *v)))
+                .collect();
+            if !is_struct_like(field)? {
+                return Err(Error::failed(alloc::format!(
+                    "$Json.flatten applied to non-struct field {}",
+                    options.name
+                )));
+            }
+            let selected = is_union_member(field) && discriminator.is_some();
+            if !selected && !claims_any(field, &inner)? {
+                continue;
+            }
+            let inner_builder = match field.get_proto().which()? {
+                field::Group(_) if !is_union_member(field) => builder.reborrow().get(field)?,
+                _ => builder.reborrow().init(field)?,
+            };
+            decode_struct(&inner, inner_builder.downcast(), options.discriminator)?;
+            continue;
+        }
+
+        if let Some(value) = lookup(entries, key) {
+            decode_field(builder.reborrow(), field, &options, value)?;
+        }
+    }
+    Ok(())
+}
+
+fn lookup<'v>(entries: &[(&str, &'v Value)], key: &str) -> Option<&'v Value> {
+    entries.iter().find(|(k, _)| *k == key).map(|(_, v)| *v)
+}
+
+/// Returns true if any of `entries` names a field of the struct type of `field`.
+fn claims_any(field: Field, entries: &[(&str, &Value)]) -> Result<bool> {
+    let TypeVariant::Struct(inner) = field.get_type().which() else {
+        return Ok(false);
+    };
+    for inner_field in StructSchema::from(inner).get_fields()? {
+        let options = field_options(inner_field)?;
+        let claimed = match &options.flatten_prefix {
+            Some(_) => true,
+            None => lookup(entries, &options.name).is_some(),
+        };
+        if claimed {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+fn decode_field<'a>(
+    mut builder: dynamic_struct::Builder<'a>,
+    field: Field<'a>,
+    options: &FieldOptions,
+    value: &Value,
+) -> Result<()> {
+    if let Value::Null = value {
+        return builder.clear(field);
+    }
+    if is_struct_like(field)? {
+        let Value::Object(members) = value else {
+            return Err(type_error("object", value));
+        };
+        let entries: Vec<(&str, &Value)> = members.iter().map(|(k, v)| (k.as_str(), v)).collect();
+        let inner_discriminator = match &options.discriminator {
+            Some(d) => Some(Discriminator {
+                name: Some(discriminator_name(d, Some(&options.name))?),
+                value_name: d.value_name.clone(),
+            }),
+            None => None,
+        };
+        let inner = builder.init(field)?;
+        return decode_struct(&entries, inner.downcast(), inner_discriminator);
+    }
+    let ty = field.get_type();
+    match ty.which() {
+        TypeVariant::List(element_type) => {
+            let Value::Array(elements) = value else {
+                return Err(type_error("array", value));
+            };
+            let list = builder.initn(field, elements.len() as u32)?;
+            decode_list(
+                list.downcast(),
+                element_type,
+                elements,
+                options.data_encoding,
+            )
+        }
+        TypeVariant::Text => {
+            let Value::String(s) = value else {
+                return Err(type_error("string", value));
+            };
+            builder.set(field, dynamic_value::Reader::Text(s.as_str().into()))
+        }
+        TypeVariant::Data => {
+            let bytes = decode_data(value, options.data_encoding)?;
+            builder.set(field, dynamic_value::Reader::Data(&bytes))
+        }
+        _ => builder.set(field, decode_primitive(ty, value)?),
+    }
+}
+
+fn decode_list(
+    mut list: dynamic_list::Builder,
+    element_type: Type,
+    elements: &[Value],
+    data_encoding: DataEncoding,
+) -> Result<()> {
+    for (idx, element) in elements.iter().enumerate() {
+        let idx = idx as u32;
+        match element_type.which() {
+            TypeVariant::Struct(_) => {
+                let Value::Object(members) = element else {
+                    return Err(type_error("object", element));
+                };
+                let entries: Vec<(&str, &Value)> =
+                    members.iter().map(|(k, v)| (k.as_str(), v)).collect();
+                let inner = list.reborrow().get(idx)?;
+                decode_struct(&entries, inner.downcast(), None)?;
+            }
+            TypeVariant::List(inner_type) => {
+                let Value::Array(inner_elements) = element else {
+                    return Err(type_error("array", element));
+                };
+                let inner = list.reborrow().init(idx, inner_elements.len() as u32)?;
+                decode_list(inner.downcast(), inner_type, inner_elements, data_encoding)?;
+            }
+            TypeVariant::Text => {
+                let Value::String(s) = element else {
+                    return Err(type_error("string", element));
+                };
+                list.set(idx, dynamic_value::Reader::Text(s.as_str().into()))?;
+            }
+            TypeVariant::Data => {
+                let bytes = decode_data(element, data_encoding)?;
+                list.set(idx, dynamic_value::Reader::Data(&bytes))?;
+            }
+            _ => list.set(idx, decode_primitive(element_type, element)?)?,
+        }
+    }
+    Ok(())
+}
+
+fn decode_data(value: &Value, data_encoding: DataEncoding) -> Result<Vec<u8>> {
+    match value {
+        Value::String(s) => match data_encoding {
+            DataEncoding::Base64 => base64_decode(s),
+            DataEncoding::Hex => hex_decode(s),
+        },
+        Value::Array(elements) => {
+            let mut bytes = Vec::with_capacity(elements.len());
+            for element in elements {
+                bytes.push(decode_integer::<u8>(element)?);
+            }
+            Ok(bytes)
+        }
+        _ => Err(type_error("string", value)),
+    }
+}
+
+fn decode_integer<T>(value: &Value) -> Result<T>
+where
+    T: core::str::FromStr,
+{
+    let text = match value {
+        Value::Number(n) => n.as_str(),
+        Value::String(s) => s.trim(),
+        _ => return Err(type_error("number", value)),
+    };
+    if let Ok(x) = text.parse::<T>() {
+        return Ok(x);
+    }
+    // Accept integral values written with a fraction or exponent, e.g. `1e3`. This is done
+    // on the decimal digits rather than through `f64`, so that nothing is rounded.
+    integral_digits(text)
+        .and_then(|digits| digits.parse::<T>().ok())
+        .ok_or_else(|| Error::failed(alloc::format!("integer value out of range: {text}")))
+}
+
+// Rewrites a number with a fraction or exponent as plain integer digits, if its value is
+// an integer. Returns `None` otherwise, or if it has too many digits for any integer type.
+fn integral_digits(text: &str) -> Option<String> {
+    const MAX_DIGITS: usize = 40;
+    let (negative, text) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text),
+    };
+    let (mantissa, exponent) = match text.find(['e', 'E']) {
+        Some(i) => (&text[..i], text[i + 1..].parse::<i64>().ok()?),
+        None => (text, 0),
+    };
+    let (int_part, frac_part) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    if int_part.is_empty() && frac_part.is_empty()
+        || !int_part
+            .bytes()
+            .chain(frac_part.bytes())
+            .all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+    let mut digits = String::with_capacity(int_part.len() + frac_part.len());
+    digits.push_str(int_part.trim_start_matches('0'));
+    digits.push_str(frac_part);
+    let shift = exponent.checked_sub(frac_part.len() as i64)?;
+    if shift < 0 {
+        // The digits after the decimal point must all be zero.
+        let keep = digits.len().saturating_sub(shift.unsigned_abs() as usize);
+        if !digits[keep..].bytes().all(|b| b == b'0') {
+            return None;
+        }
+        digits.truncate(keep);
+    }
+    let digits = digits.trim_start_matches('0');
+    if digits.is_empty() {
+        return Some(String::from("0"));
+    }
+    let zeros = shift.max(0) as usize;
+    if digits.len() + zeros.min(MAX_DIGITS) > MAX_DIGITS {
+        return None;
+    }
+    let mut result = String::with_capacity(1 + digits.len() + zeros);
+    if negative {
+        result.push('-');
+    }
+    result.push_str(digits);
+    result.extend(core::iter::repeat('0').take(zeros));
+    Some(result)
+}
+
+fn decode_float(value: &Value) -> Result<f64> {
+    let text = match value {
+        Value::Number(n) => n.as_str(),
+        Value::String(s) => match s.as_str() {
+            "NaN" => return Ok(f64::NAN),
+            "Infinity" => return Ok(f64::INFINITY),
+            "-Infinity" => return Ok(f64::NEG_INFINITY),
+            s => s.trim(),
+        },
+        _ => return Err(type_error("number", value)),
+    };
+    text.parse::<f64>()
+        .map_err(|_| Error::failed(alloc::format!("invalid floating point value: {text}")))
+}
+
+/// Like `decode_float()`, but rejects finite values that are too large for a `Float32`
+/// instead of letting them become infinite.
+fn decode_float32(value: &Value) -> Result<f32> {
+    let x = decode_float(value)?;
+    let narrowed = x as f32;
+    if x.is_finite() && narrowed.is_infinite() {
+        return Err(Error::failed(alloc::format!(
+            "floating point value is out of range for Float32: {x}"
+        )));
+    }
+    Ok(narrowed)
+}
+
+fn decode_primitive<'a>(ty: Type<'a>, value: &Value) -> Result<dynamic_value::Reader<'a>> {
+    Ok(match ty.which() {
+        TypeVariant::Void => dynamic_value::Reader::Void,
+        TypeVariant::Bool => match value {
+            Value::Bool(b) => dynamic_value::Reader::Bool(*b),
+            _ => return Err(type_error("boolean", value)),
+        },
+        TypeVariant::Int8 => dynamic_value::Reader::Int8(decode_integer(value)?),
+        TypeVariant::Int16 => dynamic_value::Reader::Int16(decode_integer(value)?),
+        TypeVariant::Int32 => dynamic_value::Reader::Int32(decode_integer(value)?),
+        TypeVariant::Int64 => dynamic_value::Reader::Int64(decode_integer(value)?),
+        TypeVariant::UInt8 => dynamic_value::Reader::UInt8(decode_integer(value)?),
+        TypeVariant::UInt16 => dynamic_value::Reader::UInt16(decode_integer(value)?),
+        TypeVariant::UInt32 => dynamic_value::Reader::UInt32(decode_integer(value)?),
+        TypeVariant::UInt64 => dynamic_value::Reader::UInt64(decode_integer(value)?),
+        TypeVariant::Float32 => dynamic_value::Reader::Float32(decode_float32(value)?),
+        TypeVariant::Float64 => dynamic_value::Reader::Float64(decode_float(value)?),
+        TypeVariant::Enum(raw) => {
+            let schema: EnumSchema = raw.into();
+            let ordinal = match value {
+                Value::String(name) => find_enumerant(schema, name)?,
+                _ => decode_integer::<u16>(value)?,
+            };
+            dynamic_value::Enum::new(ordinal, schema).into()
+        }
+        TypeVariant::AnyPointer => {
+            return Err(Error::unimplemented(
+                "AnyPointer values cannot be decoded from JSON".to_string(),
+            ))
+        }
+        TypeVariant::Capability(_) => {
+            return Err(Error::unimplemented(
+                "capabilities cannot be decoded from JSON".to_string(),
+            ))
+        }
+        TypeVariant::Text | TypeVariant::Data | TypeVariant::Struct(_) | TypeVariant::List(_) => {
+            return Err(Error::from_kind(ErrorKind::TypeMismatch))
+        }
+    })
+}
+
+fn find_enumerant(schema: EnumSchema, name: &str) -> Result<u16> {
+    for enumerant in schema.get_enumerants()? {
+        if enumerant_json_name(enumerant)? == name {
+            return Ok(enumerant.get_ordinal());
+        }
+    }
+    Err(Error::failed(alloc::format!("unknown enumerant: {name}")))
+}
+
+// ----------------------------------------------------------------------------
+// Base64 and hex
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+fn base64_encode(bytes: &[u8]) -> String {
+    let mut out = String::new();
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = (u32::from(b[0]) << 16) | (u32::from(b[1]) << 8) | u32::from(b[2]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(BASE64_ALPHABET[(n >> (18 - 6 * i)) as usize & 0x3f] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+fn base64_decode(text: &str) -> Result<Vec<u8>> {
+    let invalid = || Error::failed(alloc::format!("invalid base64: {text:?}"));
+    let input = text.trim_end_matches('=').as_bytes();
+    // Padding is optional, but if present it must fill out the last group of four.
+    let padding = text.len() - input.len();
+    if padding > 2 || (padding > 0 && text.len() % 4 != 0) {
+        return Err(invalid());
+    }
+    let mut out = Vec::new();
+    let mut accumulator: u32 = 0;
+    let mut bits = 0;
+    for &c in input {
+        let v = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'+' | b'-' => 62,
+            b'/' | b'_' => 63,
+            _ => return Err(invalid()),
+        };
+        accumulator = (accumulator << 6) | u32::from(v);
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((accumulator >> bits) as u8);
+        }
+    }
+    // A lone character in the last group encodes no whole byte, and the bits left over
+    // after the last byte must be zero, so that each byte string has only one encoding.
+    if bits >= 6 || accumulator & ((1 << bits) - 1) != 0 {
+        return Err(invalid());
+    }
+    Ok(out)
+}
+
+fn hex_encode(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len() * 2);
+    for b in bytes {
+        out.push_str(&alloc::format!("{b:02x}"));
+    }
+    out
+}
+
+fn hex_decode(text: &str) -> Result<Vec<u8>> {
+    let invalid = || Error::failed(alloc::format!("invalid hex: {text:?}"));
+    if text.len() % 2 != 0 {
+        return Err(invalid());
+    }
+    text.as_bytes()
+        .chunks(2)
+        .map(|pair| match (hex_digit(pair[0]), hex_digit(pair[1])) {
+            (Some(high), Some(low)) => Ok((high << 4) | low),
+            _ => Err(invalid()),
+        })
+        .collect()
+}
+
+/// The value of an ASCII hex digit. Unlike `from_str_radix()`, this does not accept signs.
+fn hex_digit(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Writing JSON text
+
+fn write_value(value: &Value, out: &mut String) {
+    match value {
+        Value::Null => out.push_str("null"),
+        Value::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+        Value::Number(n) => out.push_str(n),
+        Value::String(s) => write_string(s, out),
+        Value::Array(elements) => {
+            out.push('[');
+            for (idx, element) in elements.iter().enumerate() {
+                if idx > 0 {
+                    out.push(',');
+                }
+                write_value(element, out);
+            }
+            out.push(']');
+        }
+        Value::Object(members) => {
+            out.push('{');
+            for (idx, (key, value)) in members.iter().enumerate() {
+                if idx > 0 {
+                    out.push(',');
+                }
+                write_string(key, out);
+                out.push(':');
+                write_value(value, out);
+            }
+            out.push('}');
+        }
+    }
+}
+
+fn write_string(s: &str, out: &mut String) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{08}' => out.push_str("\\b"),
+            '\u{0c}' => out.push_str("\\f"),
+            c if (c as u32) < 0x20 => out.push_str(&alloc::format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+// ----------------------------------------------------------------------------
+// Parsing JSON text
+
+struct Parser<'a> {
+    input: &'a [u8],
+    pos: usize,
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str) -> Self {
+        Self {
+            input: input.as_bytes(),
+            pos: 0,
+            depth: 0,
+        }
+    }
+
+    fn error(&self, message: &str) -> Error {
+        Error::failed(alloc::format!(
+            "JSON parse error at byte {}: {message}",
+            self.pos
+        ))
+    }
+
+    fn parse_document(mut self) -> Result<Value> {
+        let value = self.parse_value()?;
+        self.skip_whitespace();
+        if self.pos < self.input.len() {
+            return Err(self.error("unexpected trailing characters"));
+        }
+        Ok(value)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.peek() {
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.input.get(self.pos).copied()
+    }
+
+    fn expect(&mut self, c: u8) -> Result<()> {
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.error(&alloc::format!("expected '{}'", c as char)))
+        }
+    }
+
+    fn expect_literal(&mut self, literal: &str, value: Value) -> Result<Value> {
+        if self.input[self.pos..].starts_with(literal.as_bytes()) {
+            self.pos += literal.len();
+            Ok(value)
+        } else {
+            Err(self.error("unexpected character"))
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<Value> {
+        self.skip_whitespace();
+        match self.peek() {
+            None => Err(self.error("unexpected end of input")),
+            Some(b'n') => self.expect_literal("null", Value::Null),
+            Some(b't') => self.expect_literal("true", Value::Bool(true)),
+            Some(b'f') => self.expect_literal("false", Value::Bool(false)),
+            Some(b'"') => Ok(Value::String(self.parse_string()?)),
+            Some(b'[') => self.parse_array(),
+            Some(b'{') => self.parse_object(),
+            Some(b'-' | b'0'..=b'9') => self.parse_number(),
+            Some(_) => Err(self.error("unexpected character")),
+        }
+    }
+
+    fn enter(&mut self) -> Result<()> {
+        self.depth += 1;
+        if self.depth > MAX_NESTING_DEPTH {
+            Err(Error::from_kind(ErrorKind::NestingLimitExceeded))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn parse_array(&mut self) -> Result<Value> {
+        self.enter()?;
+        self.expect(b'[')?;
+        let mut elements = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(b']') {
+            self.pos += 1;
+        } else {
+            loop {
+                elements.push(self.parse_value()?);
+                self.skip_whitespace();
+                match self.peek() {
+                    Some(b',') => self.pos += 1,
+                    Some(b']') => {
+                        self.pos += 1;
+                        break;
+                    }
+                    _ => return Err(self.error("expected ',' or ']'")),
+                }
+            }
+        }
+        self.depth -= 1;
+        Ok(Value::Array(elements))
+    }
+
+    fn parse_object(&mut self) -> Result<Value> {
+        self.enter()?;
+        self.expect(b'{')?;
+        let mut members = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(b'}') {
+            self.pos += 1;
+        } else {
+            loop {
+                self.skip_whitespace();
+                if self.peek() != Some(b'"') {
+                    return Err(self.error("expected object key"));
+                }
+                let key = self.parse_string()?;
+                self.skip_whitespace();
+                self.expect(b':')?;
+                let value = self.parse_value()?;
+                members.push((key, value));
+                self.skip_whitespace();
+                match self.peek() {
+                    Some(b',') => self.pos += 1,
+                    Some(b'}') => {
+                        self.pos += 1;
+                        break;
+                    }
+                    _ => return Err(self.error("expected ',' or '}'")),
+                }
+            }
+        }
+        self.depth -= 1;
+        Ok(Value::Object(members))
+    }
+
+    fn parse_number(&mut self) -> Result<Value> {
+        let start = self.pos;
+        if self.peek() == Some(b'-') {
+            self.pos += 1;
+        }
+        match self.peek() {
+            Some(b'0') => self.pos += 1,
+            Some(b'1'..=b'9') => self.skip_digits(),
+            _ => return Err(self.error("invalid number")),
+        }
+        if self.peek() == Some(b'.') {
+            self.pos += 1;
+            if !matches!(self.peek(), Some(b'0'..=b'9')) {
+                return Err(self.error("invalid number"));
+            }
+            self.skip_digits();
+        }
+        if let Some(b'e' | b'E') = self.peek() {
+            self.pos += 1;
+            if let Some(b'+' | b'-') = self.peek() {
+                self.pos += 1;
+            }
+            if !matches!(self.peek(), Some(b'0'..=b'9')) {
+                return Err(self.error("invalid number"));
+            }
+            self.skip_digits();
+        }
+        // Only ASCII has been consumed, so this cannot fail.
+        let text = core::str::from_utf8(&self.input[start..self.pos])?;
+        Ok(Value::Number(text.to_string()))
+    }
+
+    fn skip_digits(&mut self) {
+        while let Some(b'0'..=b'9') = self.peek() {
+            self.pos += 1;
+        }
+    }
+
+    fn parse_hex4(&mut self) -> Result<u32> {
+        let digits = self
+            .input
+            .get(self.pos..self.pos + 4)
+            .and_then(|d| {
+                d.iter()
+                    .try_fold(0, |n, &c| Some((n << 4) | u32::from(hex_digit(c)?)))
+            })
+            .ok_or_else(|| self.error("invalid unicode escape"))?;
+        self.pos += 4;
+        Ok(digits)
+    }
+
+    fn parse_string(&mut self) -> Result<String> {
+        self.expect(b'"')?;
+        let mut out = Vec::new();
+        loop {
+            let Some(c) = self.peek() else {
+                return Err(self.error("unterminated string"));
+            };
+            self.pos += 1;
+            match c {
+                b'"' => break,
+                b'\\' => {
+                    let Some(escape) = self.peek() else {
+                        return Err(self.error("unterminated string"));
+                    };
+                    self.pos += 1;
+                    let unescaped = match escape {
+                        b'"' => '"',
+                        b'\\' => '\\',
+                        b'/' => '/',
+                        b'b' => '\u{08}',
+                        b'f' => '\u{0c}',
+                        b'n' => '\n',
+                        b'r' => '\r',
+                        b't' => '\t',
+                        b'u' => {
+                            let mut code = self.parse_hex4()?;
+                            if (0xd800..0xdc00).contains(&code) {
+                                if !self.input[self.pos..].starts_with(b"\\u") {
+                                    return Err(self.error("unpaired surrogate"));
+                                }
+                                self.pos += 2;
+                                let low = self.parse_hex4()?;
+                                if !(0xdc00..0xe000).contains(&low) {
+                                    return Err(self.error("unpaired surrogate"));
+                                }
+                                code = 0x10000 + ((code - 0xd800) << 10) + (low - 0xdc00);
+                            }
+                            char::from_u32(code)
+                                .ok_or_else(|| self.error("invalid unicode escape"))?
+                        }
+                        _ => return Err(self.error("invalid escape sequence")),
+                    };
+                    let mut buf = [0; 4];
+                    out.extend_from_slice(unescaped.encode_utf8(&mut buf).as_bytes());
+                }
+                c if c < 0x20 => return Err(self.error("control character in string")),
+                c => out.push(c),
+            }
+        }
+        // The input was a &str and escapes produce valid UTF-8.
+        Ok(String::from_utf8(out)?)
+    }
+}
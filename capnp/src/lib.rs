@@ -39,30 +39,51 @@ pub mod schema_capnp;
 
 pub mod any_pointer;
 pub mod any_pointer_list;
+#[cfg(feature = "alloc")]
+pub mod canonical;
 pub mod capability;
 pub mod capability_list;
 pub mod constant;
 pub mod data;
 pub mod data_list;
+#[cfg(feature = "alloc")]
+pub mod diff;
+#[cfg(feature = "alloc")]
+pub mod dynamic_capability;
 pub mod dynamic_list;
 pub mod dynamic_struct;
 pub mod dynamic_value;
 pub mod enum_list;
+#[cfg(feature = "alloc")]
+pub mod field_mask;
 pub mod introspect;
 pub mod io;
+#[cfg(feature = "alloc")]
+pub mod json;
 pub mod list_list;
+#[cfg(all(feature = "alloc", feature = "std"))]
+pub mod log;
 pub mod message;
+#[cfg(all(feature = "mmap", target_os = "linux"))]
+pub mod mmap;
+pub mod orphan;
 pub mod primitive_list;
 pub mod private;
 pub mod raw;
 pub mod schema;
+#[cfg(all(feature = "alloc", feature = "std"))]
+pub mod schema_loader;
 pub mod serialize;
 pub mod serialize_packed;
 pub(crate) mod stringify;
 pub mod struct_list;
 pub mod text;
+#[cfg(feature = "alloc")]
+pub mod text_format;
 pub mod text_list;
 pub mod traits;
+#[cfg(feature = "alloc")]
+pub mod validate;
 
 ///
 /// 8 bytes, aligned to an 8-byte boundary.
@@ -171,6 +192,26 @@ pub struct Error {
     /// Extra context about error
     #[cfg(feature = "alloc")]
     pub extra: alloc::string::String,
+
+    /// Where in a message the error happened, innermost first. See `context()`. Boxed, and
+    /// usually `None`, so that recording it does not make every `Result` larger.
+    #[cfg(feature = "alloc")]
+    #[allow(clippy::box_collection)]
+    context: Option<alloc::boxed::Box<alloc::vec::Vec<ErrorContext>>>,
+}
+
+/// One step of the way from the root of a message to where an error happened.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorContext {
+    /// A field, with its name in the schema and the id of the struct or group that has it.
+    Field {
+        type_id: u64,
+        name: alloc::borrow::Cow<'static, str>,
+    },
+
+    /// An element of a list.
+    Index(u32),
 }
 
 /// The general nature of an error. The purpose of this enum is not to describe the error itself,
@@ -197,6 +238,10 @@ pub enum ErrorKind {
     /// approach based on other methods.
     Unimplemented,
 
+    /// The allocator of a message builder could not provide a new segment, for example
+    /// because a size limit was reached.
+    AllocationFailed,
+
     /// Buffer is not large enough
     BufferNotLargeEnough,
 
@@ -209,6 +254,9 @@ pub enum ErrorKind {
     /// Don't know how to handle non-STRUCT inline composite.
     CantHandleNonStructInlineComposite,
 
+    /// The checksum of a log record does not match its contents.
+    ChecksumMismatch,
+
     /// Empty buffer
     EmptyBuffer,
 
@@ -275,6 +323,12 @@ pub enum ErrorKind {
     /// InlineComposite lists of non-STRUCT type are not supported.
     InlineCompositeListsOfNonStructTypeAreNotSupported,
 
+    /// Invalid log record
+    InvalidLogRecord,
+
+    /// Invalid message trailer
+    InvalidMessageTrailer,
+
     /// Too many or too few segments {segment_count}
     InvalidNumberOfSegments(usize),
 
@@ -344,6 +398,9 @@ pub enum ErrorKind {
     /// Message is too large
     MessageTooLarge(usize),
 
+    /// method not found
+    MethodNotFound,
+
     /// Nesting limit exceeded
     NestingLimitExceeded,
 
@@ -353,6 +410,9 @@ pub enum ErrorKind {
     /// Only one of the section pointers is pointing to ourself
     OnlyOneOfTheSectionPointersIsPointingToOurself,
 
+    /// Orphan belongs to a different message
+    OrphanBelongsToADifferentMessage,
+
     /// Packed input did not end cleanly on a segment boundary.
     PackedInputDidNotEndCleanlyOnASegmentBoundary,
 
@@ -410,6 +470,7 @@ impl Error {
         Self {
             extra: description,
             kind: ErrorKind::Failed,
+            context: None,
         }
     }
 
@@ -420,14 +481,74 @@ impl Error {
         return Self {
             kind,
             extra: alloc::string::String::new(),
+            context: None,
         };
     }
 
+    /// Records that the error happened while reading the field `name` of the struct or group
+    /// with id `type_id`. Generated getters call this on the errors that they return.
+    #[cold]
+    #[cfg_attr(not(feature = "alloc"), allow(unused_variables))]
+    pub fn with_field(self, type_id: u64, name: &'static str) -> Self {
+        #[cfg(feature = "alloc")]
+        return self.with_context(ErrorContext::Field {
+            type_id,
+            name: name.into(),
+        });
+        #[cfg(not(feature = "alloc"))]
+        self
+    }
+
+    /// Like `with_field()`, for a name that is not known at compile time.
+    #[cold]
+    #[cfg_attr(not(feature = "alloc"), allow(unused_variables))]
+    pub(crate) fn with_field_name(self, type_id: u64, name: &str) -> Self {
+        #[cfg(feature = "alloc")]
+        return self.with_context(ErrorContext::Field {
+            type_id,
+            name: alloc::string::String::from(name).into(),
+        });
+        #[cfg(not(feature = "alloc"))]
+        self
+    }
+
+    /// Records that the error happened while reading element `index` of a list.
+    #[cold]
+    #[cfg_attr(not(feature = "alloc"), allow(unused_variables))]
+    pub fn with_index(self, index: u32) -> Self {
+        #[cfg(feature = "alloc")]
+        return self.with_context(ErrorContext::Index(index));
+        #[cfg(not(feature = "alloc"))]
+        self
+    }
+
+    #[cfg(feature = "alloc")]
+    fn with_context(mut self, step: ErrorContext) -> Self {
+        self.context.get_or_insert_with(Default::default).push(step);
+        self
+    }
+
+    /// Forgets where the error happened.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn clear_context(&mut self) {
+        self.context = None;
+    }
+
+    /// Where in a message the error happened, as recorded by `with_field()` and
+    /// `with_index()` while the error was returned through getters. The innermost step comes
+    /// first. Getting an element from a list of structs cannot fail, so such lists do not
+    /// record an index.
+    #[cfg(feature = "alloc")]
+    pub fn context(&self) -> &[ErrorContext] {
+        self.context.as_deref().map_or(&[], |context| &context[..])
+    }
+
     #[cfg(feature = "alloc")]
     pub fn overloaded(description: alloc::string::String) -> Self {
         Self {
             extra: description,
             kind: ErrorKind::Overloaded,
+            context: None,
         }
     }
     #[cfg(feature = "alloc")]
@@ -435,6 +556,7 @@ impl Error {
         Self {
             extra: description,
             kind: ErrorKind::Disconnected,
+            context: None,
         }
     }
 
@@ -443,6 +565,7 @@ impl Error {
         Self {
             extra: description,
             kind: ErrorKind::Unimplemented,
+            context: None,
         }
     }
 }
@@ -465,6 +588,7 @@ impl core::convert::From<::std::io::Error> for Error {
         return Self {
             kind,
             extra: format!("{err}"),
+            context: None,
         };
         #[cfg(not(feature = "alloc"))]
         return Self { kind };
@@ -523,6 +647,7 @@ impl core::fmt::Display for ErrorKind {
             Self::Overloaded => write!(fmt, "Overloaded"),
             Self::Disconnected => write!(fmt, "Disconnected"),
             Self::Unimplemented => write!(fmt, "Unimplemented"),
+            Self::AllocationFailed => write!(fmt, "Allocation failed"),
             Self::BufferNotLargeEnough => write!(fmt, "buffer is not large enough"),
             Self::ExistingListPointerIsNotByteSized => write!(fmt, "Called get_writable_{{data|text}}_pointer() but existing list pointer is not byte-sized."),
             Self::ExistingPointerIsNotAList => write!(fmt, "Called get_writable_{{data|text|list|struct_list}}_pointer() but existing pointer is not a list."),
@@ -531,6 +656,7 @@ impl core::fmt::Display for ErrorKind {
             Self::FourByteSegmentLengthTooBigForUSize => write!(fmt, "Cannot represent 4 byte segment length as usize. This may indicate that you are running on 8 or 16 bit platform or segment is too large"),
             Self::CannotSetAnyPointerFieldToAPrimitiveValue => write!(fmt, "cannot set AnyPointer field to a primitive value"),
             Self::CantHandleNonStructInlineComposite => write!(fmt, "Don't know how to handle non-STRUCT inline composite."),
+            Self::ChecksumMismatch => write!(fmt, "The checksum of a log record does not match its contents."),
             Self::EmptyBuffer => write!(fmt, "empty buffer"),
             Self::EmptySlice => write!(fmt, "empty slice"),
             Self::EnumValueOrUnionDiscriminantNotPresent(val) => write!(fmt, "Enum value or union discriminant {val} was not present in schema"),
@@ -549,6 +675,8 @@ impl core::fmt::Display for ErrorKind {
             Self::InlineCompositeListWithNonStructElementsNotSupported => write!(fmt, "InlineComposite list with non-STRUCT elements not supported."),
             Self::InlineCompositeListsElementsOverrunItsWordCount => write!(fmt, "InlineComposite list's elements overrun its word count."),
             Self::InlineCompositeListsOfNonStructTypeAreNotSupported => write!(fmt, "InlineComposite lists of non-STRUCT type are not supported."),
+            Self::InvalidLogRecord => write!(fmt, "Invalid log record"),
+            Self::InvalidMessageTrailer => write!(fmt, "Invalid message trailer"),
             Self::InvalidNumberOfSegments(segment_count) => write!(fmt, "Too many or too few segments {segment_count}"),
             Self::InvalidSegmentId(id) => write!(fmt, "Invalid segment id {id}"),
             Self::ListAnyPointerNotSupported => write!(fmt, "List(AnyPointer) not supported."),
@@ -572,9 +700,11 @@ impl core::fmt::Display for ErrorKind {
             Self::MessageSizeOverflow => write!(fmt, "Message's size cannot be represented in usize"),
             Self::MessageTooLarge(val) => write!(fmt, "Message is too large: {val}"),
             Self::MessageNotAlignedBy8BytesBoundary => write!(fmt, "Message was not aligned by 8 bytes boundary. Either ensure that message is properly aligned or compile `capnp` crate with \"unaligned\" feature enabled."),
+            Self::MethodNotFound => write!(fmt, "method not found"),
             Self::NestingLimitExceeded => write!(fmt, "nesting limit exceeded"),
             Self::NotAStruct => write!(fmt, "not a struct"),
             Self::OnlyOneOfTheSectionPointersIsPointingToOurself => write!(fmt, "Only one of the section pointers is pointing to ourself"),
+            Self::OrphanBelongsToADifferentMessage => write!(fmt, "Orphan belongs to a different message"),
             Self::PackedInputDidNotEndCleanlyOnASegmentBoundary => write!(fmt, "Packed input did not end cleanly on a segment boundary."),
             Self::PrematureEndOfFile => write!(fmt, "Premature end of file"),
             Self::PrematureEndOfPackedInput => write!(fmt, "Premature end of packed input."),
@@ -602,7 +732,40 @@ impl core::fmt::Display for Error {
         };
         #[cfg(not(feature = "alloc"))]
         let result = write!(fmt, "{}", self.kind);
-        result
+        result?;
+        #[cfg(feature = "alloc")]
+        self.fmt_context(fmt)?;
+        Ok(())
+    }
+}
+
+impl Error {
+    // Writes the context as a path from the outermost step, e.g. ", at nestedNodes[1].name of
+    // struct @0xe682ab4cf923a417".
+    #[cfg(feature = "alloc")]
+    fn fmt_context(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let context = self.context();
+        if context.is_empty() {
+            return Ok(());
+        }
+        write!(fmt, ", at ")?;
+        let mut outer_type_id = None;
+        for (i, step) in context.iter().rev().enumerate() {
+            match step {
+                ErrorContext::Field { type_id, name } => {
+                    if i > 0 {
+                        write!(fmt, ".")?;
+                    }
+                    write!(fmt, "{name}")?;
+                    outer_type_id = outer_type_id.or(Some(*type_id));
+                }
+                ErrorContext::Index(index) => write!(fmt, "[{index}]")?,
+            }
+        }
+        if let Some(type_id) = outer_type_id {
+            write!(fmt, " of struct @{type_id:#x}")?;
+        }
+        Ok(())
     }
 }
 
@@ -38,7 +38,7 @@ impl<T> introspect::Introspect for Owned<T>
 where
     T: introspect::Introspect + crate::traits::Owned,
 {
-    fn introspect() -> introspect::Type {
+    fn introspect() -> introspect::Type<'static> {
         introspect::Type::list_of(T::introspect())
     }
 }
@@ -120,16 +120,17 @@ where
     pub fn get(self, index: u32) -> Result<T::Reader<'a>> {
         assert!(index < self.len());
         FromPointerReader::get_from_pointer(&self.reader.get_pointer_element(index), None)
+            .map_err(|e| e.with_index(index))
     }
 
     /// Gets the element at position `index`. Returns `None` if `index`
     /// is greater than or equal to `len()`.
     pub fn try_get(self, index: u32) -> Option<Result<T::Reader<'a>>> {
         if index < self.len() {
-            Some(FromPointerReader::get_from_pointer(
-                &self.reader.get_pointer_element(index),
-                None,
-            ))
+            Some(
+                FromPointerReader::get_from_pointer(&self.reader.get_pointer_element(index), None)
+                    .map_err(|e| e.with_index(index)),
+            )
         } else {
             None
         }
@@ -180,6 +181,11 @@ where
     pub fn init(self, index: u32, size: u32) -> T::Builder<'a> {
         FromPointerBuilder::init_pointer(self.builder.get_pointer_element(index), size)
     }
+
+    /// Like `init()`, but returns an error if the allocator runs out of space.
+    pub fn try_init(self, index: u32, size: u32) -> Result<T::Builder<'a>> {
+        FromPointerBuilder::try_init_pointer(self.builder.get_pointer_element(index), size)
+    }
 }
 
 impl<'a, T> Builder<'a, T>
@@ -204,6 +210,12 @@ where
             builder: builder.init_list(Pointer, size),
         }
     }
+    fn try_init_pointer(builder: PointerBuilder<'a>, size: u32) -> Result<Builder<'a, T>> {
+        Ok(Builder {
+            marker: ::core::marker::PhantomData,
+            builder: builder.try_init_list(Pointer, size)?,
+        })
+    }
     fn get_from_pointer(
         builder: PointerBuilder<'a>,
         default: Option<&'a [crate::Word]>,
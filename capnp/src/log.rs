@@ -0,0 +1,726 @@
+//! Append-only logs of messages, with checksums and an index for random access.
+//!
+//! A log is a file (or any other seekable stream) that starts with a header and
+//! continues with a sequence of records. The body of a message record is the message
+//! in the [standard stream framing](crate::serialize), and every record is wrapped in
+//! a header and a trailer:
+//!
+//! ```text
+//! log header:     b"capnplog" | version: u32 | index interval: u32
+//! record header:  kind: [u8; 4] | 0: u32 | body length in bytes: u64
+//! record body:    ...
+//! record trailer: CRC-32 of the body: u32 | kind: [u8; 4] | body length in bytes: u64
+//! ```
+//!
+//! All integers are little-endian. After every `index interval` messages, the [`Writer`]
+//! appends an index record, which holds the offset of the previous index record
+//! (or `u64::MAX` if there is none), the number of messages since then, and their offsets.
+//!
+//! A [`Reader`] finds the messages by following the chain of index records back from the
+//! end of the log, without touching the messages themselves. If the end of the log is
+//! damaged, for example because a process died halfway through appending a record, it
+//! scans the records from the start instead, and ignores everything after the last one
+//! that is complete. [`Writer::open()`] cuts that part off before appending, as long as
+//! it is a single record that was cut short.
+
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::Path;
+
+use crate::message::{self, ReaderOptions, ReaderSegments};
+use crate::serialize::{self, OwnedSegments};
+use crate::{Error, ErrorKind, Result};
+
+const MAGIC: &[u8; 8] = b"capnplog";
+const VERSION: u32 = 1;
+const LOG_HEADER_BYTES: u64 = 16;
+
+const MESSAGE_RECORD: [u8; 4] = *b"msg\0";
+const INDEX_RECORD: [u8; 4] = *b"idx\0";
+const RECORD_HEADER_BYTES: u64 = 16;
+const RECORD_TRAILER_BYTES: u64 = 16;
+const RECORD_FRAMING_BYTES: u64 = RECORD_HEADER_BYTES + RECORD_TRAILER_BYTES;
+
+const NO_INDEX: u64 = u64::MAX;
+
+/// The number of messages between index records that [`Writer::new()`] uses.
+pub const DEFAULT_INDEX_INTERVAL: u32 = 256;
+
+const CRC_TABLE: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut k = 0;
+        while k < 8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+            k += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+/// Continues the CRC-32 (as used by zlib) `crc` of some bytes with `bytes`.
+fn crc32(crc: u32, bytes: &[u8]) -> u32 {
+    let mut crc = !crc;
+    for &b in bytes {
+        crc = CRC_TABLE[((crc ^ u32::from(b)) & 0xff) as usize] ^ (crc >> 8);
+    }
+    !crc
+}
+
+fn invalid_record(offset: u64, reason: &str) -> Error {
+    let mut error = Error::from_kind(ErrorKind::InvalidLogRecord);
+    write!(error, "at offset {offset}: {reason}");
+    error
+}
+
+struct RecordHeader {
+    kind: [u8; 4],
+    body_len: u64,
+}
+
+impl RecordHeader {
+    fn parse(bytes: &[u8; 16]) -> Option<Self> {
+        let kind: [u8; 4] = bytes[0..4].try_into().unwrap();
+        let reserved = u32::from_le_bytes(bytes[4..8].try_into().unwrap());
+        let body_len = u64::from_le_bytes(bytes[8..16].try_into().unwrap());
+        if (kind != MESSAGE_RECORD && kind != INDEX_RECORD) || reserved != 0 || body_len % 8 != 0 {
+            return None;
+        }
+        Some(Self { kind, body_len })
+    }
+
+    fn to_bytes(&self) -> [u8; 16] {
+        let mut bytes = [0; 16];
+        bytes[0..4].copy_from_slice(&self.kind);
+        bytes[8..16].copy_from_slice(&self.body_len.to_le_bytes());
+        bytes
+    }
+}
+
+struct RecordTrailer {
+    crc: u32,
+    kind: [u8; 4],
+    body_len: u64,
+}
+
+impl RecordTrailer {
+    fn parse(bytes: &[u8; 16]) -> Self {
+        Self {
+            crc: u32::from_le_bytes(bytes[0..4].try_into().unwrap()),
+            kind: bytes[4..8].try_into().unwrap(),
+            body_len: u64::from_le_bytes(bytes[8..16].try_into().unwrap()),
+        }
+    }
+
+    fn to_bytes(&self) -> [u8; 16] {
+        let mut bytes = [0; 16];
+        bytes[0..4].copy_from_slice(&self.crc.to_le_bytes());
+        bytes[4..8].copy_from_slice(&self.kind);
+        bytes[8..16].copy_from_slice(&self.body_len.to_le_bytes());
+        bytes
+    }
+}
+
+/// Passes writes through to `write`, keeping a checksum of everything written.
+struct ChecksumWriter<'a, W> {
+    write: &'a mut W,
+    crc: u32,
+}
+
+impl<W: Write> Write for ChecksumWriter<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.write.write(buf)?;
+        self.crc = crc32(self.crc, &buf[..n]);
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.write.flush()
+    }
+}
+
+/// Reads at most `remaining` bytes from `read`, keeping a checksum of everything read.
+struct ChecksumReader<'a, R> {
+    read: &'a mut R,
+    remaining: u64,
+    crc: u32,
+}
+
+impl<R: Read> Read for ChecksumReader<'_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let len = buf
+            .len()
+            .min(usize::try_from(self.remaining).unwrap_or(usize::MAX));
+        let n = self.read.read(&mut buf[..len])?;
+        self.crc = crc32(self.crc, &buf[..n]);
+        self.remaining -= n as u64;
+        Ok(n)
+    }
+}
+
+/// Appends messages to a log. See the [module docs](self) for the format.
+///
+/// The offsets of the records are tracked by the writer itself, so `write` must be
+/// positioned at the end of the log, and nothing else may write to it in the meantime.
+/// If writing a record fails, the log may end with an incomplete record; it should then
+/// be reopened, which discards that record.
+pub struct Writer<W> {
+    write: W,
+    position: u64,
+    index_interval: u32,
+    len: usize,
+    last_index: u64,
+
+    // Offsets of the messages written since the last index record.
+    unindexed: Vec<u64>,
+}
+
+impl<W: Write> Writer<W> {
+    /// Starts a new log in `write`, with an index record every [`DEFAULT_INDEX_INTERVAL`]
+    /// messages.
+    pub fn new(write: W) -> Result<Self> {
+        Self::with_index_interval(write, DEFAULT_INDEX_INTERVAL)
+    }
+
+    /// Starts a new log in `write`, with an index record every `index_interval` messages.
+    /// A smaller interval makes opening a large log faster, and costs 8 bytes per
+    /// message plus 48 bytes per index record.
+    ///
+    /// Panics if `index_interval` is zero.
+    pub fn with_index_interval(mut write: W, index_interval: u32) -> Result<Self> {
+        assert!(index_interval > 0, "index interval must be positive");
+        let mut header = [0; LOG_HEADER_BYTES as usize];
+        header[0..8].copy_from_slice(MAGIC);
+        header[8..12].copy_from_slice(&VERSION.to_le_bytes());
+        header[12..16].copy_from_slice(&index_interval.to_le_bytes());
+        write.write_all(&header)?;
+        Ok(Self {
+            write,
+            position: LOG_HEADER_BYTES,
+            index_interval,
+            len: 0,
+            last_index: NO_INDEX,
+            unindexed: Vec::new(),
+        })
+    }
+
+    /// Appends `message` to the log, and returns its position in the log.
+    pub fn append<A>(&mut self, message: &message::Builder<A>) -> Result<usize>
+    where
+        A: message::Allocator,
+    {
+        self.append_segments(&message.get_segments_for_output())
+    }
+
+    /// Like `append()`, but takes a `ReaderSegments`, allowing it to be used on
+    /// `message::Reader` objects (via `into_segments()`).
+    pub fn append_segments<R>(&mut self, segments: &R) -> Result<usize>
+    where
+        R: ReaderSegments,
+    {
+        let offset = self.position;
+        let body_len = serialize::compute_serialized_size(segments) as u64 * 8;
+        self.write_record(MESSAGE_RECORD, body_len, |write| {
+            serialize::write_message_segments(write, segments)
+        })?;
+        self.unindexed.push(offset);
+        self.len += 1;
+        if self.unindexed.len() == self.index_interval as usize {
+            self.write_index()?;
+        }
+        Ok(self.len - 1)
+    }
+
+    /// The number of messages in the log.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the log holds no messages.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Flushes the underlying writer.
+    pub fn flush(&mut self) -> Result<()> {
+        self.write.flush()?;
+        Ok(())
+    }
+
+    /// Writes an index record for the messages that were appended since the last one, so
+    /// that the log can be opened quickly, flushes, and returns the underlying writer.
+    pub fn finish(mut self) -> Result<W> {
+        if !self.unindexed.is_empty() {
+            self.write_index()?;
+        }
+        self.write.flush()?;
+        Ok(self.write)
+    }
+
+    fn write_index(&mut self) -> Result<()> {
+        let offset = self.position;
+        let mut body = Vec::with_capacity(16 + 8 * self.unindexed.len());
+        body.extend_from_slice(&self.last_index.to_le_bytes());
+        body.extend_from_slice(&(self.unindexed.len() as u64).to_le_bytes());
+        for message_offset in &self.unindexed {
+            body.extend_from_slice(&message_offset.to_le_bytes());
+        }
+        self.write_record(INDEX_RECORD, body.len() as u64, |write| {
+            write.write_all(&body)?;
+            Ok(())
+        })?;
+        self.last_index = offset;
+        self.unindexed.clear();
+        Ok(())
+    }
+
+    fn write_record<F>(&mut self, kind: [u8; 4], body_len: u64, write_body: F) -> Result<()>
+    where
+        F: FnOnce(&mut ChecksumWriter<W>) -> Result<()>,
+    {
+        self.write
+            .write_all(&RecordHeader { kind, body_len }.to_bytes())?;
+        let mut write = ChecksumWriter {
+            write: &mut self.write,
+            crc: 0,
+        };
+        write_body(&mut write)?;
+        let crc = write.crc;
+        self.write.write_all(
+            &RecordTrailer {
+                crc,
+                kind,
+                body_len,
+            }
+            .to_bytes(),
+        )?;
+        self.position += RECORD_FRAMING_BYTES + body_len;
+        Ok(())
+    }
+}
+
+impl Writer<BufWriter<File>> {
+    /// Creates a log file at `path`, truncating it if it exists.
+    pub fn create(path: impl AsRef<Path>) -> Result<Self> {
+        Self::new(BufWriter::new(File::create(path)?))
+    }
+
+    /// Opens the log file at `path` for appending, creating it if it does not exist.
+    /// A final record that was cut short is cut off. Returns an `InvalidLogRecord` error
+    /// if a damaged record is followed by complete ones, which would be lost.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let mut file = File::options()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)?;
+        if file.metadata()?.len() == 0 {
+            return Self::new(BufWriter::new(file));
+        }
+        let mut reader = Reader::new(BufReader::new(&mut file))?;
+        if reader.is_truncated() {
+            reader.check_incomplete_tail()?;
+        }
+        let position = reader.valid_len;
+        let index_interval = reader.index_interval;
+        let len = reader.offsets.len();
+        let last_index = reader.last_index;
+        let unindexed = reader.offsets[len - reader.unindexed..].to_vec();
+        drop(reader);
+        file.set_len(position)?;
+        file.seek(SeekFrom::Start(position))?;
+        Ok(Self {
+            write: BufWriter::new(file),
+            position,
+            index_interval,
+            len,
+            last_index,
+            unindexed,
+        })
+    }
+}
+
+/// Reads messages from a log, in any order. See the [module docs](self) for the format.
+pub struct Reader<R> {
+    read: R,
+    index_interval: u32,
+    stream_len: u64,
+
+    // Length of the part of the stream that holds complete records.
+    valid_len: u64,
+
+    // Offsets of the message records.
+    offsets: Vec<u64>,
+
+    // Offset of the last index record, and the number of messages after it.
+    last_index: u64,
+    unindexed: usize,
+}
+
+impl<R: Read + Seek> Reader<R> {
+    /// Opens the log that takes up all of `read`, and finds the messages in it.
+    pub fn new(mut read: R) -> Result<Self> {
+        let stream_len = read.seek(SeekFrom::End(0))?;
+        let mut header = [0; LOG_HEADER_BYTES as usize];
+        read.seek(SeekFrom::Start(0))?;
+        read.read_exact(&mut header)?;
+        if &header[0..8] != MAGIC {
+            return Err(invalid_record(0, "not a Cap'n Proto log"));
+        }
+        let version = u32::from_le_bytes(header[8..12].try_into().unwrap());
+        if version != VERSION {
+            return Err(invalid_record(0, &format!("unsupported version {version}")));
+        }
+        let index_interval = u32::from_le_bytes(header[12..16].try_into().unwrap());
+
+        let mut reader = Self {
+            read,
+            index_interval,
+            stream_len,
+            valid_len: LOG_HEADER_BYTES,
+            offsets: Vec::new(),
+            last_index: NO_INDEX,
+            unindexed: 0,
+        };
+        if !reader.index_from_end()? {
+            reader.scan()?;
+        }
+        Ok(reader)
+    }
+
+    /// The number of messages in the log.
+    pub fn len(&self) -> usize {
+        self.offsets.len()
+    }
+
+    /// Whether the log holds no messages.
+    pub fn is_empty(&self) -> bool {
+        self.offsets.is_empty()
+    }
+
+    /// The number of messages between index records, as chosen by the writer.
+    pub fn index_interval(&self) -> u32 {
+        self.index_interval
+    }
+
+    /// The length of the part of the stream that holds complete records.
+    pub fn valid_len(&self) -> u64 {
+        self.valid_len
+    }
+
+    /// Whether the stream ends with something other than a complete record, which
+    /// is ignored.
+    pub fn is_truncated(&self) -> bool {
+        self.valid_len < self.stream_len
+    }
+
+    /// Reads message number `index`, and checks that it matches its checksum.
+    ///
+    /// Panics if `index >= self.len()`.
+    pub fn read(
+        &mut self,
+        index: usize,
+        options: ReaderOptions,
+    ) -> Result<message::Reader<OwnedSegments>> {
+        let offset = self.offsets[index];
+        let header = self.read_header(offset)?;
+        let mut body = ChecksumReader {
+            read: &mut self.read,
+            remaining: header.body_len,
+            crc: 0,
+        };
+        let message = serialize::read_message(&mut body, options)?;
+        if body.remaining != 0 {
+            return Err(invalid_record(offset, "message is shorter than the record"));
+        }
+        let crc = body.crc;
+        self.check_trailer(offset, &header, crc)?;
+        Ok(message)
+    }
+
+    /// Checks that message number `index` matches its checksum, without decoding it.
+    ///
+    /// Panics if `index >= self.len()`.
+    pub fn verify(&mut self, index: usize) -> Result<()> {
+        let offset = self.offsets[index];
+        let header = self.read_header(offset)?;
+        let mut body = ChecksumReader {
+            read: &mut self.read,
+            remaining: header.body_len,
+            crc: 0,
+        };
+        io::copy(&mut body, &mut io::sink())?;
+        let crc = body.crc;
+        self.check_trailer(offset, &header, crc)
+    }
+
+    /// Returns an iterator over the messages, which can also go backwards.
+    pub fn iter(&mut self, options: ReaderOptions) -> Iter<'_, R> {
+        let back = self.len();
+        Iter {
+            reader: self,
+            options,
+            front: 0,
+            back,
+        }
+    }
+
+    pub fn into_inner(self) -> R {
+        self.read
+    }
+
+    fn read_header(&mut self, offset: u64) -> Result<RecordHeader> {
+        let mut bytes = [0; RECORD_HEADER_BYTES as usize];
+        self.read.seek(SeekFrom::Start(offset))?;
+        self.read.read_exact(&mut bytes)?;
+        match RecordHeader::parse(&bytes) {
+            Some(header) if header.kind == MESSAGE_RECORD => Ok(header),
+            _ => Err(invalid_record(offset, "expected a message record")),
+        }
+    }
+
+    /// Reads the trailer that follows the body of the record at `offset`, and compares it
+    /// with the header and the checksum of the body.
+    fn check_trailer(&mut self, offset: u64, header: &RecordHeader, crc: u32) -> Result<()> {
+        let mut bytes = [0; RECORD_TRAILER_BYTES as usize];
+        self.read.read_exact(&mut bytes)?;
+        let trailer = RecordTrailer::parse(&bytes);
+        if trailer.kind != header.kind || trailer.body_len != header.body_len {
+            return Err(invalid_record(offset, "trailer does not match header"));
+        }
+        if trailer.crc != crc {
+            let mut error = Error::from_kind(ErrorKind::ChecksumMismatch);
+            write!(error, "at offset {offset}");
+            return Err(error);
+        }
+        Ok(())
+    }
+
+    /// Reads the header and trailer of the record at `offset` and checks that they agree
+    /// with each other, and that the record ends by `limit`.
+    fn record_at(&mut self, offset: u64, limit: u64) -> Result<Option<(RecordHeader, u32)>> {
+        if limit - offset < RECORD_FRAMING_BYTES {
+            return Ok(None);
+        }
+        let mut bytes = [0; RECORD_HEADER_BYTES as usize];
+        self.read.seek(SeekFrom::Start(offset))?;
+        self.read.read_exact(&mut bytes)?;
+        let Some(header) = RecordHeader::parse(&bytes) else {
+            return Ok(None);
+        };
+        if header.body_len > limit - offset - RECORD_FRAMING_BYTES {
+            return Ok(None);
+        }
+        self.read.seek(SeekFrom::Start(
+            offset + RECORD_HEADER_BYTES + header.body_len,
+        ))?;
+        self.read.read_exact(&mut bytes)?;
+        let trailer = RecordTrailer::parse(&bytes);
+        if trailer.kind != header.kind || trailer.body_len != header.body_len {
+            return Ok(None);
+        }
+        Ok(Some((header, trailer.crc)))
+    }
+
+    /// Finds the messages by walking back from the end of the stream to the last index
+    /// record, and then following the chain of index records. Returns false if the end of
+    /// the stream or an index record is damaged.
+    fn index_from_end(&mut self) -> Result<bool> {
+        let mut end = self.stream_len;
+        let mut unindexed = Vec::new();
+        let mut index = NO_INDEX;
+        while end > LOG_HEADER_BYTES {
+            if end - LOG_HEADER_BYTES < RECORD_FRAMING_BYTES {
+                return Ok(false);
+            }
+            let mut bytes = [0; RECORD_TRAILER_BYTES as usize];
+            self.read
+                .seek(SeekFrom::Start(end - RECORD_TRAILER_BYTES))?;
+            self.read.read_exact(&mut bytes)?;
+            let trailer = RecordTrailer::parse(&bytes);
+            let Some(start) = (end - LOG_HEADER_BYTES - RECORD_FRAMING_BYTES)
+                .checked_sub(trailer.body_len)
+                .map(|n| n + LOG_HEADER_BYTES)
+            else {
+                return Ok(false);
+            };
+            match self.record_at(start, end)? {
+                Some((header, _)) if start + RECORD_FRAMING_BYTES + header.body_len == end => {
+                    if header.kind == INDEX_RECORD {
+                        index = start;
+                        break;
+                    }
+                    unindexed.push(start);
+                    end = start;
+                }
+                _ => return Ok(false),
+            }
+        }
+
+        self.last_index = index;
+        self.unindexed = unindexed.len();
+        let mut chunks = Vec::new();
+        while index != NO_INDEX {
+            let Some((offsets, previous)) = self.read_index(index)? else {
+                return Ok(false);
+            };
+            chunks.push(offsets);
+            index = previous;
+        }
+        self.offsets = chunks.into_iter().rev().flatten().collect();
+        self.offsets.extend(unindexed.into_iter().rev());
+        self.valid_len = self.stream_len;
+        Ok(true)
+    }
+
+    /// Reads the index record at `offset`, and returns the message offsets in it and the
+    /// offset of the previous index record.
+    fn read_index(&mut self, offset: u64) -> Result<Option<(Vec<u64>, u64)>> {
+        let Some((header, crc)) = self.record_at(offset, self.stream_len)? else {
+            return Ok(None);
+        };
+        if header.kind != INDEX_RECORD || header.body_len < 16 {
+            return Ok(None);
+        }
+        let Ok(body_len) = usize::try_from(header.body_len) else {
+            return Ok(None);
+        };
+        let mut body = vec![0; body_len];
+        self.read
+            .seek(SeekFrom::Start(offset + RECORD_HEADER_BYTES))?;
+        self.read.read_exact(&mut body)?;
+        if crc32(0, &body) != crc {
+            return Ok(None);
+        }
+        let mut words = body
+            .chunks_exact(8)
+            .map(|w| u64::from_le_bytes(w.try_into().unwrap()));
+        let previous = words.next().unwrap();
+        let count = words.next().unwrap();
+        let offsets: Vec<u64> = words.collect();
+        if count != offsets.len() as u64
+            || (previous != NO_INDEX && previous >= offset)
+            || offsets.iter().any(|&o| o >= offset)
+        {
+            return Ok(None);
+        }
+        Ok(Some((offsets, previous)))
+    }
+
+    /// Checks that what follows the complete records is a single record that was cut
+    /// short, as when a writer dies while appending it, rather than damage that more
+    /// records follow.
+    fn check_incomplete_tail(&mut self) -> Result<()> {
+        let offset = self.valid_len;
+        let remaining = self.stream_len - offset;
+        if remaining < RECORD_HEADER_BYTES {
+            return Ok(());
+        }
+        let mut bytes = [0; RECORD_HEADER_BYTES as usize];
+        self.read.seek(SeekFrom::Start(offset))?;
+        self.read.read_exact(&mut bytes)?;
+        let cut_short = match RecordHeader::parse(&bytes) {
+            Some(header) => {
+                remaining < RECORD_FRAMING_BYTES
+                    || header.body_len > remaining - RECORD_FRAMING_BYTES
+            }
+            None => false,
+        };
+        if !cut_short || self.ends_with_record_after(offset)? {
+            return Err(invalid_record(
+                offset,
+                "damaged record is followed by more records",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Whether the stream ends with a complete record that starts at or after `offset`.
+    fn ends_with_record_after(&mut self, offset: u64) -> Result<bool> {
+        if self.stream_len - offset < RECORD_FRAMING_BYTES {
+            return Ok(false);
+        }
+        let mut bytes = [0; RECORD_TRAILER_BYTES as usize];
+        self.read
+            .seek(SeekFrom::Start(self.stream_len - RECORD_TRAILER_BYTES))?;
+        self.read.read_exact(&mut bytes)?;
+        let trailer = RecordTrailer::parse(&bytes);
+        match (self.stream_len - offset - RECORD_FRAMING_BYTES).checked_sub(trailer.body_len) {
+            Some(gap) => Ok(self.record_at(offset + gap, self.stream_len)?.is_some()),
+            None => Ok(false),
+        }
+    }
+
+    /// Finds the messages by visiting every record from the start of the stream, stopping
+    /// at the first one that is incomplete.
+    fn scan(&mut self) -> Result<()> {
+        let mut offset = LOG_HEADER_BYTES;
+        self.offsets.clear();
+        self.last_index = NO_INDEX;
+        self.unindexed = 0;
+        while let Some((header, _)) = self.record_at(offset, self.stream_len)? {
+            if header.kind == INDEX_RECORD {
+                self.last_index = offset;
+                self.unindexed = 0;
+            } else {
+                self.offsets.push(offset);
+                self.unindexed += 1;
+            }
+            offset += RECORD_FRAMING_BYTES + header.body_len;
+        }
+        self.valid_len = offset;
+        Ok(())
+    }
+}
+
+impl Reader<BufReader<File>> {
+    /// Opens the log file at `path` for reading.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Self::new(BufReader::new(File::open(path)?))
+    }
+}
+
+/// Iterator over the messages of a log, returned by [`Reader::iter()`].
+pub struct Iter<'a, R> {
+    reader: &'a mut Reader<R>,
+    options: ReaderOptions,
+    front: usize,
+    back: usize,
+}
+
+impl<R: Read + Seek> Iterator for Iter<'_, R> {
+    type Item = Result<message::Reader<OwnedSegments>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.front += 1;
+        Some(self.reader.read(self.front - 1, self.options))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.back - self.front, Some(self.back - self.front))
+    }
+}
+
+impl<R: Read + Seek> DoubleEndedIterator for Iter<'_, R> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.reader.read(self.back, self.options))
+    }
+}
+
+impl<R: Read + Seek> ExactSizeIterator for Iter<'_, R> {}
@@ -77,7 +77,7 @@ use crate::private::units::BYTES_PER_WORD;
 use crate::traits::{FromPointerBuilder, SetterInput};
 use crate::traits::{FromPointerReader, Owned};
 use crate::OutputSegments;
-use crate::Result;
+use crate::{Error, ErrorKind, Result};
 
 /// Options controlling how data is read.
 #[derive(Clone, Copy, Debug)]
@@ -165,6 +165,17 @@ pub trait ReaderSegments {
     }
 }
 
+/// Segments that keep the same contents for as long as a reader holds them, so that a
+/// message can be checked once and trusted from then on. See `Reader::into_trusted()`.
+///
+/// This trait is sealed. It is implemented for the segment types of this crate that own
+/// their memory or hold a shared borrow of it.
+pub trait StableSegments: ReaderSegments + sealed::Sealed {}
+
+pub(crate) mod sealed {
+    pub trait Sealed {}
+}
+
 impl<S> ReaderSegments for &S
 where
     S: ReaderSegments,
@@ -199,6 +210,12 @@ impl<'b> ReaderSegments for SegmentArray<'b> {
     }
 }
 
+impl<S> sealed::Sealed for &S where S: StableSegments {}
+impl<S> StableSegments for &S where S: StableSegments {}
+
+impl<'b> sealed::Sealed for SegmentArray<'b> {}
+impl<'b> StableSegments for SegmentArray<'b> {}
+
 impl<'b> ReaderSegments for [&'b [u8]] {
     fn get_segment(&self, id: u32) -> Option<&[u8]> {
         self.get(id as usize).copied()
@@ -227,6 +244,28 @@ where
         }
     }
 
+    /// Like `new()`, but the returned reader trusts the message: it skips the bounds checks
+    /// on pointers and does not enforce the traversal limit. The nesting limit is still
+    /// enforced. This makes reads faster, and is meant for messages that were produced by
+    /// this process or that have been checked already. See also `into_trusted()`.
+    ///
+    /// # Safety
+    /// Every pointer that can be reached from the root of the message must point within
+    /// its segments, as is the case for the segments of a `Builder`. Otherwise reads may
+    /// access memory outside of the message.
+    pub unsafe fn new_trusted(segments: S, options: ReaderOptions) -> Self {
+        let mut arena = ReaderArenaImpl::new(segments, options);
+        unsafe {
+            arena.set_trusted();
+        }
+        Self { arena }
+    }
+
+    /// Whether this reader skips bounds checks. See `new_trusted()`.
+    pub fn is_trusted(&self) -> bool {
+        self.arena.is_trusted()
+    }
+
     fn get_root_internal(&self) -> Result<any_pointer::Reader<'_>> {
         let (segment_start, _seg_len) = self.arena.get_segment(0)?;
         let pointer_reader = layout::PointerReader::get_root(
@@ -294,6 +333,54 @@ where
     pub fn into_typed<T: Owned>(self) -> TypedReader<S, T> {
         TypedReader::new(self)
     }
+
+    /// Reads everything that is reachable from the root, interpreted as a `T`, and reports
+    /// every problem that was found on the way, with its location. See [`crate::validate`].
+    ///
+    /// Reads made by validation do not count against the traversal limit of later reads,
+    /// but validation itself is limited by what is left of it.
+    #[cfg(feature = "alloc")]
+    pub fn validate<T: Owned + crate::introspect::Introspect>(&self) -> crate::validate::Report {
+        match T::introspect().which() {
+            crate::introspect::TypeVariant::Struct(schema) => self.validate_dynamic(schema.into()),
+            _ => crate::validate::Report {
+                problems: alloc::vec![crate::validate::Problem {
+                    path: crate::validate::Path(alloc::vec![crate::validate::PathElement::Field(
+                        "root".into()
+                    )]),
+                    error: Error::from_kind(ErrorKind::NotAStruct),
+                }],
+            },
+        }
+    }
+
+    /// Like `validate()`, but takes the schema of the root struct at run time.
+    #[cfg(feature = "alloc")]
+    pub fn validate_dynamic(&self, schema: crate::schema::StructSchema) -> crate::validate::Report {
+        let arena = self.arena.with_own_read_limit();
+        let root = self.arena.get_segment(0).and_then(|(segment_start, _)| {
+            layout::PointerReader::get_root(&arena, 0, segment_start, arena.nesting_limit())
+        });
+        crate::validate::validate_root(root.map(any_pointer::Reader::new), schema)
+    }
+}
+
+impl<S> Reader<S>
+where
+    S: StableSegments,
+{
+    /// Checks every pointer that can be reached from the root of the message, subject to
+    /// this reader's limits, and if they are all valid returns a reader that trusts the
+    /// message, as if it had been created with `new_trusted()`.
+    pub fn into_trusted(mut self) -> Result<Self> {
+        if !self.arena.is_trusted() {
+            self.get_root_internal()?.target_size()?;
+            unsafe {
+                self.arena.set_trusted();
+            }
+        }
+        Ok(self)
+    }
 }
 
 /// A message reader whose value is known to be of type `T`.
@@ -359,6 +446,91 @@ where
     }
 }
 
+/// An immutable message of type `T` that can be shared between threads, e.g. in a cache.
+/// Clones are cheap, and share the message's segments through an `Arc`.
+///
+/// The message is checked once, when the `SharedMessage` is made. After that it is read
+/// as a trusted message (see `Reader::new_trusted()`), so readers on different threads do
+/// not wear down a common traversal limit.
+#[cfg(all(feature = "alloc", feature = "sync_reader"))]
+pub struct SharedMessage<T>
+where
+    T: Owned,
+{
+    message: alloc::sync::Arc<TypedReader<crate::serialize::OwnedSegments, T>>,
+}
+
+#[cfg(all(feature = "alloc", feature = "sync_reader"))]
+fn _assert_shared_message_send_sync() {
+    fn _assert_send_sync<U: Send + Sync>() {}
+    fn _assert_shared_message<T: Owned + Send + Sync>() {
+        _assert_send_sync::<SharedMessage<T>>();
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "sync_reader"))]
+impl<T> SharedMessage<T>
+where
+    T: Owned,
+{
+    /// Copies the segments of `message`, which must have a root of type `T`.
+    pub fn freeze<A: Allocator>(message: &Builder<A>) -> Self {
+        let segments = message.get_segments_for_output();
+        let mut lengths = crate::serialize::SegmentLengthsBuilder::with_capacity(segments.len());
+        for segment in segments.iter() {
+            lengths
+                .try_push_segment(segment.len() / BYTES_PER_WORD)
+                .expect("segments of a builder fit in memory");
+        }
+        let mut owned = lengths.into_owned_segments();
+        let mut start = 0;
+        for segment in segments.iter() {
+            owned[start..start + segment.len()].copy_from_slice(segment);
+            start += segment.len();
+        }
+        // The segments were written by a builder, so all of their pointers are in bounds.
+        let reader = unsafe { Reader::new_trusted(owned, ReaderOptions::new()) };
+        Self {
+            message: alloc::sync::Arc::new(reader.into_typed()),
+        }
+    }
+
+    /// Checks the message with the reader's options and takes it over. See
+    /// `Reader::into_trusted()`.
+    pub fn from_reader(message: Reader<crate::serialize::OwnedSegments>) -> Result<Self> {
+        Ok(Self {
+            message: alloc::sync::Arc::new(message.into_trusted()?.into_typed()),
+        })
+    }
+
+    /// Reads a message in the standard serialization format from `bytes` into memory of
+    /// its own, and checks it with `options`.
+    pub fn from_bytes(bytes: &[u8], options: ReaderOptions) -> Result<Self> {
+        Self::from_reader(crate::serialize::read_message(bytes, options)?)
+    }
+
+    pub fn get(&self) -> Result<T::Reader<'_>> {
+        self.message.get()
+    }
+
+    /// Whether `self` and `other` are clones of each other.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        alloc::sync::Arc::ptr_eq(&self.message, &other.message)
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "sync_reader"))]
+impl<T> Clone for SharedMessage<T>
+where
+    T: Owned,
+{
+    fn clone(&self) -> Self {
+        Self {
+            message: self.message.clone(),
+        }
+    }
+}
+
 /// An object that allocates memory for a Cap'n Proto message as it is being built.
 /// Users of capnproto-rust who wish to provide memory in non-standard ways should
 /// implement this trait. Objects implementing this trait are intended to be wrapped
@@ -382,6 +554,15 @@ pub unsafe trait Allocator {
     /// previous segment.
     fn allocate_segment(&mut self, minimum_size: u32) -> (*mut u8, u32);
 
+    /// Like [`allocate_segment()`](Self::allocate_segment), but returns an error of kind
+    /// [`ErrorKind::AllocationFailed`](crate::ErrorKind::AllocationFailed) instead of panicking
+    /// when the memory is not available. Message builders always allocate through this method,
+    /// so that the `try_` variants of the builder methods can report the failure. The default
+    /// implementation calls `allocate_segment()`.
+    fn try_allocate_segment(&mut self, minimum_size: u32) -> Result<(*mut u8, u32)> {
+        Ok(self.allocate_segment(minimum_size))
+    }
+
     /// Indicates that a segment, previously allocated via allocate_segment(), is no longer in use.
     /// `word_size` is the length of the segment in words, as returned from `allocate_segment()`.
     /// `words_used` is always less than or equal to `word_size`, and indicates how many
@@ -426,17 +607,21 @@ where
     }
 
     fn get_root_internal(&mut self) -> any_pointer::Builder<'_> {
+        self.try_get_root_internal().expect("allocate root pointer")
+    }
+
+    fn try_get_root_internal(&mut self) -> Result<any_pointer::Builder<'_>> {
         if self.arena.is_empty() {
-            self.arena
-                .allocate_segment(1)
-                .expect("allocate root pointer");
+            self.arena.allocate_segment(1)?;
             self.arena.allocate(0, 1).expect("allocate root pointer");
         }
         let (seg_start, _seg_len) = self.arena.get_segment_mut(0);
         let location: *mut u8 = seg_start;
         let Self { arena } = self;
 
-        any_pointer::Builder::new(layout::PointerBuilder::get_root(arena, 0, location))
+        Ok(any_pointer::Builder::new(layout::PointerBuilder::get_root(
+            arena, 0, location,
+        )))
     }
 
     /// Initializes the root as a value of the given type.
@@ -445,18 +630,41 @@ where
         root.init_as()
     }
 
+    /// Like [`init_root()`](Self::init_root), but returns an error of kind
+    /// [`ErrorKind::AllocationFailed`] instead of panicking if the allocator cannot provide
+    /// the space.
+    pub fn try_init_root<'a, T: FromPointerBuilder<'a>>(&'a mut self) -> Result<T> {
+        let root = self.try_get_root_internal()?;
+        root.try_init_as()
+    }
+
     /// Initializes the root as a value of the given list type, with the given length.
     pub fn initn_root<'a, T: FromPointerBuilder<'a>>(&'a mut self, length: u32) -> T {
         let root = self.get_root_internal();
         root.initn_as(length)
     }
 
+    /// Like [`initn_root()`](Self::initn_root), but returns an error instead of panicking
+    /// if the allocator cannot provide the space.
+    pub fn try_initn_root<'a, T: FromPointerBuilder<'a>>(&'a mut self, length: u32) -> Result<T> {
+        let root = self.try_get_root_internal()?;
+        root.try_initn_as(length)
+    }
+
     /// Gets the root, interpreting it as the given type.
     pub fn get_root<'a, T: FromPointerBuilder<'a>>(&'a mut self) -> Result<T> {
-        let root = self.get_root_internal();
+        let root = self.try_get_root_internal()?;
         root.get_as()
     }
 
+    /// Gets the orphanage of this message, for creating objects that are not attached
+    /// to the message tree yet. See [`orphan`](crate::orphan).
+    pub fn get_orphanage(&mut self) -> crate::orphan::Orphanage<'_> {
+        // Make sure the root pointer is allocated first, so an orphan cannot take its place.
+        self.get_root_internal();
+        crate::orphan::Orphanage::new(&mut self.arena)
+    }
+
     pub fn get_root_as_reader<'a, T: FromPointerReader<'a>>(&'a self) -> Result<T> {
         if self.arena.is_empty() {
             any_pointer::Reader::new(layout::PointerReader::new_default()).get_as()
@@ -475,7 +683,7 @@ where
 
     /// Sets the root to a deep copy of the given value.
     pub fn set_root<T: Owned>(&mut self, value: impl SetterInput<T>) -> Result<()> {
-        let mut root = self.get_root_internal();
+        let mut root = self.try_get_root_internal()?;
         root.set_as(value)
     }
 
@@ -484,9 +692,7 @@ where
     /// a single segment, containing the full canonicalized message.
     pub fn set_root_canonical<T: Owned>(&mut self, value: impl SetterInput<T>) -> Result<()> {
         if self.arena.is_empty() {
-            self.arena
-                .allocate_segment(1)
-                .expect("allocate root pointer");
+            self.arena.allocate_segment(1)?;
             self.arena.allocate(0, 1).expect("allocate root pointer");
         }
         let (seg_start, _seg_len) = self.arena.get_segment_mut(0);
@@ -500,6 +706,23 @@ where
         self.arena.get_segments_for_output()
     }
 
+    /// The number of segments, including released ones.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn segment_count(&self) -> usize {
+        self.arena.len()
+    }
+
+    #[cfg(feature = "alloc")]
+    pub(crate) fn get_segment_for_output(&self, id: u32) -> Option<&[u8]> {
+        self.arena.get_segment_for_output(id)
+    }
+
+    /// See `BuilderArenaImpl::release_segment()`.
+    #[cfg(feature = "alloc")]
+    pub(crate) unsafe fn release_segment(&mut self, id: u32) {
+        unsafe { self.arena.release_segment(id) }
+    }
+
     pub fn into_reader(self) -> Reader<Self> {
         Reader::new(
             self,
@@ -514,6 +737,48 @@ where
         TypedBuilder::new(self)
     }
 
+    /// Returns the number of words in the message that are no longer reachable from the
+    /// root, for example because the fields that pointed to them were overwritten or cleared.
+    /// This traverses the whole message. See [`compact()`](Self::compact).
+    pub fn wasted_words(&self) -> Result<usize> {
+        self.arena.wasted_words()
+    }
+
+    /// Rebuilds the message from its root in freshly allocated segments, dropping everything
+    /// that is no longer reachable, and returns the number of bytes reclaimed. The result
+    /// is a single segment as long as the allocator honors the requested minimum size.
+    ///
+    /// [Orphans](crate::orphan) of this message become invalid, and adopting them fails.
+    /// On error, the message is left unchanged.
+    #[cfg(feature = "alloc")]
+    pub fn compact(&mut self) -> Result<usize> {
+        let before = self.arena.allocated_words();
+        self.arena.compact()?;
+        Ok((before - self.arena.allocated_words()) * BYTES_PER_WORD)
+    }
+
+    /// Copies everything reachable from the root into a new message that uses `allocator`.
+    /// Like [`compact()`](Self::compact), but leaves this message alone.
+    pub fn compact_into<B: Allocator>(&self, allocator: B) -> Result<Builder<B>> {
+        let mut message = Builder::new(allocator);
+        if !self.arena.is_empty() {
+            let (segment_start, _segment_len) = self.arena.get_segment(0)?;
+            message
+                .arena
+                .copy_root_from(self.arena.as_reader(), segment_start)?;
+        }
+        Ok(message)
+    }
+
+    /// Clears the message so that another one can be built in it, keeping the segments
+    /// that have been allocated so far. Only their used parts are zeroed, which is usually
+    /// much cheaper than deallocating them and allocating new ones.
+    ///
+    /// [Orphans](crate::orphan) of this message become invalid, and adopting them fails.
+    pub fn reset(&mut self) {
+        self.arena.reset()
+    }
+
     /// Retrieves the underlying `Allocator`, deallocating all currently-allocated
     /// segments.
     pub fn into_allocator(self) -> A {
@@ -603,6 +868,14 @@ where
         self.message.initn_root(length)
     }
 
+    pub fn try_init_root(&mut self) -> Result<T::Builder<'_>> {
+        self.message.try_init_root()
+    }
+
+    pub fn try_initn_root(&mut self, length: u32) -> Result<T::Builder<'_>> {
+        self.message.try_initn_root(length)
+    }
+
     pub fn get_root(&mut self) -> Result<T::Builder<'_>> {
         self.message.get_root()
     }
@@ -630,6 +903,12 @@ where
     pub fn into_reader(self) -> TypedReader<Builder<A>, T> {
         TypedReader::new(self.message.into_reader())
     }
+
+    /// Copies the message into a `SharedMessage`.
+    #[cfg(all(feature = "alloc", feature = "sync_reader"))]
+    pub fn freeze(&self) -> SharedMessage<T> {
+        SharedMessage::freeze(&self.message)
+    }
 }
 
 impl<T, A> From<Builder<A>> for TypedBuilder<T, A>
@@ -708,15 +987,31 @@ impl HeapAllocator {
 }
 
 #[cfg(feature = "alloc")]
-unsafe impl Allocator for HeapAllocator {
-    fn allocate_segment(&mut self, minimum_size: u32) -> (*mut u8, u32) {
-        let size = core::cmp::max(minimum_size, self.next_size);
+impl HeapAllocator {
+    /// Allocates a segment, or returns the layout that could not be allocated.
+    fn allocate(
+        &mut self,
+        minimum_size: u32,
+    ) -> core::result::Result<(*mut u8, u32), alloc::alloc::Layout> {
+        let size = self.segment_size(minimum_size);
         let layout =
             alloc::alloc::Layout::from_size_align(size as usize * BYTES_PER_WORD, 8).unwrap();
         let ptr = unsafe { alloc::alloc::alloc_zeroed(layout) };
         if ptr.is_null() {
-            alloc::alloc::handle_alloc_error(layout);
+            return Err(layout);
         }
+        self.grow(size);
+        Ok((ptr, size))
+    }
+
+    /// The number of words that the next segment should have.
+    pub(crate) fn segment_size(&self, minimum_size: u32) -> u32 {
+        core::cmp::max(minimum_size, self.next_size)
+    }
+
+    /// Updates `next_size` according to the allocation strategy, after a segment of
+    /// `size` words has been handed out.
+    pub(crate) fn grow(&mut self, size: u32) {
         match self.allocation_strategy {
             AllocationStrategy::GrowHeuristically => {
                 if size < self.max_segment_words - self.next_size {
@@ -727,7 +1022,24 @@ unsafe impl Allocator for HeapAllocator {
             }
             AllocationStrategy::FixedSize => {}
         }
-        (ptr, size)
+    }
+}
+
+#[cfg(feature = "alloc")]
+unsafe impl Allocator for HeapAllocator {
+    fn allocate_segment(&mut self, minimum_size: u32) -> (*mut u8, u32) {
+        match self.allocate(minimum_size) {
+            Ok(segment) => segment,
+            Err(layout) => alloc::alloc::handle_alloc_error(layout),
+        }
+    }
+
+    fn try_allocate_segment(&mut self, minimum_size: u32) -> Result<(*mut u8, u32)> {
+        self.allocate(minimum_size).map_err(|layout| {
+            let mut error = Error::from_kind(ErrorKind::AllocationFailed);
+            write!(error, "could not allocate {} bytes", layout.size());
+            error
+        })
     }
 
     unsafe fn deallocate_segment(&mut self, ptr: *mut u8, word_size: u32, _words_used: u32) {
@@ -865,6 +1177,16 @@ unsafe impl<'a> Allocator for ScratchSpaceHeapAllocator<'a> {
         }
     }
 
+    fn try_allocate_segment(&mut self, minimum_size: u32) -> Result<(*mut u8, u32)> {
+        if (minimum_size as usize) < (self.scratch_space.len() / BYTES_PER_WORD)
+            && !self.scratch_space_allocated
+        {
+            Ok(self.allocate_segment(minimum_size))
+        } else {
+            self.allocator.try_allocate_segment(minimum_size)
+        }
+    }
+
     unsafe fn deallocate_segment(&mut self, ptr: *mut u8, word_size: u32, words_used: u32) {
         let seg_ptr = self.scratch_space.as_mut_ptr();
         if ptr == seg_ptr {
@@ -885,8 +1207,271 @@ unsafe impl<'a> Allocator for ScratchSpaceHeapAllocator<'a> {
     }
 }
 
+/// A cache of zeroed segments that lets messages reuse the memory of messages that have
+/// been dropped, instead of returning it to the heap and allocating it again. It is
+/// shared by any number of [`PooledAllocator`]s through a [`SharedSegmentPool`] handle.
+///
+/// A freed segment is kept if it is at most `max_segment_words` long and the pool holds
+/// no more than `max_pooled_words` words in total afterwards; otherwise it is deallocated.
+/// Only the part of a segment that was written to is zeroed when it is returned.
+#[cfg(feature = "alloc")]
+pub struct SegmentPool {
+    // Free segments, by size in words.
+    free: alloc::collections::BTreeMap<u32, alloc::vec::Vec<PooledSegment>>,
+    pooled_words: usize,
+    max_segment_words: u32,
+    max_pooled_words: usize,
+    hits: u64,
+    misses: u64,
+}
+
+// A free segment, owned by the pool that holds it.
+#[cfg(feature = "alloc")]
+struct PooledSegment(*mut u8);
+
+#[cfg(feature = "alloc")]
+unsafe impl Send for PooledSegment {}
+
+/// Counters describing how well a [`SegmentPool`] is doing.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SegmentPoolStats {
+    /// Number of segment allocations served from the pool.
+    pub hits: u64,
+
+    /// Number of segment allocations that went to the heap.
+    pub misses: u64,
+
+    /// Number of segments currently in the pool.
+    pub pooled_segments: usize,
+
+    /// Total size of the segments currently in the pool, in words.
+    pub pooled_words: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl Default for SegmentPool {
+    fn default() -> Self {
+        Self {
+            free: alloc::collections::BTreeMap::new(),
+            pooled_words: 0,
+            max_segment_words: 1 << 20,
+            max_pooled_words: 1 << 22,
+            hits: 0,
+            misses: 0,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl SegmentPool {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the size, in words, of the largest segment that the pool keeps.
+    pub fn max_segment_words(mut self, value: u32) -> Self {
+        self.max_segment_words = value;
+        self
+    }
+
+    /// Sets the total number of words that the pool keeps at most.
+    pub fn max_pooled_words(mut self, value: usize) -> Self {
+        self.max_pooled_words = value;
+        self
+    }
+
+    pub fn stats(&self) -> SegmentPoolStats {
+        SegmentPoolStats {
+            hits: self.hits,
+            misses: self.misses,
+            pooled_segments: self.free.values().map(|v| v.len()).sum(),
+            pooled_words: self.pooled_words,
+        }
+    }
+
+    /// Deallocates all the segments in the pool.
+    pub fn clear(&mut self) {
+        for (size, segments) in core::mem::take(&mut self.free) {
+            let layout =
+                alloc::alloc::Layout::from_size_align(size as usize * BYTES_PER_WORD, 8).unwrap();
+            for segment in segments {
+                unsafe { alloc::alloc::dealloc(segment.0, layout) }
+            }
+        }
+        self.pooled_words = 0;
+    }
+
+    /// Takes the smallest pooled segment with at least `minimum_size` words.
+    fn take(&mut self, minimum_size: u32) -> Option<(*mut u8, u32)> {
+        let Some((&size, segments)) = self.free.range_mut(minimum_size..).next() else {
+            self.misses += 1;
+            return None;
+        };
+        let segment = segments.pop().expect("no empty lists in the pool");
+        if segments.is_empty() {
+            self.free.remove(&size);
+        }
+        self.pooled_words -= size as usize;
+        self.hits += 1;
+        Some((segment.0, size))
+    }
+
+    /// Puts a segment allocated with the global allocator into the pool, zeroing its first
+    /// `words_used` words. Returns false, leaving the segment alone, if the pool is full.
+    unsafe fn put(&mut self, ptr: *mut u8, word_size: u32, words_used: u32) -> bool {
+        if word_size > self.max_segment_words
+            || self.pooled_words + word_size as usize > self.max_pooled_words
+        {
+            return false;
+        }
+        unsafe {
+            core::ptr::write_bytes(ptr, 0u8, words_used as usize * BYTES_PER_WORD);
+        }
+        self.free
+            .entry(word_size)
+            .or_default()
+            .push(PooledSegment(ptr));
+        self.pooled_words += word_size as usize;
+        true
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Drop for SegmentPool {
+    fn drop(&mut self) {
+        self.clear()
+    }
+}
+
+/// A handle to a [`SegmentPool`] that can be shared between allocators.
+/// `Rc<RefCell<SegmentPool>>` is the handle for pools used on a single thread, and
+/// `Arc<Mutex<SegmentPool>>` the one for pools shared between threads.
+#[cfg(feature = "alloc")]
+pub trait SharedSegmentPool {
+    fn with_pool<R>(&self, f: impl FnOnce(&mut SegmentPool) -> R) -> R;
+}
+
+#[cfg(feature = "alloc")]
+impl SharedSegmentPool for alloc::rc::Rc<core::cell::RefCell<SegmentPool>> {
+    fn with_pool<R>(&self, f: impl FnOnce(&mut SegmentPool) -> R) -> R {
+        f(&mut self.borrow_mut())
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "std"))]
+impl SharedSegmentPool for std::sync::Arc<std::sync::Mutex<SegmentPool>> {
+    fn with_pool<R>(&self, f: impl FnOnce(&mut SegmentPool) -> R) -> R {
+        // The pool is consistent between method calls, so a poisoned lock is harmless.
+        f(&mut self.lock().unwrap_or_else(|e| e.into_inner()))
+    }
+}
+
+/// An Allocator that takes segments from a [`SegmentPool`] when it can, and from the heap
+/// otherwise, and gives them back to the pool when the message is done with them.
+/// Segment sizes follow the same rules as for [`HeapAllocator`], which can be configured
+/// through the same methods.
+///
+/// Hand a clone of the pool handle to each message builder:
+///
+/// ```
+/// use std::{cell::RefCell, rc::Rc};
+/// use capnp::message::{Builder, PooledAllocator, SegmentPool};
+///
+/// let pool = Rc::new(RefCell::new(SegmentPool::new()));
+/// for i in 0..10 {
+///     let mut message = Builder::new(PooledAllocator::new(pool.clone()));
+///     message.set_root(&*format!("message {i}")).unwrap();
+/// }
+/// assert_eq!(pool.borrow().stats().hits, 9);
+/// ```
+#[cfg(feature = "alloc")]
+pub struct PooledAllocator<P = alloc::rc::Rc<core::cell::RefCell<SegmentPool>>>
+where
+    P: SharedSegmentPool,
+{
+    pool: P,
+    heap: HeapAllocator,
+    first_segment_words: u32,
+}
+
+#[cfg(feature = "alloc")]
+impl<P> PooledAllocator<P>
+where
+    P: SharedSegmentPool,
+{
+    pub fn new(pool: P) -> Self {
+        Self {
+            pool,
+            heap: HeapAllocator::new(),
+            first_segment_words: SUGGESTED_FIRST_SEGMENT_WORDS,
+        }
+    }
+
+    /// Sets the size of the initial segment in words, where 1 word = 8 bytes.
+    pub fn first_segment_words(mut self, value: u32) -> Self {
+        self.heap = self.heap.first_segment_words(value);
+        self.first_segment_words = value;
+        self
+    }
+
+    /// Sets the allocation strategy for segments after the first one.
+    pub fn allocation_strategy(mut self, value: AllocationStrategy) -> Self {
+        self.heap = self.heap.allocation_strategy(value);
+        self
+    }
+
+    /// Sets the maximum number of words allowed in a single allocation.
+    pub fn max_segment_words(mut self, value: u32) -> Self {
+        self.heap = self.heap.max_segment_words(value);
+        self
+    }
+
+    pub fn pool(&self) -> &P {
+        &self.pool
+    }
+
+    fn take_from_pool(&mut self, minimum_size: u32) -> Option<(*mut u8, u32)> {
+        let size = self.heap.segment_size(minimum_size);
+        let segment = self.pool.with_pool(|pool| pool.take(size))?;
+        self.heap.grow(segment.1);
+        Some(segment)
+    }
+}
+
+#[cfg(feature = "alloc")]
+unsafe impl<P> Allocator for PooledAllocator<P>
+where
+    P: SharedSegmentPool,
+{
+    fn allocate_segment(&mut self, minimum_size: u32) -> (*mut u8, u32) {
+        match self.take_from_pool(minimum_size) {
+            Some(segment) => segment,
+            None => self.heap.allocate_segment(minimum_size),
+        }
+    }
+
+    fn try_allocate_segment(&mut self, minimum_size: u32) -> Result<(*mut u8, u32)> {
+        match self.take_from_pool(minimum_size) {
+            Some(segment) => Ok(segment),
+            None => self.heap.try_allocate_segment(minimum_size),
+        }
+    }
+
+    unsafe fn deallocate_segment(&mut self, ptr: *mut u8, word_size: u32, words_used: u32) {
+        let pooled = self
+            .pool
+            .with_pool(|pool| unsafe { pool.put(ptr, word_size, words_used) });
+        if !pooled {
+            unsafe { self.heap.deallocate_segment(ptr, word_size, words_used) }
+        }
+        self.heap.next_size = self.first_segment_words;
+    }
+}
+
 /// An Allocator whose first and only segment is a backed by a user-provided buffer.
-/// If the segment fills up, subsequent allocations trigger panics.
+/// If the segment fills up, subsequent allocations trigger panics, or return errors
+/// when made through the `try_` methods of the builders.
 ///
 /// The main purpose of this struct is to be used in situations where heap allocation
 /// is not available.
@@ -951,6 +1536,24 @@ unsafe impl<'a> Allocator for SingleSegmentAllocator<'a> {
         }
     }
 
+    fn try_allocate_segment(&mut self, minimum_size: u32) -> Result<(*mut u8, u32)> {
+        let available_word_count = self.segment.len() / BYTES_PER_WORD;
+        if (minimum_size as usize) > available_word_count {
+            let mut error = Error::from_kind(ErrorKind::AllocationFailed);
+            write!(
+                error,
+                "asked for {minimum_size} words, but only {available_word_count} are available"
+            );
+            Err(error)
+        } else if self.segment_allocated {
+            let mut error = Error::from_kind(ErrorKind::AllocationFailed);
+            write!(error, "SingleSegmentAllocator has no more segments");
+            Err(error)
+        } else {
+            Ok(self.allocate_segment(minimum_size))
+        }
+    }
+
     unsafe fn deallocate_segment(&mut self, ptr: *mut u8, _word_size: u32, words_used: u32) {
         let seg_ptr = self.segment.as_mut_ptr();
         if ptr == seg_ptr {
@@ -968,6 +1571,136 @@ unsafe impl<'a> Allocator for SingleSegmentAllocator<'a> {
     }
 }
 
+/// An Allocator that wraps another allocator and caps the total number of words of all
+/// segments that it hands out, so that a message cannot grow beyond a fixed size.
+///
+/// Once the limit is reached, allocations fail. Through the `try_` methods of the builders
+/// (for example [`Builder::try_init_root()`] or any `set_*()` method that returns a
+/// `Result`) this surfaces as an error of kind [`ErrorKind::AllocationFailed`]; the
+/// infallible methods panic.
+///
+/// If the wrapped allocator returns a segment that is larger than the space left under the
+/// limit, only the part that fits is made available to the message.
+pub struct BoundedAllocator<A: Allocator> {
+    allocator: A,
+    limit: u32,
+    allocated: u32,
+
+    // Addresses and real sizes of the live segments that were truncated to fit the limit,
+    // which the wrapped allocator needs back when they are deallocated. Without "alloc" there
+    // is room for only one, and allocations that would truncate a second one fail instead.
+    #[cfg(feature = "alloc")]
+    truncated: alloc::vec::Vec<(usize, u32)>,
+    #[cfg(not(feature = "alloc"))]
+    truncated: Option<(usize, u32)>,
+}
+
+impl<A: Allocator> BoundedAllocator<A> {
+    /// Constructs an allocator that lets messages use at most `limit` words.
+    pub fn new(allocator: A, limit: u32) -> Self {
+        Self {
+            allocator,
+            limit,
+            allocated: 0,
+            truncated: Default::default(),
+        }
+    }
+
+    /// The maximum number of words that this allocator hands out.
+    pub fn limit(&self) -> u32 {
+        self.limit
+    }
+
+    /// The number of words in the segments that are currently allocated.
+    pub fn allocated_words(&self) -> u32 {
+        self.allocated
+    }
+
+    /// Returns the wrapped allocator.
+    pub fn into_inner(self) -> A {
+        self.allocator
+    }
+
+    fn limit_exceeded(&self, minimum_size: u32) -> Error {
+        let mut error = Error::from_kind(ErrorKind::AllocationFailed);
+        write!(
+            error,
+            "message size limit of {} words exceeded: asked for {minimum_size} words, \
+             but only {} are available",
+            self.limit,
+            self.limit - self.allocated
+        );
+        error
+    }
+
+    // Returns false if there is no room to remember the segment.
+    fn remember_truncated(&mut self, ptr: *mut u8, real_size: u32) -> bool {
+        #[cfg(feature = "alloc")]
+        self.truncated.push((ptr as usize, real_size));
+        #[cfg(not(feature = "alloc"))]
+        if self.truncated.is_some() {
+            return false;
+        } else {
+            self.truncated = Some((ptr as usize, real_size));
+        }
+        true
+    }
+
+    fn forget_truncated(&mut self, ptr: *mut u8) -> Option<u32> {
+        #[cfg(feature = "alloc")]
+        {
+            let i = self
+                .truncated
+                .iter()
+                .position(|&(addr, _)| addr == ptr as usize)?;
+            Some(self.truncated.swap_remove(i).1)
+        }
+        #[cfg(not(feature = "alloc"))]
+        match self.truncated {
+            Some((addr, real_size)) if addr == ptr as usize => {
+                self.truncated = None;
+                Some(real_size)
+            }
+            _ => None,
+        }
+    }
+}
+
+unsafe impl<A: Allocator> Allocator for BoundedAllocator<A> {
+    fn allocate_segment(&mut self, minimum_size: u32) -> (*mut u8, u32) {
+        match self.try_allocate_segment(minimum_size) {
+            Ok(segment) => segment,
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    fn try_allocate_segment(&mut self, minimum_size: u32) -> Result<(*mut u8, u32)> {
+        let available = self.limit - self.allocated;
+        if minimum_size > available {
+            return Err(self.limit_exceeded(minimum_size));
+        }
+        let (ptr, size) = self.allocator.try_allocate_segment(minimum_size)?;
+        let size = if size > available {
+            if !self.remember_truncated(ptr, size) {
+                unsafe { self.allocator.deallocate_segment(ptr, size, 0) };
+                return Err(self.limit_exceeded(minimum_size));
+            }
+            available
+        } else {
+            size
+        };
+        self.allocated += size;
+        Ok((ptr, size))
+    }
+
+    unsafe fn deallocate_segment(&mut self, ptr: *mut u8, word_size: u32, words_used: u32) {
+        self.allocated -= word_size;
+        let word_size = self.forget_truncated(ptr).unwrap_or(word_size);
+        self.allocator
+            .deallocate_segment(ptr, word_size, words_used)
+    }
+}
+
 unsafe impl<'a, A> Allocator for &'a mut A
 where
     A: Allocator,
@@ -976,6 +1709,10 @@ where
         (*self).allocate_segment(minimum_size)
     }
 
+    fn try_allocate_segment(&mut self, minimum_size: u32) -> Result<(*mut u8, u32)> {
+        (*self).try_allocate_segment(minimum_size)
+    }
+
     unsafe fn deallocate_segment(&mut self, ptr: *mut u8, word_size: u32, words_used: u32) {
         (*self).deallocate_segment(ptr, word_size, words_used)
     }
@@ -0,0 +1,318 @@
+//! Messages stored in memory-mapped files.
+//!
+//! [`Mmap`] maps a file for reading, so that a message in it can be read without copying
+//! it into memory first; see [`serialize::read_message_from_mmap()`](crate::serialize::read_message_from_mmap).
+//! [`FileAllocator`] goes the other way: it places the segments of a message builder
+//! directly in a file, which holds the finished message in the
+//! [standard stream framing](https://capnproto.org/encoding.html#serialization-over-a-stream)
+//! once the builder is done with it.
+//!
+//! Only available on Linux, with the "mmap" feature.
+
+use std::fs::File;
+use std::io;
+use std::os::unix::fs::FileExt;
+use std::os::unix::io::AsRawFd;
+use std::path::Path;
+
+use crate::message::{AllocationStrategy, Allocator, HeapAllocator};
+use crate::private::units::BYTES_PER_WORD;
+use crate::serialize::SEGMENTS_COUNT_LIMIT;
+use crate::{Error, ErrorKind, Result};
+
+/// A read-only memory mapping of a whole file.
+pub struct Mmap {
+    ptr: *mut u8,
+    len: usize,
+}
+
+// The mapping is never written to, and is owned by this struct.
+unsafe impl Send for Mmap {}
+unsafe impl Sync for Mmap {}
+
+impl Mmap {
+    /// Maps the current contents of `file`, which must be open for reading.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated, by this process or any other, for as
+    /// long as the mapping exists. The mapped bytes are handed out as a `&[u8]`, which
+    /// must not change underneath its borrowers, and accessing a page past the end of a
+    /// truncated file raises `SIGBUS`.
+    pub unsafe fn map(file: &File) -> io::Result<Self> {
+        let len = usize::try_from(file.metadata()?.len())
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "file is too large"))?;
+        if len == 0 {
+            // mmap() rejects empty mappings.
+            return Ok(Self {
+                ptr: core::ptr::NonNull::<crate::Word>::dangling().as_ptr() as *mut u8,
+                len,
+            });
+        }
+        let ptr = unsafe {
+            libc::mmap(
+                core::ptr::null_mut(),
+                len,
+                libc::PROT_READ,
+                libc::MAP_SHARED,
+                file.as_raw_fd(),
+                0,
+            )
+        };
+        if ptr == libc::MAP_FAILED {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(Self {
+            ptr: ptr as *mut u8,
+            len,
+        })
+    }
+
+    /// Opens the file at `path` and maps it.
+    ///
+    /// # Safety
+    ///
+    /// As for [`map()`](Self::map).
+    pub unsafe fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::map(&File::open(path)?)
+    }
+}
+
+impl core::ops::Deref for Mmap {
+    type Target = [u8];
+    fn deref(&self) -> &[u8] {
+        unsafe { core::slice::from_raw_parts(self.ptr, self.len) }
+    }
+}
+
+impl Drop for Mmap {
+    fn drop(&mut self) {
+        if self.len > 0 {
+            unsafe {
+                libc::munmap(self.ptr as *mut libc::c_void, self.len);
+            }
+        }
+    }
+}
+
+// The file starts with a segment table of this many bytes, which has room for
+// `SEGMENT_SLOTS` segments. Slots that are not used hold empty segments.
+const HEADER_BYTES: usize = SEGMENTS_COUNT_LIMIT * 4;
+const SEGMENT_SLOTS: usize = SEGMENTS_COUNT_LIMIT - 1;
+
+struct FileSegment {
+    ptr: *mut u8,
+    offset: u64,
+    word_size: u32,
+}
+
+/// An Allocator that places segments in a file, each one in its own shared memory mapping,
+/// so that whatever is written to the message goes straight to the file.
+///
+/// The file always holds a well-formed message in the standard stream framing, with a
+/// segment table of fixed size at the start that is padded out with empty segments.
+/// Segments are appended to the file as they are allocated, and their sizes are filled
+/// into the table. When the last segment is deallocated, the file is truncated to the
+/// part of it that was used. Once the message builder is dropped (or `into_allocator()`
+/// has been called), the file can be read with any Cap'n Proto implementation, for example
+/// with [`serialize::read_message_from_mmap()`](crate::serialize::read_message_from_mmap).
+///
+/// A `FileAllocator` holds a single message of up to 511 segments. Segment sizes are
+/// chosen as for [`HeapAllocator`], and can be configured through the same methods.
+pub struct FileAllocator {
+    file: File,
+    sizes: HeapAllocator,
+    page_size: u64,
+    segment_count: usize,
+    end: u64,
+    mapped: Vec<FileSegment>,
+
+    // Whether a segment has been deallocated, which means that the message is done.
+    finished: bool,
+}
+
+// The mappings are owned by the allocator.
+unsafe impl Send for FileAllocator {}
+
+impl FileAllocator {
+    /// Creates the file at `path`, truncating it if it exists, and constructs an allocator
+    /// that builds a message in it.
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        let file = File::options()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)?;
+        Self::new(file)
+    }
+
+    /// Constructs an allocator that builds a message in `file`, which must be open for
+    /// reading and writing. The current contents of the file are discarded.
+    pub fn new(file: File) -> io::Result<Self> {
+        file.set_len(0)?;
+        file.set_len(HEADER_BYTES as u64)?;
+        file.write_all_at(&(SEGMENT_SLOTS as u32 - 1).to_le_bytes(), 0)?;
+        let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) } as u64;
+        Ok(Self {
+            file,
+            sizes: HeapAllocator::new(),
+            page_size,
+            segment_count: 0,
+            end: HEADER_BYTES as u64,
+            mapped: Vec::new(),
+            finished: false,
+        })
+    }
+
+    /// Sets the size of the initial segment in words, where 1 word = 8 bytes.
+    pub fn first_segment_words(mut self, value: u32) -> Self {
+        self.sizes = core::mem::take(&mut self.sizes).first_segment_words(value);
+        self
+    }
+
+    /// Sets the allocation strategy for segments after the first one.
+    pub fn allocation_strategy(mut self, value: AllocationStrategy) -> Self {
+        self.sizes = core::mem::take(&mut self.sizes).allocation_strategy(value);
+        self
+    }
+
+    /// The number of segments allocated so far.
+    pub fn segment_count(&self) -> usize {
+        self.segment_count
+    }
+
+    /// Flushes the file, including the parts of the message that have been written through
+    /// mappings that are still live, to disk.
+    pub fn sync_all(&self) -> io::Result<()> {
+        self.file.sync_all()
+    }
+
+    /// Returns the file. Any segments that are still allocated are unmapped.
+    pub fn into_file(self) -> File {
+        let mut this = core::mem::ManuallyDrop::new(self);
+        for segment in core::mem::take(&mut this.mapped) {
+            unsafe { this.unmap(&segment) }
+        }
+        // The other fields own no resources.
+        unsafe { core::ptr::read(&this.file) }
+    }
+
+    fn set_segment_size(&self, index: usize, word_size: u32) -> io::Result<()> {
+        self.file
+            .write_all_at(&word_size.to_le_bytes(), 4 + 4 * index as u64)
+    }
+
+    fn map_segment(&mut self, minimum_size: u32) -> io::Result<(*mut u8, u32)> {
+        let word_size = self.sizes.segment_size(minimum_size);
+        let offset = self.end;
+        let end = offset + u64::from(word_size) * BYTES_PER_WORD as u64;
+        self.file.set_len(end)?;
+
+        // Mappings have to start at a page boundary, which segments need not.
+        let map_offset = offset - offset % self.page_size;
+        let ptr = unsafe {
+            libc::mmap(
+                core::ptr::null_mut(),
+                (end - map_offset) as usize,
+                libc::PROT_READ | libc::PROT_WRITE,
+                libc::MAP_SHARED,
+                self.file.as_raw_fd(),
+                map_offset as libc::off_t,
+            )
+        };
+        if ptr == libc::MAP_FAILED {
+            let err = io::Error::last_os_error();
+            let _ = self.file.set_len(offset);
+            return Err(err);
+        }
+        let ptr = unsafe { (ptr as *mut u8).add((offset - map_offset) as usize) };
+        if let Err(err) = self.set_segment_size(self.segment_count, word_size) {
+            unsafe {
+                self.unmap(&FileSegment {
+                    ptr,
+                    offset,
+                    word_size,
+                })
+            };
+            let _ = self.file.set_len(offset);
+            return Err(err);
+        }
+        self.mapped.push(FileSegment {
+            ptr,
+            offset,
+            word_size,
+        });
+        self.segment_count += 1;
+        self.end = end;
+        self.sizes.grow(word_size);
+        Ok((ptr, word_size))
+    }
+
+    unsafe fn unmap(&self, segment: &FileSegment) {
+        let delta = segment.offset % self.page_size;
+        unsafe {
+            libc::munmap(
+                segment.ptr.sub(delta as usize) as *mut libc::c_void,
+                delta as usize + segment.word_size as usize * BYTES_PER_WORD,
+            );
+        }
+    }
+}
+
+unsafe impl Allocator for FileAllocator {
+    fn allocate_segment(&mut self, minimum_size: u32) -> (*mut u8, u32) {
+        match self.try_allocate_segment(minimum_size) {
+            Ok(segment) => segment,
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    fn try_allocate_segment(&mut self, minimum_size: u32) -> Result<(*mut u8, u32)> {
+        if self.finished {
+            let mut error = Error::from_kind(ErrorKind::AllocationFailed);
+            write!(error, "FileAllocator already holds a finished message");
+            return Err(error);
+        }
+        if self.segment_count == SEGMENT_SLOTS {
+            let mut error = Error::from_kind(ErrorKind::AllocationFailed);
+            write!(error, "FileAllocator has no more room in its segment table");
+            return Err(error);
+        }
+        self.map_segment(minimum_size).map_err(|e| {
+            let mut error = Error::from_kind(ErrorKind::AllocationFailed);
+            write!(error, "could not map segment: {e}");
+            error
+        })
+    }
+
+    unsafe fn deallocate_segment(&mut self, ptr: *mut u8, _word_size: u32, words_used: u32) {
+        let Some(idx) = self.mapped.iter().position(|s| s.ptr == ptr) else {
+            return;
+        };
+        let segment = self.mapped.swap_remove(idx);
+        unsafe { self.unmap(&segment) };
+        self.finished = true;
+
+        // Give back the unused tail of the last segment in the file.
+        let used_end = segment.offset + u64::from(words_used) * BYTES_PER_WORD as u64;
+        if segment.offset + u64::from(segment.word_size) * BYTES_PER_WORD as u64 == self.end
+            && self.file.set_len(used_end).is_ok()
+        {
+            let index = self.segment_count - 1;
+            if self.set_segment_size(index, words_used).is_ok() {
+                self.end = used_end;
+            } else {
+                let _ = self.file.set_len(self.end);
+            }
+        }
+    }
+}
+
+impl Drop for FileAllocator {
+    fn drop(&mut self) {
+        for segment in core::mem::take(&mut self.mapped) {
+            unsafe { self.unmap(&segment) }
+        }
+    }
+}
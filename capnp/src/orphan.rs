@@ -0,0 +1,161 @@
+//! Orphans: objects that are part of a message but not reachable from its root.
+//!
+//! Disowning a pointer field detaches the object it points to and returns it as an
+//! [`Orphan`], leaving the field null. Adopting the orphan into another field of the same
+//! message attaches it there. Neither step copies the object, so this is the way to move
+//! a subtree, or to reorder the elements of a list of lists, without leaving a copy behind.
+//!
+//! Generated builders have `disown_foo()` and `adopt_foo()` methods for each pointer
+//! field `foo`, and [`dynamic_struct::Builder`](crate::dynamic_struct::Builder) has
+//! [`disown()`](crate::dynamic_struct::Builder::disown) and
+//! [`adopt()`](crate::dynamic_struct::Builder::adopt). New orphans can be created, and
+//! existing ones read or modified, through the [`Orphanage`] of a message.
+//!
+//! An orphan that is dropped without being adopted stays in the message as garbage,
+//! in the same way as an object overwritten by a setter.
+
+use core::marker::PhantomData;
+
+use crate::private::arena::BuilderArena;
+use crate::private::layout::{CapTableBuilder, OrphanBuilder, PointerBuilder, PrimitiveElement};
+use crate::traits::{FromPointerBuilder, FromPointerReader, Owned, OwnedStruct, SetterInput};
+use crate::{any_pointer, data, primitive_list, struct_list, text, Result};
+
+/// An object of type `T` that has been detached from its message, or created in a
+/// message without being attached anywhere yet.
+#[must_use]
+pub struct Orphan<T> {
+    builder: OrphanBuilder,
+    marker: PhantomData<T>,
+}
+
+impl<T> Orphan<T> {
+    /// Detaches the object that `pointer` points to, leaving `pointer` null.
+    pub fn disown(mut pointer: PointerBuilder<'_>) -> Self {
+        Self {
+            builder: pointer.disown(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Makes `pointer` point to this orphan, replacing whatever it pointed to before.
+    /// Fails if `pointer` belongs to a different message than the orphan.
+    pub fn adopt_into(self, mut pointer: PointerBuilder<'_>) -> Result<()> {
+        pointer.adopt(self.builder)
+    }
+
+    pub fn into_any_pointer(self) -> Orphan<any_pointer::Owned> {
+        Orphan {
+            builder: self.builder,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl Orphan<any_pointer::Owned> {
+    /// Reinterprets the orphan as a `T`. Like
+    /// [`any_pointer::Builder::get_as()`](crate::any_pointer::Builder::get_as), this
+    /// does not check the type; accessing the orphan as the wrong type fails later.
+    pub fn into_typed<T: Owned>(self) -> Orphan<T> {
+        Orphan {
+            builder: self.builder,
+            marker: PhantomData,
+        }
+    }
+}
+
+/// Creates orphans in a message, and gives access to their contents.
+pub struct Orphanage<'a> {
+    arena: &'a mut dyn BuilderArena,
+    cap_table: CapTableBuilder,
+}
+
+impl<'a> Orphanage<'a> {
+    pub(crate) fn new(arena: &'a mut dyn BuilderArena) -> Self {
+        Self {
+            arena,
+            cap_table: Default::default(),
+        }
+    }
+
+    /// Creates a null orphan. Initialize it with [`init()`](Self::init), or with
+    /// [`get()`](Self::get) for struct types.
+    pub fn new_orphan<T: Owned>(&mut self) -> Orphan<T> {
+        Orphan {
+            builder: OrphanBuilder::new(self.arena, 0, self.cap_table),
+            marker: PhantomData,
+        }
+    }
+
+    /// Creates an orphan holding a deep copy of `value`.
+    pub fn new_orphan_copy<T: Owned>(&mut self, value: impl SetterInput<T>) -> Result<Orphan<T>> {
+        let mut orphan = self.new_orphan();
+        let pointer = orphan.builder.as_pointer_builder(self.arena)?;
+        SetterInput::set_pointer_builder(pointer, value, false)?;
+        Ok(orphan)
+    }
+
+    /// Gets a builder for the contents of `orphan`. A null struct orphan is initialized
+    /// to the default value, as with a struct field getter.
+    pub fn get<'b, T: Owned>(&'b mut self, orphan: &'b mut Orphan<T>) -> Result<T::Builder<'b>> {
+        FromPointerBuilder::get_from_pointer(orphan.builder.as_pointer_builder(self.arena)?, None)
+    }
+
+    /// Initializes `orphan` as a new value, with `size` elements if `T` is a list type.
+    pub fn init<'b, T: Owned>(
+        &'b mut self,
+        orphan: &'b mut Orphan<T>,
+        size: u32,
+    ) -> Result<T::Builder<'b>> {
+        Ok(FromPointerBuilder::init_pointer(
+            orphan.builder.as_pointer_builder(self.arena)?,
+            size,
+        ))
+    }
+
+    /// Gets a builder for the text in `orphan` that can be appended to, setting it to empty
+    /// text if it is null. See [`text::GrowableBuilder`].
+    pub fn get_growable_text<'b>(
+        &'b mut self,
+        orphan: &'b mut Orphan<text::Owned>,
+    ) -> Result<text::GrowableBuilder<'b>> {
+        text::GrowableBuilder::new(orphan.builder.as_pointer_builder(self.arena)?)
+    }
+
+    /// Gets a builder for the data in `orphan` that can be appended to, setting it to empty
+    /// data if it is null. See [`data::GrowableBuilder`].
+    pub fn get_growable_data<'b>(
+        &'b mut self,
+        orphan: &'b mut Orphan<data::Owned>,
+    ) -> Result<data::GrowableBuilder<'b>> {
+        data::GrowableBuilder::new(orphan.builder.as_pointer_builder(self.arena)?)
+    }
+
+    /// Gets a builder for the list in `orphan` that can grow and shrink, setting it to an
+    /// empty list if it is null. See [`primitive_list::GrowableBuilder`].
+    pub fn get_growable_list<'b, T: PrimitiveElement>(
+        &'b mut self,
+        orphan: &'b mut Orphan<primitive_list::Owned<T>>,
+    ) -> Result<primitive_list::GrowableBuilder<'b, T>> {
+        primitive_list::GrowableBuilder::new(orphan.builder.as_pointer_builder(self.arena)?)
+    }
+
+    /// Gets a builder for the struct list in `orphan` that can grow and shrink, setting it to
+    /// an empty list if it is null. See [`struct_list::GrowableBuilder`].
+    pub fn get_growable_struct_list<'b, T: OwnedStruct>(
+        &'b mut self,
+        orphan: &'b mut Orphan<struct_list::Owned<T>>,
+    ) -> Result<struct_list::GrowableBuilder<'b, T>> {
+        struct_list::GrowableBuilder::new(orphan.builder.as_pointer_builder(self.arena)?)
+    }
+
+    /// Gets a reader for the contents of `orphan`.
+    pub fn get_reader<'b, T: Owned>(&'b self, orphan: &'b Orphan<T>) -> Result<T::Reader<'b>> {
+        FromPointerReader::get_from_pointer(&orphan.builder.as_pointer_reader(&*self.arena)?, None)
+    }
+
+    /// Checks whether `orphan` is null.
+    pub fn is_null<T>(&self, orphan: &Orphan<T>) -> Result<bool> {
+        Ok(orphan.builder.as_pointer_reader(&*self.arena)?.is_null())
+    }
+}
@@ -25,10 +25,11 @@ use core::marker;
 
 use crate::introspect;
 use crate::private::layout::{
-    data_bits_per_element, ListBuilder, ListReader, PointerBuilder, PointerReader, PrimitiveElement,
+    data_bits_per_element, ListBuilder, ListReader, PointerBuilder, PointerReader,
+    PrimitiveElement, StructSize,
 };
 use crate::traits::{FromPointerBuilder, FromPointerReader, IndexMove, ListIter};
-use crate::Result;
+use crate::{Error, ErrorKind, Result};
 
 #[derive(Clone, Copy)]
 pub struct Owned<T> {
@@ -39,7 +40,7 @@ impl<T> introspect::Introspect for Owned<T>
 where
     T: introspect::Introspect,
 {
-    fn introspect() -> introspect::Type {
+    fn introspect() -> introspect::Type<'static> {
         introspect::Type::list_of(T::introspect())
     }
 }
@@ -237,6 +238,12 @@ impl<'a, T: PrimitiveElement> FromPointerBuilder<'a> for Builder<'a, T> {
             marker: marker::PhantomData,
         }
     }
+    fn try_init_pointer(builder: PointerBuilder<'a>, size: u32) -> Result<Builder<'a, T>> {
+        Ok(Builder {
+            builder: builder.try_init_list(T::element_size(), size)?,
+            marker: marker::PhantomData,
+        })
+    }
     fn get_from_pointer(
         builder: PointerBuilder<'a>,
         default: Option<&'a [crate::Word]>,
@@ -274,6 +281,158 @@ impl<'a, T: PrimitiveElement> Builder<'a, T> {
     }
 }
 
+/// A list builder that can grow and shrink after it has been created, unlike [`Builder`],
+/// whose length is fixed when it is initialized.
+///
+/// When new elements do not fit in the space allocated so far, the list is extended in place
+/// if it is the last object in its segment, and otherwise moved to a new allocation in the
+/// same message with double the capacity. A move leaves the old space behind as zeroed
+/// garbage, as does any spare capacity; [`compact()`](crate::message::Builder::compact)
+/// reclaims both. Shrinking never moves the list.
+///
+/// A `GrowableBuilder` for a field is usually obtained from
+/// [`Orphanage::get_growable_list()`](crate::orphan::Orphanage::get_growable_list) and the
+/// orphan then adopted into the field.
+pub struct GrowableBuilder<'a, T>
+where
+    T: PrimitiveElement,
+{
+    marker: marker::PhantomData<T>,
+    pointer: PointerBuilder<'a>,
+    len: u32,
+
+    /// Words allocated for the list.
+    capacity: u32,
+}
+
+impl<'a, T> GrowableBuilder<'a, T>
+where
+    T: PrimitiveElement,
+{
+    const NO_STRUCT: StructSize = StructSize {
+        data: 0,
+        pointers: 0,
+    };
+
+    /// Creates a builder for the list that `pointer` points to, setting it to an empty list
+    /// if it is null.
+    pub fn new(mut pointer: PointerBuilder<'a>) -> Result<Self> {
+        let len = pointer.reborrow().get_list(T::element_size(), None)?.len();
+        let mut capacity = 0;
+        pointer.resize_list(T::element_size(), Self::NO_STRUCT, len, &mut capacity)?;
+        Ok(Self {
+            marker: marker::PhantomData,
+            pointer,
+            len,
+            capacity,
+        })
+    }
+
+    fn set_len(&mut self, len: u32) -> Result<()> {
+        self.pointer
+            .resize_list(T::element_size(), Self::NO_STRUCT, len, &mut self.capacity)?;
+        self.len = len;
+        Ok(())
+    }
+
+    pub fn len(&self) -> u32 {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The number of elements the list can hold without being moved.
+    pub fn capacity(&self) -> u32 {
+        match data_bits_per_element(T::element_size()) {
+            0 => u32::MAX,
+            bits => (u64::from(self.capacity) * 64 / u64::from(bits)) as u32,
+        }
+    }
+
+    /// The length after adding `additional` elements, or an error if that overflows.
+    fn grown_len(&self, additional: usize) -> Result<u32> {
+        u32::try_from(additional)
+            .ok()
+            .and_then(|additional| self.len.checked_add(additional))
+            .ok_or_else(|| {
+                let mut error = Error::from_kind(ErrorKind::Failed);
+                write!(error, "list cannot grow by {additional} elements");
+                error
+            })
+    }
+
+    /// Makes sure that at least `additional` more elements can be added without moving the list.
+    pub fn reserve(&mut self, additional: u32) -> Result<()> {
+        let len = self.len;
+        self.set_len(self.grown_len(additional as usize)?)?;
+        self.set_len(len)
+    }
+
+    /// Appends `value` to the end of the list.
+    pub fn push(&mut self, value: T) -> Result<()> {
+        let index = self.len;
+        self.set_len(self.grown_len(1)?)?;
+        self.as_builder().set(index, value);
+        Ok(())
+    }
+
+    /// Appends all of `values` to the end of the list.
+    pub fn extend_from_slice(&mut self, values: &[T]) -> Result<()>
+    where
+        T: Copy,
+    {
+        let start = self.len;
+        self.set_len(self.grown_len(values.len())?)?;
+        let mut builder = self.as_builder();
+        for (index, value) in (start..).zip(values) {
+            builder.set(index, *value);
+        }
+        Ok(())
+    }
+
+    /// Shortens the list to `len` elements, zeroing the rest. Does nothing if the list is
+    /// already shorter. The allocated space stays the same.
+    pub fn truncate(&mut self, len: u32) -> Result<()> {
+        if len < self.len {
+            self.set_len(len)?;
+        }
+        Ok(())
+    }
+
+    /// Removes all elements, zeroing them.
+    pub fn clear(&mut self) -> Result<()> {
+        self.truncate(0)
+    }
+
+    /// Gets a fixed-length builder for the current elements.
+    pub fn as_builder(&mut self) -> Builder<'_, T> {
+        Builder {
+            marker: marker::PhantomData,
+            builder: self
+                .pointer
+                .reborrow()
+                .get_list(T::element_size(), None)
+                .expect("list was checked in new()"),
+        }
+    }
+
+    pub fn into_builder(self) -> Builder<'a, T> {
+        Builder {
+            marker: marker::PhantomData,
+            builder: self
+                .pointer
+                .get_list(T::element_size(), None)
+                .expect("list was checked in new()"),
+        }
+    }
+
+    pub fn into_reader(self) -> Reader<'a, T> {
+        self.into_builder().into_reader()
+    }
+}
+
 impl<'a, T> crate::traits::SetterInput<Owned<T>> for Reader<'a, T>
 where
     T: PrimitiveElement,
@@ -299,7 +458,7 @@ where
         _canonicalize: bool,
     ) -> Result<()> {
         let builder =
-            pointer.init_list(<T as PrimitiveElement>::element_size(), value.len() as u32);
+            pointer.try_init_list(<T as PrimitiveElement>::element_size(), value.len() as u32)?;
         for (idx, v) in value.iter().enumerate() {
             PrimitiveElement::set(&builder, idx as u32, *v)
         }
@@ -19,10 +19,12 @@
 // THE SOFTWARE.
 
 use core::slice;
+use core::sync::atomic::{AtomicUsize, Ordering};
 
 use crate::message;
 use crate::message::Allocator;
 use crate::message::ReaderSegments;
+use crate::private::layout::{PointerBuilder, PointerReader};
 use crate::private::read_limiter::ReadLimiter;
 use crate::private::units::*;
 use crate::OutputSegments;
@@ -54,6 +56,9 @@ pub struct ReaderArenaImpl<S> {
     segments: S,
     read_limiter: ReadLimiter,
     nesting_limit: i32,
+
+    // If set, pointers are assumed to be in bounds and reads are not counted.
+    trusted: bool,
 }
 
 #[cfg(feature = "sync_reader")]
@@ -74,12 +79,70 @@ where
             segments,
             read_limiter: limiter,
             nesting_limit: options.nesting_limit,
+            trusted: false,
         }
     }
 
     pub fn into_segments(self) -> S {
         self.segments
     }
+
+    /// Stops bounds checking and read limiting.
+    ///
+    /// # Safety
+    /// Every pointer that can be reached from the root of the message must be in bounds.
+    pub unsafe fn set_trusted(&mut self) {
+        self.trusted = true;
+    }
+
+    pub fn is_trusted(&self) -> bool {
+        self.trusted
+    }
+
+    /// Returns a view of the arena whose reads are limited by what is left of its
+    /// traversal limit, but are not counted against it.
+    pub fn with_own_read_limit(&self) -> LimitedReaderArena<'_, S> {
+        LimitedReaderArena {
+            arena: self,
+            read_limiter: self.read_limiter.split_off(),
+        }
+    }
+
+    fn contains_interval_limited(
+        &self,
+        read_limiter: &ReadLimiter,
+        id: u32,
+        start: *const u8,
+        size_in_words: usize,
+    ) -> Result<()> {
+        if self.trusted {
+            return Ok(());
+        }
+        let (segment_start, segment_len) = self.get_segment(id)?;
+        let this_start: usize = segment_start as usize;
+        let this_size: usize = segment_len as usize * BYTES_PER_WORD;
+        let start = start as usize;
+        let size = size_in_words * BYTES_PER_WORD;
+
+        if !(start >= this_start && start - this_start + size <= this_size) {
+            Err(Error::from_kind(
+                ErrorKind::MessageContainsOutOfBoundsPointer,
+            ))
+        } else {
+            read_limiter.can_read(size_in_words)
+        }
+    }
+
+    fn amplified_read_limited(
+        &self,
+        read_limiter: &ReadLimiter,
+        virtual_amount: u64,
+    ) -> Result<()> {
+        if self.trusted {
+            return Ok(());
+        }
+        read_limiter.can_read(virtual_amount as usize)
+    }
 }
 
 impl<S> ReaderArena for ReaderArenaImpl<S>
@@ -108,10 +171,13 @@ where
         start: *const u8,
         offset_in_words: i32,
     ) -> Result<*const u8> {
+        let offset: i64 = i64::from(offset_in_words) * BYTES_PER_WORD as i64;
+        if self.trusted {
+            return unsafe { Ok(start.offset(offset as isize)) };
+        }
         let (segment_start, segment_len) = self.get_segment(segment_id)?;
         let this_start: usize = segment_start as usize;
         let this_size: usize = segment_len as usize * BYTES_PER_WORD;
-        let offset: i64 = i64::from(offset_in_words) * BYTES_PER_WORD as i64;
         let start_idx = start as usize;
         if start_idx < this_start || ((start_idx - this_start) as i64 + offset) as usize > this_size
         {
@@ -124,23 +190,11 @@ where
     }
 
     fn contains_interval(&self, id: u32, start: *const u8, size_in_words: usize) -> Result<()> {
-        let (segment_start, segment_len) = self.get_segment(id)?;
-        let this_start: usize = segment_start as usize;
-        let this_size: usize = segment_len as usize * BYTES_PER_WORD;
-        let start = start as usize;
-        let size = size_in_words * BYTES_PER_WORD;
-
-        if !(start >= this_start && start - this_start + size <= this_size) {
-            Err(Error::from_kind(
-                ErrorKind::MessageContainsOutOfBoundsPointer,
-            ))
-        } else {
-            self.read_limiter.can_read(size_in_words)
-        }
+        self.contains_interval_limited(&self.read_limiter, id, start, size_in_words)
     }
 
     fn amplified_read(&self, virtual_amount: u64) -> Result<()> {
-        self.read_limiter.can_read(virtual_amount as usize)
+        self.amplified_read_limited(&self.read_limiter, virtual_amount)
     }
 
     fn nesting_limit(&self) -> i32 {
@@ -148,14 +202,74 @@ where
     }
 }
 
+/// A `ReaderArenaImpl` that counts reads against a read limiter of its own.
+/// See `ReaderArenaImpl::with_own_read_limit()`.
+pub struct LimitedReaderArena<'a, S> {
+    arena: &'a ReaderArenaImpl<S>,
+    read_limiter: ReadLimiter,
+}
+
+impl<S> ReaderArena for LimitedReaderArena<'_, S>
+where
+    S: ReaderSegments,
+{
+    fn get_segment(&self, id: u32) -> Result<(*const u8, u32)> {
+        self.arena.get_segment(id)
+    }
+
+    unsafe fn check_offset(
+        &self,
+        segment_id: u32,
+        start: *const u8,
+        offset_in_words: i32,
+    ) -> Result<*const u8> {
+        unsafe { self.arena.check_offset(segment_id, start, offset_in_words) }
+    }
+
+    fn contains_interval(&self, id: u32, start: *const u8, size_in_words: usize) -> Result<()> {
+        self.arena
+            .contains_interval_limited(&self.read_limiter, id, start, size_in_words)
+    }
+
+    fn amplified_read(&self, virtual_amount: u64) -> Result<()> {
+        self.arena
+            .amplified_read_limited(&self.read_limiter, virtual_amount)
+    }
+
+    fn nesting_limit(&self) -> i32 {
+        self.arena.nesting_limit
+    }
+}
+
 pub trait BuilderArena: ReaderArena {
     fn allocate(&mut self, segment_id: u32, amount: WordCount32) -> Option<u32>;
-    fn allocate_anywhere(&mut self, amount: u32) -> (SegmentId, u32);
+    fn allocate_anywhere(&mut self, amount: u32) -> Result<(SegmentId, u32)>;
     fn get_segment_mut(&mut self, id: u32) -> (*mut u8, u32);
 
+    /// Identifies this arena among all arenas created so far, so that orphans
+    /// can check that they are being used with the message they came from.
+    fn id(&self) -> usize;
+
     fn as_reader(&self) -> &dyn ReaderArena;
 }
 
+fn next_arena_id() -> usize {
+    static NEXT_ARENA_ID: AtomicUsize = AtomicUsize::new(1);
+
+    #[cfg(target_has_atomic = "ptr")]
+    {
+        NEXT_ARENA_ID.fetch_add(1, Ordering::Relaxed)
+    }
+
+    // Targets without atomic read-modify-write are single-core.
+    #[cfg(not(target_has_atomic = "ptr"))]
+    {
+        let id = NEXT_ARENA_ID.load(Ordering::Relaxed);
+        NEXT_ARENA_ID.store(id + 1, Ordering::Relaxed);
+        id
+    }
+}
+
 /// A wrapper around a memory segment used in building a message.
 struct BuilderSegment {
     /// Pointer to the start of the segment.
@@ -233,6 +347,7 @@ where
     A: Allocator,
 {
     inner: BuilderArenaImplInner<A>,
+    id: usize,
 }
 
 impl<A> BuilderArenaImpl<A>
@@ -245,6 +360,7 @@ where
                 allocator: Some(allocator),
                 segments: Default::default(),
             },
+            id: next_arena_id(),
         }
     }
 
@@ -255,7 +371,14 @@ where
 
     pub fn get_segments_for_output(&self) -> OutputSegments {
         let reff = &self.inner;
-        if reff.segments.len() == 1 {
+
+        // Segments that are still unused after a reset() need not be output.
+        let mut len = reff.segments.len();
+        while len > 1 && reff.segments[len - 1].allocated == 0 {
+            len -= 1;
+        }
+
+        if len == 1 {
             let seg = &reff.segments[0];
 
             // The user must mutably borrow the `message::Builder` to be able to modify segment memory.
@@ -268,8 +391,8 @@ where
         } else {
             #[cfg(feature = "alloc")]
             {
-                let mut v = alloc::vec::Vec::with_capacity(reff.segments.len());
-                for seg in &reff.segments {
+                let mut v = alloc::vec::Vec::with_capacity(len);
+                for seg in &reff.segments[..len] {
                     // See safety argument in above branch.
                     let slice = unsafe {
                         slice::from_raw_parts(
@@ -292,10 +415,138 @@ where
         self.inner.segments.len()
     }
 
+    /// The used part of segment `id`, or `None` if the segment has been released.
+    pub fn get_segment_for_output(&self, id: u32) -> Option<&[u8]> {
+        let seg = &self.inner.segments[id as usize];
+        if seg.ptr.is_null() {
+            return None;
+        }
+        // See the safety argument in get_segments_for_output().
+        Some(unsafe {
+            slice::from_raw_parts(seg.ptr as *const _, seg.allocated as usize * BYTES_PER_WORD)
+        })
+    }
+
+    /// Gives the memory of segment `id` back to the allocator. Nothing more is allocated
+    /// in the segment.
+    ///
+    /// # Safety
+    /// Nothing in the segment may be accessed afterwards, e.g. through pointers in other
+    /// segments, or by `get_segments_for_output()`, `reset()` or `compact()`.
+    #[cfg(feature = "alloc")]
+    pub unsafe fn release_segment(&mut self, id: u32) {
+        let seg = &mut self.inner.segments[id as usize];
+        if seg.ptr.is_null() {
+            return;
+        }
+        if let Some(a) = &mut self.inner.allocator {
+            unsafe {
+                a.deallocate_segment(seg.ptr, seg.capacity, seg.allocated);
+            }
+        }
+        seg.ptr = core::ptr::null_mut();
+        seg.capacity = seg.allocated;
+    }
+
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
 
+    /// Total number of words allocated in all segments.
+    pub fn allocated_words(&self) -> usize {
+        let mut total = 0;
+        for id in 0..self.len() {
+            total += self.inner.segments[id].allocated as usize;
+        }
+        total
+    }
+
+    /// Number of allocated words that are not reachable from the root pointer: objects that
+    /// have been overwritten or cleared, orphans, and landing pads of far pointers.
+    /// Computed by traversing the message.
+    pub fn wasted_words(&self) -> Result<usize> {
+        if self.is_empty() {
+            return Ok(0);
+        }
+        let (seg_start, _seg_len) = self.get_segment(0)?;
+        let root = PointerReader::get_root(self, 0, seg_start, i32::MAX)?;
+        let reachable = root.total_size()?.word_count as usize + POINTER_SIZE_IN_WORDS;
+        Ok(self.allocated_words() - reachable)
+    }
+
+    /// Copies everything reachable from the root pointer into fresh segments and
+    /// deallocates the old ones. The new first segment is big enough for the whole
+    /// message, so the result is a single segment unless the allocator is unusual.
+    ///
+    /// Any orphans become invalid, and using them results in an error.
+    #[cfg(feature = "alloc")]
+    pub fn compact(&mut self) -> Result<()> {
+        if self.is_empty() {
+            return Ok(());
+        }
+        let old_segments = core::mem::take(&mut self.inner.segments);
+        let slices: alloc::vec::Vec<&[u8]> = old_segments
+            .iter()
+            .map(|seg| unsafe {
+                slice::from_raw_parts(seg.ptr as *const _, seg.allocated as usize * BYTES_PER_WORD)
+            })
+            .collect();
+        let old_arena = ReaderArenaImpl::new(
+            message::SegmentArray::new(&slices),
+            message::ReaderOptions {
+                traversal_limit_in_words: None,
+                nesting_limit: i32::MAX,
+            },
+        );
+        let old_id = self.id;
+        self.id = next_arena_id();
+
+        let result = self.copy_root_from(&old_arena, slices[0].as_ptr());
+        let to_deallocate = if result.is_ok() {
+            old_segments
+        } else {
+            self.id = old_id;
+            core::mem::replace(&mut self.inner.segments, old_segments)
+        };
+        if let Some(a) = &mut self.inner.allocator {
+            for seg in &to_deallocate {
+                unsafe {
+                    a.deallocate_segment(seg.ptr, seg.capacity, seg.allocated);
+                }
+            }
+        }
+        result
+    }
+
+    /// Copies everything reachable from the root pointer at `root` in `arena` into this
+    /// arena, which must be empty, allocating a first segment big enough to hold it all.
+    pub fn copy_root_from(&mut self, arena: &dyn ReaderArena, root: *const u8) -> Result<()> {
+        let root = PointerReader::get_root(arena, 0, root, i32::MAX)?;
+        let size = root.total_size()?.word_count as u32 + POINTER_SIZE_IN_WORDS as u32;
+        self.allocate_segment(size)?;
+        self.allocate(0, POINTER_SIZE_IN_WORDS as u32)
+            .expect("allocate root pointer");
+        let (seg_start, _seg_len) = self.get_segment_mut(0);
+        PointerBuilder::get_root(self, 0, seg_start).copy_from(root, false)
+    }
+
+    /// Zeroes the used part of every segment and marks it as unused, then allocates the
+    /// root pointer in the first one again. Any orphans become invalid.
+    pub fn reset(&mut self) {
+        for id in 0..self.len() {
+            let seg = &mut self.inner.segments[id];
+            unsafe {
+                core::ptr::write_bytes(seg.ptr, 0u8, seg.allocated as usize * BYTES_PER_WORD);
+            }
+            seg.allocated = 0;
+        }
+        if !self.is_empty() {
+            self.allocate(0, POINTER_SIZE_IN_WORDS as u32)
+                .expect("allocate root pointer");
+        }
+        self.id = next_arena_id();
+    }
+
     /// Retrieves the underlying `Allocator`, deallocating all currently-allocated
     /// segments.
     pub fn into_allocator(mut self) -> A {
@@ -310,6 +561,11 @@ where
 {
     fn get_segment(&self, id: u32) -> Result<(*const u8, u32)> {
         let seg = &self.inner.segments[id as usize];
+        if seg.ptr.is_null() {
+            let mut error = Error::from_kind(ErrorKind::Failed);
+            write!(error, "segment {id} has been released");
+            return Err(error);
+        }
         Ok((seg.ptr, seg.allocated))
     }
 
@@ -342,7 +598,7 @@ where
     /// Allocates a new segment with capacity for at least `minimum_size` words.
     fn allocate_segment(&mut self, minimum_size: WordCount32) -> Result<()> {
         let seg = match &mut self.allocator {
-            Some(a) => a.allocate_segment(minimum_size),
+            Some(a) => a.try_allocate_segment(minimum_size)?,
             None => unreachable!(),
         };
         self.segments.push(BuilderSegment {
@@ -364,29 +620,33 @@ where
         }
     }
 
-    fn allocate_anywhere(&mut self, amount: u32) -> (SegmentId, u32) {
+    fn allocate_anywhere(&mut self, amount: u32) -> Result<(SegmentId, u32)> {
         // first try the existing segments, then try allocating a new segment.
         let allocated_len = self.segments.len() as u32;
         for segment_id in 0..allocated_len {
             if let Some(idx) = self.allocate(segment_id, amount) {
-                return (segment_id, idx);
+                return Ok((segment_id, idx));
             }
         }
 
         // Need to allocate a new segment.
 
-        self.allocate_segment(amount).expect("allocate new segment");
-        (
+        self.allocate_segment(amount)?;
+        Ok((
             allocated_len,
             self.allocate(allocated_len, amount)
                 .expect("use freshly-allocated segment"),
-        )
+        ))
     }
 
     fn deallocate_all(&mut self) {
         if let Some(a) = &mut self.allocator {
             #[cfg(feature = "alloc")]
             for seg in &self.segments {
+                // Released segments have been deallocated already.
+                if seg.ptr.is_null() {
+                    continue;
+                }
                 unsafe {
                     a.deallocate_segment(seg.ptr, seg.capacity, seg.allocated);
                 }
@@ -415,7 +675,7 @@ where
         self.inner.allocate(segment_id, amount)
     }
 
-    fn allocate_anywhere(&mut self, amount: u32) -> (SegmentId, u32) {
+    fn allocate_anywhere(&mut self, amount: u32) -> Result<(SegmentId, u32)> {
         self.inner.allocate_anywhere(amount)
     }
 
@@ -423,6 +683,10 @@ where
         self.inner.get_segment_mut(id)
     }
 
+    fn id(&self) -> usize {
+        self.id
+    }
+
     fn as_reader(&self) -> &dyn ReaderArena {
         self
     }
@@ -411,7 +411,7 @@ mod wire_helpers {
         segment_id: u32,
         amount: WordCount32,
         kind: WirePointerKind,
-    ) -> (*mut u8, *mut WirePointer, u32) {
+    ) -> Result<(*mut u8, *mut WirePointer, u32)> {
         let is_null = (*reff).is_null();
         if !is_null {
             zero_object(arena, segment_id, reff)
@@ -419,7 +419,7 @@ mod wire_helpers {
 
         if amount == 0 && kind == WirePointerKind::Struct {
             (*reff).set_kind_and_target_for_empty_struct();
-            return (reff as *mut _, reff, segment_id);
+            return Ok((reff as *mut _, reff, segment_id));
         }
 
         match arena.allocate(segment_id, amount) {
@@ -429,7 +429,14 @@ mod wire_helpers {
                 //# the landing pad for a far pointer.
 
                 let amount_plus_ref = amount + POINTER_SIZE_IN_WORDS as u32;
-                let (segment_id, word_idx) = arena.allocate_anywhere(amount_plus_ref);
+                let (segment_id, word_idx) = match arena.allocate_anywhere(amount_plus_ref) {
+                    Ok(r) => r,
+                    Err(e) => {
+                        // The old object is gone, so don't leave the pointer pointing to it.
+                        ptr::write_bytes(reff, 0, 1);
+                        return Err(e);
+                    }
+                };
                 let (seg_start, _seg_len) = arena.get_segment_mut(segment_id);
                 let ptr = seg_start.offset(word_idx as isize * BYTES_PER_WORD as isize);
 
@@ -444,13 +451,13 @@ mod wire_helpers {
 
                 let ptr1 = ptr.add(BYTES_PER_WORD);
                 (*reff).set_kind_and_target(kind, ptr1);
-                (ptr1, reff, segment_id)
+                Ok((ptr1, reff, segment_id))
             }
             Some(idx) => {
                 let (seg_start, _seg_len) = arena.get_segment_mut(segment_id);
                 let ptr = (seg_start).offset(idx as isize * BYTES_PER_WORD as isize);
                 (*reff).set_kind_and_target(kind, ptr);
-                (ptr, reff, segment_id)
+                Ok((ptr, reff, segment_id))
             }
         }
     }
@@ -839,7 +846,7 @@ mod wire_helpers {
         src: *const u8,
         data_size: isize,
         pointer_count: isize,
-    ) {
+    ) -> Result<()> {
         ptr::copy_nonoverlapping(src, dst, data_size as usize * BYTES_PER_WORD);
 
         let src_refs: *const WirePointer = (src as *const WirePointer).offset(data_size);
@@ -852,8 +859,9 @@ mod wire_helpers {
                 cap_table,
                 dst_refs.offset(ii),
                 src_refs.offset(ii),
-            );
+            )?;
         }
+        Ok(())
     }
 
     // Copies from a trusted message.
@@ -864,12 +872,12 @@ mod wire_helpers {
         cap_table: CapTableBuilder,
         dst: *mut WirePointer,
         src: *const WirePointer,
-    ) -> (*mut u8, *mut WirePointer, u32) {
+    ) -> Result<(*mut u8, *mut WirePointer, u32)> {
         match (*src).kind() {
             WirePointerKind::Struct => {
                 if (*src).is_null() {
                     ptr::write_bytes(dst, 0, 1);
-                    (ptr::null_mut(), dst, segment_id)
+                    Ok((ptr::null_mut(), dst, segment_id))
                 } else {
                     let src_ptr = WirePointer::target(src);
                     let (dst_ptr, dst, segment_id) = allocate(
@@ -878,7 +886,7 @@ mod wire_helpers {
                         segment_id,
                         (*src).struct_word_size(),
                         WirePointerKind::Struct,
-                    );
+                    )?;
                     copy_struct(
                         arena,
                         segment_id,
@@ -887,12 +895,12 @@ mod wire_helpers {
                         src_ptr,
                         (*src).struct_data_size() as isize,
                         (*src).struct_ptr_count() as isize,
-                    );
+                    )?;
                     (*dst).set_struct_size_from_pieces(
                         (*src).struct_data_size(),
                         (*src).struct_ptr_count(),
                     );
-                    (dst_ptr, dst, segment_id)
+                    Ok((dst_ptr, dst, segment_id))
                 }
             }
             WirePointerKind::List => match (*src).list_element_size() {
@@ -908,7 +916,7 @@ mod wire_helpers {
                     );
                     let src_ptr = WirePointer::target(src);
                     let (dst_ptr, dst, segment_id) =
-                        allocate(arena, dst, segment_id, word_count, WirePointerKind::List);
+                        allocate(arena, dst, segment_id, word_count, WirePointerKind::List)?;
                     ptr::copy_nonoverlapping(
                         src_ptr,
                         dst_ptr,
@@ -918,7 +926,7 @@ mod wire_helpers {
                         (*src).list_element_size(),
                         (*src).list_element_count(),
                     );
-                    (dst_ptr, dst, segment_id)
+                    Ok((dst_ptr, dst, segment_id))
                 }
 
                 ElementSize::Pointer => {
@@ -929,7 +937,7 @@ mod wire_helpers {
                         segment_id,
                         (*src).list_element_count(),
                         WirePointerKind::List,
-                    );
+                    )?;
                     for ii in 0..((*src).list_element_count() as isize) {
                         copy_message(
                             arena,
@@ -937,11 +945,11 @@ mod wire_helpers {
                             cap_table,
                             dst_refs.offset(ii * BYTES_PER_WORD as isize) as *mut WirePointer,
                             src_refs.offset(ii),
-                        );
+                        )?;
                     }
                     (*dst)
                         .set_list_size_and_count(ElementSize::Pointer, (*src).list_element_count());
-                    (dst_refs, dst, segment_id)
+                    Ok((dst_refs, dst, segment_id))
                 }
                 ElementSize::InlineComposite => {
                     let src_ptr = WirePointer::target(src);
@@ -951,7 +959,7 @@ mod wire_helpers {
                         segment_id,
                         (*src).list_inline_composite_word_count() + 1,
                         WirePointerKind::List,
-                    );
+                    )?;
 
                     (*dst).set_list_inline_composite((*src).list_inline_composite_word_count());
 
@@ -973,7 +981,7 @@ mod wire_helpers {
                             src_element,
                             (*src_tag).struct_data_size() as isize,
                             (*src_tag).struct_ptr_count() as isize,
-                        );
+                        )?;
                         src_element = src_element.offset(
                             BYTES_PER_WORD as isize * (*src_tag).struct_word_size() as isize,
                         );
@@ -981,7 +989,7 @@ mod wire_helpers {
                             BYTES_PER_WORD as isize * (*src_tag).struct_word_size() as isize,
                         );
                     }
-                    (dst_ptr, dst, segment_id)
+                    Ok((dst_ptr, dst, segment_id))
                 }
             },
             WirePointerKind::Other => {
@@ -999,7 +1007,7 @@ mod wire_helpers {
         dst: *mut WirePointer,
         src_segment_id: u32,
         src: *mut WirePointer,
-    ) {
+    ) -> Result<()> {
         //# Make *dst point to the same object as *src. Both must
         //# reside in the same message, but can be in different
         //# segments. Not always-inline because this is rarely used.
@@ -1023,10 +1031,11 @@ mod wire_helpers {
                 src_segment_id,
                 src,
                 WirePointer::mut_target(src),
-            );
+            )?;
         } else {
             ptr::copy_nonoverlapping(src, dst, 1);
         }
+        Ok(())
     }
 
     pub unsafe fn transfer_pointer_split(
@@ -1036,7 +1045,7 @@ mod wire_helpers {
         src_segment_id: u32,
         src_tag: *mut WirePointer,
         src_ptr: *mut u8,
-    ) {
+    ) -> Result<()> {
         // Like the other transfer_pointer, but splits src into a tag and a
         // target. Particularly useful for OrphanBuilder.
 
@@ -1057,7 +1066,7 @@ mod wire_helpers {
             match arena.allocate(src_segment_id, 1) {
                 None => {
                     //# Darn, need a double-far.
-                    let (far_segment_id, word_idx) = arena.allocate_anywhere(2);
+                    let (far_segment_id, word_idx) = arena.allocate_anywhere(2)?;
                     let (seg_start, _seg_len) = arena.get_segment_mut(far_segment_id);
                     let landing_pad: *mut WirePointer =
                         (seg_start as *mut WirePointer).offset(word_idx as isize);
@@ -1100,6 +1109,7 @@ mod wire_helpers {
                 }
             }
         }
+        Ok(())
     }
 
     #[inline]
@@ -1109,17 +1119,17 @@ mod wire_helpers {
         segment_id: u32,
         cap_table: CapTableBuilder,
         size: StructSize,
-    ) -> StructBuilder<'_> {
+    ) -> Result<StructBuilder<'_>> {
         let (ptr, reff, segment_id) = allocate(
             arena,
             reff,
             segment_id,
             size.total(),
             WirePointerKind::Struct,
-        );
+        )?;
         (*reff).set_struct_size(size);
 
-        StructBuilder {
+        Ok(StructBuilder {
             arena,
             segment_id,
             cap_table,
@@ -1127,7 +1137,7 @@ mod wire_helpers {
             pointers: ptr.offset((size.data as usize) as isize * BYTES_PER_WORD as isize) as *mut _,
             data_size: u32::from(size.data) * (BITS_PER_WORD as BitCount32),
             pointer_count: size.pointers,
-        }
+        })
     }
 
     #[inline]
@@ -1143,15 +1153,9 @@ mod wire_helpers {
 
         if (*reff).is_null() {
             match default {
-                None => {
-                    return Ok(init_struct_pointer(
-                        arena, reff, segment_id, cap_table, size,
-                    ))
-                }
+                None => return init_struct_pointer(arena, reff, segment_id, cap_table, size),
                 Some(d) if (*(d.as_ptr() as *const WirePointer)).is_null() => {
-                    return Ok(init_struct_pointer(
-                        arena, reff, segment_id, cap_table, size,
-                    ))
+                    return init_struct_pointer(arena, reff, segment_id, cap_table, size)
                 }
                 Some(d) => {
                     let (new_ref_target, new_reff, new_segment_id) = copy_message(
@@ -1160,7 +1164,7 @@ mod wire_helpers {
                         cap_table,
                         reff,
                         d.as_ptr() as *const WirePointer,
-                    );
+                    )?;
                     reff = new_reff;
                     segment_id = new_segment_id;
                     ref_target = new_ref_target;
@@ -1197,7 +1201,7 @@ mod wire_helpers {
             zero_pointer_and_fars(arena, segment_id, reff)?;
 
             let (ptr, reff, segment_id) =
-                allocate(arena, reff, segment_id, total_size, WirePointerKind::Struct);
+                allocate(arena, reff, segment_id, total_size, WirePointerKind::Struct)?;
             (*reff).set_struct_size_from_pieces(new_data_size, new_pointer_count);
 
             // Copy data section.
@@ -1214,7 +1218,7 @@ mod wire_helpers {
                     new_pointer_section.offset(i),
                     old_segment_id,
                     old_pointer_section.offset(i),
-                );
+                )?;
             }
 
             ptr::write_bytes(
@@ -1253,7 +1257,7 @@ mod wire_helpers {
         cap_table: CapTableBuilder,
         element_count: ElementCount32,
         element_size: ElementSize,
-    ) -> ListBuilder<'_> {
+    ) -> Result<ListBuilder<'_>> {
         assert!(
             element_size != InlineComposite,
             "Should have called initStructListPointer() instead"
@@ -1264,11 +1268,11 @@ mod wire_helpers {
         let step = data_size + pointer_count * BITS_PER_POINTER as u32;
         let word_count = round_bits_up_to_words(u64::from(element_count) * u64::from(step));
         let (ptr, reff, segment_id) =
-            allocate(arena, reff, segment_id, word_count, WirePointerKind::List);
+            allocate(arena, reff, segment_id, word_count, WirePointerKind::List)?;
 
         (*reff).set_list_size_and_count(element_size, element_count);
 
-        ListBuilder {
+        Ok(ListBuilder {
             arena,
             segment_id,
             cap_table,
@@ -1278,7 +1282,7 @@ mod wire_helpers {
             element_size,
             struct_data_size: data_size,
             struct_pointer_count: pointer_count as u16,
-        }
+        })
     }
 
     #[inline]
@@ -1289,7 +1293,7 @@ mod wire_helpers {
         cap_table: CapTableBuilder,
         element_count: ElementCount32,
         element_size: StructSize,
-    ) -> ListBuilder<'_> {
+    ) -> Result<ListBuilder<'_>> {
         let words_per_element = element_size.total();
 
         //# Allocate the list, prefixed by a single WirePointer.
@@ -1300,7 +1304,7 @@ mod wire_helpers {
             segment_id,
             POINTER_SIZE_IN_WORDS as u32 + word_count,
             WirePointerKind::List,
-        );
+        )?;
         let ptr = ptr as *mut WirePointer;
 
         //# Initialize the pointer.
@@ -1313,7 +1317,7 @@ mod wire_helpers {
 
         let ptr1 = ptr.add(POINTER_SIZE_IN_WORDS);
 
-        ListBuilder {
+        Ok(ListBuilder {
             arena,
             segment_id,
             cap_table,
@@ -1323,7 +1327,7 @@ mod wire_helpers {
             element_size: ElementSize::InlineComposite,
             struct_data_size: u32::from(element_size.data) * (BITS_PER_WORD as u32),
             struct_pointer_count: element_size.pointers,
-        }
+        })
     }
 
     #[inline]
@@ -1352,7 +1356,7 @@ mod wire_helpers {
                 cap_table,
                 orig_ref,
                 default_value as *const WirePointer,
-            );
+            )?;
             orig_ref_target = new_orig_ref_target;
             orig_ref = new_orig_ref;
             orig_segment_id = new_orig_segment_id;
@@ -1482,7 +1486,7 @@ mod wire_helpers {
                 cap_table,
                 orig_ref,
                 default_value as *const WirePointer,
-            );
+            )?;
             orig_ref_target = new_orig_ref_target;
             orig_ref = new_orig_ref;
             orig_segment_id = new_orig_segment_id;
@@ -1549,7 +1553,7 @@ mod wire_helpers {
                 orig_segment_id,
                 total_size + POINTER_SIZE_IN_WORDS as u32,
                 WirePointerKind::List,
-            );
+            )?;
             (*new_ref).set_list_inline_composite(total_size);
 
             let new_tag: *mut WirePointer = new_ptr as *mut _;
@@ -1576,7 +1580,7 @@ mod wire_helpers {
                         new_pointer_section.offset(jj),
                         old_segment_id,
                         old_pointer_section.offset(jj),
-                    );
+                    )?;
                 }
 
                 dst = dst.offset(new_step as isize);
@@ -1617,7 +1621,7 @@ mod wire_helpers {
                     cap_table,
                     element_count,
                     element_size,
-                ))
+                )?)
             } else {
                 // Upgrade to an inline composite list.
 
@@ -1650,7 +1654,7 @@ mod wire_helpers {
                     orig_segment_id,
                     total_words + POINTER_SIZE_IN_WORDS as u32,
                     WirePointerKind::List,
-                );
+                )?;
                 (*new_ref).set_list_inline_composite(total_words);
 
                 let tag: *mut WirePointer = new_ptr as *mut _;
@@ -1665,7 +1669,13 @@ mod wire_helpers {
                     let mut dst = new_ptr.offset(new_data_size as isize * BYTES_PER_WORD as isize);
                     let mut src: *mut WirePointer = old_ptr as *mut _;
                     for _ in 0..element_count {
-                        transfer_pointer(arena, new_segment_id, dst as *mut _, old_segment_id, src);
+                        transfer_pointer(
+                            arena,
+                            new_segment_id,
+                            dst as *mut _,
+                            old_segment_id,
+                            src,
+                        )?;
                         dst = dst.offset(new_step as isize * BYTES_PER_WORD as isize);
                         src = src.offset(1);
                     }
@@ -1708,7 +1718,7 @@ mod wire_helpers {
         reff: *mut WirePointer,
         segment_id: u32,
         size: ByteCount32,
-    ) -> SegmentAnd<text::Builder<'_>> {
+    ) -> Result<SegmentAnd<text::Builder<'_>>> {
         //# The byte list must include a NUL terminator.
         let byte_size = size + 1;
 
@@ -1719,15 +1729,15 @@ mod wire_helpers {
             segment_id,
             round_bytes_up_to_words(byte_size),
             WirePointerKind::List,
-        );
+        )?;
 
         //# Initialize the pointer.
         (*reff).set_list_size_and_count(Byte, byte_size);
 
-        SegmentAnd {
+        Ok(SegmentAnd {
             segment_id,
             value: text::Builder::new(slice::from_raw_parts_mut(ptr, size as usize)),
-        }
+        })
     }
 
     #[inline]
@@ -1736,16 +1746,16 @@ mod wire_helpers {
         reff: *mut WirePointer,
         segment_id: u32,
         value: crate::text::Reader<'_>,
-    ) -> SegmentAnd<text::Builder<'a>> {
+    ) -> Result<SegmentAnd<text::Builder<'a>>> {
         let value_bytes = value.as_bytes();
         // TODO make sure the string is not longer than 2 ** 29.
-        let mut allocation = init_text_pointer(arena, reff, segment_id, value_bytes.len() as u32);
+        let mut allocation = init_text_pointer(arena, reff, segment_id, value_bytes.len() as u32)?;
         allocation
             .value
             .reborrow()
             .as_bytes_mut()
             .copy_from_slice(value_bytes);
-        allocation
+        Ok(allocation)
     }
 
     #[inline]
@@ -1765,7 +1775,7 @@ mod wire_helpers {
                         Default::default(),
                         reff,
                         d.as_ptr() as *const _,
-                    );
+                    )?;
                     reff = new_reff;
                     segment_id = new_segment_id;
                     new_ref_target
@@ -1804,7 +1814,7 @@ mod wire_helpers {
         reff: *mut WirePointer,
         segment_id: u32,
         size: ByteCount32,
-    ) -> SegmentAnd<data::Builder<'_>> {
+    ) -> Result<SegmentAnd<data::Builder<'_>>> {
         //# Allocate the space.
         let (ptr, reff, segment_id) = allocate(
             arena,
@@ -1812,15 +1822,15 @@ mod wire_helpers {
             segment_id,
             round_bytes_up_to_words(size),
             WirePointerKind::List,
-        );
+        )?;
 
         //# Initialize the pointer.
         (*reff).set_list_size_and_count(Byte, size);
 
-        SegmentAnd {
+        Ok(SegmentAnd {
             segment_id,
             value: data::builder_from_raw_parts(ptr, size),
-        }
+        })
     }
 
     #[inline]
@@ -1829,10 +1839,10 @@ mod wire_helpers {
         reff: *mut WirePointer,
         segment_id: u32,
         value: &[u8],
-    ) -> SegmentAnd<data::Builder<'a>> {
-        let allocation = init_data_pointer(arena, reff, segment_id, value.len() as u32);
+    ) -> Result<SegmentAnd<data::Builder<'a>>> {
+        let allocation = init_data_pointer(arena, reff, segment_id, value.len() as u32)?;
         ptr::copy_nonoverlapping(value.as_ptr(), allocation.value.as_mut_ptr(), value.len());
-        allocation
+        Ok(allocation)
     }
 
     #[inline]
@@ -1852,7 +1862,7 @@ mod wire_helpers {
                         Default::default(),
                         reff,
                         d.as_ptr() as *const _,
-                    );
+                    )?;
                     reff = new_reff;
                     segment_id = new_segment_id;
                     new_ref_target
@@ -1879,6 +1889,218 @@ mod wire_helpers {
         ))
     }
 
+    /// Number of words taken by `count` elements of a list, including the tag of an
+    /// inline composite list. Fails if the list pointer could not encode the list.
+    fn list_words(
+        element_size: ElementSize,
+        struct_size: StructSize,
+        count: u32,
+    ) -> Result<WordCount32> {
+        if count >= 1 << 29 {
+            let mut error = Error::from_kind(ErrorKind::Failed);
+            write!(error, "Lists are limited to 2**29 elements, not {count}");
+            return Err(error);
+        }
+        if element_size == InlineComposite {
+            let words = u64::from(count) * u64::from(struct_size.total());
+            if words >= 1 << 29 {
+                let mut error = Error::from_kind(ErrorKind::Failed);
+                write!(
+                    error,
+                    "Inline composite lists are limited to 2**29 words, not {words}"
+                );
+                return Err(error);
+            }
+            Ok(POINTER_SIZE_IN_WORDS as u32 + words as u32)
+        } else {
+            let step = data_bits_per_element(element_size)
+                + pointers_per_element(element_size) * BITS_PER_POINTER as u32;
+            Ok(round_bits_up_to_words(u64::from(count) * u64::from(step)))
+        }
+    }
+
+    unsafe fn set_list_count(
+        tag: *mut WirePointer,
+        ptr: *mut u8,
+        element_size: ElementSize,
+        struct_size: StructSize,
+        count: u32,
+    ) {
+        if element_size == InlineComposite {
+            (*tag).set_list_inline_composite(count * struct_size.total());
+            let element_tag = ptr as *mut WirePointer;
+            (*element_tag)
+                .set_kind_and_inline_composite_list_element_count(WirePointerKind::Struct, count);
+            (*element_tag).set_struct_size(struct_size);
+        } else {
+            (*tag).set_list_size_and_count(element_size, count);
+        }
+    }
+
+    /// Sets the element count of the list that `reff` points to, and returns a pointer to
+    /// the start of the list, which for an inline composite list is its tag. `capacity` is
+    /// the number of words known to be allocated for the list, which may be more than its
+    /// count requires; it is updated when the list is extended in place or moved.
+    ///
+    /// Removed elements are zeroed, along with any objects they point to. New elements are
+    /// zero. A null pointer is initialized to a new list. The element size, and for struct
+    /// lists the struct size, of an existing list are kept; `struct_size` is only used for a
+    /// new list.
+    pub unsafe fn resize_list(
+        arena: &mut dyn BuilderArena,
+        reff: *mut WirePointer,
+        segment_id: u32,
+        element_size: ElementSize,
+        struct_size: StructSize,
+        size: ElementCount32,
+        capacity: &mut WordCount32,
+    ) -> Result<*mut u8> {
+        if (*reff).is_null() {
+            let needed = list_words(element_size, struct_size, size)?;
+            let (ptr, tag, _segment_id) =
+                allocate(arena, reff, segment_id, needed, WirePointerKind::List)?;
+            set_list_count(tag, ptr, element_size, struct_size, size);
+            *capacity = needed;
+            return Ok(ptr);
+        }
+
+        let (ptr, tag, list_segment_id) =
+            follow_builder_fars(arena, reff, WirePointer::mut_target(reff), segment_id)?;
+        if (*tag).kind() != WirePointerKind::List {
+            return Err(Error::from_kind(ErrorKind::ExistingPointerIsNotAList));
+        }
+        if (*tag).list_element_size() != element_size {
+            return Err(Error::from_kind(if element_size == Byte {
+                ErrorKind::ExistingListPointerIsNotByteSized
+            } else {
+                ErrorKind::ExistingListValueIsIncompatibleWithExpectedType
+            }));
+        }
+        let (struct_size, count) = if element_size == InlineComposite {
+            let element_tag = ptr as *mut WirePointer;
+            let struct_size = StructSize {
+                data: (*element_tag).struct_data_size(),
+                pointers: (*element_tag).struct_ptr_count(),
+            };
+            (
+                struct_size,
+                (*element_tag).inline_composite_list_element_count(),
+            )
+        } else {
+            (struct_size, (*tag).list_element_count())
+        };
+        let needed = list_words(element_size, struct_size, size)?;
+
+        if size < count {
+            //# Zero the removed elements.
+            if element_size == InlineComposite {
+                let words_per_element = struct_size.total() as usize;
+                let first = ptr.add(BYTES_PER_WORD) as *mut WirePointer;
+                for i in size as usize..count as usize {
+                    let element = first.add(i * words_per_element);
+                    let pointers = element.add(struct_size.data as usize);
+                    for j in 0..struct_size.pointers as usize {
+                        zero_object(arena, list_segment_id, pointers.add(j));
+                    }
+                    ptr::write_bytes(element, 0, words_per_element);
+                }
+            } else if element_size == Pointer {
+                let first = ptr as *mut WirePointer;
+                for i in size as usize..count as usize {
+                    zero_object(arena, list_segment_id, first.add(i));
+                    ptr::write_bytes(first.add(i), 0, 1);
+                }
+            } else {
+                let bits = u64::from(data_bits_per_element(element_size));
+                let mut start = u64::from(size) * bits;
+                let end = (u64::from(count) * bits + 7) / 8;
+                if start % 8 != 0 {
+                    *ptr.add((start / 8) as usize) &= (1u8 << (start % 8)) - 1;
+                    start += 8;
+                }
+                let start = start / 8;
+                if start < end {
+                    ptr::write_bytes(ptr.add(start as usize), 0, (end - start) as usize);
+                }
+            }
+        }
+        *capacity = (*capacity).max(list_words(element_size, struct_size, count)?);
+        if needed <= *capacity {
+            set_list_count(tag, ptr, element_size, struct_size, size);
+            return Ok(ptr);
+        }
+
+        //# If the list is the last object in its segment, try to extend it in place.
+        let (seg_start, seg_allocated) = arena.as_reader().get_segment(list_segment_id)?;
+        let end = ptr.add(*capacity as usize * BYTES_PER_WORD);
+        if ptr::eq(end, seg_start.add(seg_allocated as usize * BYTES_PER_WORD))
+            && arena
+                .allocate(list_segment_id, needed - *capacity)
+                .is_some()
+        {
+            set_list_count(tag, ptr, element_size, struct_size, size);
+            *capacity = needed;
+            return Ok(ptr);
+        }
+
+        //# Otherwise move it, doubling the capacity so that repeated appends stay cheap.
+        //# The pointer is cleared first so that `allocate()` does not zero the old list
+        //# before it has been copied.
+        let landing_pad = match (*reff).kind() {
+            WirePointerKind::Far if (*reff).is_double_far() => Some((tag.offset(-1), 2)),
+            WirePointerKind::Far => Some((tag, 1)),
+            _ => None,
+        };
+        let old_capacity = *capacity;
+        ptr::write_bytes(reff, 0, 1);
+        *capacity = needed.max(old_capacity.saturating_mul(2));
+        let (new_ptr, new_tag, new_segment_id) =
+            allocate(arena, reff, segment_id, *capacity, WirePointerKind::List)?;
+        set_list_count(new_tag, new_ptr, element_size, struct_size, size);
+
+        //# Pointers are relative to their own location, so they have to be transferred
+        //# rather than copied.
+        if element_size == InlineComposite {
+            let words_per_element = struct_size.total() as usize;
+            let src = ptr.add(BYTES_PER_WORD) as *mut WirePointer;
+            let dst = new_ptr.add(BYTES_PER_WORD) as *mut WirePointer;
+            for i in 0..count as usize {
+                let src = src.add(i * words_per_element);
+                let dst = dst.add(i * words_per_element);
+                ptr::copy_nonoverlapping(src, dst, struct_size.data as usize);
+                for j in struct_size.data as usize..words_per_element {
+                    transfer_pointer(
+                        arena,
+                        new_segment_id,
+                        dst.add(j),
+                        list_segment_id,
+                        src.add(j),
+                    )?;
+                }
+            }
+        } else if element_size == Pointer {
+            let src = ptr as *mut WirePointer;
+            let dst = new_ptr as *mut WirePointer;
+            for i in 0..count as usize {
+                transfer_pointer(
+                    arena,
+                    new_segment_id,
+                    dst.add(i),
+                    list_segment_id,
+                    src.add(i),
+                )?;
+            }
+        } else {
+            let bytes = (u64::from(count) * u64::from(data_bits_per_element(element_size)) + 7) / 8;
+            ptr::copy_nonoverlapping(ptr, new_ptr, bytes as usize);
+        }
+        ptr::write_bytes(ptr, 0, old_capacity as usize * BYTES_PER_WORD);
+        if let Some((pad, words)) = landing_pad {
+            ptr::write_bytes(pad, 0, words);
+        }
+        Ok(new_ptr)
+    }
+
     pub unsafe fn set_struct_pointer(
         arena: &mut dyn BuilderArena,
         segment_id: u32,
@@ -1928,7 +2150,7 @@ mod wire_helpers {
         let total_size: WordCount32 = data_words + u32::from(ptr_count) * WORDS_PER_POINTER as u32;
 
         let (ptr, reff, segment_id) =
-            allocate(arena, reff, segment_id, total_size, WirePointerKind::Struct);
+            allocate(arena, reff, segment_id, total_size, WirePointerKind::Struct)?;
         (*reff).set_struct_size_from_pieces(data_words as u16, ptr_count);
 
         if value.data_size == 1 {
@@ -1991,7 +2213,7 @@ mod wire_helpers {
         if value.element_size != ElementSize::InlineComposite {
             //# List of non-structs.
             let (ptr, reff, segment_id) =
-                allocate(arena, reff, segment_id, total_size, WirePointerKind::List);
+                allocate(arena, reff, segment_id, total_size, WirePointerKind::List)?;
 
             if value.struct_pointer_count == 1 {
                 //# List of pointers.
@@ -2098,7 +2320,7 @@ mod wire_helpers {
                 segment_id,
                 total_size + POINTER_SIZE_IN_WORDS as u32,
                 WirePointerKind::List,
-            );
+            )?;
             (*reff).set_list_inline_composite(total_size);
 
             let tag: *mut WirePointer = ptr as *mut _;
@@ -3200,6 +3422,11 @@ impl<'a> PointerBuilder<'a> {
     }
 
     pub fn init_struct(self, size: StructSize) -> StructBuilder<'a> {
+        self.try_init_struct(size)
+            .expect("failed to allocate struct")
+    }
+
+    pub fn try_init_struct(self, size: StructSize) -> Result<StructBuilder<'a>> {
         unsafe {
             wire_helpers::init_struct_pointer(
                 self.arena,
@@ -3216,6 +3443,15 @@ impl<'a> PointerBuilder<'a> {
         element_size: ElementSize,
         element_count: ElementCount32,
     ) -> ListBuilder<'a> {
+        self.try_init_list(element_size, element_count)
+            .expect("failed to allocate list")
+    }
+
+    pub fn try_init_list(
+        self,
+        element_size: ElementSize,
+        element_count: ElementCount32,
+    ) -> Result<ListBuilder<'a>> {
         unsafe {
             wire_helpers::init_list_pointer(
                 self.arena,
@@ -3233,6 +3469,15 @@ impl<'a> PointerBuilder<'a> {
         element_count: ElementCount32,
         element_size: StructSize,
     ) -> ListBuilder<'a> {
+        self.try_init_struct_list(element_count, element_size)
+            .expect("failed to allocate struct list")
+    }
+
+    pub fn try_init_struct_list(
+        self,
+        element_count: ElementCount32,
+        element_size: StructSize,
+    ) -> Result<ListBuilder<'a>> {
         unsafe {
             wire_helpers::init_struct_list_pointer(
                 self.arena,
@@ -3246,14 +3491,28 @@ impl<'a> PointerBuilder<'a> {
     }
 
     pub fn init_text(self, size: ByteCount32) -> text::Builder<'a> {
+        self.try_init_text(size).expect("failed to allocate text")
+    }
+
+    pub fn try_init_text(self, size: ByteCount32) -> Result<text::Builder<'a>> {
         unsafe {
-            wire_helpers::init_text_pointer(self.arena, self.pointer, self.segment_id, size).value
+            Ok(
+                wire_helpers::init_text_pointer(self.arena, self.pointer, self.segment_id, size)?
+                    .value,
+            )
         }
     }
 
     pub fn init_data(self, size: ByteCount32) -> data::Builder<'a> {
+        self.try_init_data(size).expect("failed to allocate data")
+    }
+
+    pub fn try_init_data(self, size: ByteCount32) -> Result<data::Builder<'a>> {
         unsafe {
-            wire_helpers::init_data_pointer(self.arena, self.pointer, self.segment_id, size).value
+            Ok(
+                wire_helpers::init_data_pointer(self.arena, self.pointer, self.segment_id, size)?
+                    .value,
+            )
         }
     }
 
@@ -3285,16 +3544,61 @@ impl<'a> PointerBuilder<'a> {
         }
     }
 
+    /// Sets the element count of the list that this pointer points to, moving it if
+    /// `capacity` words are not enough. Returns a pointer to the start of the list.
+    pub fn resize_list(
+        &mut self,
+        element_size: ElementSize,
+        struct_size: StructSize,
+        size: ElementCount32,
+        capacity: &mut WordCount32,
+    ) -> Result<*mut u8> {
+        unsafe {
+            wire_helpers::resize_list(
+                self.arena,
+                self.pointer,
+                self.segment_id,
+                element_size,
+                struct_size,
+                size,
+                capacity,
+            )
+        }
+    }
+
+    /// Like [`resize_list()`](Self::resize_list), for the byte list of text or data.
+    pub fn resize_byte_list(
+        &mut self,
+        size: ByteCount32,
+        capacity: &mut WordCount32,
+    ) -> Result<*mut u8> {
+        let struct_size = StructSize {
+            data: 0,
+            pointers: 0,
+        };
+        self.resize_list(ElementSize::Byte, struct_size, size, capacity)
+    }
+
     pub fn set_text(&mut self, value: crate::text::Reader<'_>) {
+        self.try_set_text(value).expect("failed to allocate text")
+    }
+
+    pub fn try_set_text(&mut self, value: crate::text::Reader<'_>) -> Result<()> {
         unsafe {
-            wire_helpers::set_text_pointer(self.arena, self.pointer, self.segment_id, value);
+            wire_helpers::set_text_pointer(self.arena, self.pointer, self.segment_id, value)?;
         }
+        Ok(())
     }
 
     pub fn set_data(&mut self, value: &[u8]) {
+        self.try_set_data(value).expect("failed to allocate data")
+    }
+
+    pub fn try_set_data(&mut self, value: &[u8]) -> Result<()> {
         unsafe {
-            wire_helpers::set_data_pointer(self.arena, self.pointer, self.segment_id, value);
+            wire_helpers::set_data_pointer(self.arena, self.pointer, self.segment_id, value)?;
         }
+        Ok(())
     }
 
     #[cfg(feature = "alloc")]
@@ -3361,6 +3665,113 @@ impl<'a> PointerBuilder<'a> {
             nesting_limit: 0x7fffffff,
         }
     }
+
+    /// Detaches the object that this pointer points to, leaving the pointer null.
+    pub fn disown(&mut self) -> OrphanBuilder {
+        let orphan = OrphanBuilder::new(self.arena, self.segment_id, self.cap_table);
+        unsafe {
+            wire_helpers::transfer_pointer(
+                self.arena,
+                orphan.segment_id,
+                orphan.anchor,
+                self.segment_id,
+                self.pointer,
+            )
+            .expect("failed to allocate far pointer");
+            ptr::write_bytes(self.pointer, 0, 1);
+        }
+        orphan
+    }
+
+    /// Makes this pointer point to the orphaned object, which must belong to the same
+    /// message. Whatever this pointer pointed to before is zeroed.
+    pub fn adopt(&mut self, orphan: OrphanBuilder) -> Result<()> {
+        orphan.check_arena(self.arena)?;
+        unsafe {
+            if !(*self.pointer).is_null() {
+                wire_helpers::zero_object(self.arena, self.segment_id, self.pointer);
+                ptr::write_bytes(self.pointer, 0, 1);
+            }
+            wire_helpers::transfer_pointer(
+                self.arena,
+                self.segment_id,
+                self.pointer,
+                orphan.segment_id,
+                orphan.anchor,
+            )?;
+            ptr::write_bytes(orphan.anchor, 0, 1);
+        }
+        Ok(())
+    }
+}
+
+/// An object that is not reachable from the root of its message.
+///
+/// The orphan owns a one-word anchor in the message, which holds an ordinary pointer to
+/// the object. Any operation on the object goes through a `PointerBuilder` for the anchor,
+/// so it needs the arena back, and checks that it is the one the anchor was allocated in.
+pub struct OrphanBuilder {
+    arena_id: usize,
+    segment_id: u32,
+    anchor: *mut WirePointer,
+    cap_table: CapTableBuilder,
+}
+
+impl OrphanBuilder {
+    /// Creates a null orphan, allocating its anchor in segment `segment_id` if it has room.
+    pub fn new(arena: &mut dyn BuilderArena, segment_id: u32, cap_table: CapTableBuilder) -> Self {
+        let (segment_id, word_idx) = match arena.allocate(segment_id, 1) {
+            Some(word_idx) => (segment_id, word_idx),
+            None => arena
+                .allocate_anywhere(1)
+                .expect("failed to allocate orphan anchor"),
+        };
+        let (seg_start, _seg_len) = arena.get_segment_mut(segment_id);
+        let anchor = unsafe { (seg_start as *mut WirePointer).offset(word_idx as isize) };
+        unsafe {
+            ptr::write_bytes(anchor, 0, 1);
+        }
+        Self {
+            arena_id: arena.id(),
+            segment_id,
+            anchor,
+            cap_table,
+        }
+    }
+
+    fn check_arena(&self, arena: &dyn BuilderArena) -> Result<()> {
+        if arena.id() == self.arena_id {
+            Ok(())
+        } else {
+            Err(Error::from_kind(
+                ErrorKind::OrphanBelongsToADifferentMessage,
+            ))
+        }
+    }
+
+    pub fn as_pointer_builder<'a>(
+        &mut self,
+        arena: &'a mut dyn BuilderArena,
+    ) -> Result<PointerBuilder<'a>> {
+        self.check_arena(arena)?;
+        Ok(PointerBuilder {
+            arena,
+            segment_id: self.segment_id,
+            cap_table: self.cap_table,
+            pointer: self.anchor,
+        })
+    }
+
+    pub fn as_pointer_reader<'a>(&self, arena: &'a dyn BuilderArena) -> Result<PointerReader<'a>> {
+        self.check_arena(arena)?;
+        Ok(PointerReader {
+            arena: arena.as_reader(),
+            segment_id: self.segment_id,
+            cap_table: self.cap_table.into_reader(),
+            pointer: self.anchor,
+            nesting_limit: 0x7fffffff,
+        })
+    }
 }
 
 #[derive(Clone, Copy)]
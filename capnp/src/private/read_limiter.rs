@@ -66,6 +66,14 @@ mod sync {
             }
             Ok(())
         }
+
+        /// A new limiter that starts out with what is left of this one.
+        pub fn split_off(&self) -> Self {
+            Self {
+                limit: AtomicUsize::new(self.limit.load(Ordering::Relaxed)),
+                error_on_limit_exceeded: self.error_on_limit_exceeded,
+            }
+        }
     }
 }
 
@@ -109,5 +117,13 @@ mod unsync {
                 Ok(())
             }
         }
+
+        /// A new limiter that starts out with what is left of this one.
+        pub fn split_off(&self) -> Self {
+            Self {
+                limit: Cell::new(self.limit.get()),
+                error_on_limit_exceeded: self.error_on_limit_exceeded,
+            }
+        }
     }
 }
@@ -1,38 +1,39 @@
 //! Convenience wrappers of the datatypes defined in schema.capnp.
 
 use crate::dynamic_value;
-use crate::introspect::{self, RawBrandedStructSchema, RawEnumSchema};
+use crate::introspect::{
+    self, EnumType, InterfaceType, RawBrandedInterfaceSchema, RawBrandedStructSchema,
+    RawEnumSchema, StructType, TypeVariant,
+};
 use crate::private::layout;
-use crate::schema_capnp::{annotation, enumerant, field, node};
+use crate::schema_capnp::{annotation, enumerant, field, method, node, superclass};
 use crate::struct_list;
 use crate::traits::{IndexMove, ListIter, ShortListIter};
 use crate::Result;
 
 /// A struct node, with generics applied.
 #[derive(Clone, Copy)]
-pub struct StructSchema {
-    pub(crate) raw: RawBrandedStructSchema,
-    pub(crate) proto: node::Reader<'static>,
-}
-
-impl StructSchema {
-    pub fn new(raw: RawBrandedStructSchema) -> Self {
-        let proto =
-            crate::any_pointer::Reader::new(unsafe {
-                layout::PointerReader::get_root_unchecked(
-                    raw.generic.encoded_node.as_ptr() as *const u8
-                )
-            })
-            .get_as()
-            .unwrap();
+pub struct StructSchema<'a> {
+    pub(crate) raw: StructType<'a>,
+    pub(crate) proto: node::Reader<'a>,
+}
+
+impl<'a> StructSchema<'a> {
+    pub fn new(raw: impl Into<StructType<'a>>) -> Self {
+        let raw = raw.into();
+        let proto = crate::any_pointer::Reader::new(unsafe {
+            layout::PointerReader::get_root_unchecked(raw.encoded_node().as_ptr() as *const u8)
+        })
+        .get_as()
+        .unwrap();
         Self { raw, proto }
     }
 
-    pub fn get_proto(&self) -> node::Reader<'static> {
+    pub fn get_proto(&self) -> node::Reader<'a> {
         self.proto
     }
 
-    pub fn get_fields(self) -> crate::Result<FieldList> {
+    pub fn get_fields(self) -> crate::Result<FieldList<'a>> {
         if let node::Struct(s) = self.proto.which()? {
             Ok(FieldList {
                 fields: s.get_fields()?,
@@ -43,11 +44,10 @@ impl StructSchema {
         }
     }
 
-    pub fn get_field_by_discriminant(self, discriminant: u16) -> Result<Option<Field>> {
+    pub fn get_field_by_discriminant(self, discriminant: u16) -> Result<Option<Field<'a>>> {
         match self
             .raw
-            .generic
-            .members_by_discriminant
+            .members_by_discriminant()
             .get(discriminant as usize)
         {
             None => Ok(None),
@@ -56,14 +56,14 @@ impl StructSchema {
     }
 
     /// Looks up a field by name using binary search. Returns `None` if no matching field is found.
-    pub fn find_field_by_name(&self, name: &str) -> Result<Option<Field>> {
+    pub fn find_field_by_name(&self, name: &str) -> Result<Option<Field<'a>>> {
         let fields = self.get_fields()?;
         let mut lower: usize = 0;
-        let mut upper: usize = self.raw.generic.members_by_name.len();
+        let mut upper: usize = self.raw.members_by_name().len();
 
         while lower < upper {
             let mid: usize = (lower + upper) / 2;
-            let candidate_index = self.raw.generic.members_by_name[mid];
+            let candidate_index = self.raw.members_by_name()[mid];
             let candidate_name = fields.get(candidate_index).get_proto().get_name()?;
 
             use core::cmp::Ordering;
@@ -78,7 +78,7 @@ impl StructSchema {
     }
 
     /// Like `find_field_by_name()`, but returns an error if the field is not found.
-    pub fn get_field_by_name(&self, name: &str) -> Result<Field> {
+    pub fn get_field_by_name(&self, name: &str) -> Result<Field<'a>> {
         if let Some(field) = self.find_field_by_name(name)? {
             Ok(field)
         } else {
@@ -88,11 +88,11 @@ impl StructSchema {
         }
     }
 
-    pub fn get_union_fields(self) -> Result<FieldSubset> {
+    pub fn get_union_fields(self) -> Result<FieldSubset<'a>> {
         if let node::Struct(s) = self.proto.which()? {
             Ok(FieldSubset {
                 fields: s.get_fields()?,
-                indices: self.raw.generic.members_by_discriminant,
+                indices: self.raw.members_by_discriminant(),
                 parent: self,
             })
         } else {
@@ -100,11 +100,11 @@ impl StructSchema {
         }
     }
 
-    pub fn get_non_union_fields(self) -> Result<FieldSubset> {
+    pub fn get_non_union_fields(self) -> Result<FieldSubset<'a>> {
         if let node::Struct(s) = self.proto.which()? {
             Ok(FieldSubset {
                 fields: s.get_fields()?,
-                indices: self.raw.generic.nonunion_members,
+                indices: self.raw.nonunion_members(),
                 parent: self,
             })
         } else {
@@ -112,59 +112,65 @@ impl StructSchema {
         }
     }
 
-    pub fn get_annotations(self) -> Result<AnnotationList> {
+    pub fn get_annotations(self) -> Result<AnnotationList<'a>> {
         Ok(AnnotationList {
             annotations: self.proto.get_annotations()?,
             child_index: None,
-            get_annotation_type: self.raw.annotation_types,
+            owner: AnnotationOwner::Struct(self.raw),
         })
     }
 }
 
-impl From<RawBrandedStructSchema> for StructSchema {
-    fn from(rs: RawBrandedStructSchema) -> StructSchema {
+impl From<RawBrandedStructSchema> for StructSchema<'_> {
+    fn from(rs: RawBrandedStructSchema) -> Self {
         StructSchema::new(rs)
     }
 }
 
+impl<'a> From<StructType<'a>> for StructSchema<'a> {
+    fn from(st: StructType<'a>) -> Self {
+        StructSchema::new(st)
+    }
+}
+
 /// A field of a struct, with generics applied.
 #[derive(Clone, Copy)]
-pub struct Field {
-    proto: field::Reader<'static>,
+pub struct Field<'a> {
+    proto: field::Reader<'a>,
     index: u16,
-    pub(crate) parent: StructSchema,
+    pub(crate) parent: StructSchema<'a>,
 }
 
-impl Field {
-    pub fn get_proto(self) -> field::Reader<'static> {
+impl<'a> Field<'a> {
+    pub fn get_proto(self) -> field::Reader<'a> {
         self.proto
     }
 
-    pub fn get_type(&self) -> introspect::Type {
-        (self.parent.raw.field_types)(self.index)
+    pub fn get_type(&self) -> introspect::Type<'a> {
+        self.parent.raw.field_type(self.index)
     }
 
     pub fn get_index(&self) -> u16 {
         self.index
     }
 
-    pub fn get_annotations(self) -> Result<AnnotationList> {
+    pub fn get_annotations(self) -> Result<AnnotationList<'a>> {
         Ok(AnnotationList {
             annotations: self.proto.get_annotations()?,
             child_index: Some(self.index),
-            get_annotation_type: self.parent.raw.annotation_types,
+            owner: AnnotationOwner::Struct(self.parent.raw),
         })
     }
 }
 
 /// A list of fields of a struct, with generics applied.
 #[derive(Clone, Copy)]
-pub struct FieldList {
-    pub(crate) fields: crate::struct_list::Reader<'static, field::Owned>,
-    pub(crate) parent: StructSchema,
+pub struct FieldList<'a> {
+    pub(crate) fields: crate::struct_list::Reader<'a, field::Owned>,
+    pub(crate) parent: StructSchema<'a>,
 }
 
-impl FieldList {
+impl<'a> FieldList<'a> {
     pub fn len(&self) -> u16 {
         self.fields.len() as u16
     }
@@ -173,7 +179,7 @@ impl FieldList {
         self.len() == 0
     }
 
-    pub fn get(self, index: u16) -> Field {
+    pub fn get(self, index: u16) -> Field<'a> {
         Field {
             proto: self.fields.get(index as u32),
             index,
@@ -181,20 +187,20 @@ impl FieldList {
         }
     }
 
-    pub fn iter(self) -> ShortListIter<Self, Field> {
+    pub fn iter(self) -> ShortListIter<Self, Field<'a>> {
         ShortListIter::new(self, self.len())
     }
 }
 
-impl IndexMove<u16, Field> for FieldList {
-    fn index_move(&self, index: u16) -> Field {
+impl<'a> IndexMove<u16, Field<'a>> for FieldList<'a> {
+    fn index_move(&self, index: u16) -> Field<'a> {
         self.get(index)
     }
 }
 
-impl ::core::iter::IntoIterator for FieldList {
-    type Item = Field;
-    type IntoIter = ShortListIter<FieldList, Self::Item>;
+impl<'a> ::core::iter::IntoIterator for FieldList<'a> {
+    type Item = Field<'a>;
+    type IntoIter = ShortListIter<Self, Self::Item>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
@@ -203,13 +209,13 @@ impl ::core::iter::IntoIterator for FieldList {
 
 /// A list of a subset of fields of a struct, with generics applied.
 #[derive(Clone, Copy)]
-pub struct FieldSubset {
-    fields: struct_list::Reader<'static, field::Owned>,
-    indices: &'static [u16],
-    parent: StructSchema,
+pub struct FieldSubset<'a> {
+    fields: struct_list::Reader<'a, field::Owned>,
+    indices: &'a [u16],
+    parent: StructSchema<'a>,
 }
 
-impl FieldSubset {
+impl<'a> FieldSubset<'a> {
     pub fn len(&self) -> u16 {
         self.indices.len() as u16
     }
@@ -218,7 +224,7 @@ impl FieldSubset {
         self.len() == 0
     }
 
-    pub fn get(self, index: u16) -> Field {
+    pub fn get(self, index: u16) -> Field<'a> {
         let index = self.indices[index as usize];
         Field {
             proto: self.fields.get(index as u32),
@@ -227,20 +233,20 @@ impl FieldSubset {
         }
     }
 
-    pub fn iter(self) -> ShortListIter<Self, Field> {
+    pub fn iter(self) -> ShortListIter<Self, Field<'a>> {
         ShortListIter::new(self, self.len())
     }
 }
 
-impl IndexMove<u16, Field> for FieldSubset {
-    fn index_move(&self, index: u16) -> Field {
+impl<'a> IndexMove<u16, Field<'a>> for FieldSubset<'a> {
+    fn index_move(&self, index: u16) -> Field<'a> {
         self.get(index)
     }
 }
 
-impl ::core::iter::IntoIterator for FieldSubset {
-    type Item = Field;
-    type IntoIter = ShortListIter<FieldSubset, Self::Item>;
+impl<'a> ::core::iter::IntoIterator for FieldSubset<'a> {
+    type Item = Field<'a>;
+    type IntoIter = ShortListIter<Self, Self::Item>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
@@ -249,26 +255,27 @@ impl ::core::iter::IntoIterator for FieldSubset {
 
 /// An enum, with generics applied. (Generics may affect types of annotations.)
 #[derive(Clone, Copy)]
-pub struct EnumSchema {
-    pub(crate) raw: RawEnumSchema,
-    pub(crate) proto: node::Reader<'static>,
+pub struct EnumSchema<'a> {
+    pub(crate) raw: EnumType<'a>,
+    pub(crate) proto: node::Reader<'a>,
 }
 
-impl EnumSchema {
-    pub fn new(raw: RawEnumSchema) -> Self {
+impl<'a> EnumSchema<'a> {
+    pub fn new(raw: impl Into<EnumType<'a>>) -> Self {
+        let raw = raw.into();
         let proto = crate::any_pointer::Reader::new(unsafe {
-            layout::PointerReader::get_root_unchecked(raw.encoded_node.as_ptr() as *const u8)
+            layout::PointerReader::get_root_unchecked(raw.encoded_node().as_ptr() as *const u8)
         })
         .get_as()
         .unwrap();
         Self { raw, proto }
     }
 
-    pub fn get_proto(self) -> node::Reader<'static> {
+    pub fn get_proto(self) -> node::Reader<'a> {
         self.proto
     }
 
-    pub fn get_enumerants(self) -> crate::Result<EnumerantList> {
+    pub fn get_enumerants(self) -> crate::Result<EnumerantList<'a>> {
         if let node::Enum(s) = self.proto.which()? {
             Ok(EnumerantList {
                 enumerants: s.get_enumerants()?,
@@ -279,31 +286,37 @@ impl EnumSchema {
         }
     }
 
-    pub fn get_annotations(self) -> Result<AnnotationList> {
+    pub fn get_annotations(self) -> Result<AnnotationList<'a>> {
         Ok(AnnotationList {
             annotations: self.proto.get_annotations()?,
             child_index: None,
-            get_annotation_type: self.raw.annotation_types,
+            owner: AnnotationOwner::Enum(self.raw),
         })
     }
 }
 
-impl From<RawEnumSchema> for EnumSchema {
-    fn from(re: RawEnumSchema) -> EnumSchema {
+impl From<RawEnumSchema> for EnumSchema<'_> {
+    fn from(re: RawEnumSchema) -> Self {
         EnumSchema::new(re)
     }
 }
 
+impl<'a> From<EnumType<'a>> for EnumSchema<'a> {
+    fn from(et: EnumType<'a>) -> Self {
+        EnumSchema::new(et)
+    }
+}
+
 /// An enumerant, with generics applied. (Generics may affect types of annotations.)
 #[derive(Clone, Copy)]
-pub struct Enumerant {
+pub struct Enumerant<'a> {
     ordinal: u16,
-    parent: EnumSchema,
-    proto: enumerant::Reader<'static>,
+    parent: EnumSchema<'a>,
+    proto: enumerant::Reader<'a>,
 }
 
-impl Enumerant {
-    pub fn get_containing_enum(self) -> EnumSchema {
+impl<'a> Enumerant<'a> {
+    pub fn get_containing_enum(self) -> EnumSchema<'a> {
         self.parent
     }
 
@@ -311,27 +324,27 @@ impl Enumerant {
         self.ordinal
     }
 
-    pub fn get_proto(self) -> enumerant::Reader<'static> {
+    pub fn get_proto(self) -> enumerant::Reader<'a> {
         self.proto
     }
 
-    pub fn get_annotations(self) -> Result<AnnotationList> {
+    pub fn get_annotations(self) -> Result<AnnotationList<'a>> {
         Ok(AnnotationList {
             annotations: self.proto.get_annotations()?,
             child_index: Some(self.ordinal),
-            get_annotation_type: self.parent.raw.annotation_types,
+            owner: AnnotationOwner::Enum(self.parent.raw),
         })
     }
 }
 
 /// A list of enumerants.
 #[derive(Clone, Copy)]
-pub struct EnumerantList {
-    enumerants: struct_list::Reader<'static, enumerant::Owned>,
-    parent: EnumSchema,
+pub struct EnumerantList<'a> {
+    enumerants: struct_list::Reader<'a, enumerant::Owned>,
+    parent: EnumSchema<'a>,
 }
 
-impl EnumerantList {
+impl<'a> EnumerantList<'a> {
     pub fn len(&self) -> u16 {
         self.enumerants.len() as u16
     }
@@ -340,7 +353,7 @@ impl EnumerantList {
         self.len() == 0
     }
 
-    pub fn get(self, ordinal: u16) -> Enumerant {
+    pub fn get(self, ordinal: u16) -> Enumerant<'a> {
         Enumerant {
             proto: self.enumerants.get(ordinal as u32),
             ordinal,
@@ -348,19 +361,19 @@ impl EnumerantList {
         }
     }
 
-    pub fn iter(self) -> ShortListIter<Self, Enumerant> {
+    pub fn iter(self) -> ShortListIter<Self, Enumerant<'a>> {
         ShortListIter::new(self, self.len())
     }
 }
 
-impl IndexMove<u16, Enumerant> for EnumerantList {
-    fn index_move(&self, index: u16) -> Enumerant {
+impl<'a> IndexMove<u16, Enumerant<'a>> for EnumerantList<'a> {
+    fn index_move(&self, index: u16) -> Enumerant<'a> {
         self.get(index)
     }
 }
 
-impl ::core::iter::IntoIterator for EnumerantList {
-    type Item = Enumerant;
+impl<'a> ::core::iter::IntoIterator for EnumerantList<'a> {
+    type Item = Enumerant<'a>;
     type IntoIter = ShortListIter<Self, Self::Item>;
 
     fn into_iter(self) -> Self::IntoIter {
@@ -368,16 +381,358 @@ impl ::core::iter::IntoIterator for EnumerantList {
     }
 }
 
+/// The interfaces visited on the way from the one where a search through superclasses
+/// started to the one being searched. A superclass that is already among them means that
+/// a (loaded) set of schemas is cyclic, and the search fails instead of going around forever.
+#[derive(Clone, Copy)]
+struct Visited<'v> {
+    id: u64,
+    rest: Option<&'v Visited<'v>>,
+}
+
+impl<'v> Visited<'v> {
+    fn check(&'v self, superclass: InterfaceSchema<'_>) -> Result<Visited<'v>> {
+        let id = superclass.proto.get_id();
+        let mut visited = Some(self);
+        while let Some(v) = visited {
+            if v.id == id {
+                let mut error = crate::Error::from_kind(crate::ErrorKind::Failed);
+                write!(error, "interface @{id:#x} inherits from itself");
+                return Err(error);
+            }
+            visited = v.rest;
+        }
+        Ok(Visited {
+            id,
+            rest: Some(self),
+        })
+    }
+}
+
+/// An interface node, with generics applied.
+#[derive(Clone, Copy)]
+pub struct InterfaceSchema<'a> {
+    pub(crate) raw: InterfaceType<'a>,
+    pub(crate) proto: node::Reader<'a>,
+}
+
+impl<'a> InterfaceSchema<'a> {
+    pub fn new(raw: impl Into<InterfaceType<'a>>) -> Self {
+        let raw = raw.into();
+        let proto = crate::any_pointer::Reader::new(unsafe {
+            layout::PointerReader::get_root_unchecked(raw.encoded_node().as_ptr() as *const u8)
+        })
+        .get_as()
+        .unwrap();
+        Self { raw, proto }
+    }
+
+    pub fn get_proto(self) -> node::Reader<'a> {
+        self.proto
+    }
+
+    /// Gets the methods declared directly by this interface. Methods inherited
+    /// from superclasses are not included.
+    pub fn get_methods(self) -> Result<MethodList<'a>> {
+        if let node::Interface(i) = self.proto.which()? {
+            Ok(MethodList {
+                methods: i.get_methods()?,
+                parent: self,
+            })
+        } else {
+            panic!()
+        }
+    }
+
+    /// Gets the method with the given ordinal, not looking at superclasses.
+    pub fn get_method_by_ordinal(self, ordinal: u16) -> Result<Option<Method<'a>>> {
+        let methods = self.get_methods()?;
+        if ordinal < methods.len() {
+            Ok(Some(methods.get(ordinal)))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Gets the method that a call to (`interface_id`, `ordinal`) invokes, i.e. looks
+    /// for the method among this interface and its superclasses.
+    pub fn find_method_by_id(self, interface_id: u64, ordinal: u16) -> Result<Option<Method<'a>>> {
+        self.find_method_by_id_aux(interface_id, ordinal, self.visited())
+    }
+
+    fn visited(self) -> Visited<'static> {
+        Visited {
+            id: self.proto.get_id(),
+            rest: None,
+        }
+    }
+
+    fn find_method_by_id_aux(
+        self,
+        interface_id: u64,
+        ordinal: u16,
+        visited: Visited<'_>,
+    ) -> Result<Option<Method<'a>>> {
+        if self.proto.get_id() == interface_id {
+            return self.get_method_by_ordinal(ordinal);
+        }
+        for superclass in self.get_superclasses()? {
+            let found = superclass.find_method_by_id_aux(
+                interface_id,
+                ordinal,
+                visited.check(superclass)?,
+            )?;
+            if found.is_some() {
+                return Ok(found);
+            }
+        }
+        Ok(None)
+    }
+
+    /// Looks up a method by name, first among the methods of this interface using binary
+    /// search, and then in the superclasses. Returns `None` if no matching method is found.
+    pub fn find_method_by_name(self, name: &str) -> Result<Option<Method<'a>>> {
+        self.find_method_by_name_aux(name, self.visited())
+    }
+
+    fn find_method_by_name_aux(
+        self,
+        name: &str,
+        visited: Visited<'_>,
+    ) -> Result<Option<Method<'a>>> {
+        let methods = self.get_methods()?;
+        let methods_by_name = self.raw.methods_by_name();
+        let mut lower: usize = 0;
+        let mut upper: usize = methods_by_name.len();
+
+        while lower < upper {
+            let mid: usize = (lower + upper) / 2;
+            let candidate_index = methods_by_name[mid];
+            let candidate_name = methods.get(candidate_index).get_proto().get_name()?;
+
+            use core::cmp::Ordering;
+            match (&name).partial_cmp(&candidate_name) {
+                Some(Ordering::Equal) => return Ok(Some(methods.get(candidate_index))),
+                Some(Ordering::Greater) => lower = mid + 1,
+                Some(Ordering::Less) => upper = mid,
+                None => unreachable!(),
+            }
+        }
+
+        for superclass in self.get_superclasses()? {
+            let found = superclass.find_method_by_name_aux(name, visited.check(superclass)?)?;
+            if found.is_some() {
+                return Ok(found);
+            }
+        }
+        Ok(None)
+    }
+
+    /// Like `find_method_by_name()`, but returns an error if the method is not found.
+    pub fn get_method_by_name(self, name: &str) -> Result<Method<'a>> {
+        if let Some(method) = self.find_method_by_name(name)? {
+            Ok(method)
+        } else {
+            let mut error = crate::Error::from_kind(crate::ErrorKind::MethodNotFound);
+            write!(error, "{}", name);
+            Err(error)
+        }
+    }
+
+    /// Gets the interfaces that this interface directly extends.
+    pub fn get_superclasses(self) -> Result<SuperclassList<'a>> {
+        if let node::Interface(i) = self.proto.which()? {
+            Ok(SuperclassList {
+                superclasses: i.get_superclasses()?,
+                parent: self,
+            })
+        } else {
+            panic!()
+        }
+    }
+
+    /// Returns true if this interface is `other`, or inherits from it.
+    pub fn extends(self, other: InterfaceSchema<'_>) -> Result<bool> {
+        self.extends_aux(other, self.visited())
+    }
+
+    fn extends_aux(self, other: InterfaceSchema<'_>, visited: Visited<'_>) -> Result<bool> {
+        if self.raw == other.raw {
+            return Ok(true);
+        }
+        for superclass in self.get_superclasses()? {
+            if superclass.extends_aux(other, visited.check(superclass)?)? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    pub fn get_annotations(self) -> Result<AnnotationList<'a>> {
+        Ok(AnnotationList {
+            annotations: self.proto.get_annotations()?,
+            child_index: None,
+            owner: AnnotationOwner::Interface(self.raw),
+        })
+    }
+}
+
+impl From<RawBrandedInterfaceSchema> for InterfaceSchema<'_> {
+    fn from(ri: RawBrandedInterfaceSchema) -> Self {
+        InterfaceSchema::new(ri)
+    }
+}
+
+impl<'a> From<InterfaceType<'a>> for InterfaceSchema<'a> {
+    fn from(it: InterfaceType<'a>) -> Self {
+        InterfaceSchema::new(it)
+    }
+}
+
+/// A method of an interface, with generics applied.
+#[derive(Clone, Copy)]
+pub struct Method<'a> {
+    proto: method::Reader<'a>,
+    ordinal: u16,
+    parent: InterfaceSchema<'a>,
+}
+
+impl<'a> Method<'a> {
+    pub fn get_proto(self) -> method::Reader<'a> {
+        self.proto
+    }
+
+    pub fn get_ordinal(self) -> u16 {
+        self.ordinal
+    }
+
+    pub fn get_containing_interface(self) -> InterfaceSchema<'a> {
+        self.parent
+    }
+
+    /// Gets the schema of the struct that holds the method's parameters.
+    pub fn get_param_type(self) -> StructSchema<'a> {
+        expect_struct(self.parent.raw.method_types(self.ordinal).0)
+    }
+
+    /// Gets the schema of the struct that holds the method's results.
+    pub fn get_result_type(self) -> StructSchema<'a> {
+        expect_struct(self.parent.raw.method_types(self.ordinal).1)
+    }
+
+    pub fn get_annotations(self) -> Result<AnnotationList<'a>> {
+        Ok(AnnotationList {
+            annotations: self.proto.get_annotations()?,
+            child_index: Some(self.ordinal),
+            owner: AnnotationOwner::Interface(self.parent.raw),
+        })
+    }
+}
+
+fn expect_struct(ty: introspect::Type<'_>) -> StructSchema<'_> {
+    match ty.which() {
+        TypeVariant::Struct(st) => st.into(),
+        _ => panic!("method params and results must be structs"),
+    }
+}
+
+/// A list of methods of an interface.
+#[derive(Clone, Copy)]
+pub struct MethodList<'a> {
+    methods: struct_list::Reader<'a, method::Owned>,
+    parent: InterfaceSchema<'a>,
+}
+
+impl<'a> MethodList<'a> {
+    pub fn len(&self) -> u16 {
+        self.methods.len() as u16
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get(self, ordinal: u16) -> Method<'a> {
+        Method {
+            proto: self.methods.get(ordinal as u32),
+            ordinal,
+            parent: self.parent,
+        }
+    }
+
+    pub fn iter(self) -> ShortListIter<Self, Method<'a>> {
+        ShortListIter::new(self, self.len())
+    }
+}
+
+impl<'a> IndexMove<u16, Method<'a>> for MethodList<'a> {
+    fn index_move(&self, index: u16) -> Method<'a> {
+        self.get(index)
+    }
+}
+
+impl<'a> ::core::iter::IntoIterator for MethodList<'a> {
+    type Item = Method<'a>;
+    type IntoIter = ShortListIter<Self, Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// The superclasses of an interface, with generics applied.
+#[derive(Clone, Copy)]
+pub struct SuperclassList<'a> {
+    superclasses: struct_list::Reader<'a, superclass::Owned>,
+    parent: InterfaceSchema<'a>,
+}
+
+impl<'a> SuperclassList<'a> {
+    pub fn len(&self) -> u32 {
+        self.superclasses.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get(self, index: u32) -> InterfaceSchema<'a> {
+        match self.parent.raw.superclass_type(index).which() {
+            TypeVariant::Capability(it) => it.into(),
+            _ => panic!("superclasses must be interfaces"),
+        }
+    }
+
+    pub fn iter(self) -> ListIter<Self, InterfaceSchema<'a>> {
+        ListIter::new(self, self.len())
+    }
+}
+
+impl<'a> IndexMove<u32, InterfaceSchema<'a>> for SuperclassList<'a> {
+    fn index_move(&self, index: u32) -> InterfaceSchema<'a> {
+        self.get(index)
+    }
+}
+
+impl<'a> ::core::iter::IntoIterator for SuperclassList<'a> {
+    type Item = InterfaceSchema<'a>;
+    type IntoIter = ListIter<Self, Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
 /// An annotation.
 #[derive(Clone, Copy)]
-pub struct Annotation {
-    proto: annotation::Reader<'static>,
-    ty: introspect::Type,
+pub struct Annotation<'a> {
+    proto: annotation::Reader<'a>,
+    ty: introspect::Type<'a>,
 }
 
-impl Annotation {
+impl<'a> Annotation<'a> {
     /// Gets the value held in this annotation.
-    pub fn get_value(self) -> Result<dynamic_value::Reader<'static>> {
+    pub fn get_value(self) -> Result<dynamic_value::Reader<'a>> {
         dynamic_value::Reader::new(self.proto.get_value()?, self.ty)
     }
 
@@ -387,20 +742,28 @@ impl Annotation {
     }
 
     /// Gets the type of the value held in this annotation.
-    pub fn get_type(&self) -> introspect::Type {
+    pub fn get_type(&self) -> introspect::Type<'a> {
         self.ty
     }
 }
 
+/// The node that an `AnnotationList` belongs to, used to look up the types of annotation values.
+#[derive(Clone, Copy)]
+enum AnnotationOwner<'a> {
+    Struct(StructType<'a>),
+    Enum(EnumType<'a>),
+    Interface(InterfaceType<'a>),
+}
+
 /// A list of annotations.
 #[derive(Clone, Copy)]
-pub struct AnnotationList {
-    annotations: struct_list::Reader<'static, annotation::Owned>,
+pub struct AnnotationList<'a> {
+    annotations: struct_list::Reader<'a, annotation::Owned>,
     child_index: Option<u16>,
-    get_annotation_type: fn(Option<u16>, u32) -> introspect::Type,
+    owner: AnnotationOwner<'a>,
 }
 
-impl AnnotationList {
+impl<'a> AnnotationList<'a> {
     pub fn len(&self) -> u32 {
         self.annotations.len()
     }
@@ -409,31 +772,35 @@ impl AnnotationList {
         self.len() == 0
     }
 
-    pub fn get(self, index: u32) -> Annotation {
+    pub fn get(self, index: u32) -> Annotation<'a> {
         let proto = self.annotations.get(index);
-        let ty = (self.get_annotation_type)(self.child_index, index);
+        let ty = match self.owner {
+            AnnotationOwner::Struct(s) => s.annotation_type(self.child_index, index),
+            AnnotationOwner::Enum(e) => e.annotation_type(self.child_index, index),
+            AnnotationOwner::Interface(i) => i.annotation_type(self.child_index, index),
+        };
         Annotation { proto, ty }
     }
 
     /// Returns the first annotation in the list that matches `id`.
     /// Otherwise returns `None`.
-    pub fn find(self, id: u64) -> Option<Annotation> {
+    pub fn find(self, id: u64) -> Option<Annotation<'a>> {
         self.iter().find(|&annotation| annotation.get_id() == id)
     }
 
-    pub fn iter(self) -> ListIter<Self, Annotation> {
+    pub fn iter(self) -> ListIter<Self, Annotation<'a>> {
         ListIter::new(self, self.len())
     }
 }
 
-impl IndexMove<u32, Annotation> for AnnotationList {
-    fn index_move(&self, index: u32) -> Annotation {
+impl<'a> IndexMove<u32, Annotation<'a>> for AnnotationList<'a> {
+    fn index_move(&self, index: u32) -> Annotation<'a> {
         self.get(index)
     }
 }
 
-impl ::core::iter::IntoIterator for AnnotationList {
-    type Item = Annotation;
+impl<'a> ::core::iter::IntoIterator for AnnotationList<'a> {
+    type Item = Annotation<'a>;
     type IntoIter = ListIter<Self, Self::Item>;
 
     fn into_iter(self) -> Self::IntoIter {
@@ -8,12 +8,15 @@ pub mod node {
     #[derive(Copy, Clone)]
     pub struct Owned(());
     impl crate::introspect::Introspect for Owned {
-        fn introspect() -> crate::introspect::Type {
-            crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+        fn introspect() -> crate::introspect::Type<'static> {
+            crate::introspect::TypeVariant::Struct(
+                crate::introspect::RawBrandedStructSchema {
+                    generic: &_private::RAW_SCHEMA,
+                    field_types: _private::get_field_types,
+                    annotation_types: _private::get_annotation_types,
+                }
+                .into(),
+            )
             .into()
         }
     }
@@ -113,6 +116,7 @@ pub mod node {
                 &self.reader.get_pointer_field(0),
                 ::core::option::Option::None,
             )
+            .map_err(|e| e.with_field(_private::TYPE_ID, "displayName"))
         }
         #[inline]
         pub fn has_display_name(&self) -> bool {
@@ -136,6 +140,7 @@ pub mod node {
                 &self.reader.get_pointer_field(1),
                 ::core::option::Option::None,
             )
+            .map_err(|e| e.with_field(_private::TYPE_ID, "nestedNodes"))
         }
         #[inline]
         pub fn has_nested_nodes(&self) -> bool {
@@ -150,6 +155,7 @@ pub mod node {
                 &self.reader.get_pointer_field(2),
                 ::core::option::Option::None,
             )
+            .map_err(|e| e.with_field(_private::TYPE_ID, "annotations"))
         }
         #[inline]
         pub fn has_annotations(&self) -> bool {
@@ -165,6 +171,7 @@ pub mod node {
                 &self.reader.get_pointer_field(5),
                 ::core::option::Option::None,
             )
+            .map_err(|e| e.with_field(_private::TYPE_ID, "parameters"))
         }
         #[inline]
         pub fn has_parameters(&self) -> bool {
@@ -233,6 +240,16 @@ pub mod node {
                 .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                 .into()
         }
+        fn try_init_pointer(
+            builder: crate::private::layout::PointerBuilder<'a>,
+            _size: u32,
+        ) -> crate::Result<Self> {
+            ::core::result::Result::Ok(
+                builder
+                    .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                    .into(),
+            )
+        }
         fn get_from_pointer(
             builder: crate::private::layout::PointerBuilder<'a>,
             default: ::core::option::Option<&'a [crate::Word]>,
@@ -299,10 +316,36 @@ pub mod node {
             .unwrap()
         }
         #[inline]
+        pub fn try_set_display_name(
+            &mut self,
+            value: impl crate::traits::SetterInput<crate::text::Owned>,
+        ) -> crate::Result<()> {
+            crate::traits::SetterInput::set_pointer_builder(
+                self.builder.reborrow().get_pointer_field(0),
+                value,
+                false,
+            )
+        }
+        #[inline]
         pub fn init_display_name(self, size: u32) -> crate::text::Builder<'a> {
             self.builder.get_pointer_field(0).init_text(size)
         }
         #[inline]
+        pub fn try_init_display_name(self, size: u32) -> crate::Result<crate::text::Builder<'a>> {
+            self.builder.get_pointer_field(0).try_init_text(size)
+        }
+        #[inline]
+        pub fn disown_display_name(&mut self) -> crate::orphan::Orphan<crate::text::Owned> {
+            crate::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
+        pub fn adopt_display_name(
+            &mut self,
+            orphan: crate::orphan::Orphan<crate::text::Owned>,
+        ) -> crate::Result<()> {
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
         pub fn has_display_name(&self) -> bool {
             !self.builder.is_pointer_field_null(0)
         }
@@ -353,6 +396,35 @@ pub mod node {
             crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(1), size)
         }
         #[inline]
+        pub fn try_init_nested_nodes(
+            self,
+            size: u32,
+        ) -> crate::Result<
+            crate::struct_list::Builder<'a, crate::schema_capnp::node::nested_node::Owned>,
+        > {
+            crate::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(1),
+                size,
+            )
+        }
+        #[inline]
+        pub fn disown_nested_nodes(
+            &mut self,
+        ) -> crate::orphan::Orphan<
+            crate::struct_list::Owned<crate::schema_capnp::node::nested_node::Owned>,
+        > {
+            crate::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(1))
+        }
+        #[inline]
+        pub fn adopt_nested_nodes(
+            &mut self,
+            orphan: crate::orphan::Orphan<
+                crate::struct_list::Owned<crate::schema_capnp::node::nested_node::Owned>,
+            >,
+        ) -> crate::Result<()> {
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(1))
+        }
+        #[inline]
         pub fn has_nested_nodes(&self) -> bool {
             !self.builder.is_pointer_field_null(1)
         }
@@ -385,6 +457,33 @@ pub mod node {
             crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(2), size)
         }
         #[inline]
+        pub fn try_init_annotations(
+            self,
+            size: u32,
+        ) -> crate::Result<crate::struct_list::Builder<'a, crate::schema_capnp::annotation::Owned>>
+        {
+            crate::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(2),
+                size,
+            )
+        }
+        #[inline]
+        pub fn disown_annotations(
+            &mut self,
+        ) -> crate::orphan::Orphan<crate::struct_list::Owned<crate::schema_capnp::annotation::Owned>>
+        {
+            crate::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(2))
+        }
+        #[inline]
+        pub fn adopt_annotations(
+            &mut self,
+            orphan: crate::orphan::Orphan<
+                crate::struct_list::Owned<crate::schema_capnp::annotation::Owned>,
+            >,
+        ) -> crate::Result<()> {
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(2))
+        }
+        #[inline]
         pub fn has_annotations(&self) -> bool {
             !self.builder.is_pointer_field_null(2)
         }
@@ -472,6 +571,35 @@ pub mod node {
             crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(5), size)
         }
         #[inline]
+        pub fn try_init_parameters(
+            self,
+            size: u32,
+        ) -> crate::Result<
+            crate::struct_list::Builder<'a, crate::schema_capnp::node::parameter::Owned>,
+        > {
+            crate::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(5),
+                size,
+            )
+        }
+        #[inline]
+        pub fn disown_parameters(
+            &mut self,
+        ) -> crate::orphan::Orphan<
+            crate::struct_list::Owned<crate::schema_capnp::node::parameter::Owned>,
+        > {
+            crate::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(5))
+        }
+        #[inline]
+        pub fn adopt_parameters(
+            &mut self,
+            orphan: crate::orphan::Orphan<
+                crate::struct_list::Owned<crate::schema_capnp::node::parameter::Owned>,
+            >,
+        ) -> crate::Result<()> {
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(5))
+        }
+        #[inline]
         pub fn has_parameters(&self) -> bool {
             !self.builder.is_pointer_field_null(5)
         }
@@ -736,7 +864,7 @@ pub mod node {
             crate::word(0, 0, 0, 0, 0, 0, 0, 0),
             crate::word(0, 0, 0, 0, 0, 0, 0, 0),
         ];
-        pub fn get_field_types(index: u16) -> crate::introspect::Type {
+        pub fn get_field_types(index: u16) -> crate::introspect::Type<'static> {
             match index {
         0 => <u64 as crate::introspect::Introspect>::introspect(),
         1 => <crate::text::Owned as crate::introspect::Introspect>::introspect(),
@@ -758,7 +886,7 @@ pub mod node {
         pub fn get_annotation_types(
             child_index: Option<u16>,
             index: u32,
-        ) -> crate::introspect::Type {
+        ) -> crate::introspect::Type<'static> {
             panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
         }
         pub static RAW_SCHEMA: crate::introspect::RawStructSchema =
@@ -800,12 +928,15 @@ pub mod node {
         #[derive(Copy, Clone)]
         pub struct Owned(());
         impl crate::introspect::Introspect for Owned {
-            fn introspect() -> crate::introspect::Type {
-                crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                })
+            fn introspect() -> crate::introspect::Type<'static> {
+                crate::introspect::TypeVariant::Struct(
+                    crate::introspect::RawBrandedStructSchema {
+                        generic: &_private::RAW_SCHEMA,
+                        field_types: _private::get_field_types,
+                        annotation_types: _private::get_annotation_types,
+                    }
+                    .into(),
+                )
                 .into()
             }
         }
@@ -901,6 +1032,7 @@ pub mod node {
                     &self.reader.get_pointer_field(0),
                     ::core::option::Option::None,
                 )
+                .map_err(|e| e.with_field(_private::TYPE_ID, "name"))
             }
             #[inline]
             pub fn has_name(&self) -> bool {
@@ -956,6 +1088,16 @@ pub mod node {
                     .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                     .into()
             }
+            fn try_init_pointer(
+                builder: crate::private::layout::PointerBuilder<'a>,
+                _size: u32,
+            ) -> crate::Result<Self> {
+                ::core::result::Result::Ok(
+                    builder
+                        .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                        .into(),
+                )
+            }
             fn get_from_pointer(
                 builder: crate::private::layout::PointerBuilder<'a>,
                 default: ::core::option::Option<&'a [crate::Word]>,
@@ -1011,10 +1153,36 @@ pub mod node {
                 .unwrap()
             }
             #[inline]
+            pub fn try_set_name(
+                &mut self,
+                value: impl crate::traits::SetterInput<crate::text::Owned>,
+            ) -> crate::Result<()> {
+                crate::traits::SetterInput::set_pointer_builder(
+                    self.builder.reborrow().get_pointer_field(0),
+                    value,
+                    false,
+                )
+            }
+            #[inline]
             pub fn init_name(self, size: u32) -> crate::text::Builder<'a> {
                 self.builder.get_pointer_field(0).init_text(size)
             }
             #[inline]
+            pub fn try_init_name(self, size: u32) -> crate::Result<crate::text::Builder<'a>> {
+                self.builder.get_pointer_field(0).try_init_text(size)
+            }
+            #[inline]
+            pub fn disown_name(&mut self) -> crate::orphan::Orphan<crate::text::Owned> {
+                crate::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(0))
+            }
+            #[inline]
+            pub fn adopt_name(
+                &mut self,
+                orphan: crate::orphan::Orphan<crate::text::Owned>,
+            ) -> crate::Result<()> {
+                orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+            }
+            #[inline]
             pub fn has_name(&self) -> bool {
                 !self.builder.is_pointer_field_null(0)
             }
@@ -1067,7 +1235,7 @@ pub mod node {
                 crate::word(0, 0, 0, 0, 0, 0, 0, 0),
                 crate::word(0, 0, 0, 0, 0, 0, 0, 0),
             ];
-            pub fn get_field_types(index: u16) -> crate::introspect::Type {
+            pub fn get_field_types(index: u16) -> crate::introspect::Type<'static> {
                 match index {
                     0 => <crate::text::Owned as crate::introspect::Introspect>::introspect(),
                     _ => panic!("invalid field index {}", index),
@@ -1076,7 +1244,7 @@ pub mod node {
             pub fn get_annotation_types(
                 child_index: Option<u16>,
                 index: u32,
-            ) -> crate::introspect::Type {
+            ) -> crate::introspect::Type<'static> {
                 panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
             }
             pub static RAW_SCHEMA: crate::introspect::RawStructSchema =
@@ -1097,12 +1265,15 @@ pub mod node {
         #[derive(Copy, Clone)]
         pub struct Owned(());
         impl crate::introspect::Introspect for Owned {
-            fn introspect() -> crate::introspect::Type {
-                crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                })
+            fn introspect() -> crate::introspect::Type<'static> {
+                crate::introspect::TypeVariant::Struct(
+                    crate::introspect::RawBrandedStructSchema {
+                        generic: &_private::RAW_SCHEMA,
+                        field_types: _private::get_field_types,
+                        annotation_types: _private::get_annotation_types,
+                    }
+                    .into(),
+                )
                 .into()
             }
         }
@@ -1198,6 +1369,7 @@ pub mod node {
                     &self.reader.get_pointer_field(0),
                     ::core::option::Option::None,
                 )
+                .map_err(|e| e.with_field(_private::TYPE_ID, "name"))
             }
             #[inline]
             pub fn has_name(&self) -> bool {
@@ -1257,6 +1429,16 @@ pub mod node {
                     .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                     .into()
             }
+            fn try_init_pointer(
+                builder: crate::private::layout::PointerBuilder<'a>,
+                _size: u32,
+            ) -> crate::Result<Self> {
+                ::core::result::Result::Ok(
+                    builder
+                        .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                        .into(),
+                )
+            }
             fn get_from_pointer(
                 builder: crate::private::layout::PointerBuilder<'a>,
                 default: ::core::option::Option<&'a [crate::Word]>,
@@ -1312,10 +1494,36 @@ pub mod node {
                 .unwrap()
             }
             #[inline]
+            pub fn try_set_name(
+                &mut self,
+                value: impl crate::traits::SetterInput<crate::text::Owned>,
+            ) -> crate::Result<()> {
+                crate::traits::SetterInput::set_pointer_builder(
+                    self.builder.reborrow().get_pointer_field(0),
+                    value,
+                    false,
+                )
+            }
+            #[inline]
             pub fn init_name(self, size: u32) -> crate::text::Builder<'a> {
                 self.builder.get_pointer_field(0).init_text(size)
             }
             #[inline]
+            pub fn try_init_name(self, size: u32) -> crate::Result<crate::text::Builder<'a>> {
+                self.builder.get_pointer_field(0).try_init_text(size)
+            }
+            #[inline]
+            pub fn disown_name(&mut self) -> crate::orphan::Orphan<crate::text::Owned> {
+                crate::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(0))
+            }
+            #[inline]
+            pub fn adopt_name(
+                &mut self,
+                orphan: crate::orphan::Orphan<crate::text::Owned>,
+            ) -> crate::Result<()> {
+                orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+            }
+            #[inline]
             pub fn has_name(&self) -> bool {
                 !self.builder.is_pointer_field_null(0)
             }
@@ -1391,7 +1599,7 @@ pub mod node {
                 crate::word(0, 0, 0, 0, 0, 0, 0, 0),
                 crate::word(0, 0, 0, 0, 0, 0, 0, 0),
             ];
-            pub fn get_field_types(index: u16) -> crate::introspect::Type {
+            pub fn get_field_types(index: u16) -> crate::introspect::Type<'static> {
                 match index {
                     0 => <crate::text::Owned as crate::introspect::Introspect>::introspect(),
                     1 => <u64 as crate::introspect::Introspect>::introspect(),
@@ -1401,7 +1609,7 @@ pub mod node {
             pub fn get_annotation_types(
                 child_index: Option<u16>,
                 index: u32,
-            ) -> crate::introspect::Type {
+            ) -> crate::introspect::Type<'static> {
                 panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
             }
             pub static RAW_SCHEMA: crate::introspect::RawStructSchema =
@@ -1422,12 +1630,15 @@ pub mod node {
         #[derive(Copy, Clone)]
         pub struct Owned(());
         impl crate::introspect::Introspect for Owned {
-            fn introspect() -> crate::introspect::Type {
-                crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                })
+            fn introspect() -> crate::introspect::Type<'static> {
+                crate::introspect::TypeVariant::Struct(
+                    crate::introspect::RawBrandedStructSchema {
+                        generic: &_private::RAW_SCHEMA,
+                        field_types: _private::get_field_types,
+                        annotation_types: _private::get_annotation_types,
+                    }
+                    .into(),
+                )
                 .into()
             }
         }
@@ -1527,6 +1738,7 @@ pub mod node {
                     &self.reader.get_pointer_field(0),
                     ::core::option::Option::None,
                 )
+                .map_err(|e| e.with_field(_private::TYPE_ID, "docComment"))
             }
             #[inline]
             pub fn has_doc_comment(&self) -> bool {
@@ -1545,6 +1757,7 @@ pub mod node {
                     &self.reader.get_pointer_field(1),
                     ::core::option::Option::None,
                 )
+                .map_err(|e| e.with_field(_private::TYPE_ID, "members"))
             }
             #[inline]
             pub fn has_members(&self) -> bool {
@@ -1600,6 +1813,16 @@ pub mod node {
                     .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                     .into()
             }
+            fn try_init_pointer(
+                builder: crate::private::layout::PointerBuilder<'a>,
+                _size: u32,
+            ) -> crate::Result<Self> {
+                ::core::result::Result::Ok(
+                    builder
+                        .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                        .into(),
+                )
+            }
             fn get_from_pointer(
                 builder: crate::private::layout::PointerBuilder<'a>,
                 default: ::core::option::Option<&'a [crate::Word]>,
@@ -1666,10 +1889,39 @@ pub mod node {
                 .unwrap()
             }
             #[inline]
+            pub fn try_set_doc_comment(
+                &mut self,
+                value: impl crate::traits::SetterInput<crate::text::Owned>,
+            ) -> crate::Result<()> {
+                crate::traits::SetterInput::set_pointer_builder(
+                    self.builder.reborrow().get_pointer_field(0),
+                    value,
+                    false,
+                )
+            }
+            #[inline]
             pub fn init_doc_comment(self, size: u32) -> crate::text::Builder<'a> {
                 self.builder.get_pointer_field(0).init_text(size)
             }
             #[inline]
+            pub fn try_init_doc_comment(
+                self,
+                size: u32,
+            ) -> crate::Result<crate::text::Builder<'a>> {
+                self.builder.get_pointer_field(0).try_init_text(size)
+            }
+            #[inline]
+            pub fn disown_doc_comment(&mut self) -> crate::orphan::Orphan<crate::text::Owned> {
+                crate::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(0))
+            }
+            #[inline]
+            pub fn adopt_doc_comment(
+                &mut self,
+                orphan: crate::orphan::Orphan<crate::text::Owned>,
+            ) -> crate::Result<()> {
+                orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+            }
+            #[inline]
             pub fn has_doc_comment(&self) -> bool {
                 !self.builder.is_pointer_field_null(0)
             }
@@ -1715,6 +1967,40 @@ pub mod node {
                 )
             }
             #[inline]
+            pub fn try_init_members(
+                self,
+                size: u32,
+            ) -> crate::Result<
+                crate::struct_list::Builder<
+                    'a,
+                    crate::schema_capnp::node::source_info::member::Owned,
+                >,
+            > {
+                crate::traits::FromPointerBuilder::try_init_pointer(
+                    self.builder.get_pointer_field(1),
+                    size,
+                )
+            }
+            #[inline]
+            pub fn disown_members(
+                &mut self,
+            ) -> crate::orphan::Orphan<
+                crate::struct_list::Owned<crate::schema_capnp::node::source_info::member::Owned>,
+            > {
+                crate::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(1))
+            }
+            #[inline]
+            pub fn adopt_members(
+                &mut self,
+                orphan: crate::orphan::Orphan<
+                    crate::struct_list::Owned<
+                        crate::schema_capnp::node::source_info::member::Owned,
+                    >,
+                >,
+            ) -> crate::Result<()> {
+                orphan.adopt_into(self.builder.reborrow().get_pointer_field(1))
+            }
+            #[inline]
             pub fn has_members(&self) -> bool {
                 !self.builder.is_pointer_field_null(1)
             }
@@ -1805,7 +2091,7 @@ pub mod node {
                 crate::word(0, 0, 0, 0, 0, 0, 0, 0),
                 crate::word(0, 0, 0, 0, 0, 0, 0, 0),
             ];
-            pub fn get_field_types(index: u16) -> crate::introspect::Type {
+            pub fn get_field_types(index: u16) -> crate::introspect::Type<'static> {
                 match index {
                     0 => <u64 as crate::introspect::Introspect>::introspect(),
                     1 => <crate::text::Owned as crate::introspect::Introspect>::introspect(),
@@ -1818,7 +2104,7 @@ pub mod node {
             pub fn get_annotation_types(
                 child_index: Option<u16>,
                 index: u32,
-            ) -> crate::introspect::Type {
+            ) -> crate::introspect::Type<'static> {
                 panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
             }
             pub static RAW_SCHEMA: crate::introspect::RawStructSchema =
@@ -1838,13 +2124,14 @@ pub mod node {
             #[derive(Copy, Clone)]
             pub struct Owned(());
             impl crate::introspect::Introspect for Owned {
-                fn introspect() -> crate::introspect::Type {
+                fn introspect() -> crate::introspect::Type<'static> {
                     crate::introspect::TypeVariant::Struct(
                         crate::introspect::RawBrandedStructSchema {
                             generic: &_private::RAW_SCHEMA,
                             field_types: _private::get_field_types,
                             annotation_types: _private::get_annotation_types,
-                        },
+                        }
+                        .into(),
                     )
                     .into()
                 }
@@ -1943,6 +2230,7 @@ pub mod node {
                         &self.reader.get_pointer_field(0),
                         ::core::option::Option::None,
                     )
+                    .map_err(|e| e.with_field(_private::TYPE_ID, "docComment"))
                 }
                 #[inline]
                 pub fn has_doc_comment(&self) -> bool {
@@ -2000,6 +2288,16 @@ pub mod node {
                         .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                         .into()
                 }
+                fn try_init_pointer(
+                    builder: crate::private::layout::PointerBuilder<'a>,
+                    _size: u32,
+                ) -> crate::Result<Self> {
+                    ::core::result::Result::Ok(
+                        builder
+                            .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                            .into(),
+                    )
+                }
                 fn get_from_pointer(
                     builder: crate::private::layout::PointerBuilder<'a>,
                     default: ::core::option::Option<&'a [crate::Word]>,
@@ -2061,10 +2359,39 @@ pub mod node {
                     .unwrap()
                 }
                 #[inline]
+                pub fn try_set_doc_comment(
+                    &mut self,
+                    value: impl crate::traits::SetterInput<crate::text::Owned>,
+                ) -> crate::Result<()> {
+                    crate::traits::SetterInput::set_pointer_builder(
+                        self.builder.reborrow().get_pointer_field(0),
+                        value,
+                        false,
+                    )
+                }
+                #[inline]
                 pub fn init_doc_comment(self, size: u32) -> crate::text::Builder<'a> {
                     self.builder.get_pointer_field(0).init_text(size)
                 }
                 #[inline]
+                pub fn try_init_doc_comment(
+                    self,
+                    size: u32,
+                ) -> crate::Result<crate::text::Builder<'a>> {
+                    self.builder.get_pointer_field(0).try_init_text(size)
+                }
+                #[inline]
+                pub fn disown_doc_comment(&mut self) -> crate::orphan::Orphan<crate::text::Owned> {
+                    crate::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(0))
+                }
+                #[inline]
+                pub fn adopt_doc_comment(
+                    &mut self,
+                    orphan: crate::orphan::Orphan<crate::text::Owned>,
+                ) -> crate::Result<()> {
+                    orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+                }
+                #[inline]
                 pub fn has_doc_comment(&self) -> bool {
                     !self.builder.is_pointer_field_null(0)
                 }
@@ -2119,7 +2446,7 @@ pub mod node {
                     crate::word(0, 0, 0, 0, 0, 0, 0, 0),
                     crate::word(0, 0, 0, 0, 0, 0, 0, 0),
                 ];
-                pub fn get_field_types(index: u16) -> crate::introspect::Type {
+                pub fn get_field_types(index: u16) -> crate::introspect::Type<'static> {
                     match index {
                         0 => <crate::text::Owned as crate::introspect::Introspect>::introspect(),
                         _ => panic!("invalid field index {}", index),
@@ -2128,7 +2455,7 @@ pub mod node {
                 pub fn get_annotation_types(
                     child_index: Option<u16>,
                     index: u32,
-                ) -> crate::introspect::Type {
+                ) -> crate::introspect::Type<'static> {
                     panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
                 }
                 pub static RAW_SCHEMA: crate::introspect::RawStructSchema =
@@ -2150,12 +2477,15 @@ pub mod node {
         #[derive(Copy, Clone)]
         pub struct Owned(());
         impl crate::introspect::Introspect for Owned {
-            fn introspect() -> crate::introspect::Type {
-                crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                })
+            fn introspect() -> crate::introspect::Type<'static> {
+                crate::introspect::TypeVariant::Struct(
+                    crate::introspect::RawBrandedStructSchema {
+                        generic: &_private::RAW_SCHEMA,
+                        field_types: _private::get_field_types,
+                        annotation_types: _private::get_annotation_types,
+                    }
+                    .into(),
+                )
                 .into()
             }
         }
@@ -2281,6 +2611,7 @@ pub mod node {
                     &self.reader.get_pointer_field(3),
                     ::core::option::Option::None,
                 )
+                .map_err(|e| e.with_field(_private::TYPE_ID, "fields"))
             }
             #[inline]
             pub fn has_fields(&self) -> bool {
@@ -2336,6 +2667,16 @@ pub mod node {
                     .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                     .into()
             }
+            fn try_init_pointer(
+                builder: crate::private::layout::PointerBuilder<'a>,
+                _size: u32,
+            ) -> crate::Result<Self> {
+                ::core::result::Result::Ok(
+                    builder
+                        .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                        .into(),
+                )
+            }
             fn get_from_pointer(
                 builder: crate::private::layout::PointerBuilder<'a>,
                 default: ::core::option::Option<&'a [crate::Word]>,
@@ -2457,6 +2798,33 @@ pub mod node {
                 )
             }
             #[inline]
+            pub fn try_init_fields(
+                self,
+                size: u32,
+            ) -> crate::Result<crate::struct_list::Builder<'a, crate::schema_capnp::field::Owned>>
+            {
+                crate::traits::FromPointerBuilder::try_init_pointer(
+                    self.builder.get_pointer_field(3),
+                    size,
+                )
+            }
+            #[inline]
+            pub fn disown_fields(
+                &mut self,
+            ) -> crate::orphan::Orphan<crate::struct_list::Owned<crate::schema_capnp::field::Owned>>
+            {
+                crate::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(3))
+            }
+            #[inline]
+            pub fn adopt_fields(
+                &mut self,
+                orphan: crate::orphan::Orphan<
+                    crate::struct_list::Owned<crate::schema_capnp::field::Owned>,
+                >,
+            ) -> crate::Result<()> {
+                orphan.adopt_into(self.builder.reborrow().get_pointer_field(3))
+            }
+            #[inline]
             pub fn has_fields(&self) -> bool {
                 !self.builder.is_pointer_field_null(3)
             }
@@ -2610,7 +2978,7 @@ pub mod node {
                 crate::word(0, 0, 0, 0, 0, 0, 0, 0),
                 crate::word(0, 0, 0, 0, 0, 0, 0, 0),
             ];
-            pub fn get_field_types(index: u16) -> crate::introspect::Type {
+            pub fn get_field_types(index: u16) -> crate::introspect::Type<'static> {
                 match index {
           0 => <u16 as crate::introspect::Introspect>::introspect(),
           1 => <u16 as crate::introspect::Introspect>::introspect(),
@@ -2625,7 +2993,7 @@ pub mod node {
             pub fn get_annotation_types(
                 child_index: Option<u16>,
                 index: u32,
-            ) -> crate::introspect::Type {
+            ) -> crate::introspect::Type<'static> {
                 panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
             }
             pub static RAW_SCHEMA: crate::introspect::RawStructSchema =
@@ -2646,12 +3014,15 @@ pub mod node {
         #[derive(Copy, Clone)]
         pub struct Owned(());
         impl crate::introspect::Introspect for Owned {
-            fn introspect() -> crate::introspect::Type {
-                crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                })
+            fn introspect() -> crate::introspect::Type<'static> {
+                crate::introspect::TypeVariant::Struct(
+                    crate::introspect::RawBrandedStructSchema {
+                        generic: &_private::RAW_SCHEMA,
+                        field_types: _private::get_field_types,
+                        annotation_types: _private::get_annotation_types,
+                    }
+                    .into(),
+                )
                 .into()
             }
         }
@@ -2750,6 +3121,7 @@ pub mod node {
                     &self.reader.get_pointer_field(3),
                     ::core::option::Option::None,
                 )
+                .map_err(|e| e.with_field(_private::TYPE_ID, "enumerants"))
             }
             #[inline]
             pub fn has_enumerants(&self) -> bool {
@@ -2805,6 +3177,16 @@ pub mod node {
                     .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                     .into()
             }
+            fn try_init_pointer(
+                builder: crate::private::layout::PointerBuilder<'a>,
+                _size: u32,
+            ) -> crate::Result<Self> {
+                ::core::result::Result::Ok(
+                    builder
+                        .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                        .into(),
+                )
+            }
             fn get_from_pointer(
                 builder: crate::private::layout::PointerBuilder<'a>,
                 default: ::core::option::Option<&'a [crate::Word]>,
@@ -2876,6 +3258,34 @@ pub mod node {
                 )
             }
             #[inline]
+            pub fn try_init_enumerants(
+                self,
+                size: u32,
+            ) -> crate::Result<crate::struct_list::Builder<'a, crate::schema_capnp::enumerant::Owned>>
+            {
+                crate::traits::FromPointerBuilder::try_init_pointer(
+                    self.builder.get_pointer_field(3),
+                    size,
+                )
+            }
+            #[inline]
+            pub fn disown_enumerants(
+                &mut self,
+            ) -> crate::orphan::Orphan<
+                crate::struct_list::Owned<crate::schema_capnp::enumerant::Owned>,
+            > {
+                crate::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(3))
+            }
+            #[inline]
+            pub fn adopt_enumerants(
+                &mut self,
+                orphan: crate::orphan::Orphan<
+                    crate::struct_list::Owned<crate::schema_capnp::enumerant::Owned>,
+                >,
+            ) -> crate::Result<()> {
+                orphan.adopt_into(self.builder.reborrow().get_pointer_field(3))
+            }
+            #[inline]
             pub fn has_enumerants(&self) -> bool {
                 !self.builder.is_pointer_field_null(3)
             }
@@ -2931,7 +3341,7 @@ pub mod node {
                 crate::word(0, 0, 0, 0, 0, 0, 0, 0),
                 crate::word(0, 0, 0, 0, 0, 0, 0, 0),
             ];
-            pub fn get_field_types(index: u16) -> crate::introspect::Type {
+            pub fn get_field_types(index: u16) -> crate::introspect::Type<'static> {
                 match index {
           0 => <crate::struct_list::Owned<crate::schema_capnp::enumerant::Owned> as crate::introspect::Introspect>::introspect(),
           _ => panic!("invalid field index {}", index),
@@ -2940,7 +3350,7 @@ pub mod node {
             pub fn get_annotation_types(
                 child_index: Option<u16>,
                 index: u32,
-            ) -> crate::introspect::Type {
+            ) -> crate::introspect::Type<'static> {
                 panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
             }
             pub static RAW_SCHEMA: crate::introspect::RawStructSchema =
@@ -2961,12 +3371,15 @@ pub mod node {
         #[derive(Copy, Clone)]
         pub struct Owned(());
         impl crate::introspect::Introspect for Owned {
-            fn introspect() -> crate::introspect::Type {
-                crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                })
+            fn introspect() -> crate::introspect::Type<'static> {
+                crate::introspect::TypeVariant::Struct(
+                    crate::introspect::RawBrandedStructSchema {
+                        generic: &_private::RAW_SCHEMA,
+                        field_types: _private::get_field_types,
+                        annotation_types: _private::get_annotation_types,
+                    }
+                    .into(),
+                )
                 .into()
             }
         }
@@ -3065,6 +3478,7 @@ pub mod node {
                     &self.reader.get_pointer_field(3),
                     ::core::option::Option::None,
                 )
+                .map_err(|e| e.with_field(_private::TYPE_ID, "methods"))
             }
             #[inline]
             pub fn has_methods(&self) -> bool {
@@ -3079,6 +3493,7 @@ pub mod node {
                     &self.reader.get_pointer_field(4),
                     ::core::option::Option::None,
                 )
+                .map_err(|e| e.with_field(_private::TYPE_ID, "superclasses"))
             }
             #[inline]
             pub fn has_superclasses(&self) -> bool {
@@ -3134,6 +3549,16 @@ pub mod node {
                     .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                     .into()
             }
+            fn try_init_pointer(
+                builder: crate::private::layout::PointerBuilder<'a>,
+                _size: u32,
+            ) -> crate::Result<Self> {
+                ::core::result::Result::Ok(
+                    builder
+                        .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                        .into(),
+                )
+            }
             fn get_from_pointer(
                 builder: crate::private::layout::PointerBuilder<'a>,
                 default: ::core::option::Option<&'a [crate::Word]>,
@@ -3204,6 +3629,33 @@ pub mod node {
                 )
             }
             #[inline]
+            pub fn try_init_methods(
+                self,
+                size: u32,
+            ) -> crate::Result<crate::struct_list::Builder<'a, crate::schema_capnp::method::Owned>>
+            {
+                crate::traits::FromPointerBuilder::try_init_pointer(
+                    self.builder.get_pointer_field(3),
+                    size,
+                )
+            }
+            #[inline]
+            pub fn disown_methods(
+                &mut self,
+            ) -> crate::orphan::Orphan<crate::struct_list::Owned<crate::schema_capnp::method::Owned>>
+            {
+                crate::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(3))
+            }
+            #[inline]
+            pub fn adopt_methods(
+                &mut self,
+                orphan: crate::orphan::Orphan<
+                    crate::struct_list::Owned<crate::schema_capnp::method::Owned>,
+                >,
+            ) -> crate::Result<()> {
+                orphan.adopt_into(self.builder.reborrow().get_pointer_field(3))
+            }
+            #[inline]
             pub fn has_methods(&self) -> bool {
                 !self.builder.is_pointer_field_null(3)
             }
@@ -3241,6 +3693,35 @@ pub mod node {
                 )
             }
             #[inline]
+            pub fn try_init_superclasses(
+                self,
+                size: u32,
+            ) -> crate::Result<
+                crate::struct_list::Builder<'a, crate::schema_capnp::superclass::Owned>,
+            > {
+                crate::traits::FromPointerBuilder::try_init_pointer(
+                    self.builder.get_pointer_field(4),
+                    size,
+                )
+            }
+            #[inline]
+            pub fn disown_superclasses(
+                &mut self,
+            ) -> crate::orphan::Orphan<
+                crate::struct_list::Owned<crate::schema_capnp::superclass::Owned>,
+            > {
+                crate::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(4))
+            }
+            #[inline]
+            pub fn adopt_superclasses(
+                &mut self,
+                orphan: crate::orphan::Orphan<
+                    crate::struct_list::Owned<crate::schema_capnp::superclass::Owned>,
+                >,
+            ) -> crate::Result<()> {
+                orphan.adopt_into(self.builder.reborrow().get_pointer_field(4))
+            }
+            #[inline]
             pub fn has_superclasses(&self) -> bool {
                 !self.builder.is_pointer_field_null(4)
             }
@@ -3316,7 +3797,7 @@ pub mod node {
                 crate::word(0, 0, 0, 0, 0, 0, 0, 0),
                 crate::word(0, 0, 0, 0, 0, 0, 0, 0),
             ];
-            pub fn get_field_types(index: u16) -> crate::introspect::Type {
+            pub fn get_field_types(index: u16) -> crate::introspect::Type<'static> {
                 match index {
           0 => <crate::struct_list::Owned<crate::schema_capnp::method::Owned> as crate::introspect::Introspect>::introspect(),
           1 => <crate::struct_list::Owned<crate::schema_capnp::superclass::Owned> as crate::introspect::Introspect>::introspect(),
@@ -3326,7 +3807,7 @@ pub mod node {
             pub fn get_annotation_types(
                 child_index: Option<u16>,
                 index: u32,
-            ) -> crate::introspect::Type {
+            ) -> crate::introspect::Type<'static> {
                 panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
             }
             pub static RAW_SCHEMA: crate::introspect::RawStructSchema =
@@ -3347,12 +3828,15 @@ pub mod node {
         #[derive(Copy, Clone)]
         pub struct Owned(());
         impl crate::introspect::Introspect for Owned {
-            fn introspect() -> crate::introspect::Type {
-                crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                })
+            fn introspect() -> crate::introspect::Type<'static> {
+                crate::introspect::TypeVariant::Struct(
+                    crate::introspect::RawBrandedStructSchema {
+                        generic: &_private::RAW_SCHEMA,
+                        field_types: _private::get_field_types,
+                        annotation_types: _private::get_annotation_types,
+                    }
+                    .into(),
+                )
                 .into()
             }
         }
@@ -3448,6 +3932,7 @@ pub mod node {
                     &self.reader.get_pointer_field(3),
                     ::core::option::Option::None,
                 )
+                .map_err(|e| e.with_field(_private::TYPE_ID, "type"))
             }
             #[inline]
             pub fn has_type(&self) -> bool {
@@ -3459,6 +3944,7 @@ pub mod node {
                     &self.reader.get_pointer_field(4),
                     ::core::option::Option::None,
                 )
+                .map_err(|e| e.with_field(_private::TYPE_ID, "value"))
             }
             #[inline]
             pub fn has_value(&self) -> bool {
@@ -3514,6 +4000,16 @@ pub mod node {
                     .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                     .into()
             }
+            fn try_init_pointer(
+                builder: crate::private::layout::PointerBuilder<'a>,
+                _size: u32,
+            ) -> crate::Result<Self> {
+                ::core::result::Result::Ok(
+                    builder
+                        .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                        .into(),
+                )
+            }
             fn get_from_pointer(
                 builder: crate::private::layout::PointerBuilder<'a>,
                 default: ::core::option::Option<&'a [crate::Word]>,
@@ -3578,6 +4074,26 @@ pub mod node {
                 )
             }
             #[inline]
+            pub fn try_init_type(self) -> crate::Result<crate::schema_capnp::type_::Builder<'a>> {
+                crate::traits::FromPointerBuilder::try_init_pointer(
+                    self.builder.get_pointer_field(3),
+                    0,
+                )
+            }
+            #[inline]
+            pub fn disown_type(
+                &mut self,
+            ) -> crate::orphan::Orphan<crate::schema_capnp::type_::Owned> {
+                crate::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(3))
+            }
+            #[inline]
+            pub fn adopt_type(
+                &mut self,
+                orphan: crate::orphan::Orphan<crate::schema_capnp::type_::Owned>,
+            ) -> crate::Result<()> {
+                orphan.adopt_into(self.builder.reborrow().get_pointer_field(3))
+            }
+            #[inline]
             pub fn has_type(&self) -> bool {
                 !self.builder.is_pointer_field_null(3)
             }
@@ -3607,6 +4123,26 @@ pub mod node {
                 )
             }
             #[inline]
+            pub fn try_init_value(self) -> crate::Result<crate::schema_capnp::value::Builder<'a>> {
+                crate::traits::FromPointerBuilder::try_init_pointer(
+                    self.builder.get_pointer_field(4),
+                    0,
+                )
+            }
+            #[inline]
+            pub fn disown_value(
+                &mut self,
+            ) -> crate::orphan::Orphan<crate::schema_capnp::value::Owned> {
+                crate::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(4))
+            }
+            #[inline]
+            pub fn adopt_value(
+                &mut self,
+                orphan: crate::orphan::Orphan<crate::schema_capnp::value::Owned>,
+            ) -> crate::Result<()> {
+                orphan.adopt_into(self.builder.reborrow().get_pointer_field(4))
+            }
+            #[inline]
             pub fn has_value(&self) -> bool {
                 !self.builder.is_pointer_field_null(4)
             }
@@ -3679,7 +4215,7 @@ pub mod node {
                 crate::word(0, 0, 0, 0, 0, 0, 0, 0),
                 crate::word(0, 0, 0, 0, 0, 0, 0, 0),
             ];
-            pub fn get_field_types(index: u16) -> crate::introspect::Type {
+            pub fn get_field_types(index: u16) -> crate::introspect::Type<'static> {
                 match index {
           0 => <crate::schema_capnp::type_::Owned as crate::introspect::Introspect>::introspect(),
           1 => <crate::schema_capnp::value::Owned as crate::introspect::Introspect>::introspect(),
@@ -3689,7 +4225,7 @@ pub mod node {
             pub fn get_annotation_types(
                 child_index: Option<u16>,
                 index: u32,
-            ) -> crate::introspect::Type {
+            ) -> crate::introspect::Type<'static> {
                 panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
             }
             pub static RAW_SCHEMA: crate::introspect::RawStructSchema =
@@ -3710,12 +4246,15 @@ pub mod node {
         #[derive(Copy, Clone)]
         pub struct Owned(());
         impl crate::introspect::Introspect for Owned {
-            fn introspect() -> crate::introspect::Type {
-                crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                })
+            fn introspect() -> crate::introspect::Type<'static> {
+                crate::introspect::TypeVariant::Struct(
+                    crate::introspect::RawBrandedStructSchema {
+                        generic: &_private::RAW_SCHEMA,
+                        field_types: _private::get_field_types,
+                        annotation_types: _private::get_annotation_types,
+                    }
+                    .into(),
+                )
                 .into()
             }
         }
@@ -3811,6 +4350,7 @@ pub mod node {
                     &self.reader.get_pointer_field(3),
                     ::core::option::Option::None,
                 )
+                .map_err(|e| e.with_field(_private::TYPE_ID, "type"))
             }
             #[inline]
             pub fn has_type(&self) -> bool {
@@ -3914,6 +4454,16 @@ pub mod node {
                     .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                     .into()
             }
+            fn try_init_pointer(
+                builder: crate::private::layout::PointerBuilder<'a>,
+                _size: u32,
+            ) -> crate::Result<Self> {
+                ::core::result::Result::Ok(
+                    builder
+                        .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                        .into(),
+                )
+            }
             fn get_from_pointer(
                 builder: crate::private::layout::PointerBuilder<'a>,
                 default: ::core::option::Option<&'a [crate::Word]>,
@@ -3978,6 +4528,26 @@ pub mod node {
                 )
             }
             #[inline]
+            pub fn try_init_type(self) -> crate::Result<crate::schema_capnp::type_::Builder<'a>> {
+                crate::traits::FromPointerBuilder::try_init_pointer(
+                    self.builder.get_pointer_field(3),
+                    0,
+                )
+            }
+            #[inline]
+            pub fn disown_type(
+                &mut self,
+            ) -> crate::orphan::Orphan<crate::schema_capnp::type_::Owned> {
+                crate::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(3))
+            }
+            #[inline]
+            pub fn adopt_type(
+                &mut self,
+                orphan: crate::orphan::Orphan<crate::schema_capnp::type_::Owned>,
+            ) -> crate::Result<()> {
+                orphan.adopt_into(self.builder.reborrow().get_pointer_field(3))
+            }
+            #[inline]
             pub fn has_type(&self) -> bool {
                 !self.builder.is_pointer_field_null(3)
             }
@@ -4324,7 +4894,7 @@ pub mod node {
                 crate::word(0, 0, 0, 0, 0, 0, 0, 0),
                 crate::word(0, 0, 0, 0, 0, 0, 0, 0),
             ];
-            pub fn get_field_types(index: u16) -> crate::introspect::Type {
+            pub fn get_field_types(index: u16) -> crate::introspect::Type<'static> {
                 match index {
           0 => <crate::schema_capnp::type_::Owned as crate::introspect::Introspect>::introspect(),
           1 => <bool as crate::introspect::Introspect>::introspect(),
@@ -4345,7 +4915,7 @@ pub mod node {
             pub fn get_annotation_types(
                 child_index: Option<u16>,
                 index: u32,
-            ) -> crate::introspect::Type {
+            ) -> crate::introspect::Type<'static> {
                 panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
             }
             pub static RAW_SCHEMA: crate::introspect::RawStructSchema =
@@ -4369,12 +4939,15 @@ pub mod field {
     #[derive(Copy, Clone)]
     pub struct Owned(());
     impl crate::introspect::Introspect for Owned {
-        fn introspect() -> crate::introspect::Type {
-            crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+        fn introspect() -> crate::introspect::Type<'static> {
+            crate::introspect::TypeVariant::Struct(
+                crate::introspect::RawBrandedStructSchema {
+                    generic: &_private::RAW_SCHEMA,
+                    field_types: _private::get_field_types,
+                    annotation_types: _private::get_annotation_types,
+                }
+                .into(),
+            )
             .into()
         }
     }
@@ -4470,6 +5043,7 @@ pub mod field {
                 &self.reader.get_pointer_field(0),
                 ::core::option::Option::None,
             )
+            .map_err(|e| e.with_field(_private::TYPE_ID, "name"))
         }
         #[inline]
         pub fn has_name(&self) -> bool {
@@ -4488,6 +5062,7 @@ pub mod field {
                 &self.reader.get_pointer_field(1),
                 ::core::option::Option::None,
             )
+            .map_err(|e| e.with_field(_private::TYPE_ID, "annotations"))
         }
         #[inline]
         pub fn has_annotations(&self) -> bool {
@@ -4556,6 +5131,16 @@ pub mod field {
                 .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                 .into()
         }
+        fn try_init_pointer(
+            builder: crate::private::layout::PointerBuilder<'a>,
+            _size: u32,
+        ) -> crate::Result<Self> {
+            ::core::result::Result::Ok(
+                builder
+                    .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                    .into(),
+            )
+        }
         fn get_from_pointer(
             builder: crate::private::layout::PointerBuilder<'a>,
             default: ::core::option::Option<&'a [crate::Word]>,
@@ -4611,10 +5196,36 @@ pub mod field {
             .unwrap()
         }
         #[inline]
+        pub fn try_set_name(
+            &mut self,
+            value: impl crate::traits::SetterInput<crate::text::Owned>,
+        ) -> crate::Result<()> {
+            crate::traits::SetterInput::set_pointer_builder(
+                self.builder.reborrow().get_pointer_field(0),
+                value,
+                false,
+            )
+        }
+        #[inline]
         pub fn init_name(self, size: u32) -> crate::text::Builder<'a> {
             self.builder.get_pointer_field(0).init_text(size)
         }
         #[inline]
+        pub fn try_init_name(self, size: u32) -> crate::Result<crate::text::Builder<'a>> {
+            self.builder.get_pointer_field(0).try_init_text(size)
+        }
+        #[inline]
+        pub fn disown_name(&mut self) -> crate::orphan::Orphan<crate::text::Owned> {
+            crate::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
+        pub fn adopt_name(
+            &mut self,
+            orphan: crate::orphan::Orphan<crate::text::Owned>,
+        ) -> crate::Result<()> {
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
         pub fn has_name(&self) -> bool {
             !self.builder.is_pointer_field_null(0)
         }
@@ -4655,6 +5266,33 @@ pub mod field {
             crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(1), size)
         }
         #[inline]
+        pub fn try_init_annotations(
+            self,
+            size: u32,
+        ) -> crate::Result<crate::struct_list::Builder<'a, crate::schema_capnp::annotation::Owned>>
+        {
+            crate::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(1),
+                size,
+            )
+        }
+        #[inline]
+        pub fn disown_annotations(
+            &mut self,
+        ) -> crate::orphan::Orphan<crate::struct_list::Owned<crate::schema_capnp::annotation::Owned>>
+        {
+            crate::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(1))
+        }
+        #[inline]
+        pub fn adopt_annotations(
+            &mut self,
+            orphan: crate::orphan::Orphan<
+                crate::struct_list::Owned<crate::schema_capnp::annotation::Owned>,
+            >,
+        ) -> crate::Result<()> {
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(1))
+        }
+        #[inline]
         pub fn has_annotations(&self) -> bool {
             !self.builder.is_pointer_field_null(1)
         }
@@ -4832,7 +5470,7 @@ pub mod field {
             crate::word(103, 114, 111, 117, 112, 0, 0, 0),
             crate::word(111, 114, 100, 105, 110, 97, 108, 0),
         ];
-        pub fn get_field_types(index: u16) -> crate::introspect::Type {
+        pub fn get_field_types(index: u16) -> crate::introspect::Type<'static> {
             match index {
         0 => <crate::text::Owned as crate::introspect::Introspect>::introspect(),
         1 => <u16 as crate::introspect::Introspect>::introspect(),
@@ -4847,7 +5485,7 @@ pub mod field {
         pub fn get_annotation_types(
             child_index: Option<u16>,
             index: u32,
-        ) -> crate::introspect::Type {
+        ) -> crate::introspect::Type<'static> {
             panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
         }
         pub static RAW_SCHEMA: crate::introspect::RawStructSchema =
@@ -4880,12 +5518,15 @@ pub mod field {
         #[derive(Copy, Clone)]
         pub struct Owned(());
         impl crate::introspect::Introspect for Owned {
-            fn introspect() -> crate::introspect::Type {
-                crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                })
+            fn introspect() -> crate::introspect::Type<'static> {
+                crate::introspect::TypeVariant::Struct(
+                    crate::introspect::RawBrandedStructSchema {
+                        generic: &_private::RAW_SCHEMA,
+                        field_types: _private::get_field_types,
+                        annotation_types: _private::get_annotation_types,
+                    }
+                    .into(),
+                )
                 .into()
             }
         }
@@ -4985,6 +5626,7 @@ pub mod field {
                     &self.reader.get_pointer_field(2),
                     ::core::option::Option::None,
                 )
+                .map_err(|e| e.with_field(_private::TYPE_ID, "type"))
             }
             #[inline]
             pub fn has_type(&self) -> bool {
@@ -4998,6 +5640,7 @@ pub mod field {
                     &self.reader.get_pointer_field(3),
                     ::core::option::Option::None,
                 )
+                .map_err(|e| e.with_field(_private::TYPE_ID, "defaultValue"))
             }
             #[inline]
             pub fn has_default_value(&self) -> bool {
@@ -5057,6 +5700,16 @@ pub mod field {
                     .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                     .into()
             }
+            fn try_init_pointer(
+                builder: crate::private::layout::PointerBuilder<'a>,
+                _size: u32,
+            ) -> crate::Result<Self> {
+                ::core::result::Result::Ok(
+                    builder
+                        .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                        .into(),
+                )
+            }
             fn get_from_pointer(
                 builder: crate::private::layout::PointerBuilder<'a>,
                 default: ::core::option::Option<&'a [crate::Word]>,
@@ -5129,6 +5782,26 @@ pub mod field {
                 )
             }
             #[inline]
+            pub fn try_init_type(self) -> crate::Result<crate::schema_capnp::type_::Builder<'a>> {
+                crate::traits::FromPointerBuilder::try_init_pointer(
+                    self.builder.get_pointer_field(2),
+                    0,
+                )
+            }
+            #[inline]
+            pub fn disown_type(
+                &mut self,
+            ) -> crate::orphan::Orphan<crate::schema_capnp::type_::Owned> {
+                crate::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(2))
+            }
+            #[inline]
+            pub fn adopt_type(
+                &mut self,
+                orphan: crate::orphan::Orphan<crate::schema_capnp::type_::Owned>,
+            ) -> crate::Result<()> {
+                orphan.adopt_into(self.builder.reborrow().get_pointer_field(2))
+            }
+            #[inline]
             pub fn has_type(&self) -> bool {
                 !self.builder.is_pointer_field_null(2)
             }
@@ -5160,6 +5833,28 @@ pub mod field {
                 )
             }
             #[inline]
+            pub fn try_init_default_value(
+                self,
+            ) -> crate::Result<crate::schema_capnp::value::Builder<'a>> {
+                crate::traits::FromPointerBuilder::try_init_pointer(
+                    self.builder.get_pointer_field(3),
+                    0,
+                )
+            }
+            #[inline]
+            pub fn disown_default_value(
+                &mut self,
+            ) -> crate::orphan::Orphan<crate::schema_capnp::value::Owned> {
+                crate::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(3))
+            }
+            #[inline]
+            pub fn adopt_default_value(
+                &mut self,
+                orphan: crate::orphan::Orphan<crate::schema_capnp::value::Owned>,
+            ) -> crate::Result<()> {
+                orphan.adopt_into(self.builder.reborrow().get_pointer_field(3))
+            }
+            #[inline]
             pub fn has_default_value(&self) -> bool {
                 !self.builder.is_pointer_field_null(3)
             }
@@ -5273,7 +5968,7 @@ pub mod field {
                 crate::word(0, 0, 0, 0, 0, 0, 0, 0),
                 crate::word(0, 0, 0, 0, 0, 0, 0, 0),
             ];
-            pub fn get_field_types(index: u16) -> crate::introspect::Type {
+            pub fn get_field_types(index: u16) -> crate::introspect::Type<'static> {
                 match index {
           0 => <u32 as crate::introspect::Introspect>::introspect(),
           1 => <crate::schema_capnp::type_::Owned as crate::introspect::Introspect>::introspect(),
@@ -5285,7 +5980,7 @@ pub mod field {
             pub fn get_annotation_types(
                 child_index: Option<u16>,
                 index: u32,
-            ) -> crate::introspect::Type {
+            ) -> crate::introspect::Type<'static> {
                 panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
             }
             pub static RAW_SCHEMA: crate::introspect::RawStructSchema =
@@ -5306,12 +6001,15 @@ pub mod field {
         #[derive(Copy, Clone)]
         pub struct Owned(());
         impl crate::introspect::Introspect for Owned {
-            fn introspect() -> crate::introspect::Type {
-                crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                })
+            fn introspect() -> crate::introspect::Type<'static> {
+                crate::introspect::TypeVariant::Struct(
+                    crate::introspect::RawBrandedStructSchema {
+                        generic: &_private::RAW_SCHEMA,
+                        field_types: _private::get_field_types,
+                        annotation_types: _private::get_annotation_types,
+                    }
+                    .into(),
+                )
                 .into()
             }
         }
@@ -5455,6 +6153,16 @@ pub mod field {
                     .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                     .into()
             }
+            fn try_init_pointer(
+                builder: crate::private::layout::PointerBuilder<'a>,
+                _size: u32,
+            ) -> crate::Result<Self> {
+                ::core::result::Result::Ok(
+                    builder
+                        .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                        .into(),
+                )
+            }
             fn get_from_pointer(
                 builder: crate::private::layout::PointerBuilder<'a>,
                 default: ::core::option::Option<&'a [crate::Word]>,
@@ -5549,7 +6257,7 @@ pub mod field {
                 crate::word(0, 0, 0, 0, 0, 0, 0, 0),
                 crate::word(0, 0, 0, 0, 0, 0, 0, 0),
             ];
-            pub fn get_field_types(index: u16) -> crate::introspect::Type {
+            pub fn get_field_types(index: u16) -> crate::introspect::Type<'static> {
                 match index {
                     0 => <u64 as crate::introspect::Introspect>::introspect(),
                     _ => panic!("invalid field index {}", index),
@@ -5558,7 +6266,7 @@ pub mod field {
             pub fn get_annotation_types(
                 child_index: Option<u16>,
                 index: u32,
-            ) -> crate::introspect::Type {
+            ) -> crate::introspect::Type<'static> {
                 panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
             }
             pub static RAW_SCHEMA: crate::introspect::RawStructSchema =
@@ -5581,12 +6289,15 @@ pub mod field {
         #[derive(Copy, Clone)]
         pub struct Owned(());
         impl crate::introspect::Introspect for Owned {
-            fn introspect() -> crate::introspect::Type {
-                crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                })
+            fn introspect() -> crate::introspect::Type<'static> {
+                crate::introspect::TypeVariant::Struct(
+                    crate::introspect::RawBrandedStructSchema {
+                        generic: &_private::RAW_SCHEMA,
+                        field_types: _private::get_field_types,
+                        annotation_types: _private::get_annotation_types,
+                    }
+                    .into(),
+                )
                 .into()
             }
         }
@@ -5734,6 +6445,16 @@ pub mod field {
                     .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                     .into()
             }
+            fn try_init_pointer(
+                builder: crate::private::layout::PointerBuilder<'a>,
+                _size: u32,
+            ) -> crate::Result<Self> {
+                ::core::result::Result::Ok(
+                    builder
+                        .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                        .into(),
+                )
+            }
             fn get_from_pointer(
                 builder: crate::private::layout::PointerBuilder<'a>,
                 default: ::core::option::Option<&'a [crate::Word]>,
@@ -5856,7 +6577,7 @@ pub mod field {
                 crate::word(0, 0, 0, 0, 0, 0, 0, 0),
                 crate::word(0, 0, 0, 0, 0, 0, 0, 0),
             ];
-            pub fn get_field_types(index: u16) -> crate::introspect::Type {
+            pub fn get_field_types(index: u16) -> crate::introspect::Type<'static> {
                 match index {
                     0 => <() as crate::introspect::Introspect>::introspect(),
                     1 => <u16 as crate::introspect::Introspect>::introspect(),
@@ -5866,7 +6587,7 @@ pub mod field {
             pub fn get_annotation_types(
                 child_index: Option<u16>,
                 index: u32,
-            ) -> crate::introspect::Type {
+            ) -> crate::introspect::Type<'static> {
                 panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
             }
             pub static RAW_SCHEMA: crate::introspect::RawStructSchema =
@@ -5894,12 +6615,15 @@ pub mod enumerant {
     #[derive(Copy, Clone)]
     pub struct Owned(());
     impl crate::introspect::Introspect for Owned {
-        fn introspect() -> crate::introspect::Type {
-            crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+        fn introspect() -> crate::introspect::Type<'static> {
+            crate::introspect::TypeVariant::Struct(
+                crate::introspect::RawBrandedStructSchema {
+                    generic: &_private::RAW_SCHEMA,
+                    field_types: _private::get_field_types,
+                    annotation_types: _private::get_annotation_types,
+                }
+                .into(),
+            )
             .into()
         }
     }
@@ -5995,6 +6719,7 @@ pub mod enumerant {
                 &self.reader.get_pointer_field(0),
                 ::core::option::Option::None,
             )
+            .map_err(|e| e.with_field(_private::TYPE_ID, "name"))
         }
         #[inline]
         pub fn has_name(&self) -> bool {
@@ -6013,6 +6738,7 @@ pub mod enumerant {
                 &self.reader.get_pointer_field(1),
                 ::core::option::Option::None,
             )
+            .map_err(|e| e.with_field(_private::TYPE_ID, "annotations"))
         }
         #[inline]
         pub fn has_annotations(&self) -> bool {
@@ -6065,6 +6791,16 @@ pub mod enumerant {
                 .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                 .into()
         }
+        fn try_init_pointer(
+            builder: crate::private::layout::PointerBuilder<'a>,
+            _size: u32,
+        ) -> crate::Result<Self> {
+            ::core::result::Result::Ok(
+                builder
+                    .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                    .into(),
+            )
+        }
         fn get_from_pointer(
             builder: crate::private::layout::PointerBuilder<'a>,
             default: ::core::option::Option<&'a [crate::Word]>,
@@ -6120,10 +6856,36 @@ pub mod enumerant {
             .unwrap()
         }
         #[inline]
+        pub fn try_set_name(
+            &mut self,
+            value: impl crate::traits::SetterInput<crate::text::Owned>,
+        ) -> crate::Result<()> {
+            crate::traits::SetterInput::set_pointer_builder(
+                self.builder.reborrow().get_pointer_field(0),
+                value,
+                false,
+            )
+        }
+        #[inline]
         pub fn init_name(self, size: u32) -> crate::text::Builder<'a> {
             self.builder.get_pointer_field(0).init_text(size)
         }
         #[inline]
+        pub fn try_init_name(self, size: u32) -> crate::Result<crate::text::Builder<'a>> {
+            self.builder.get_pointer_field(0).try_init_text(size)
+        }
+        #[inline]
+        pub fn disown_name(&mut self) -> crate::orphan::Orphan<crate::text::Owned> {
+            crate::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
+        pub fn adopt_name(
+            &mut self,
+            orphan: crate::orphan::Orphan<crate::text::Owned>,
+        ) -> crate::Result<()> {
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
         pub fn has_name(&self) -> bool {
             !self.builder.is_pointer_field_null(0)
         }
@@ -6164,6 +6926,33 @@ pub mod enumerant {
             crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(1), size)
         }
         #[inline]
+        pub fn try_init_annotations(
+            self,
+            size: u32,
+        ) -> crate::Result<crate::struct_list::Builder<'a, crate::schema_capnp::annotation::Owned>>
+        {
+            crate::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(1),
+                size,
+            )
+        }
+        #[inline]
+        pub fn disown_annotations(
+            &mut self,
+        ) -> crate::orphan::Orphan<crate::struct_list::Owned<crate::schema_capnp::annotation::Owned>>
+        {
+            crate::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(1))
+        }
+        #[inline]
+        pub fn adopt_annotations(
+            &mut self,
+            orphan: crate::orphan::Orphan<
+                crate::struct_list::Owned<crate::schema_capnp::annotation::Owned>,
+            >,
+        ) -> crate::Result<()> {
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(1))
+        }
+        #[inline]
         pub fn has_annotations(&self) -> bool {
             !self.builder.is_pointer_field_null(1)
         }
@@ -6251,7 +7040,7 @@ pub mod enumerant {
             crate::word(0, 0, 0, 0, 0, 0, 0, 0),
             crate::word(0, 0, 0, 0, 0, 0, 0, 0),
         ];
-        pub fn get_field_types(index: u16) -> crate::introspect::Type {
+        pub fn get_field_types(index: u16) -> crate::introspect::Type<'static> {
             match index {
         0 => <crate::text::Owned as crate::introspect::Introspect>::introspect(),
         1 => <u16 as crate::introspect::Introspect>::introspect(),
@@ -6262,7 +7051,7 @@ pub mod enumerant {
         pub fn get_annotation_types(
             child_index: Option<u16>,
             index: u32,
-        ) -> crate::introspect::Type {
+        ) -> crate::introspect::Type<'static> {
             panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
         }
         pub static RAW_SCHEMA: crate::introspect::RawStructSchema =
@@ -6283,12 +7072,15 @@ pub mod superclass {
     #[derive(Copy, Clone)]
     pub struct Owned(());
     impl crate::introspect::Introspect for Owned {
-        fn introspect() -> crate::introspect::Type {
-            crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+        fn introspect() -> crate::introspect::Type<'static> {
+            crate::introspect::TypeVariant::Struct(
+                crate::introspect::RawBrandedStructSchema {
+                    generic: &_private::RAW_SCHEMA,
+                    field_types: _private::get_field_types,
+                    annotation_types: _private::get_annotation_types,
+                }
+                .into(),
+            )
             .into()
         }
     }
@@ -6388,6 +7180,7 @@ pub mod superclass {
                 &self.reader.get_pointer_field(0),
                 ::core::option::Option::None,
             )
+            .map_err(|e| e.with_field(_private::TYPE_ID, "brand"))
         }
         #[inline]
         pub fn has_brand(&self) -> bool {
@@ -6440,6 +7233,16 @@ pub mod superclass {
                 .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                 .into()
         }
+        fn try_init_pointer(
+            builder: crate::private::layout::PointerBuilder<'a>,
+            _size: u32,
+        ) -> crate::Result<Self> {
+            ::core::result::Result::Ok(
+                builder
+                    .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                    .into(),
+            )
+        }
         fn get_from_pointer(
             builder: crate::private::layout::PointerBuilder<'a>,
             default: ::core::option::Option<&'a [crate::Word]>,
@@ -6509,6 +7312,24 @@ pub mod superclass {
             crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_brand(self) -> crate::Result<crate::schema_capnp::brand::Builder<'a>> {
+            crate::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[inline]
+        pub fn disown_brand(&mut self) -> crate::orphan::Orphan<crate::schema_capnp::brand::Owned> {
+            crate::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
+        pub fn adopt_brand(
+            &mut self,
+            orphan: crate::orphan::Orphan<crate::schema_capnp::brand::Owned>,
+        ) -> crate::Result<()> {
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
         pub fn has_brand(&self) -> bool {
             !self.builder.is_pointer_field_null(0)
         }
@@ -6579,7 +7400,7 @@ pub mod superclass {
             crate::word(0, 0, 0, 0, 0, 0, 0, 0),
             crate::word(0, 0, 0, 0, 0, 0, 0, 0),
         ];
-        pub fn get_field_types(index: u16) -> crate::introspect::Type {
+        pub fn get_field_types(index: u16) -> crate::introspect::Type<'static> {
             match index {
                 0 => <u64 as crate::introspect::Introspect>::introspect(),
                 1 => {
@@ -6592,7 +7413,7 @@ pub mod superclass {
         pub fn get_annotation_types(
             child_index: Option<u16>,
             index: u32,
-        ) -> crate::introspect::Type {
+        ) -> crate::introspect::Type<'static> {
             panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
         }
         pub static RAW_SCHEMA: crate::introspect::RawStructSchema =
@@ -6613,12 +7434,15 @@ pub mod method {
     #[derive(Copy, Clone)]
     pub struct Owned(());
     impl crate::introspect::Introspect for Owned {
-        fn introspect() -> crate::introspect::Type {
-            crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+        fn introspect() -> crate::introspect::Type<'static> {
+            crate::introspect::TypeVariant::Struct(
+                crate::introspect::RawBrandedStructSchema {
+                    generic: &_private::RAW_SCHEMA,
+                    field_types: _private::get_field_types,
+                    annotation_types: _private::get_annotation_types,
+                }
+                .into(),
+            )
             .into()
         }
     }
@@ -6714,6 +7538,7 @@ pub mod method {
                 &self.reader.get_pointer_field(0),
                 ::core::option::Option::None,
             )
+            .map_err(|e| e.with_field(_private::TYPE_ID, "name"))
         }
         #[inline]
         pub fn has_name(&self) -> bool {
@@ -6740,6 +7565,7 @@ pub mod method {
                 &self.reader.get_pointer_field(1),
                 ::core::option::Option::None,
             )
+            .map_err(|e| e.with_field(_private::TYPE_ID, "annotations"))
         }
         #[inline]
         pub fn has_annotations(&self) -> bool {
@@ -6751,6 +7577,7 @@ pub mod method {
                 &self.reader.get_pointer_field(2),
                 ::core::option::Option::None,
             )
+            .map_err(|e| e.with_field(_private::TYPE_ID, "paramBrand"))
         }
         #[inline]
         pub fn has_param_brand(&self) -> bool {
@@ -6762,6 +7589,7 @@ pub mod method {
                 &self.reader.get_pointer_field(3),
                 ::core::option::Option::None,
             )
+            .map_err(|e| e.with_field(_private::TYPE_ID, "resultBrand"))
         }
         #[inline]
         pub fn has_result_brand(&self) -> bool {
@@ -6777,6 +7605,7 @@ pub mod method {
                 &self.reader.get_pointer_field(4),
                 ::core::option::Option::None,
             )
+            .map_err(|e| e.with_field(_private::TYPE_ID, "implicitParameters"))
         }
         #[inline]
         pub fn has_implicit_parameters(&self) -> bool {
@@ -6829,6 +7658,16 @@ pub mod method {
                 .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                 .into()
         }
+        fn try_init_pointer(
+            builder: crate::private::layout::PointerBuilder<'a>,
+            _size: u32,
+        ) -> crate::Result<Self> {
+            ::core::result::Result::Ok(
+                builder
+                    .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                    .into(),
+            )
+        }
         fn get_from_pointer(
             builder: crate::private::layout::PointerBuilder<'a>,
             default: ::core::option::Option<&'a [crate::Word]>,
@@ -6884,10 +7723,36 @@ pub mod method {
             .unwrap()
         }
         #[inline]
+        pub fn try_set_name(
+            &mut self,
+            value: impl crate::traits::SetterInput<crate::text::Owned>,
+        ) -> crate::Result<()> {
+            crate::traits::SetterInput::set_pointer_builder(
+                self.builder.reborrow().get_pointer_field(0),
+                value,
+                false,
+            )
+        }
+        #[inline]
         pub fn init_name(self, size: u32) -> crate::text::Builder<'a> {
             self.builder.get_pointer_field(0).init_text(size)
         }
         #[inline]
+        pub fn try_init_name(self, size: u32) -> crate::Result<crate::text::Builder<'a>> {
+            self.builder.get_pointer_field(0).try_init_text(size)
+        }
+        #[inline]
+        pub fn disown_name(&mut self) -> crate::orphan::Orphan<crate::text::Owned> {
+            crate::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
+        pub fn adopt_name(
+            &mut self,
+            orphan: crate::orphan::Orphan<crate::text::Owned>,
+        ) -> crate::Result<()> {
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
         pub fn has_name(&self) -> bool {
             !self.builder.is_pointer_field_null(0)
         }
@@ -6944,6 +7809,33 @@ pub mod method {
             crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(1), size)
         }
         #[inline]
+        pub fn try_init_annotations(
+            self,
+            size: u32,
+        ) -> crate::Result<crate::struct_list::Builder<'a, crate::schema_capnp::annotation::Owned>>
+        {
+            crate::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(1),
+                size,
+            )
+        }
+        #[inline]
+        pub fn disown_annotations(
+            &mut self,
+        ) -> crate::orphan::Orphan<crate::struct_list::Owned<crate::schema_capnp::annotation::Owned>>
+        {
+            crate::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(1))
+        }
+        #[inline]
+        pub fn adopt_annotations(
+            &mut self,
+            orphan: crate::orphan::Orphan<
+                crate::struct_list::Owned<crate::schema_capnp::annotation::Owned>,
+            >,
+        ) -> crate::Result<()> {
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(1))
+        }
+        #[inline]
         pub fn has_annotations(&self) -> bool {
             !self.builder.is_pointer_field_null(1)
         }
@@ -6970,6 +7862,28 @@ pub mod method {
             crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(2), 0)
         }
         #[inline]
+        pub fn try_init_param_brand(
+            self,
+        ) -> crate::Result<crate::schema_capnp::brand::Builder<'a>> {
+            crate::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(2),
+                0,
+            )
+        }
+        #[inline]
+        pub fn disown_param_brand(
+            &mut self,
+        ) -> crate::orphan::Orphan<crate::schema_capnp::brand::Owned> {
+            crate::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(2))
+        }
+        #[inline]
+        pub fn adopt_param_brand(
+            &mut self,
+            orphan: crate::orphan::Orphan<crate::schema_capnp::brand::Owned>,
+        ) -> crate::Result<()> {
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(2))
+        }
+        #[inline]
         pub fn has_param_brand(&self) -> bool {
             !self.builder.is_pointer_field_null(2)
         }
@@ -6996,6 +7910,28 @@ pub mod method {
             crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(3), 0)
         }
         #[inline]
+        pub fn try_init_result_brand(
+            self,
+        ) -> crate::Result<crate::schema_capnp::brand::Builder<'a>> {
+            crate::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(3),
+                0,
+            )
+        }
+        #[inline]
+        pub fn disown_result_brand(
+            &mut self,
+        ) -> crate::orphan::Orphan<crate::schema_capnp::brand::Owned> {
+            crate::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(3))
+        }
+        #[inline]
+        pub fn adopt_result_brand(
+            &mut self,
+            orphan: crate::orphan::Orphan<crate::schema_capnp::brand::Owned>,
+        ) -> crate::Result<()> {
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(3))
+        }
+        #[inline]
         pub fn has_result_brand(&self) -> bool {
             !self.builder.is_pointer_field_null(3)
         }
@@ -7029,6 +7965,35 @@ pub mod method {
             crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(4), size)
         }
         #[inline]
+        pub fn try_init_implicit_parameters(
+            self,
+            size: u32,
+        ) -> crate::Result<
+            crate::struct_list::Builder<'a, crate::schema_capnp::node::parameter::Owned>,
+        > {
+            crate::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(4),
+                size,
+            )
+        }
+        #[inline]
+        pub fn disown_implicit_parameters(
+            &mut self,
+        ) -> crate::orphan::Orphan<
+            crate::struct_list::Owned<crate::schema_capnp::node::parameter::Owned>,
+        > {
+            crate::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(4))
+        }
+        #[inline]
+        pub fn adopt_implicit_parameters(
+            &mut self,
+            orphan: crate::orphan::Orphan<
+                crate::struct_list::Owned<crate::schema_capnp::node::parameter::Owned>,
+            >,
+        ) -> crate::Result<()> {
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(4))
+        }
+        #[inline]
         pub fn has_implicit_parameters(&self) -> bool {
             !self.builder.is_pointer_field_null(4)
         }
@@ -7209,7 +8174,7 @@ pub mod method {
             crate::word(0, 0, 0, 0, 0, 0, 0, 0),
             crate::word(0, 0, 0, 0, 0, 0, 0, 0),
         ];
-        pub fn get_field_types(index: u16) -> crate::introspect::Type {
+        pub fn get_field_types(index: u16) -> crate::introspect::Type<'static> {
             match index {
         0 => <crate::text::Owned as crate::introspect::Introspect>::introspect(),
         1 => <u16 as crate::introspect::Introspect>::introspect(),
@@ -7225,7 +8190,7 @@ pub mod method {
         pub fn get_annotation_types(
             child_index: Option<u16>,
             index: u32,
-        ) -> crate::introspect::Type {
+        ) -> crate::introspect::Type<'static> {
             panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
         }
         pub static RAW_SCHEMA: crate::introspect::RawStructSchema =
@@ -7251,12 +8216,15 @@ pub mod type_ {
     #[derive(Copy, Clone)]
     pub struct Owned(());
     impl crate::introspect::Introspect for Owned {
-        fn introspect() -> crate::introspect::Type {
-            crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+        fn introspect() -> crate::introspect::Type<'static> {
+            crate::introspect::TypeVariant::Struct(
+                crate::introspect::RawBrandedStructSchema {
+                    generic: &_private::RAW_SCHEMA,
+                    field_types: _private::get_field_types,
+                    annotation_types: _private::get_annotation_types,
+                }
+                .into(),
+            )
             .into()
         }
     }
@@ -7418,6 +8386,16 @@ pub mod type_ {
                 .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                 .into()
         }
+        fn try_init_pointer(
+            builder: crate::private::layout::PointerBuilder<'a>,
+            _size: u32,
+        ) -> crate::Result<Self> {
+            ::core::result::Result::Ok(
+                builder
+                    .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                    .into(),
+            )
+        }
         fn get_from_pointer(
             builder: crate::private::layout::PointerBuilder<'a>,
             default: ::core::option::Option<&'a [crate::Word]>,
@@ -7859,7 +8837,7 @@ pub mod type_ {
             crate::word(97, 110, 121, 80, 111, 105, 110, 116),
             crate::word(101, 114, 0, 0, 0, 0, 0, 0),
         ];
-        pub fn get_field_types(index: u16) -> crate::introspect::Type {
+        pub fn get_field_types(index: u16) -> crate::introspect::Type<'static> {
             match index {
         0 => <() as crate::introspect::Introspect>::introspect(),
         1 => <() as crate::introspect::Introspect>::introspect(),
@@ -7886,7 +8864,7 @@ pub mod type_ {
         pub fn get_annotation_types(
             child_index: Option<u16>,
             index: u32,
-        ) -> crate::introspect::Type {
+        ) -> crate::introspect::Type<'static> {
             panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
         }
         pub static RAW_SCHEMA: crate::introspect::RawStructSchema =
@@ -7945,12 +8923,15 @@ pub mod type_ {
         #[derive(Copy, Clone)]
         pub struct Owned(());
         impl crate::introspect::Introspect for Owned {
-            fn introspect() -> crate::introspect::Type {
-                crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                })
+            fn introspect() -> crate::introspect::Type<'static> {
+                crate::introspect::TypeVariant::Struct(
+                    crate::introspect::RawBrandedStructSchema {
+                        generic: &_private::RAW_SCHEMA,
+                        field_types: _private::get_field_types,
+                        annotation_types: _private::get_annotation_types,
+                    }
+                    .into(),
+                )
                 .into()
             }
         }
@@ -8046,6 +9027,7 @@ pub mod type_ {
                     &self.reader.get_pointer_field(0),
                     ::core::option::Option::None,
                 )
+                .map_err(|e| e.with_field(_private::TYPE_ID, "elementType"))
             }
             #[inline]
             pub fn has_element_type(&self) -> bool {
@@ -8101,6 +9083,16 @@ pub mod type_ {
                     .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                     .into()
             }
+            fn try_init_pointer(
+                builder: crate::private::layout::PointerBuilder<'a>,
+                _size: u32,
+            ) -> crate::Result<Self> {
+                ::core::result::Result::Ok(
+                    builder
+                        .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                        .into(),
+                )
+            }
             fn get_from_pointer(
                 builder: crate::private::layout::PointerBuilder<'a>,
                 default: ::core::option::Option<&'a [crate::Word]>,
@@ -8167,6 +9159,28 @@ pub mod type_ {
                 )
             }
             #[inline]
+            pub fn try_init_element_type(
+                self,
+            ) -> crate::Result<crate::schema_capnp::type_::Builder<'a>> {
+                crate::traits::FromPointerBuilder::try_init_pointer(
+                    self.builder.get_pointer_field(0),
+                    0,
+                )
+            }
+            #[inline]
+            pub fn disown_element_type(
+                &mut self,
+            ) -> crate::orphan::Orphan<crate::schema_capnp::type_::Owned> {
+                crate::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(0))
+            }
+            #[inline]
+            pub fn adopt_element_type(
+                &mut self,
+                orphan: crate::orphan::Orphan<crate::schema_capnp::type_::Owned>,
+            ) -> crate::Result<()> {
+                orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+            }
+            #[inline]
             pub fn has_element_type(&self) -> bool {
                 !self.builder.is_pointer_field_null(0)
             }
@@ -8222,7 +9236,7 @@ pub mod type_ {
                 crate::word(0, 0, 0, 0, 0, 0, 0, 0),
                 crate::word(0, 0, 0, 0, 0, 0, 0, 0),
             ];
-            pub fn get_field_types(index: u16) -> crate::introspect::Type {
+            pub fn get_field_types(index: u16) -> crate::introspect::Type<'static> {
                 match index {
           0 => <crate::schema_capnp::type_::Owned as crate::introspect::Introspect>::introspect(),
           _ => panic!("invalid field index {}", index),
@@ -8231,7 +9245,7 @@ pub mod type_ {
             pub fn get_annotation_types(
                 child_index: Option<u16>,
                 index: u32,
-            ) -> crate::introspect::Type {
+            ) -> crate::introspect::Type<'static> {
                 panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
             }
             pub static RAW_SCHEMA: crate::introspect::RawStructSchema =
@@ -8252,12 +9266,15 @@ pub mod type_ {
         #[derive(Copy, Clone)]
         pub struct Owned(());
         impl crate::introspect::Introspect for Owned {
-            fn introspect() -> crate::introspect::Type {
-                crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                })
+            fn introspect() -> crate::introspect::Type<'static> {
+                crate::introspect::TypeVariant::Struct(
+                    crate::introspect::RawBrandedStructSchema {
+                        generic: &_private::RAW_SCHEMA,
+                        field_types: _private::get_field_types,
+                        annotation_types: _private::get_annotation_types,
+                    }
+                    .into(),
+                )
                 .into()
             }
         }
@@ -8357,6 +9374,7 @@ pub mod type_ {
                     &self.reader.get_pointer_field(0),
                     ::core::option::Option::None,
                 )
+                .map_err(|e| e.with_field(_private::TYPE_ID, "brand"))
             }
             #[inline]
             pub fn has_brand(&self) -> bool {
@@ -8412,6 +9430,16 @@ pub mod type_ {
                     .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                     .into()
             }
+            fn try_init_pointer(
+                builder: crate::private::layout::PointerBuilder<'a>,
+                _size: u32,
+            ) -> crate::Result<Self> {
+                ::core::result::Result::Ok(
+                    builder
+                        .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                        .into(),
+                )
+            }
             fn get_from_pointer(
                 builder: crate::private::layout::PointerBuilder<'a>,
                 default: ::core::option::Option<&'a [crate::Word]>,
@@ -8484,6 +9512,26 @@ pub mod type_ {
                 )
             }
             #[inline]
+            pub fn try_init_brand(self) -> crate::Result<crate::schema_capnp::brand::Builder<'a>> {
+                crate::traits::FromPointerBuilder::try_init_pointer(
+                    self.builder.get_pointer_field(0),
+                    0,
+                )
+            }
+            #[inline]
+            pub fn disown_brand(
+                &mut self,
+            ) -> crate::orphan::Orphan<crate::schema_capnp::brand::Owned> {
+                crate::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(0))
+            }
+            #[inline]
+            pub fn adopt_brand(
+                &mut self,
+                orphan: crate::orphan::Orphan<crate::schema_capnp::brand::Owned>,
+            ) -> crate::Result<()> {
+                orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+            }
+            #[inline]
             pub fn has_brand(&self) -> bool {
                 !self.builder.is_pointer_field_null(0)
             }
@@ -8553,7 +9601,7 @@ pub mod type_ {
                 crate::word(0, 0, 0, 0, 0, 0, 0, 0),
                 crate::word(0, 0, 0, 0, 0, 0, 0, 0),
             ];
-            pub fn get_field_types(index: u16) -> crate::introspect::Type {
+            pub fn get_field_types(index: u16) -> crate::introspect::Type<'static> {
                 match index {
           0 => <u64 as crate::introspect::Introspect>::introspect(),
           1 => <crate::schema_capnp::brand::Owned as crate::introspect::Introspect>::introspect(),
@@ -8563,7 +9611,7 @@ pub mod type_ {
             pub fn get_annotation_types(
                 child_index: Option<u16>,
                 index: u32,
-            ) -> crate::introspect::Type {
+            ) -> crate::introspect::Type<'static> {
                 panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
             }
             pub static RAW_SCHEMA: crate::introspect::RawStructSchema =
@@ -8584,12 +9632,15 @@ pub mod type_ {
         #[derive(Copy, Clone)]
         pub struct Owned(());
         impl crate::introspect::Introspect for Owned {
-            fn introspect() -> crate::introspect::Type {
-                crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                })
+            fn introspect() -> crate::introspect::Type<'static> {
+                crate::introspect::TypeVariant::Struct(
+                    crate::introspect::RawBrandedStructSchema {
+                        generic: &_private::RAW_SCHEMA,
+                        field_types: _private::get_field_types,
+                        annotation_types: _private::get_annotation_types,
+                    }
+                    .into(),
+                )
                 .into()
             }
         }
@@ -8689,6 +9740,7 @@ pub mod type_ {
                     &self.reader.get_pointer_field(0),
                     ::core::option::Option::None,
                 )
+                .map_err(|e| e.with_field(_private::TYPE_ID, "brand"))
             }
             #[inline]
             pub fn has_brand(&self) -> bool {
@@ -8744,6 +9796,16 @@ pub mod type_ {
                     .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                     .into()
             }
+            fn try_init_pointer(
+                builder: crate::private::layout::PointerBuilder<'a>,
+                _size: u32,
+            ) -> crate::Result<Self> {
+                ::core::result::Result::Ok(
+                    builder
+                        .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                        .into(),
+                )
+            }
             fn get_from_pointer(
                 builder: crate::private::layout::PointerBuilder<'a>,
                 default: ::core::option::Option<&'a [crate::Word]>,
@@ -8816,6 +9878,26 @@ pub mod type_ {
                 )
             }
             #[inline]
+            pub fn try_init_brand(self) -> crate::Result<crate::schema_capnp::brand::Builder<'a>> {
+                crate::traits::FromPointerBuilder::try_init_pointer(
+                    self.builder.get_pointer_field(0),
+                    0,
+                )
+            }
+            #[inline]
+            pub fn disown_brand(
+                &mut self,
+            ) -> crate::orphan::Orphan<crate::schema_capnp::brand::Owned> {
+                crate::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(0))
+            }
+            #[inline]
+            pub fn adopt_brand(
+                &mut self,
+                orphan: crate::orphan::Orphan<crate::schema_capnp::brand::Owned>,
+            ) -> crate::Result<()> {
+                orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+            }
+            #[inline]
             pub fn has_brand(&self) -> bool {
                 !self.builder.is_pointer_field_null(0)
             }
@@ -8886,7 +9968,7 @@ pub mod type_ {
                 crate::word(0, 0, 0, 0, 0, 0, 0, 0),
                 crate::word(0, 0, 0, 0, 0, 0, 0, 0),
             ];
-            pub fn get_field_types(index: u16) -> crate::introspect::Type {
+            pub fn get_field_types(index: u16) -> crate::introspect::Type<'static> {
                 match index {
           0 => <u64 as crate::introspect::Introspect>::introspect(),
           1 => <crate::schema_capnp::brand::Owned as crate::introspect::Introspect>::introspect(),
@@ -8896,7 +9978,7 @@ pub mod type_ {
             pub fn get_annotation_types(
                 child_index: Option<u16>,
                 index: u32,
-            ) -> crate::introspect::Type {
+            ) -> crate::introspect::Type<'static> {
                 panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
             }
             pub static RAW_SCHEMA: crate::introspect::RawStructSchema =
@@ -8917,12 +9999,15 @@ pub mod type_ {
         #[derive(Copy, Clone)]
         pub struct Owned(());
         impl crate::introspect::Introspect for Owned {
-            fn introspect() -> crate::introspect::Type {
-                crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                })
+            fn introspect() -> crate::introspect::Type<'static> {
+                crate::introspect::TypeVariant::Struct(
+                    crate::introspect::RawBrandedStructSchema {
+                        generic: &_private::RAW_SCHEMA,
+                        field_types: _private::get_field_types,
+                        annotation_types: _private::get_annotation_types,
+                    }
+                    .into(),
+                )
                 .into()
             }
         }
@@ -9022,6 +10107,7 @@ pub mod type_ {
                     &self.reader.get_pointer_field(0),
                     ::core::option::Option::None,
                 )
+                .map_err(|e| e.with_field(_private::TYPE_ID, "brand"))
             }
             #[inline]
             pub fn has_brand(&self) -> bool {
@@ -9077,6 +10163,16 @@ pub mod type_ {
                     .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                     .into()
             }
+            fn try_init_pointer(
+                builder: crate::private::layout::PointerBuilder<'a>,
+                _size: u32,
+            ) -> crate::Result<Self> {
+                ::core::result::Result::Ok(
+                    builder
+                        .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                        .into(),
+                )
+            }
             fn get_from_pointer(
                 builder: crate::private::layout::PointerBuilder<'a>,
                 default: ::core::option::Option<&'a [crate::Word]>,
@@ -9149,6 +10245,26 @@ pub mod type_ {
                 )
             }
             #[inline]
+            pub fn try_init_brand(self) -> crate::Result<crate::schema_capnp::brand::Builder<'a>> {
+                crate::traits::FromPointerBuilder::try_init_pointer(
+                    self.builder.get_pointer_field(0),
+                    0,
+                )
+            }
+            #[inline]
+            pub fn disown_brand(
+                &mut self,
+            ) -> crate::orphan::Orphan<crate::schema_capnp::brand::Owned> {
+                crate::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(0))
+            }
+            #[inline]
+            pub fn adopt_brand(
+                &mut self,
+                orphan: crate::orphan::Orphan<crate::schema_capnp::brand::Owned>,
+            ) -> crate::Result<()> {
+                orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+            }
+            #[inline]
             pub fn has_brand(&self) -> bool {
                 !self.builder.is_pointer_field_null(0)
             }
@@ -9219,7 +10335,7 @@ pub mod type_ {
                 crate::word(0, 0, 0, 0, 0, 0, 0, 0),
                 crate::word(0, 0, 0, 0, 0, 0, 0, 0),
             ];
-            pub fn get_field_types(index: u16) -> crate::introspect::Type {
+            pub fn get_field_types(index: u16) -> crate::introspect::Type<'static> {
                 match index {
           0 => <u64 as crate::introspect::Introspect>::introspect(),
           1 => <crate::schema_capnp::brand::Owned as crate::introspect::Introspect>::introspect(),
@@ -9229,7 +10345,7 @@ pub mod type_ {
             pub fn get_annotation_types(
                 child_index: Option<u16>,
                 index: u32,
-            ) -> crate::introspect::Type {
+            ) -> crate::introspect::Type<'static> {
                 panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
             }
             pub static RAW_SCHEMA: crate::introspect::RawStructSchema =
@@ -9252,12 +10368,15 @@ pub mod type_ {
         #[derive(Copy, Clone)]
         pub struct Owned(());
         impl crate::introspect::Introspect for Owned {
-            fn introspect() -> crate::introspect::Type {
-                crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                })
+            fn introspect() -> crate::introspect::Type<'static> {
+                crate::introspect::TypeVariant::Struct(
+                    crate::introspect::RawBrandedStructSchema {
+                        generic: &_private::RAW_SCHEMA,
+                        field_types: _private::get_field_types,
+                        annotation_types: _private::get_annotation_types,
+                    }
+                    .into(),
+                )
                 .into()
             }
         }
@@ -9406,6 +10525,16 @@ pub mod type_ {
                     .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                     .into()
             }
+            fn try_init_pointer(
+                builder: crate::private::layout::PointerBuilder<'a>,
+                _size: u32,
+            ) -> crate::Result<Self> {
+                ::core::result::Result::Ok(
+                    builder
+                        .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                        .into(),
+                )
+            }
             fn get_from_pointer(
                 builder: crate::private::layout::PointerBuilder<'a>,
                 default: ::core::option::Option<&'a [crate::Word]>,
@@ -9540,7 +10669,7 @@ pub mod type_ {
                 crate::word(77, 101, 116, 104, 111, 100, 80, 97),
                 crate::word(114, 97, 109, 101, 116, 101, 114, 0),
             ];
-            pub fn get_field_types(index: u16) -> crate::introspect::Type {
+            pub fn get_field_types(index: u16) -> crate::introspect::Type<'static> {
                 match index {
           0 => <crate::schema_capnp::type_::any_pointer::unconstrained::Owned as crate::introspect::Introspect>::introspect(),
           1 => <crate::schema_capnp::type_::any_pointer::parameter::Owned as crate::introspect::Introspect>::introspect(),
@@ -9551,7 +10680,7 @@ pub mod type_ {
             pub fn get_annotation_types(
                 child_index: Option<u16>,
                 index: u32,
-            ) -> crate::introspect::Type {
+            ) -> crate::introspect::Type<'static> {
                 panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
             }
             pub static RAW_SCHEMA: crate::introspect::RawStructSchema =
@@ -9588,13 +10717,14 @@ pub mod type_ {
             #[derive(Copy, Clone)]
             pub struct Owned(());
             impl crate::introspect::Introspect for Owned {
-                fn introspect() -> crate::introspect::Type {
+                fn introspect() -> crate::introspect::Type<'static> {
                     crate::introspect::TypeVariant::Struct(
                         crate::introspect::RawBrandedStructSchema {
                             generic: &_private::RAW_SCHEMA,
                             field_types: _private::get_field_types,
                             annotation_types: _private::get_annotation_types,
-                        },
+                        }
+                        .into(),
                     )
                     .into()
                 }
@@ -9749,6 +10879,16 @@ pub mod type_ {
                         .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                         .into()
                 }
+                fn try_init_pointer(
+                    builder: crate::private::layout::PointerBuilder<'a>,
+                    _size: u32,
+                ) -> crate::Result<Self> {
+                    ::core::result::Result::Ok(
+                        builder
+                            .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                            .into(),
+                    )
+                }
                 fn get_from_pointer(
                     builder: crate::private::layout::PointerBuilder<'a>,
                     default: ::core::option::Option<&'a [crate::Word]>,
@@ -9912,7 +11052,7 @@ pub mod type_ {
                     crate::word(0, 0, 0, 0, 0, 0, 0, 0),
                     crate::word(0, 0, 0, 0, 0, 0, 0, 0),
                 ];
-                pub fn get_field_types(index: u16) -> crate::introspect::Type {
+                pub fn get_field_types(index: u16) -> crate::introspect::Type<'static> {
                     match index {
                         0 => <() as crate::introspect::Introspect>::introspect(),
                         1 => <() as crate::introspect::Introspect>::introspect(),
@@ -9924,7 +11064,7 @@ pub mod type_ {
                 pub fn get_annotation_types(
                     child_index: Option<u16>,
                     index: u32,
-                ) -> crate::introspect::Type {
+                ) -> crate::introspect::Type<'static> {
                     panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
                 }
                 pub static RAW_SCHEMA: crate::introspect::RawStructSchema =
@@ -9953,13 +11093,14 @@ pub mod type_ {
             #[derive(Copy, Clone)]
             pub struct Owned(());
             impl crate::introspect::Introspect for Owned {
-                fn introspect() -> crate::introspect::Type {
+                fn introspect() -> crate::introspect::Type<'static> {
                     crate::introspect::TypeVariant::Struct(
                         crate::introspect::RawBrandedStructSchema {
                             generic: &_private::RAW_SCHEMA,
                             field_types: _private::get_field_types,
                             annotation_types: _private::get_annotation_types,
-                        },
+                        }
+                        .into(),
                     )
                     .into()
                 }
@@ -10112,6 +11253,16 @@ pub mod type_ {
                         .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                         .into()
                 }
+                fn try_init_pointer(
+                    builder: crate::private::layout::PointerBuilder<'a>,
+                    _size: u32,
+                ) -> crate::Result<Self> {
+                    ::core::result::Result::Ok(
+                        builder
+                            .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                            .into(),
+                    )
+                }
                 fn get_from_pointer(
                     builder: crate::private::layout::PointerBuilder<'a>,
                     default: ::core::option::Option<&'a [crate::Word]>,
@@ -10234,7 +11385,7 @@ pub mod type_ {
                     crate::word(0, 0, 0, 0, 0, 0, 0, 0),
                     crate::word(0, 0, 0, 0, 0, 0, 0, 0),
                 ];
-                pub fn get_field_types(index: u16) -> crate::introspect::Type {
+                pub fn get_field_types(index: u16) -> crate::introspect::Type<'static> {
                     match index {
                         0 => <u64 as crate::introspect::Introspect>::introspect(),
                         1 => <u16 as crate::introspect::Introspect>::introspect(),
@@ -10244,7 +11395,7 @@ pub mod type_ {
                 pub fn get_annotation_types(
                     child_index: Option<u16>,
                     index: u32,
-                ) -> crate::introspect::Type {
+                ) -> crate::introspect::Type<'static> {
                     panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
                 }
                 pub static RAW_SCHEMA: crate::introspect::RawStructSchema =
@@ -10265,13 +11416,14 @@ pub mod type_ {
             #[derive(Copy, Clone)]
             pub struct Owned(());
             impl crate::introspect::Introspect for Owned {
-                fn introspect() -> crate::introspect::Type {
+                fn introspect() -> crate::introspect::Type<'static> {
                     crate::introspect::TypeVariant::Struct(
                         crate::introspect::RawBrandedStructSchema {
                             generic: &_private::RAW_SCHEMA,
                             field_types: _private::get_field_types,
                             annotation_types: _private::get_annotation_types,
-                        },
+                        }
+                        .into(),
                     )
                     .into()
                 }
@@ -10420,6 +11572,16 @@ pub mod type_ {
                         .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                         .into()
                 }
+                fn try_init_pointer(
+                    builder: crate::private::layout::PointerBuilder<'a>,
+                    _size: u32,
+                ) -> crate::Result<Self> {
+                    ::core::result::Result::Ok(
+                        builder
+                            .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                            .into(),
+                    )
+                }
                 fn get_from_pointer(
                     builder: crate::private::layout::PointerBuilder<'a>,
                     default: ::core::option::Option<&'a [crate::Word]>,
@@ -10521,7 +11683,7 @@ pub mod type_ {
                     crate::word(0, 0, 0, 0, 0, 0, 0, 0),
                     crate::word(0, 0, 0, 0, 0, 0, 0, 0),
                 ];
-                pub fn get_field_types(index: u16) -> crate::introspect::Type {
+                pub fn get_field_types(index: u16) -> crate::introspect::Type<'static> {
                     match index {
                         0 => <u16 as crate::introspect::Introspect>::introspect(),
                         _ => panic!("invalid field index {}", index),
@@ -10530,7 +11692,7 @@ pub mod type_ {
                 pub fn get_annotation_types(
                     child_index: Option<u16>,
                     index: u32,
-                ) -> crate::introspect::Type {
+                ) -> crate::introspect::Type<'static> {
                     panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
                 }
                 pub static RAW_SCHEMA: crate::introspect::RawStructSchema =
@@ -10553,12 +11715,15 @@ pub mod brand {
     #[derive(Copy, Clone)]
     pub struct Owned(());
     impl crate::introspect::Introspect for Owned {
-        fn introspect() -> crate::introspect::Type {
-            crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+        fn introspect() -> crate::introspect::Type<'static> {
+            crate::introspect::TypeVariant::Struct(
+                crate::introspect::RawBrandedStructSchema {
+                    generic: &_private::RAW_SCHEMA,
+                    field_types: _private::get_field_types,
+                    annotation_types: _private::get_annotation_types,
+                }
+                .into(),
+            )
             .into()
         }
     }
@@ -10657,6 +11822,7 @@ pub mod brand {
                 &self.reader.get_pointer_field(0),
                 ::core::option::Option::None,
             )
+            .map_err(|e| e.with_field(_private::TYPE_ID, "scopes"))
         }
         #[inline]
         pub fn has_scopes(&self) -> bool {
@@ -10709,6 +11875,16 @@ pub mod brand {
                 .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                 .into()
         }
+        fn try_init_pointer(
+            builder: crate::private::layout::PointerBuilder<'a>,
+            _size: u32,
+        ) -> crate::Result<Self> {
+            ::core::result::Result::Ok(
+                builder
+                    .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                    .into(),
+            )
+        }
         fn get_from_pointer(
             builder: crate::private::layout::PointerBuilder<'a>,
             default: ::core::option::Option<&'a [crate::Word]>,
@@ -10776,6 +11952,34 @@ pub mod brand {
             crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), size)
         }
         #[inline]
+        pub fn try_init_scopes(
+            self,
+            size: u32,
+        ) -> crate::Result<crate::struct_list::Builder<'a, crate::schema_capnp::brand::scope::Owned>>
+        {
+            crate::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                size,
+            )
+        }
+        #[inline]
+        pub fn disown_scopes(
+            &mut self,
+        ) -> crate::orphan::Orphan<
+            crate::struct_list::Owned<crate::schema_capnp::brand::scope::Owned>,
+        > {
+            crate::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
+        pub fn adopt_scopes(
+            &mut self,
+            orphan: crate::orphan::Orphan<
+                crate::struct_list::Owned<crate::schema_capnp::brand::scope::Owned>,
+            >,
+        ) -> crate::Result<()> {
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
         pub fn has_scopes(&self) -> bool {
             !self.builder.is_pointer_field_null(0)
         }
@@ -10837,7 +12041,7 @@ pub mod brand {
             crate::word(0, 0, 0, 0, 0, 0, 0, 0),
             crate::word(0, 0, 0, 0, 0, 0, 0, 0),
         ];
-        pub fn get_field_types(index: u16) -> crate::introspect::Type {
+        pub fn get_field_types(index: u16) -> crate::introspect::Type<'static> {
             match index {
         0 => <crate::struct_list::Owned<crate::schema_capnp::brand::scope::Owned> as crate::introspect::Introspect>::introspect(),
         _ => panic!("invalid field index {}", index),
@@ -10846,7 +12050,7 @@ pub mod brand {
         pub fn get_annotation_types(
             child_index: Option<u16>,
             index: u32,
-        ) -> crate::introspect::Type {
+        ) -> crate::introspect::Type<'static> {
             panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
         }
         pub static RAW_SCHEMA: crate::introspect::RawStructSchema =
@@ -10868,12 +12072,15 @@ pub mod brand {
         #[derive(Copy, Clone)]
         pub struct Owned(());
         impl crate::introspect::Introspect for Owned {
-            fn introspect() -> crate::introspect::Type {
-                crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                })
+            fn introspect() -> crate::introspect::Type<'static> {
+                crate::introspect::TypeVariant::Struct(
+                    crate::introspect::RawBrandedStructSchema {
+                        generic: &_private::RAW_SCHEMA,
+                        field_types: _private::get_field_types,
+                        annotation_types: _private::get_annotation_types,
+                    }
+                    .into(),
+                )
                 .into()
             }
         }
@@ -10981,7 +12188,8 @@ pub mod brand {
                         crate::traits::FromPointerReader::get_from_pointer(
                             &self.reader.get_pointer_field(0),
                             ::core::option::Option::None,
-                        ),
+                        )
+                        .map_err(|e| e.with_field(_private::TYPE_ID, "bind")),
                     )),
                     1 => ::core::result::Result::Ok(Inherit(())),
                     x => ::core::result::Result::Err(crate::NotInSchema(x)),
@@ -11037,6 +12245,16 @@ pub mod brand {
                     .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                     .into()
             }
+            fn try_init_pointer(
+                builder: crate::private::layout::PointerBuilder<'a>,
+                _size: u32,
+            ) -> crate::Result<Self> {
+                ::core::result::Result::Ok(
+                    builder
+                        .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                        .into(),
+                )
+            }
             fn get_from_pointer(
                 builder: crate::private::layout::PointerBuilder<'a>,
                 default: ::core::option::Option<&'a [crate::Word]>,
@@ -11108,6 +12326,49 @@ pub mod brand {
                 )
             }
             #[inline]
+            pub fn try_init_bind(
+                self,
+                size: u32,
+            ) -> crate::Result<
+                crate::struct_list::Builder<'a, crate::schema_capnp::brand::binding::Owned>,
+            > {
+                self.builder.set_data_field::<u16>(4, 0);
+                crate::traits::FromPointerBuilder::try_init_pointer(
+                    self.builder.get_pointer_field(0),
+                    size,
+                )
+            }
+            #[inline]
+            pub fn disown_bind(
+                &mut self,
+            ) -> crate::Result<
+                crate::orphan::Orphan<
+                    crate::struct_list::Owned<crate::schema_capnp::brand::binding::Owned>,
+                >,
+            > {
+                if self.builder.get_data_field::<u16>(4) != 0 {
+                    let mut error = crate::Error::from_kind(crate::ErrorKind::Failed);
+                    ::core::write!(
+                        error,
+                        "cannot disown bind, which is not the active member of its union"
+                    );
+                    return ::core::result::Result::Err(error);
+                }
+                ::core::result::Result::Ok(crate::orphan::Orphan::disown(
+                    self.builder.reborrow().get_pointer_field(0),
+                ))
+            }
+            #[inline]
+            pub fn adopt_bind(
+                &mut self,
+                orphan: crate::orphan::Orphan<
+                    crate::struct_list::Owned<crate::schema_capnp::brand::binding::Owned>,
+                >,
+            ) -> crate::Result<()> {
+                self.builder.set_data_field::<u16>(4, 0);
+                orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+            }
+            #[inline]
             pub fn has_bind(&self) -> bool {
                 if self.builder.get_data_field::<u16>(4) != 0 {
                     return false;
@@ -11214,7 +12475,7 @@ pub mod brand {
                 crate::word(0, 0, 0, 0, 0, 0, 0, 0),
                 crate::word(0, 0, 0, 0, 0, 0, 0, 0),
             ];
-            pub fn get_field_types(index: u16) -> crate::introspect::Type {
+            pub fn get_field_types(index: u16) -> crate::introspect::Type<'static> {
                 match index {
           0 => <u64 as crate::introspect::Introspect>::introspect(),
           1 => <crate::struct_list::Owned<crate::schema_capnp::brand::binding::Owned> as crate::introspect::Introspect>::introspect(),
@@ -11225,7 +12486,7 @@ pub mod brand {
             pub fn get_annotation_types(
                 child_index: Option<u16>,
                 index: u32,
-            ) -> crate::introspect::Type {
+            ) -> crate::introspect::Type<'static> {
                 panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
             }
             pub static RAW_SCHEMA: crate::introspect::RawStructSchema =
@@ -11262,12 +12523,15 @@ pub mod brand {
         #[derive(Copy, Clone)]
         pub struct Owned(());
         impl crate::introspect::Introspect for Owned {
-            fn introspect() -> crate::introspect::Type {
-                crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                })
+            fn introspect() -> crate::introspect::Type<'static> {
+                crate::introspect::TypeVariant::Struct(
+                    crate::introspect::RawBrandedStructSchema {
+                        generic: &_private::RAW_SCHEMA,
+                        field_types: _private::get_field_types,
+                        annotation_types: _private::get_annotation_types,
+                    }
+                    .into(),
+                )
                 .into()
             }
         }
@@ -11372,7 +12636,8 @@ pub mod brand {
                         crate::traits::FromPointerReader::get_from_pointer(
                             &self.reader.get_pointer_field(0),
                             ::core::option::Option::None,
-                        ),
+                        )
+                        .map_err(|e| e.with_field(_private::TYPE_ID, "type")),
                     )),
                     x => ::core::result::Result::Err(crate::NotInSchema(x)),
                 }
@@ -11427,6 +12692,16 @@ pub mod brand {
                     .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                     .into()
             }
+            fn try_init_pointer(
+                builder: crate::private::layout::PointerBuilder<'a>,
+                _size: u32,
+            ) -> crate::Result<Self> {
+                ::core::result::Result::Ok(
+                    builder
+                        .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                        .into(),
+                )
+            }
             fn get_from_pointer(
                 builder: crate::private::layout::PointerBuilder<'a>,
                 default: ::core::option::Option<&'a [crate::Word]>,
@@ -11490,6 +12765,39 @@ pub mod brand {
                 )
             }
             #[inline]
+            pub fn try_init_type(self) -> crate::Result<crate::schema_capnp::type_::Builder<'a>> {
+                self.builder.set_data_field::<u16>(0, 1);
+                crate::traits::FromPointerBuilder::try_init_pointer(
+                    self.builder.get_pointer_field(0),
+                    0,
+                )
+            }
+            #[inline]
+            pub fn disown_type(
+                &mut self,
+            ) -> crate::Result<crate::orphan::Orphan<crate::schema_capnp::type_::Owned>>
+            {
+                if self.builder.get_data_field::<u16>(0) != 1 {
+                    let mut error = crate::Error::from_kind(crate::ErrorKind::Failed);
+                    ::core::write!(
+                        error,
+                        "cannot disown type, which is not the active member of its union"
+                    );
+                    return ::core::result::Result::Err(error);
+                }
+                ::core::result::Result::Ok(crate::orphan::Orphan::disown(
+                    self.builder.reborrow().get_pointer_field(0),
+                ))
+            }
+            #[inline]
+            pub fn adopt_type(
+                &mut self,
+                orphan: crate::orphan::Orphan<crate::schema_capnp::type_::Owned>,
+            ) -> crate::Result<()> {
+                self.builder.set_data_field::<u16>(0, 1);
+                orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+            }
+            #[inline]
             pub fn has_type(&self) -> bool {
                 if self.builder.get_data_field::<u16>(0) != 1 {
                     return false;
@@ -11573,7 +12881,7 @@ pub mod brand {
                 crate::word(0, 0, 0, 0, 0, 0, 0, 0),
                 crate::word(0, 0, 0, 0, 0, 0, 0, 0),
             ];
-            pub fn get_field_types(index: u16) -> crate::introspect::Type {
+            pub fn get_field_types(index: u16) -> crate::introspect::Type<'static> {
                 match index {
           0 => <() as crate::introspect::Introspect>::introspect(),
           1 => <crate::schema_capnp::type_::Owned as crate::introspect::Introspect>::introspect(),
@@ -11583,7 +12891,7 @@ pub mod brand {
             pub fn get_annotation_types(
                 child_index: Option<u16>,
                 index: u32,
-            ) -> crate::introspect::Type {
+            ) -> crate::introspect::Type<'static> {
                 panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
             }
             pub static RAW_SCHEMA: crate::introspect::RawStructSchema =
@@ -11616,12 +12924,15 @@ pub mod value {
     #[derive(Copy, Clone)]
     pub struct Owned(());
     impl crate::introspect::Introspect for Owned {
-        fn introspect() -> crate::introspect::Type {
-            crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+        fn introspect() -> crate::introspect::Type<'static> {
+            crate::introspect::TypeVariant::Struct(
+                crate::introspect::RawBrandedStructSchema {
+                    generic: &_private::RAW_SCHEMA,
+                    field_types: _private::get_field_types,
+                    annotation_types: _private::get_annotation_types,
+                }
+                .into(),
+            )
             .into()
         }
     }
@@ -11765,13 +13076,15 @@ pub mod value {
                     crate::traits::FromPointerReader::get_from_pointer(
                         &self.reader.get_pointer_field(0),
                         ::core::option::Option::None,
-                    ),
+                    )
+                    .map_err(|e| e.with_field(_private::TYPE_ID, "text")),
                 )),
                 13 => ::core::result::Result::Ok(Data(
                     crate::traits::FromPointerReader::get_from_pointer(
                         &self.reader.get_pointer_field(0),
                         ::core::option::Option::None,
-                    ),
+                    )
+                    .map_err(|e| e.with_field(_private::TYPE_ID, "data")),
                 )),
                 14 => ::core::result::Result::Ok(List(crate::any_pointer::Reader::new(
                     self.reader.get_pointer_field(0),
@@ -11834,6 +13147,16 @@ pub mod value {
                 .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                 .into()
         }
+        fn try_init_pointer(
+            builder: crate::private::layout::PointerBuilder<'a>,
+            _size: u32,
+        ) -> crate::Result<Self> {
+            ::core::result::Result::Ok(
+                builder
+                    .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                    .into(),
+            )
+        }
         fn get_from_pointer(
             builder: crate::private::layout::PointerBuilder<'a>,
             default: ::core::option::Option<&'a [crate::Word]>,
@@ -11942,11 +13265,50 @@ pub mod value {
             .unwrap()
         }
         #[inline]
+        pub fn try_set_text(
+            &mut self,
+            value: impl crate::traits::SetterInput<crate::text::Owned>,
+        ) -> crate::Result<()> {
+            self.builder.set_data_field::<u16>(0, 12);
+            crate::traits::SetterInput::set_pointer_builder(
+                self.builder.reborrow().get_pointer_field(0),
+                value,
+                false,
+            )
+        }
+        #[inline]
         pub fn init_text(self, size: u32) -> crate::text::Builder<'a> {
             self.builder.set_data_field::<u16>(0, 12);
             self.builder.get_pointer_field(0).init_text(size)
         }
         #[inline]
+        pub fn try_init_text(self, size: u32) -> crate::Result<crate::text::Builder<'a>> {
+            self.builder.set_data_field::<u16>(0, 12);
+            self.builder.get_pointer_field(0).try_init_text(size)
+        }
+        #[inline]
+        pub fn disown_text(&mut self) -> crate::Result<crate::orphan::Orphan<crate::text::Owned>> {
+            if self.builder.get_data_field::<u16>(0) != 12 {
+                let mut error = crate::Error::from_kind(crate::ErrorKind::Failed);
+                ::core::write!(
+                    error,
+                    "cannot disown text, which is not the active member of its union"
+                );
+                return ::core::result::Result::Err(error);
+            }
+            ::core::result::Result::Ok(crate::orphan::Orphan::disown(
+                self.builder.reborrow().get_pointer_field(0),
+            ))
+        }
+        #[inline]
+        pub fn adopt_text(
+            &mut self,
+            orphan: crate::orphan::Orphan<crate::text::Owned>,
+        ) -> crate::Result<()> {
+            self.builder.set_data_field::<u16>(0, 12);
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
         pub fn has_text(&self) -> bool {
             if self.builder.get_data_field::<u16>(0) != 12 {
                 return false;
@@ -11959,9 +13321,44 @@ pub mod value {
             self.builder.reborrow().get_pointer_field(0).set_data(value);
         }
         #[inline]
+        pub fn try_set_data(&mut self, value: crate::data::Reader<'_>) -> crate::Result<()> {
+            self.builder.set_data_field::<u16>(0, 13);
+            self.builder
+                .reborrow()
+                .get_pointer_field(0)
+                .try_set_data(value)
+        }
+        #[inline]
         pub fn init_data(self, size: u32) -> crate::data::Builder<'a> {
             self.builder.set_data_field::<u16>(0, 13);
-            self.builder.get_pointer_field(0).init_data(size)
+            self.builder.get_pointer_field(0).init_data(size)
+        }
+        #[inline]
+        pub fn try_init_data(self, size: u32) -> crate::Result<crate::data::Builder<'a>> {
+            self.builder.set_data_field::<u16>(0, 13);
+            self.builder.get_pointer_field(0).try_init_data(size)
+        }
+        #[inline]
+        pub fn disown_data(&mut self) -> crate::Result<crate::orphan::Orphan<crate::data::Owned>> {
+            if self.builder.get_data_field::<u16>(0) != 13 {
+                let mut error = crate::Error::from_kind(crate::ErrorKind::Failed);
+                ::core::write!(
+                    error,
+                    "cannot disown data, which is not the active member of its union"
+                );
+                return ::core::result::Result::Err(error);
+            }
+            ::core::result::Result::Ok(crate::orphan::Orphan::disown(
+                self.builder.reborrow().get_pointer_field(0),
+            ))
+        }
+        #[inline]
+        pub fn adopt_data(
+            &mut self,
+            orphan: crate::orphan::Orphan<crate::data::Owned>,
+        ) -> crate::Result<()> {
+            self.builder.set_data_field::<u16>(0, 13);
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
         }
         #[inline]
         pub fn has_data(&self) -> bool {
@@ -11978,6 +13375,30 @@ pub mod value {
             result
         }
         #[inline]
+        pub fn disown_list(
+            &mut self,
+        ) -> crate::Result<crate::orphan::Orphan<crate::any_pointer::Owned>> {
+            if self.builder.get_data_field::<u16>(0) != 14 {
+                let mut error = crate::Error::from_kind(crate::ErrorKind::Failed);
+                ::core::write!(
+                    error,
+                    "cannot disown list, which is not the active member of its union"
+                );
+                return ::core::result::Result::Err(error);
+            }
+            ::core::result::Result::Ok(crate::orphan::Orphan::disown(
+                self.builder.reborrow().get_pointer_field(0),
+            ))
+        }
+        #[inline]
+        pub fn adopt_list(
+            &mut self,
+            orphan: crate::orphan::Orphan<crate::any_pointer::Owned>,
+        ) -> crate::Result<()> {
+            self.builder.set_data_field::<u16>(0, 14);
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
         pub fn has_list(&self) -> bool {
             if self.builder.get_data_field::<u16>(0) != 14 {
                 return false;
@@ -11997,6 +13418,30 @@ pub mod value {
             result
         }
         #[inline]
+        pub fn disown_struct(
+            &mut self,
+        ) -> crate::Result<crate::orphan::Orphan<crate::any_pointer::Owned>> {
+            if self.builder.get_data_field::<u16>(0) != 16 {
+                let mut error = crate::Error::from_kind(crate::ErrorKind::Failed);
+                ::core::write!(
+                    error,
+                    "cannot disown struct, which is not the active member of its union"
+                );
+                return ::core::result::Result::Err(error);
+            }
+            ::core::result::Result::Ok(crate::orphan::Orphan::disown(
+                self.builder.reborrow().get_pointer_field(0),
+            ))
+        }
+        #[inline]
+        pub fn adopt_struct(
+            &mut self,
+            orphan: crate::orphan::Orphan<crate::any_pointer::Owned>,
+        ) -> crate::Result<()> {
+            self.builder.set_data_field::<u16>(0, 16);
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
         pub fn has_struct(&self) -> bool {
             if self.builder.get_data_field::<u16>(0) != 16 {
                 return false;
@@ -12015,6 +13460,30 @@ pub mod value {
             result
         }
         #[inline]
+        pub fn disown_any_pointer(
+            &mut self,
+        ) -> crate::Result<crate::orphan::Orphan<crate::any_pointer::Owned>> {
+            if self.builder.get_data_field::<u16>(0) != 18 {
+                let mut error = crate::Error::from_kind(crate::ErrorKind::Failed);
+                ::core::write!(
+                    error,
+                    "cannot disown anyPointer, which is not the active member of its union"
+                );
+                return ::core::result::Result::Err(error);
+            }
+            ::core::result::Result::Ok(crate::orphan::Orphan::disown(
+                self.builder.reborrow().get_pointer_field(0),
+            ))
+        }
+        #[inline]
+        pub fn adopt_any_pointer(
+            &mut self,
+            orphan: crate::orphan::Orphan<crate::any_pointer::Owned>,
+        ) -> crate::Result<()> {
+            self.builder.set_data_field::<u16>(0, 18);
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
         pub fn has_any_pointer(&self) -> bool {
             if self.builder.get_data_field::<u16>(0) != 18 {
                 return false;
@@ -12382,7 +13851,7 @@ pub mod value {
             crate::word(0, 0, 0, 0, 0, 0, 0, 0),
             crate::word(0, 0, 0, 0, 0, 0, 0, 0),
         ];
-        pub fn get_field_types(index: u16) -> crate::introspect::Type {
+        pub fn get_field_types(index: u16) -> crate::introspect::Type<'static> {
             match index {
                 0 => <() as crate::introspect::Introspect>::introspect(),
                 1 => <bool as crate::introspect::Introspect>::introspect(),
@@ -12409,7 +13878,7 @@ pub mod value {
         pub fn get_annotation_types(
             child_index: Option<u16>,
             index: u32,
-        ) -> crate::introspect::Type {
+        ) -> crate::introspect::Type<'static> {
             panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
         }
         pub static RAW_SCHEMA: crate::introspect::RawStructSchema =
@@ -12469,12 +13938,15 @@ pub mod annotation {
     #[derive(Copy, Clone)]
     pub struct Owned(());
     impl crate::introspect::Introspect for Owned {
-        fn introspect() -> crate::introspect::Type {
-            crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+        fn introspect() -> crate::introspect::Type<'static> {
+            crate::introspect::TypeVariant::Struct(
+                crate::introspect::RawBrandedStructSchema {
+                    generic: &_private::RAW_SCHEMA,
+                    field_types: _private::get_field_types,
+                    annotation_types: _private::get_annotation_types,
+                }
+                .into(),
+            )
             .into()
         }
     }
@@ -12574,6 +14046,7 @@ pub mod annotation {
                 &self.reader.get_pointer_field(0),
                 ::core::option::Option::None,
             )
+            .map_err(|e| e.with_field(_private::TYPE_ID, "value"))
         }
         #[inline]
         pub fn has_value(&self) -> bool {
@@ -12585,6 +14058,7 @@ pub mod annotation {
                 &self.reader.get_pointer_field(1),
                 ::core::option::Option::None,
             )
+            .map_err(|e| e.with_field(_private::TYPE_ID, "brand"))
         }
         #[inline]
         pub fn has_brand(&self) -> bool {
@@ -12637,6 +14111,16 @@ pub mod annotation {
                 .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                 .into()
         }
+        fn try_init_pointer(
+            builder: crate::private::layout::PointerBuilder<'a>,
+            _size: u32,
+        ) -> crate::Result<Self> {
+            ::core::result::Result::Ok(
+                builder
+                    .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                    .into(),
+            )
+        }
         fn get_from_pointer(
             builder: crate::private::layout::PointerBuilder<'a>,
             default: ::core::option::Option<&'a [crate::Word]>,
@@ -12706,6 +14190,24 @@ pub mod annotation {
             crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
         }
         #[inline]
+        pub fn try_init_value(self) -> crate::Result<crate::schema_capnp::value::Builder<'a>> {
+            crate::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                0,
+            )
+        }
+        #[inline]
+        pub fn disown_value(&mut self) -> crate::orphan::Orphan<crate::schema_capnp::value::Owned> {
+            crate::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
+        pub fn adopt_value(
+            &mut self,
+            orphan: crate::orphan::Orphan<crate::schema_capnp::value::Owned>,
+        ) -> crate::Result<()> {
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
         pub fn has_value(&self) -> bool {
             !self.builder.is_pointer_field_null(0)
         }
@@ -12732,6 +14234,24 @@ pub mod annotation {
             crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(1), 0)
         }
         #[inline]
+        pub fn try_init_brand(self) -> crate::Result<crate::schema_capnp::brand::Builder<'a>> {
+            crate::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(1),
+                0,
+            )
+        }
+        #[inline]
+        pub fn disown_brand(&mut self) -> crate::orphan::Orphan<crate::schema_capnp::brand::Owned> {
+            crate::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(1))
+        }
+        #[inline]
+        pub fn adopt_brand(
+            &mut self,
+            orphan: crate::orphan::Orphan<crate::schema_capnp::brand::Owned>,
+        ) -> crate::Result<()> {
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(1))
+        }
+        #[inline]
         pub fn has_brand(&self) -> bool {
             !self.builder.is_pointer_field_null(1)
         }
@@ -12820,7 +14340,7 @@ pub mod annotation {
             crate::word(0, 0, 0, 0, 0, 0, 0, 0),
             crate::word(0, 0, 0, 0, 0, 0, 0, 0),
         ];
-        pub fn get_field_types(index: u16) -> crate::introspect::Type {
+        pub fn get_field_types(index: u16) -> crate::introspect::Type<'static> {
             match index {
                 0 => <u64 as crate::introspect::Introspect>::introspect(),
                 1 => {
@@ -12837,7 +14357,7 @@ pub mod annotation {
         pub fn get_annotation_types(
             child_index: Option<u16>,
             index: u32,
-        ) -> crate::introspect::Type {
+        ) -> crate::introspect::Type<'static> {
             panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
         }
         pub static RAW_SCHEMA: crate::introspect::RawStructSchema =
@@ -12868,11 +14388,14 @@ pub enum ElementSize {
 }
 
 impl crate::introspect::Introspect for ElementSize {
-    fn introspect() -> crate::introspect::Type {
-        crate::introspect::TypeVariant::Enum(crate::introspect::RawEnumSchema {
-            encoded_node: &element_size::ENCODED_NODE,
-            annotation_types: element_size::get_annotation_types,
-        })
+    fn introspect() -> crate::introspect::Type<'static> {
+        crate::introspect::TypeVariant::Enum(
+            crate::introspect::RawEnumSchema {
+                encoded_node: &element_size::ENCODED_NODE,
+                annotation_types: element_size::get_annotation_types,
+            }
+            .into(),
+        )
         .into()
     }
 }
@@ -12973,7 +14496,10 @@ mod element_size {
         crate::word(105, 110, 108, 105, 110, 101, 67, 111),
         crate::word(109, 112, 111, 115, 105, 116, 101, 0),
     ];
-    pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> crate::introspect::Type {
+    pub fn get_annotation_types(
+        child_index: Option<u16>,
+        index: u32,
+    ) -> crate::introspect::Type<'static> {
         panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
     }
 }
@@ -12982,12 +14508,15 @@ pub mod capnp_version {
     #[derive(Copy, Clone)]
     pub struct Owned(());
     impl crate::introspect::Introspect for Owned {
-        fn introspect() -> crate::introspect::Type {
-            crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+        fn introspect() -> crate::introspect::Type<'static> {
+            crate::introspect::TypeVariant::Struct(
+                crate::introspect::RawBrandedStructSchema {
+                    generic: &_private::RAW_SCHEMA,
+                    field_types: _private::get_field_types,
+                    annotation_types: _private::get_annotation_types,
+                }
+                .into(),
+            )
             .into()
         }
     }
@@ -13136,6 +14665,16 @@ pub mod capnp_version {
                 .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                 .into()
         }
+        fn try_init_pointer(
+            builder: crate::private::layout::PointerBuilder<'a>,
+            _size: u32,
+        ) -> crate::Result<Self> {
+            ::core::result::Result::Ok(
+                builder
+                    .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                    .into(),
+            )
+        }
         fn get_from_pointer(
             builder: crate::private::layout::PointerBuilder<'a>,
             default: ::core::option::Option<&'a [crate::Word]>,
@@ -13277,7 +14816,7 @@ pub mod capnp_version {
             crate::word(0, 0, 0, 0, 0, 0, 0, 0),
             crate::word(0, 0, 0, 0, 0, 0, 0, 0),
         ];
-        pub fn get_field_types(index: u16) -> crate::introspect::Type {
+        pub fn get_field_types(index: u16) -> crate::introspect::Type<'static> {
             match index {
                 0 => <u16 as crate::introspect::Introspect>::introspect(),
                 1 => <u8 as crate::introspect::Introspect>::introspect(),
@@ -13288,7 +14827,7 @@ pub mod capnp_version {
         pub fn get_annotation_types(
             child_index: Option<u16>,
             index: u32,
-        ) -> crate::introspect::Type {
+        ) -> crate::introspect::Type<'static> {
             panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
         }
         pub static RAW_SCHEMA: crate::introspect::RawStructSchema =
@@ -13309,12 +14848,15 @@ pub mod code_generator_request {
     #[derive(Copy, Clone)]
     pub struct Owned(());
     impl crate::introspect::Introspect for Owned {
-        fn introspect() -> crate::introspect::Type {
-            crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                generic: &_private::RAW_SCHEMA,
-                field_types: _private::get_field_types,
-                annotation_types: _private::get_annotation_types,
-            })
+        fn introspect() -> crate::introspect::Type<'static> {
+            crate::introspect::TypeVariant::Struct(
+                crate::introspect::RawBrandedStructSchema {
+                    generic: &_private::RAW_SCHEMA,
+                    field_types: _private::get_field_types,
+                    annotation_types: _private::get_annotation_types,
+                }
+                .into(),
+            )
             .into()
         }
     }
@@ -13413,6 +14955,7 @@ pub mod code_generator_request {
                 &self.reader.get_pointer_field(0),
                 ::core::option::Option::None,
             )
+            .map_err(|e| e.with_field(_private::TYPE_ID, "nodes"))
         }
         #[inline]
         pub fn has_nodes(&self) -> bool {
@@ -13431,6 +14974,7 @@ pub mod code_generator_request {
                 &self.reader.get_pointer_field(1),
                 ::core::option::Option::None,
             )
+            .map_err(|e| e.with_field(_private::TYPE_ID, "requestedFiles"))
         }
         #[inline]
         pub fn has_requested_files(&self) -> bool {
@@ -13444,6 +14988,7 @@ pub mod code_generator_request {
                 &self.reader.get_pointer_field(2),
                 ::core::option::Option::None,
             )
+            .map_err(|e| e.with_field(_private::TYPE_ID, "capnpVersion"))
         }
         #[inline]
         pub fn has_capnp_version(&self) -> bool {
@@ -13459,6 +15004,7 @@ pub mod code_generator_request {
                 &self.reader.get_pointer_field(3),
                 ::core::option::Option::None,
             )
+            .map_err(|e| e.with_field(_private::TYPE_ID, "sourceInfo"))
         }
         #[inline]
         pub fn has_source_info(&self) -> bool {
@@ -13511,6 +15057,16 @@ pub mod code_generator_request {
                 .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                 .into()
         }
+        fn try_init_pointer(
+            builder: crate::private::layout::PointerBuilder<'a>,
+            _size: u32,
+        ) -> crate::Result<Self> {
+            ::core::result::Result::Ok(
+                builder
+                    .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                    .into(),
+            )
+        }
         fn get_from_pointer(
             builder: crate::private::layout::PointerBuilder<'a>,
             default: ::core::option::Option<&'a [crate::Word]>,
@@ -13578,6 +15134,33 @@ pub mod code_generator_request {
             crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), size)
         }
         #[inline]
+        pub fn try_init_nodes(
+            self,
+            size: u32,
+        ) -> crate::Result<crate::struct_list::Builder<'a, crate::schema_capnp::node::Owned>>
+        {
+            crate::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(0),
+                size,
+            )
+        }
+        #[inline]
+        pub fn disown_nodes(
+            &mut self,
+        ) -> crate::orphan::Orphan<crate::struct_list::Owned<crate::schema_capnp::node::Owned>>
+        {
+            crate::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
+        pub fn adopt_nodes(
+            &mut self,
+            orphan: crate::orphan::Orphan<
+                crate::struct_list::Owned<crate::schema_capnp::node::Owned>,
+            >,
+        ) -> crate::Result<()> {
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+        }
+        #[inline]
         pub fn has_nodes(&self) -> bool {
             !self.builder.is_pointer_field_null(0)
         }
@@ -13620,6 +15203,42 @@ pub mod code_generator_request {
             crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(1), size)
         }
         #[inline]
+        pub fn try_init_requested_files(
+            self,
+            size: u32,
+        ) -> crate::Result<
+            crate::struct_list::Builder<
+                'a,
+                crate::schema_capnp::code_generator_request::requested_file::Owned,
+            >,
+        > {
+            crate::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(1),
+                size,
+            )
+        }
+        #[inline]
+        pub fn disown_requested_files(
+            &mut self,
+        ) -> crate::orphan::Orphan<
+            crate::struct_list::Owned<
+                crate::schema_capnp::code_generator_request::requested_file::Owned,
+            >,
+        > {
+            crate::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(1))
+        }
+        #[inline]
+        pub fn adopt_requested_files(
+            &mut self,
+            orphan: crate::orphan::Orphan<
+                crate::struct_list::Owned<
+                    crate::schema_capnp::code_generator_request::requested_file::Owned,
+                >,
+            >,
+        ) -> crate::Result<()> {
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(1))
+        }
+        #[inline]
         pub fn has_requested_files(&self) -> bool {
             !self.builder.is_pointer_field_null(1)
         }
@@ -13648,6 +15267,28 @@ pub mod code_generator_request {
             crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(2), 0)
         }
         #[inline]
+        pub fn try_init_capnp_version(
+            self,
+        ) -> crate::Result<crate::schema_capnp::capnp_version::Builder<'a>> {
+            crate::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(2),
+                0,
+            )
+        }
+        #[inline]
+        pub fn disown_capnp_version(
+            &mut self,
+        ) -> crate::orphan::Orphan<crate::schema_capnp::capnp_version::Owned> {
+            crate::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(2))
+        }
+        #[inline]
+        pub fn adopt_capnp_version(
+            &mut self,
+            orphan: crate::orphan::Orphan<crate::schema_capnp::capnp_version::Owned>,
+        ) -> crate::Result<()> {
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(2))
+        }
+        #[inline]
         pub fn has_capnp_version(&self) -> bool {
             !self.builder.is_pointer_field_null(2)
         }
@@ -13682,6 +15323,35 @@ pub mod code_generator_request {
             crate::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(3), size)
         }
         #[inline]
+        pub fn try_init_source_info(
+            self,
+            size: u32,
+        ) -> crate::Result<
+            crate::struct_list::Builder<'a, crate::schema_capnp::node::source_info::Owned>,
+        > {
+            crate::traits::FromPointerBuilder::try_init_pointer(
+                self.builder.get_pointer_field(3),
+                size,
+            )
+        }
+        #[inline]
+        pub fn disown_source_info(
+            &mut self,
+        ) -> crate::orphan::Orphan<
+            crate::struct_list::Owned<crate::schema_capnp::node::source_info::Owned>,
+        > {
+            crate::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(3))
+        }
+        #[inline]
+        pub fn adopt_source_info(
+            &mut self,
+            orphan: crate::orphan::Orphan<
+                crate::struct_list::Owned<crate::schema_capnp::node::source_info::Owned>,
+            >,
+        ) -> crate::Result<()> {
+            orphan.adopt_into(self.builder.reborrow().get_pointer_field(3))
+        }
+        #[inline]
         pub fn has_source_info(&self) -> bool {
             !self.builder.is_pointer_field_null(3)
         }
@@ -13803,7 +15473,7 @@ pub mod code_generator_request {
             crate::word(0, 0, 0, 0, 0, 0, 0, 0),
             crate::word(0, 0, 0, 0, 0, 0, 0, 0),
         ];
-        pub fn get_field_types(index: u16) -> crate::introspect::Type {
+        pub fn get_field_types(index: u16) -> crate::introspect::Type<'static> {
             match index {
         0 => <crate::struct_list::Owned<crate::schema_capnp::node::Owned> as crate::introspect::Introspect>::introspect(),
         1 => <crate::struct_list::Owned<crate::schema_capnp::code_generator_request::requested_file::Owned> as crate::introspect::Introspect>::introspect(),
@@ -13815,7 +15485,7 @@ pub mod code_generator_request {
         pub fn get_annotation_types(
             child_index: Option<u16>,
             index: u32,
-        ) -> crate::introspect::Type {
+        ) -> crate::introspect::Type<'static> {
             panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
         }
         pub static RAW_SCHEMA: crate::introspect::RawStructSchema =
@@ -13835,12 +15505,15 @@ pub mod code_generator_request {
         #[derive(Copy, Clone)]
         pub struct Owned(());
         impl crate::introspect::Introspect for Owned {
-            fn introspect() -> crate::introspect::Type {
-                crate::introspect::TypeVariant::Struct(crate::introspect::RawBrandedStructSchema {
-                    generic: &_private::RAW_SCHEMA,
-                    field_types: _private::get_field_types,
-                    annotation_types: _private::get_annotation_types,
-                })
+            fn introspect() -> crate::introspect::Type<'static> {
+                crate::introspect::TypeVariant::Struct(
+                    crate::introspect::RawBrandedStructSchema {
+                        generic: &_private::RAW_SCHEMA,
+                        field_types: _private::get_field_types,
+                        annotation_types: _private::get_annotation_types,
+                    }
+                    .into(),
+                )
                 .into()
             }
         }
@@ -13940,6 +15613,7 @@ pub mod code_generator_request {
                     &self.reader.get_pointer_field(0),
                     ::core::option::Option::None,
                 )
+                .map_err(|e| e.with_field(_private::TYPE_ID, "filename"))
             }
             #[inline]
             pub fn has_filename(&self) -> bool {
@@ -13958,6 +15632,7 @@ pub mod code_generator_request {
                     &self.reader.get_pointer_field(1),
                     ::core::option::Option::None,
                 )
+                .map_err(|e| e.with_field(_private::TYPE_ID, "imports"))
             }
             #[inline]
             pub fn has_imports(&self) -> bool {
@@ -14013,6 +15688,16 @@ pub mod code_generator_request {
                     .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                     .into()
             }
+            fn try_init_pointer(
+                builder: crate::private::layout::PointerBuilder<'a>,
+                _size: u32,
+            ) -> crate::Result<Self> {
+                ::core::result::Result::Ok(
+                    builder
+                        .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                        .into(),
+                )
+            }
             fn get_from_pointer(
                 builder: crate::private::layout::PointerBuilder<'a>,
                 default: ::core::option::Option<&'a [crate::Word]>,
@@ -14079,10 +15764,36 @@ pub mod code_generator_request {
                 .unwrap()
             }
             #[inline]
+            pub fn try_set_filename(
+                &mut self,
+                value: impl crate::traits::SetterInput<crate::text::Owned>,
+            ) -> crate::Result<()> {
+                crate::traits::SetterInput::set_pointer_builder(
+                    self.builder.reborrow().get_pointer_field(0),
+                    value,
+                    false,
+                )
+            }
+            #[inline]
             pub fn init_filename(self, size: u32) -> crate::text::Builder<'a> {
                 self.builder.get_pointer_field(0).init_text(size)
             }
             #[inline]
+            pub fn try_init_filename(self, size: u32) -> crate::Result<crate::text::Builder<'a>> {
+                self.builder.get_pointer_field(0).try_init_text(size)
+            }
+            #[inline]
+            pub fn disown_filename(&mut self) -> crate::orphan::Orphan<crate::text::Owned> {
+                crate::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(0))
+            }
+            #[inline]
+            pub fn adopt_filename(
+                &mut self,
+                orphan: crate::orphan::Orphan<crate::text::Owned>,
+            ) -> crate::Result<()> {
+                orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+            }
+            #[inline]
             pub fn has_filename(&self) -> bool {
                 !self.builder.is_pointer_field_null(0)
             }
@@ -14128,6 +15839,42 @@ pub mod code_generator_request {
                 )
             }
             #[inline]
+            pub fn try_init_imports(
+                self,
+                size: u32,
+            ) -> crate::Result<
+                crate::struct_list::Builder<
+                    'a,
+                    crate::schema_capnp::code_generator_request::requested_file::import::Owned,
+                >,
+            > {
+                crate::traits::FromPointerBuilder::try_init_pointer(
+                    self.builder.get_pointer_field(1),
+                    size,
+                )
+            }
+            #[inline]
+            pub fn disown_imports(
+                &mut self,
+            ) -> crate::orphan::Orphan<
+                crate::struct_list::Owned<
+                    crate::schema_capnp::code_generator_request::requested_file::import::Owned,
+                >,
+            > {
+                crate::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(1))
+            }
+            #[inline]
+            pub fn adopt_imports(
+                &mut self,
+                orphan: crate::orphan::Orphan<
+                    crate::struct_list::Owned<
+                        crate::schema_capnp::code_generator_request::requested_file::import::Owned,
+                    >,
+                >,
+            ) -> crate::Result<()> {
+                orphan.adopt_into(self.builder.reborrow().get_pointer_field(1))
+            }
+            #[inline]
             pub fn has_imports(&self) -> bool {
                 !self.builder.is_pointer_field_null(1)
             }
@@ -14220,7 +15967,7 @@ pub mod code_generator_request {
                 crate::word(0, 0, 0, 0, 0, 0, 0, 0),
                 crate::word(0, 0, 0, 0, 0, 0, 0, 0),
             ];
-            pub fn get_field_types(index: u16) -> crate::introspect::Type {
+            pub fn get_field_types(index: u16) -> crate::introspect::Type<'static> {
                 match index {
                     0 => <u64 as crate::introspect::Introspect>::introspect(),
                     1 => <crate::text::Owned as crate::introspect::Introspect>::introspect(),
@@ -14233,7 +15980,7 @@ pub mod code_generator_request {
             pub fn get_annotation_types(
                 child_index: Option<u16>,
                 index: u32,
-            ) -> crate::introspect::Type {
+            ) -> crate::introspect::Type<'static> {
                 panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
             }
             pub static RAW_SCHEMA: crate::introspect::RawStructSchema =
@@ -14253,13 +16000,14 @@ pub mod code_generator_request {
             #[derive(Copy, Clone)]
             pub struct Owned(());
             impl crate::introspect::Introspect for Owned {
-                fn introspect() -> crate::introspect::Type {
+                fn introspect() -> crate::introspect::Type<'static> {
                     crate::introspect::TypeVariant::Struct(
                         crate::introspect::RawBrandedStructSchema {
                             generic: &_private::RAW_SCHEMA,
                             field_types: _private::get_field_types,
                             annotation_types: _private::get_annotation_types,
-                        },
+                        }
+                        .into(),
                     )
                     .into()
                 }
@@ -14362,6 +16110,7 @@ pub mod code_generator_request {
                         &self.reader.get_pointer_field(0),
                         ::core::option::Option::None,
                     )
+                    .map_err(|e| e.with_field(_private::TYPE_ID, "name"))
                 }
                 #[inline]
                 pub fn has_name(&self) -> bool {
@@ -14419,6 +16168,16 @@ pub mod code_generator_request {
                         .init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)
                         .into()
                 }
+                fn try_init_pointer(
+                    builder: crate::private::layout::PointerBuilder<'a>,
+                    _size: u32,
+                ) -> crate::Result<Self> {
+                    ::core::result::Result::Ok(
+                        builder
+                            .try_init_struct(<Self as crate::traits::HasStructSize>::STRUCT_SIZE)?
+                            .into(),
+                    )
+                }
                 fn get_from_pointer(
                     builder: crate::private::layout::PointerBuilder<'a>,
                     default: ::core::option::Option<&'a [crate::Word]>,
@@ -14488,10 +16247,36 @@ pub mod code_generator_request {
                     .unwrap()
                 }
                 #[inline]
+                pub fn try_set_name(
+                    &mut self,
+                    value: impl crate::traits::SetterInput<crate::text::Owned>,
+                ) -> crate::Result<()> {
+                    crate::traits::SetterInput::set_pointer_builder(
+                        self.builder.reborrow().get_pointer_field(0),
+                        value,
+                        false,
+                    )
+                }
+                #[inline]
                 pub fn init_name(self, size: u32) -> crate::text::Builder<'a> {
                     self.builder.get_pointer_field(0).init_text(size)
                 }
                 #[inline]
+                pub fn try_init_name(self, size: u32) -> crate::Result<crate::text::Builder<'a>> {
+                    self.builder.get_pointer_field(0).try_init_text(size)
+                }
+                #[inline]
+                pub fn disown_name(&mut self) -> crate::orphan::Orphan<crate::text::Owned> {
+                    crate::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field(0))
+                }
+                #[inline]
+                pub fn adopt_name(
+                    &mut self,
+                    orphan: crate::orphan::Orphan<crate::text::Owned>,
+                ) -> crate::Result<()> {
+                    orphan.adopt_into(self.builder.reborrow().get_pointer_field(0))
+                }
+                #[inline]
                 pub fn has_name(&self) -> bool {
                     !self.builder.is_pointer_field_null(0)
                 }
@@ -14562,7 +16347,7 @@ pub mod code_generator_request {
                     crate::word(0, 0, 0, 0, 0, 0, 0, 0),
                     crate::word(0, 0, 0, 0, 0, 0, 0, 0),
                 ];
-                pub fn get_field_types(index: u16) -> crate::introspect::Type {
+                pub fn get_field_types(index: u16) -> crate::introspect::Type<'static> {
                     match index {
                         0 => <u64 as crate::introspect::Introspect>::introspect(),
                         1 => <crate::text::Owned as crate::introspect::Introspect>::introspect(),
@@ -14572,7 +16357,7 @@ pub mod code_generator_request {
                 pub fn get_annotation_types(
                     child_index: Option<u16>,
                     index: u32,
-                ) -> crate::introspect::Type {
+                ) -> crate::introspect::Type<'static> {
                     panic!("invalid annotation indices ({:?}, {}) ", child_index, index)
                 }
                 pub static RAW_SCHEMA: crate::introspect::RawStructSchema =
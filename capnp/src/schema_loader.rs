@@ -0,0 +1,774 @@
+//! Construction of schemas at run time, without generated code.
+//!
+//! A [`SchemaLoader`] takes `schema.capnp` nodes, for example the ones in a
+//! `CodeGeneratorRequest`, and hands out [`StructSchema`], [`EnumSchema`] and
+//! [`InterfaceSchema`] values that work with [`dynamic_struct`](crate::dynamic_struct) and
+//! [`dynamic_value`](crate::dynamic_value) just like the schemas of generated types do.
+//! Generic types are supported: field and method types are resolved against the brand
+//! through which a struct or interface was reached.
+//!
+//! The loader owns everything that it loads, and the schemas that it hands out borrow
+//! from it, so they cannot outlive it. Dropping the loader frees its nodes.
+//!
+//! Nodes may be loaded in any order. If a type is used before its node has been loaded,
+//! it resolves to an empty placeholder, and keeps doing so for schemas that were
+//! handed out before the node arrived.
+
+use std::collections::hash_map::{Entry, HashMap};
+use std::sync::{Mutex, MutexGuard};
+
+use crate::any_pointer;
+use crate::introspect::{EnumType, InterfaceType, StructType, Type, TypeVariant};
+use crate::message;
+use crate::private::layout;
+use crate::private::units::BYTES_PER_WORD;
+use crate::schema::{EnumSchema, InterfaceSchema, StructSchema};
+use crate::schema_capnp::{annotation, brand, code_generator_request, field, node, type_};
+use crate::{Error, ErrorKind, Result, Word};
+
+/// Owns a set of loaded schema nodes, and builds schemas from them.
+pub struct SchemaLoader {
+    // Boxed, because the instances inside of it point back to it, so it must not move
+    // when the loader does.
+    shared: Box<Shared>,
+}
+
+impl SchemaLoader {
+    pub fn new() -> Self {
+        Self {
+            shared: Box::new(Shared {
+                nodes: Mutex::new(HashMap::new()),
+                placeholders: Mutex::new(HashMap::new()),
+            }),
+        }
+    }
+
+    /// Loads a single node. Loading a node identical to one that was already
+    /// loaded has no effect. Loading a different node with the same ID is an error,
+    /// also when the two are loaded concurrently.
+    pub fn load(&self, node: node::Reader) -> Result<()> {
+        let loaded = LoadedNode::new(encode_node(node)?)?;
+        match lock(&self.shared.nodes).entry(loaded.id) {
+            Entry::Occupied(existing) if existing.get().encoded_node == loaded.encoded_node => {
+                Ok(())
+            }
+            Entry::Occupied(_) => Err(Error::failed(format!(
+                "conflicting definitions for node @{:#x}",
+                loaded.id
+            ))),
+            Entry::Vacant(entry) => {
+                entry.insert(loaded);
+                Ok(())
+            }
+        }
+    }
+
+    /// Loads every node in `nodes`, e.g. the elements of a `List(Node)`.
+    pub fn load_all<'a>(&self, nodes: impl IntoIterator<Item = node::Reader<'a>>) -> Result<()> {
+        for node in nodes {
+            self.load(node)?;
+        }
+        Ok(())
+    }
+
+    /// Loads all of the nodes in a request that was passed to a code generator plugin.
+    pub fn load_code_generator_request(
+        &self,
+        request: code_generator_request::Reader,
+    ) -> Result<()> {
+        self.load_all(request.get_nodes()?)
+    }
+
+    /// Gets a loaded node.
+    pub fn get_node(&self, id: u64) -> Option<node::Reader<'_>> {
+        self.shared.find(id).map(LoadedNode::proto)
+    }
+
+    /// Looks up the ID of the node named `name` that is nested inside node `scope_id`.
+    pub fn find_nested(&self, scope_id: u64, name: &str) -> Result<Option<u64>> {
+        let Some(scope) = self.shared.find(scope_id) else {
+            return Err(not_loaded(scope_id));
+        };
+        for nested in scope.proto().get_nested_nodes()? {
+            if nested.get_name()?.as_bytes() == name.as_bytes() {
+                return Ok(Some(nested.get_id()));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Gets the schema of a loaded struct. If the struct is generic,
+    /// all of its type parameters are bound to `AnyPointer`.
+    pub fn get_struct(&self, id: u64) -> Result<StructSchema<'_>> {
+        let node = self.get_loaded(id, Kind::Struct)?;
+        Ok(StructType::Loaded(self.shared.instance(node, Vec::new())).into())
+    }
+
+    /// Gets the schema of a loaded enum.
+    pub fn get_enum(&self, id: u64) -> Result<EnumSchema<'_>> {
+        let node = self.get_loaded(id, Kind::Enum)?;
+        Ok(EnumType::Loaded(self.shared.instance(node, Vec::new())).into())
+    }
+
+    /// Gets the schema of a loaded interface. If the interface is generic,
+    /// all of its type parameters are bound to `AnyPointer`.
+    pub fn get_interface(&self, id: u64) -> Result<InterfaceSchema<'_>> {
+        let node = self.get_loaded(id, Kind::Interface)?;
+        Ok(InterfaceType::Loaded(self.shared.instance(node, Vec::new())).into())
+    }
+
+    /// Resolves a `Type` as found in `schema.capnp`, including any brand it carries,
+    /// e.g. to get the schema of `Map(Text, Person)`. Type parameters that are not bound
+    /// by the brand resolve to `AnyPointer`.
+    pub fn get_type(&self, ty: type_::Reader) -> Result<Type<'_>> {
+        check_type(ty)?;
+        self.shared.resolve_type(ty, &[])
+    }
+
+    fn get_loaded(&self, id: u64, kind: Kind) -> Result<&LoadedNode> {
+        match self.shared.find(id) {
+            None => Err(not_loaded(id)),
+            Some(node) if node.kind == kind => Ok(node),
+            Some(_) => {
+                let mut error = Error::from_kind(match kind {
+                    Kind::Struct => ErrorKind::NotAStruct,
+                    _ => ErrorKind::TypeMismatch,
+                });
+                write!(error, "node @{id:#x} is not {}", kind.describe());
+                Err(error)
+            }
+        }
+    }
+}
+
+impl Default for SchemaLoader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A struct, enum or interface node loaded by a [`SchemaLoader`], with its type
+/// parameters bound. Obtained by converting the `StructType`, `EnumType` or
+/// `InterfaceType` into a schema.
+#[derive(Clone, Copy)]
+pub struct LoadedSchema<'a> {
+    instance: &'a Instance<'a>,
+}
+
+impl<'a> LoadedSchema<'a> {
+    pub(crate) fn encoded_node(&self) -> &'a [Word] {
+        &self.instance.node.encoded_node
+    }
+
+    pub(crate) fn nonunion_members(&self) -> &'a [u16] {
+        &self.instance.node.nonunion_members
+    }
+
+    pub(crate) fn members_by_discriminant(&self) -> &'a [u16] {
+        &self.instance.node.members_by_discriminant
+    }
+
+    pub(crate) fn members_by_name(&self) -> &'a [u16] {
+        &self.instance.node.members_by_name
+    }
+
+    pub(crate) fn field_type(&self, index: u16) -> Type<'a> {
+        // Loading validated the node, so this only fails when a brand binds a type
+        // parameter to something other than a pointer. Void is a safe stand-in:
+        // it never touches the message.
+        self.instance
+            .field_type(index)
+            .unwrap_or_else(|_| TypeVariant::Void.into())
+    }
+
+    pub(crate) fn method_types(&self, ordinal: u16) -> (Type<'a>, Type<'a>) {
+        // Loading validated the node, and a bad brand falls back to an unbranded
+        // struct, so this does not fail.
+        self.instance
+            .method_types(ordinal)
+            .expect("method types of a validated interface")
+    }
+
+    pub(crate) fn superclass_type(&self, index: u32) -> Type<'a> {
+        self.instance
+            .superclass_type(index)
+            .expect("superclass of a validated interface")
+    }
+
+    pub(crate) fn annotation_type(&self, child_index: Option<u16>, index: u32) -> Type<'a> {
+        self.instance
+            .annotation_type(child_index, index)
+            .unwrap_or_else(|_| TypeVariant::AnyPointer.into())
+    }
+}
+
+impl PartialEq for LoadedSchema<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        // Instances are interned, so equal schemas share one.
+        core::ptr::eq(self.instance, other.instance)
+    }
+}
+
+impl Eq for LoadedSchema<'_> {}
+
+impl core::fmt::Debug for LoadedSchema<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::result::Result<(), core::fmt::Error> {
+        write!(
+            f,
+            "LoadedSchema(@{:#x}, {:?})",
+            self.instance.node.id, self.instance as *const _
+        )
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn not_loaded(id: u64) -> Error {
+    Error::failed(format!("no node with ID @{id:#x} has been loaded"))
+}
+
+/// Everything that a loader has loaded.
+///
+/// Nodes, placeholders and instances are boxed and never removed or replaced, so they
+/// stay where they are until the `Shared` is dropped. That is what makes it sound to
+/// hand out references to them that live as long as the `Shared` is borrowed, even
+/// though the maps that hold them are behind locks.
+struct Shared {
+    nodes: Mutex<HashMap<u64, Box<LoadedNode>>>,
+
+    /// Stand-ins for nodes that were referenced before being loaded, or that were
+    /// referenced as a kind of node they are not.
+    placeholders: Mutex<HashMap<(u64, Kind), Box<LoadedNode>>>,
+}
+
+/// Extends the lifetime of a reference to an entry of `Shared` to that of the `Shared`.
+///
+/// # Safety
+/// The entry must be owned by `shared`, through one of the boxes described there.
+unsafe fn pinned<'a, T: ?Sized>(_shared: &'a Shared, entry: &T) -> &'a T {
+    unsafe { &*(entry as *const T) }
+}
+
+impl Shared {
+    fn find(&self, id: u64) -> Option<&LoadedNode> {
+        let nodes = lock(&self.nodes);
+        let node = nodes.get(&id)?;
+        Some(unsafe { pinned(self, &**node) })
+    }
+
+    fn find_or_placeholder(&self, id: u64, kind: Kind) -> Result<&LoadedNode> {
+        if let Some(node) = self.find(id) {
+            if node.kind == kind {
+                return Ok(node);
+            }
+        }
+        let mut placeholders = lock(&self.placeholders);
+        if let Some(node) = placeholders.get(&(id, kind)) {
+            return Ok(unsafe { pinned(self, &**node) });
+        }
+        let mut message = message::Builder::new_default();
+        let mut node = message.init_root::<node::Builder>();
+        node.set_id(id);
+        match kind {
+            Kind::Struct => {
+                node.reborrow().init_struct();
+            }
+            Kind::Enum => {
+                node.reborrow().init_enum();
+            }
+            Kind::Interface => {
+                node.reborrow().init_interface();
+            }
+            _ => unreachable!(),
+        }
+        let placeholder = placeholders
+            .entry((id, kind))
+            .or_insert(LoadedNode::new(encode_node(node.into_reader())?)?);
+        Ok(unsafe { pinned(self, &**placeholder) })
+    }
+
+    /// Gets the unique instance of `node` with the given bindings.
+    fn instance<'a>(
+        &'a self,
+        node: &'a LoadedNode,
+        mut bindings: Vec<ScopeBindings<'a>>,
+    ) -> LoadedSchema<'a> {
+        // Normalize, so that e.g. `Foo` and `Foo(AnyPointer)` are the same type.
+        let any_pointer: Type = TypeVariant::AnyPointer.into();
+        bindings.retain(|scope| scope.params.iter().any(|&p| p != any_pointer));
+        bindings.sort_by_key(|scope| scope.scope_id);
+        bindings.dedup_by_key(|scope| scope.scope_id);
+
+        let mut instances = lock(&node.instances);
+        let instance = match instances.iter().find(|i| i.bindings == bindings) {
+            Some(instance) => instance,
+            None => {
+                let instance = Instance {
+                    shared: self,
+                    node,
+                    bindings,
+                };
+                // The instance is owned by `node`, which is owned by `self`, so it is
+                // only ever used while `self` is borrowed. See `LoadedNode::instances`.
+                let instance =
+                    unsafe { core::mem::transmute::<Instance<'a>, Instance<'static>>(instance) };
+                instances.push(Box::new(instance));
+                instances.last().unwrap()
+            }
+        };
+        LoadedSchema {
+            instance: unsafe { pinned(self, &**instance) },
+        }
+    }
+
+    fn resolve_type<'a>(
+        &'a self,
+        ty: type_::Reader,
+        context: &[ScopeBindings<'a>],
+    ) -> Result<Type<'a>> {
+        Ok(match ty.which()? {
+            type_::Void(()) => TypeVariant::Void.into(),
+            type_::Bool(()) => TypeVariant::Bool.into(),
+            type_::Int8(()) => TypeVariant::Int8.into(),
+            type_::Int16(()) => TypeVariant::Int16.into(),
+            type_::Int32(()) => TypeVariant::Int32.into(),
+            type_::Int64(()) => TypeVariant::Int64.into(),
+            type_::Uint8(()) => TypeVariant::UInt8.into(),
+            type_::Uint16(()) => TypeVariant::UInt16.into(),
+            type_::Uint32(()) => TypeVariant::UInt32.into(),
+            type_::Uint64(()) => TypeVariant::UInt64.into(),
+            type_::Float32(()) => TypeVariant::Float32.into(),
+            type_::Float64(()) => TypeVariant::Float64.into(),
+            type_::Text(()) => TypeVariant::Text.into(),
+            type_::Data(()) => TypeVariant::Data.into(),
+            type_::List(l) => Type::list_of(self.resolve_type(l.get_element_type()?, context)?),
+            type_::Enum(e) => {
+                let node = self.find_or_placeholder(e.get_type_id(), Kind::Enum)?;
+                let bindings = self.resolve_brand(e.get_brand()?, context)?;
+                TypeVariant::Enum(EnumType::Loaded(self.instance(node, bindings))).into()
+            }
+            type_::Struct(s) => {
+                let node = self.find_or_placeholder(s.get_type_id(), Kind::Struct)?;
+                let bindings = self.resolve_brand(s.get_brand()?, context)?;
+                TypeVariant::Struct(StructType::Loaded(self.instance(node, bindings))).into()
+            }
+            type_::Interface(i) => {
+                let node = self.find_or_placeholder(i.get_type_id(), Kind::Interface)?;
+                let bindings = self.resolve_brand(i.get_brand()?, context)?;
+                TypeVariant::Capability(InterfaceType::Loaded(self.instance(node, bindings))).into()
+            }
+            type_::AnyPointer(a) => match a.which()? {
+                type_::any_pointer::Parameter(p) => context
+                    .iter()
+                    .find(|scope| scope.scope_id == p.get_scope_id())
+                    .and_then(|scope| scope.params.get(p.get_parameter_index() as usize))
+                    .copied()
+                    .unwrap_or_else(|| TypeVariant::AnyPointer.into()),
+                type_::any_pointer::Unconstrained(_)
+                | type_::any_pointer::ImplicitMethodParameter(_) => TypeVariant::AnyPointer.into(),
+            },
+        })
+    }
+
+    /// Computes the bindings described by `brand`, whose types may refer to
+    /// the parameters bound in `context`.
+    fn resolve_brand<'a>(
+        &'a self,
+        brand: brand::Reader,
+        context: &[ScopeBindings<'a>],
+    ) -> Result<Vec<ScopeBindings<'a>>> {
+        let mut result = Vec::new();
+        for scope in brand.get_scopes()? {
+            let scope_id = scope.get_scope_id();
+            match scope.which()? {
+                brand::scope::Bind(bindings) => {
+                    let mut params = Vec::new();
+                    for binding in bindings? {
+                        let ty = match binding.which()? {
+                            brand::binding::Unbound(()) => TypeVariant::AnyPointer.into(),
+                            brand::binding::Type(ty) => self.resolve_type(ty?, context)?,
+                        };
+                        if !ty.is_pointer_type() {
+                            return Err(Error::failed(format!(
+                                "parameter of scope @{scope_id:#x} is bound to a non-pointer type"
+                            )));
+                        }
+                        params.push(ty);
+                    }
+                    result.push(ScopeBindings { scope_id, params });
+                }
+                brand::scope::Inherit(()) => {
+                    if let Some(inherited) = context.iter().find(|s| s.scope_id == scope_id) {
+                        result.push(inherited.clone());
+                    }
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    /// Gets the type of the value of `annotation`, applied in `context`.
+    fn annotation_type<'a>(
+        &'a self,
+        annotation: annotation::Reader,
+        context: &[ScopeBindings<'a>],
+    ) -> Result<Type<'a>> {
+        let Some(decl) = self.find(annotation.get_id()) else {
+            return Ok(TypeVariant::AnyPointer.into());
+        };
+        let node::Annotation(a) = decl.proto().which()? else {
+            return Err(Error::failed(format!(
+                "node @{:#x} is not an annotation",
+                annotation.get_id()
+            )));
+        };
+        let bindings = self.resolve_brand(annotation.get_brand()?, context)?;
+        self.resolve_type(a.get_type()?, &bindings)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+enum Kind {
+    File,
+    Struct,
+    Enum,
+    Interface,
+    Const,
+    Annotation,
+}
+
+impl Kind {
+    fn describe(self) -> &'static str {
+        match self {
+            Self::File => "a file",
+            Self::Struct => "a struct",
+            Self::Enum => "an enum",
+            Self::Interface => "an interface",
+            Self::Const => "a constant",
+            Self::Annotation => "an annotation",
+        }
+    }
+}
+
+struct LoadedNode {
+    id: u64,
+    kind: Kind,
+
+    /// The node, as a single segment message.
+    encoded_node: Box<[Word]>,
+
+    /// The member indices, as in `RawStructSchema`, if the node is a struct.
+    /// For an interface, `members_by_name` holds the method ordinals sorted by name.
+    nonunion_members: Box<[u16]>,
+    members_by_discriminant: Box<[u16]>,
+    members_by_name: Box<[u16]>,
+
+    /// Branded instances of this node that have been handed out. They point into the
+    /// `Shared` that owns this node, so their real lifetime is that of the `Shared`,
+    /// which cannot be named here. Each is boxed so that it stays put when the vector grows.
+    #[allow(clippy::vec_box)]
+    instances: Mutex<Vec<Box<Instance<'static>>>>,
+}
+
+impl LoadedNode {
+    /// Validates a node encoded by `encode_node()`.
+    fn new(words: Vec<Word>) -> Result<Box<Self>> {
+        let encoded_node = words.into_boxed_slice();
+        let proto = read_node(&encoded_node);
+        let mut nonunion_members = Vec::new();
+        let mut members_by_discriminant = Vec::new();
+        let mut members_by_name = Vec::new();
+        let kind = match proto.which()? {
+            node::File(()) => Kind::File,
+            node::Struct(st) => {
+                check_struct(st)?;
+                let fields = st.get_fields()?;
+                let mut by_discriminant = vec![None; st.get_discriminant_count() as usize];
+                let mut names = Vec::new();
+                for (index, field) in fields.iter().enumerate() {
+                    let index = index as u16;
+                    let discriminant = field.get_discriminant_value();
+                    if discriminant == field::NO_DISCRIMINANT {
+                        nonunion_members.push(index);
+                    } else if let Some(slot @ None) = by_discriminant.get_mut(discriminant as usize)
+                    {
+                        *slot = Some(index);
+                    } else {
+                        return Err(Error::failed(format!(
+                            "invalid discriminant value {discriminant} in struct @{:#x}",
+                            proto.get_id()
+                        )));
+                    }
+                    names.push((field.get_name()?.as_bytes(), index));
+                }
+                members_by_discriminant = by_discriminant
+                    .into_iter()
+                    .collect::<Option<Vec<u16>>>()
+                    .ok_or_else(|| {
+                        Error::failed(format!(
+                            "missing discriminant values in struct @{:#x}",
+                            proto.get_id()
+                        ))
+                    })?;
+                names.sort_unstable();
+                members_by_name = names.into_iter().map(|(_, index)| index).collect();
+                Kind::Struct
+            }
+            node::Enum(en) => {
+                for enumerant in en.get_enumerants()? {
+                    check_annotations(enumerant.get_annotations()?)?;
+                }
+                Kind::Enum
+            }
+            node::Interface(interface) => {
+                let methods = interface.get_methods()?;
+                if methods.len() > u32::from(u16::MAX) {
+                    return Err(Error::failed("interface has too many methods".into()));
+                }
+                let mut names = Vec::new();
+                for (index, method) in methods.iter().enumerate() {
+                    check_brand(method.get_param_brand()?)?;
+                    check_brand(method.get_result_brand()?)?;
+                    check_annotations(method.get_annotations()?)?;
+                    names.push((method.get_name()?.as_bytes(), index as u16));
+                }
+                for superclass in interface.get_superclasses()? {
+                    check_brand(superclass.get_brand()?)?;
+                }
+                names.sort_unstable();
+                members_by_name = names.into_iter().map(|(_, index)| index).collect();
+                Kind::Interface
+            }
+            node::Const(c) => {
+                check_type(c.get_type()?)?;
+                Kind::Const
+            }
+            node::Annotation(a) => {
+                check_type(a.get_type()?)?;
+                Kind::Annotation
+            }
+        };
+        check_annotations(proto.get_annotations()?)?;
+        let id = proto.get_id();
+        Ok(Box::new(Self {
+            id,
+            kind,
+            encoded_node,
+            nonunion_members: nonunion_members.into_boxed_slice(),
+            members_by_discriminant: members_by_discriminant.into_boxed_slice(),
+            members_by_name: members_by_name.into_boxed_slice(),
+            instances: Mutex::new(Vec::new()),
+        }))
+    }
+
+    fn proto(&self) -> node::Reader<'_> {
+        read_node(&self.encoded_node)
+    }
+}
+
+/// The bindings of the type parameters of one scope.
+#[derive(Clone, PartialEq)]
+struct ScopeBindings<'a> {
+    scope_id: u64,
+    params: Vec<Type<'a>>,
+}
+
+/// A node together with the bindings of the type parameters that are in scope for it.
+struct Instance<'a> {
+    shared: &'a Shared,
+    node: &'a LoadedNode,
+    bindings: Vec<ScopeBindings<'a>>,
+}
+
+impl<'a> Instance<'a> {
+    fn field_type(&self, index: u16) -> Result<Type<'a>> {
+        let node::Struct(st) = self.node.proto().which()? else {
+            return Err(Error::from_kind(ErrorKind::NotAStruct));
+        };
+        match st.get_fields()?.get(u32::from(index)).which()? {
+            field::Slot(slot) => self.shared.resolve_type(slot.get_type()?, &self.bindings),
+            field::Group(group) => {
+                let group_node = self
+                    .shared
+                    .find_or_placeholder(group.get_type_id(), Kind::Struct)?;
+                let node::Struct(group_st) = group_node.proto().which()? else {
+                    return Err(Error::from_kind(ErrorKind::NotAStruct));
+                };
+                // A group is accessed through its parent's struct pointer,
+                // so its layout has to fit in the parent's.
+                if group_st.get_data_word_count() > st.get_data_word_count()
+                    || group_st.get_pointer_count() > st.get_pointer_count()
+                {
+                    return Err(Error::failed(format!(
+                        "group @{:#x} is larger than its parent",
+                        group_node.id
+                    )));
+                }
+                let group = self.shared.instance(group_node, self.bindings.clone());
+                Ok(TypeVariant::Struct(StructType::Loaded(group)).into())
+            }
+        }
+    }
+
+    fn method_types(&self, ordinal: u16) -> Result<(Type<'a>, Type<'a>)> {
+        let node::Interface(interface) = self.node.proto().which()? else {
+            return Err(Error::failed("not an interface".into()));
+        };
+        let method = interface.get_methods()?.get(u32::from(ordinal));
+        let params = self.struct_type(method.get_param_struct_type(), method.get_param_brand()?)?;
+        let results =
+            self.struct_type(method.get_result_struct_type(), method.get_result_brand()?)?;
+        Ok((params, results))
+    }
+
+    fn superclass_type(&self, index: u32) -> Result<Type<'a>> {
+        let node::Interface(interface) = self.node.proto().which()? else {
+            return Err(Error::failed("not an interface".into()));
+        };
+        let superclass = interface.get_superclasses()?.get(index);
+        let node = self
+            .shared
+            .find_or_placeholder(superclass.get_id(), Kind::Interface)?;
+        let bindings = self.branded(superclass.get_brand()?);
+        let instance = self.shared.instance(node, bindings);
+        Ok(TypeVariant::Capability(InterfaceType::Loaded(instance)).into())
+    }
+
+    /// Gets the params or results struct of a method.
+    fn struct_type(&self, id: u64, brand: brand::Reader) -> Result<Type<'a>> {
+        let node = self.shared.find_or_placeholder(id, Kind::Struct)?;
+        let instance = self.shared.instance(node, self.branded(brand));
+        Ok(TypeVariant::Struct(StructType::Loaded(instance)).into())
+    }
+
+    /// Resolves a brand found in this node. If it binds a parameter to something
+    /// other than a pointer, the brand is ignored and all parameters are `AnyPointer`.
+    fn branded(&self, brand: brand::Reader) -> Vec<ScopeBindings<'a>> {
+        self.shared
+            .resolve_brand(brand, &self.bindings)
+            .unwrap_or_default()
+    }
+
+    fn annotation_type(&self, child_index: Option<u16>, index: u32) -> Result<Type<'a>> {
+        let proto = self.node.proto();
+        let annotations = match (child_index, proto.which()?) {
+            (None, _) => proto.get_annotations()?,
+            (Some(i), node::Struct(st)) => st.get_fields()?.get(u32::from(i)).get_annotations()?,
+            (Some(i), node::Enum(en)) => {
+                en.get_enumerants()?.get(u32::from(i)).get_annotations()?
+            }
+            (Some(i), node::Interface(interface)) => interface
+                .get_methods()?
+                .get(u32::from(i))
+                .get_annotations()?,
+            (Some(_), _) => return Err(Error::failed("node has no children".into())),
+        };
+        self.shared
+            .annotation_type(annotations.get(index), &self.bindings)
+    }
+}
+
+/// Copies `node` into a fresh single-segment message, the form in which
+/// generated code embeds its nodes.
+fn encode_node(node: node::Reader) -> Result<Vec<Word>> {
+    let mut message = message::Builder::new_default();
+    message.set_root_canonical(node)?;
+    let segments = message.get_segments_for_output();
+    let mut words = Word::allocate_zeroed_vec(segments[0].len() / BYTES_PER_WORD);
+    Word::words_to_bytes_mut(&mut words).copy_from_slice(segments[0]);
+    Ok(words)
+}
+
+fn read_node(encoded_node: &[Word]) -> node::Reader<'_> {
+    // The words come from `encode_node()`, so they hold a valid message.
+    any_pointer::Reader::new(unsafe {
+        layout::PointerReader::get_root_unchecked(encoded_node.as_ptr() as *const u8)
+    })
+    .get_as()
+    .unwrap()
+}
+
+/// Checks that every field of `st` lies within the struct's data and pointer sections.
+/// Builders write to fields without bounds checks, so this is what keeps a bad schema
+/// from writing outside of the struct.
+fn check_struct(st: node::struct_::Reader) -> Result<()> {
+    let data_bits = u64::from(st.get_data_word_count()) * 64;
+    let pointer_count = u64::from(st.get_pointer_count());
+    let fits = |offset: u32, bits: u64| (u64::from(offset) + 1) * bits <= data_bits;
+    if st.get_discriminant_count() > 0 && !fits(st.get_discriminant_offset(), 16) {
+        return Err(Error::failed(
+            "union discriminant is outside of the struct".into(),
+        ));
+    }
+    let fields = st.get_fields()?;
+    if fields.len() > u32::from(u16::MAX) {
+        return Err(Error::failed("struct has too many fields".into()));
+    }
+    for field in fields {
+        check_annotations(field.get_annotations()?)?;
+        let field::Slot(slot) = field.which()? else {
+            continue;
+        };
+        let ty = slot.get_type()?;
+        check_type(ty)?;
+        let offset = slot.get_offset();
+        let in_bounds = match ty.which()? {
+            type_::Void(()) => true,
+            type_::Bool(()) => fits(offset, 1),
+            type_::Int8(()) | type_::Uint8(()) => fits(offset, 8),
+            type_::Int16(()) | type_::Uint16(()) | type_::Enum(_) => fits(offset, 16),
+            type_::Int32(()) | type_::Uint32(()) | type_::Float32(()) => fits(offset, 32),
+            type_::Int64(()) | type_::Uint64(()) | type_::Float64(()) => fits(offset, 64),
+            type_::Text(())
+            | type_::Data(())
+            | type_::List(_)
+            | type_::Struct(_)
+            | type_::Interface(_)
+            | type_::AnyPointer(_) => u64::from(offset) < pointer_count,
+        };
+        if !in_bounds {
+            return Err(Error::failed(format!(
+                "field {} is outside of the struct",
+                field.get_name()?.to_string()?
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Checks that a type, including any brands in it, can be read.
+fn check_type(ty: type_::Reader) -> Result<()> {
+    match ty.which()? {
+        type_::List(l) => check_type(l.get_element_type()?),
+        type_::Enum(e) => check_brand(e.get_brand()?),
+        type_::Struct(s) => check_brand(s.get_brand()?),
+        type_::Interface(i) => check_brand(i.get_brand()?),
+        type_::AnyPointer(a) => {
+            a.which()?;
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+fn check_brand(brand: brand::Reader) -> Result<()> {
+    for scope in brand.get_scopes()? {
+        if let brand::scope::Bind(bindings) = scope.which()? {
+            for binding in bindings? {
+                if let brand::binding::Type(ty) = binding.which()? {
+                    check_type(ty?)?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn check_annotations(annotations: crate::struct_list::Reader<annotation::Owned>) -> Result<()> {
+    for annotation in annotations {
+        check_brand(annotation.get_brand()?)?;
+    }
+    Ok(())
+}
@@ -28,6 +28,16 @@ pub use no_alloc_buffer_segments::{
     NoAllocBufferSegments, NoAllocSegmentTableInfo, NoAllocSliceSegments,
 };
 
+#[cfg(all(feature = "alloc", feature = "std"))]
+mod seekable_segments;
+#[cfg(all(feature = "alloc", feature = "std"))]
+pub use seekable_segments::{read_message_from_seekable, SeekableSegments};
+
+#[cfg(feature = "alloc")]
+mod streaming;
+#[cfg(feature = "alloc")]
+pub use streaming::{read_message_with_trailer, StreamingBuilder};
+
 use crate::io::{Read, Write};
 
 use crate::message;
@@ -138,6 +148,21 @@ impl<T: core::ops::Deref<Target = [u8]>> BufferSegments<T> {
     }
 }
 
+// Only buffers that cannot change while they are borrowed. A `Mmap` can, but creating one
+// is unsafe, and its caller promises that the file stays the same.
+#[cfg(feature = "alloc")]
+impl message::sealed::Sealed for BufferSegments<&[u8]> {}
+#[cfg(feature = "alloc")]
+impl message::StableSegments for BufferSegments<&[u8]> {}
+#[cfg(feature = "alloc")]
+impl message::sealed::Sealed for BufferSegments<alloc::vec::Vec<u8>> {}
+#[cfg(feature = "alloc")]
+impl message::StableSegments for BufferSegments<alloc::vec::Vec<u8>> {}
+#[cfg(all(feature = "mmap", target_os = "linux"))]
+impl message::sealed::Sealed for BufferSegments<crate::mmap::Mmap> {}
+#[cfg(all(feature = "mmap", target_os = "linux"))]
+impl message::StableSegments for BufferSegments<crate::mmap::Mmap> {}
+
 #[cfg(feature = "alloc")]
 impl<T: core::ops::Deref<Target = [u8]>> message::ReaderSegments for BufferSegments<T> {
     fn get_segment(&self, id: u32) -> Option<&[u8]> {
@@ -157,6 +182,25 @@ impl<T: core::ops::Deref<Target = [u8]>> message::ReaderSegments for BufferSegme
     }
 }
 
+/// Maps the file at `path` into memory and reads the message at its start, without copying.
+/// The segments of the returned reader borrow directly from the mapping, so only the parts
+/// of the file that are actually traversed get paged in. Large files may need a higher
+/// traversal limit than the default one in `options`.
+///
+/// # Safety
+///
+/// The file must not be modified or truncated while the reader is in use; see
+/// [`Mmap::map()`](crate::mmap::Mmap::map).
+#[cfg(all(feature = "mmap", target_os = "linux"))]
+pub unsafe fn read_message_from_mmap(
+    path: impl AsRef<std::path::Path>,
+    options: message::ReaderOptions,
+) -> Result<message::Reader<BufferSegments<crate::mmap::Mmap>>> {
+    let mmap = crate::mmap::Mmap::open(path)?;
+    let segments = BufferSegments::new(mmap, options)?;
+    Ok(message::Reader::new(segments, options))
+}
+
 /// Owned memory containing a message's segments sequentialized in a single contiguous buffer.
 /// The segments are guaranteed to be 8-byte aligned.
 #[cfg(feature = "alloc")]
@@ -183,6 +227,11 @@ impl core::ops::DerefMut for OwnedSegments {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl crate::message::sealed::Sealed for OwnedSegments {}
+#[cfg(feature = "alloc")]
+impl crate::message::StableSegments for OwnedSegments {}
+
 #[cfg(feature = "alloc")]
 impl crate::message::ReaderSegments for OwnedSegments {
     fn get_segment(&self, id: u32) -> Option<&[u8]> {
@@ -311,6 +360,140 @@ where
     )?))
 }
 
+/// Iterator over a stream of back-to-back messages, such as a file that was written by
+/// calling [`write_message()`] repeatedly.
+///
+/// Iteration stops when the stream ends cleanly, that is, right after a message. If it ends
+/// in the middle of a message, the iterator yields an error of kind
+/// [`PrematureEndOfFile`](ErrorKind::PrematureEndOfFile) instead. After any error, the
+/// iterator yields nothing more.
+///
+/// For optimal performance, `read` should be a buffered reader type.
+#[cfg(feature = "alloc")]
+pub struct MessageIter<R> {
+    read: R,
+    options: message::ReaderOptions,
+    done: bool,
+}
+
+#[cfg(feature = "alloc")]
+impl<R: Read> MessageIter<R> {
+    pub fn new(read: R, options: message::ReaderOptions) -> Self {
+        Self {
+            read,
+            options,
+            done: false,
+        }
+    }
+
+    pub fn into_inner(self) -> R {
+        self.read
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<R: Read> Iterator for MessageIter<R> {
+    type Item = Result<message::Reader<OwnedSegments>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let next = read_segment_table(&mut self.read, self.options).and_then(|table| {
+            table
+                .map(|table| {
+                    read_segments(&mut self.read, table.into_owned_segments(), self.options)
+                })
+                .transpose()
+        });
+        match next {
+            Ok(Some(message)) => Some(Ok(message)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(e) => {
+                self.done = true;
+                Some(Err(truncation_error(e)))
+            }
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<R: Read> core::iter::FusedIterator for MessageIter<R> {}
+
+/// Iterator over back-to-back messages in a flat slice of bytes, which reads them without
+/// copying, like [`read_message_from_flat_slice()`].
+///
+/// Iteration stops at the end of the slice. If the slice ends in the middle of a message,
+/// the iterator yields an error of kind [`PrematureEndOfFile`](ErrorKind::PrematureEndOfFile)
+/// instead. After any error, the iterator yields nothing more.
+///
+/// ALIGNMENT: If the "unaligned" feature is enabled, then there are no alignment requirements on `slice`.
+/// Otherwise, `slice` must be 8-byte aligned (attempts to read the messages will trigger errors).
+#[cfg(feature = "alloc")]
+pub struct SliceMessageIter<'a> {
+    slice: &'a [u8],
+    options: message::ReaderOptions,
+    done: bool,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> SliceMessageIter<'a> {
+    pub fn new(slice: &'a [u8], options: message::ReaderOptions) -> Self {
+        Self {
+            slice,
+            options,
+            done: false,
+        }
+    }
+
+    /// The bytes after the last message that was read.
+    pub fn remainder(&self) -> &'a [u8] {
+        self.slice
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> Iterator for SliceMessageIter<'a> {
+    type Item = Result<message::Reader<SliceSegments<'a>>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done || self.slice.is_empty() {
+            return None;
+        }
+        match read_message_from_flat_slice(&mut self.slice, self.options) {
+            Ok(message) => Some(Ok(message)),
+            Err(e) => {
+                self.done = true;
+                Some(Err(truncation_error(e)))
+            }
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::iter::FusedIterator for SliceMessageIter<'_> {}
+
+/// Gives the errors that mean that the input ended in the middle of a message the same kind.
+#[cfg(feature = "alloc")]
+pub(crate) fn truncation_error(mut error: Error) -> Error {
+    match error.kind {
+        ErrorKind::FailedToFillTheWholeBuffer
+        | ErrorKind::MessageEndsPrematurely(..)
+        | ErrorKind::PrematureEndOfPackedInput => {
+            if error.extra.is_empty() {
+                let kind = error.kind;
+                write!(error, "{kind}");
+            }
+            error.kind = ErrorKind::PrematureEndOfFile;
+            error
+        }
+        _ => error,
+    }
+}
+
 /// Like `try_read_message()`, but does not allocate any memory.
 /// Stores the message in `buffer`. Returns a `BufferNotLargeEnough`
 /// error if the buffer is not large enough.
@@ -557,9 +740,7 @@ where
     W: Write,
     A: message::Allocator,
 {
-    let segments = message.get_segments_for_output();
-    write_segment_table(&mut write, &segments)?;
-    write_segments(&mut write, &segments)
+    write_table_and_segments(&mut write, &message.get_segments_for_output())
 }
 
 /// Like `write_message()`, but takes a `ReaderSegments`, allowing it to be
@@ -569,10 +750,72 @@ where
     W: Write,
     R: message::ReaderSegments,
 {
-    write_segment_table_internal(&mut write, segments)?;
-    write_segments(&mut write, segments)
+    write_table_and_segments(&mut write, segments)
+}
+
+/// Writes the segment table and the segments of a message to `write`, with a single
+/// call to `write_all_vectored()`.
+fn write_table_and_segments<W, R>(write: &mut W, segments: &R) -> Result<()>
+where
+    W: Write,
+    R: message::ReaderSegments + ?Sized,
+{
+    // Messages with up to this many segments are written without allocating.
+    const STACK_SEGMENTS: usize = 8;
+
+    let segment_count = segments.len();
+    if segment_count <= STACK_SEGMENTS {
+        let mut table = [0; (STACK_SEGMENTS / 2 + 1) * BYTES_PER_WORD];
+        let table_len = fill_segment_table(&mut table, segments);
+        let mut bufs: [&[u8]; STACK_SEGMENTS + 1] = [&[]; STACK_SEGMENTS + 1];
+        bufs[0] = &table[..table_len];
+        for (i, buf) in bufs[1..=segment_count].iter_mut().enumerate() {
+            *buf = segments.get_segment(i as u32).unwrap();
+        }
+        return write.write_all_vectored(&bufs[..=segment_count]);
+    }
+
+    #[cfg(feature = "alloc")]
+    {
+        let mut table = vec![0; (segment_count / 2 + 1) * BYTES_PER_WORD];
+        fill_segment_table(&mut table, segments);
+        let mut bufs = alloc::vec::Vec::with_capacity(segment_count + 1);
+        bufs.push(&table[..]);
+        for i in 0..segment_count {
+            bufs.push(segments.get_segment(i as u32).unwrap());
+        }
+        write.write_all_vectored(&bufs)
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    {
+        write_segment_table_internal(write, segments)?;
+        write_segments(write, segments)
+    }
+}
+
+/// Fills the start of `buf` with the segment table for `segments`, and returns its length.
+///
+/// `segments` must contain at least one segment, and `buf` must be long enough.
+fn fill_segment_table<R>(buf: &mut [u8], segments: &R) -> usize
+where
+    R: message::ReaderSegments + ?Sized,
+{
+    let segment_count = segments.len();
+    let table_len = (segment_count / 2 + 1) * BYTES_PER_WORD;
+    let table = &mut buf[..table_len];
+    table[0..4].copy_from_slice(&(segment_count as u32 - 1).to_le_bytes());
+    for idx in 0..segment_count {
+        let words = (segments.get_segment(idx as u32).unwrap().len() / BYTES_PER_WORD) as u32;
+        table[4 + idx * 4..8 + idx * 4].copy_from_slice(&words.to_le_bytes());
+    }
+    if segment_count % 2 == 0 {
+        table[table_len - 4..].fill(0);
+    }
+    table_len
 }
 
+#[cfg(test)]
 fn write_segment_table<W>(write: &mut W, segments: &[&[u8]]) -> Result<()>
 where
     W: Write,
@@ -642,6 +885,7 @@ where
 }
 
 /// Writes segments to `write`.
+#[cfg(any(test, not(feature = "alloc")))]
 fn write_segments<W, R: message::ReaderSegments + ?Sized>(write: &mut W, segments: &R) -> Result<()>
 where
     W: Write,
@@ -656,7 +900,7 @@ where
     Ok(())
 }
 
-fn compute_serialized_size<R: message::ReaderSegments + ?Sized>(segments: &R) -> usize {
+pub(crate) fn compute_serialized_size<R: message::ReaderSegments + ?Sized>(segments: &R) -> usize {
     // Table size
     let len = segments.len();
     let mut size = (len / 2) + 1;
@@ -1010,6 +1254,48 @@ pub mod test {
         }
     }
 
+    /// A writer that accepts at most `max` bytes per call, which may span several buffers.
+    #[cfg(feature = "std")]
+    struct ShortWriter {
+        bytes: alloc::vec::Vec<u8>,
+        max: usize,
+    }
+
+    #[cfg(feature = "std")]
+    impl std::io::Write for ShortWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.write_vectored(&[std::io::IoSlice::new(buf)])
+        }
+
+        fn write_vectored(&mut self, bufs: &[std::io::IoSlice<'_>]) -> std::io::Result<usize> {
+            let mut n = 0;
+            for buf in bufs {
+                let len = buf.len().min(self.max - n);
+                self.bytes.extend_from_slice(&buf[..len]);
+                n += len;
+            }
+            Ok(n)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "std")]
+    quickcheck! {
+        #[cfg_attr(miri, ignore)] // miri takes a long time with quickcheck
+        fn test_vectored_write(segments: alloc::vec::Vec<alloc::vec::Vec<crate::Word>>, max: u8) -> TestResult {
+            if segments.is_empty() || max == 0 { return TestResult::discard(); }
+            let borrowed_segments: &[&[u8]] = &segments.iter()
+                .map(|segment| crate::Word::words_to_bytes(&segment[..]))
+                .collect::<alloc::vec::Vec<_>>()[..];
+            let mut write = ShortWriter { bytes: vec![], max: max.into() };
+            super::write_message_segments(&mut write, &message::SegmentArray::new(borrowed_segments)).unwrap();
+            TestResult::from_bool(write.bytes == flatten_segments(borrowed_segments))
+        }
+    }
+
     #[test]
     fn read_message_from_flat_slice_with_remainder() {
         let segments = vec![
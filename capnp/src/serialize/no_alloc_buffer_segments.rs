@@ -170,6 +170,9 @@ impl<T: AsRef<[u8]>> NoAllocBufferSegments<T> {
     }
 }
 
+impl<'b> crate::message::sealed::Sealed for NoAllocSliceSegments<'b> {}
+impl<'b> crate::message::StableSegments for NoAllocSliceSegments<'b> {}
+
 impl<T: AsRef<[u8]>> ReaderSegments for NoAllocBufferSegments<T> {
     fn get_segment(&self, idx: u32) -> Option<&[u8]> {
         // panic safety: we are doing a lot of `unwrap` here. We assume that underlying message slice
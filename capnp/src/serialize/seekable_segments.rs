@@ -0,0 +1,149 @@
+use core::cell::RefCell;
+use std::io::{Read, Seek, SeekFrom};
+
+use crate::message::{self, ReaderSegments};
+use crate::private::units::BYTES_PER_WORD;
+use crate::{Error, ErrorKind, Result, Word};
+
+use super::SegmentLengthsBuilder;
+
+/// Segments of a message in a seekable stream, which are read only when they are first
+/// accessed, and then kept in memory.
+///
+/// Only the segment table is read up front, so looking at a few fields of a huge message
+/// costs no more than reading the segments that they are in. Because nothing is loaded
+/// before it is needed, the number and size of segments is only limited by the length
+/// of the stream, not by [`SEGMENTS_COUNT_LIMIT`](super::SEGMENTS_COUNT_LIMIT) or the
+/// traversal limit.
+///
+/// [`ReaderSegments::get_segment()`] cannot fail with an error, so if reading a segment
+/// fails, the segment is reported as missing, and the message reader returns an error
+/// about an invalid pointer. The I/O error can be retrieved with
+/// [`take_error()`](Self::take_error).
+pub struct SeekableSegments<R> {
+    read: RefCell<R>,
+
+    // Position in the stream where the first segment starts.
+    segments_start: u64,
+
+    // (starting index (in words), ending index (in words)) of each segment,
+    // relative to `segments_start`.
+    segment_indices: Vec<(usize, usize)>,
+
+    // Segments that have been read so far. Once set, an entry is never changed,
+    // so references into the boxed words live as long as `self`.
+    loaded: RefCell<Vec<Option<Box<[Word]>>>>,
+
+    error: RefCell<Option<Error>>,
+}
+
+impl<R: Read + Seek> SeekableSegments<R> {
+    /// Reads the segment table of the message that starts at the current position of `read`.
+    pub fn new(mut read: R) -> Result<Self> {
+        let start = read.stream_position()?;
+        let end = read.seek(SeekFrom::End(0))?;
+        read.seek(SeekFrom::Start(start))?;
+
+        let mut buf = [0; 4];
+        read.read_exact(&mut buf)?;
+        let segment_count = u64::from(u32::from_le_bytes(buf)) + 1;
+        let table_bytes = (4 + 4 * segment_count + 7) & !7;
+        if segment_count > u64::from(u32::MAX) || start + table_bytes > end {
+            return Err(Error::from_kind(ErrorKind::InvalidNumberOfSegments(
+                segment_count as usize,
+            )));
+        }
+        let segment_count = segment_count as usize;
+
+        let mut table = vec![0; table_bytes as usize - 4];
+        read.read_exact(&mut table)?;
+        let mut segment_lengths_builder = SegmentLengthsBuilder::with_capacity(segment_count);
+        for length in table.chunks_exact(4).take(segment_count) {
+            segment_lengths_builder
+                .try_push_segment(u32::from_le_bytes(length.try_into().unwrap()) as usize)?;
+        }
+
+        let segments_start = start + table_bytes;
+        let total_bytes = segment_lengths_builder.total_words() as u64 * BYTES_PER_WORD as u64;
+        if segments_start + total_bytes > end {
+            return Err(Error::from_kind(ErrorKind::PrematureEndOfFile));
+        }
+
+        let mut loaded = Vec::new();
+        loaded.resize_with(segment_count, || None);
+        Ok(Self {
+            read: RefCell::new(read),
+            segments_start,
+            segment_indices: segment_lengths_builder.to_segment_indices(),
+            loaded: RefCell::new(loaded),
+            error: RefCell::new(None),
+        })
+    }
+
+    /// The number of segments that have been read from the stream so far.
+    pub fn loaded_segment_count(&self) -> usize {
+        self.loaded.borrow().iter().filter(|s| s.is_some()).count()
+    }
+
+    /// Returns the error that occurred the last time that a segment could not be read, if any.
+    pub fn take_error(&self) -> Option<Error> {
+        self.error.borrow_mut().take()
+    }
+
+    /// Returns the stream. Its position is unspecified.
+    pub fn into_inner(self) -> R {
+        self.read.into_inner()
+    }
+
+    fn load(&self, id: usize) -> Result<Box<[Word]>> {
+        let (a, b) = self.segment_indices[id];
+        let mut words = Word::allocate_zeroed_vec(b - a).into_boxed_slice();
+        let mut read = self.read.borrow_mut();
+        read.seek(SeekFrom::Start(
+            self.segments_start + (a * BYTES_PER_WORD) as u64,
+        ))?;
+        read.read_exact(Word::words_to_bytes_mut(&mut words))?;
+        Ok(words)
+    }
+}
+
+impl<R: Read + Seek> ReaderSegments for SeekableSegments<R> {
+    fn get_segment(&self, id: u32) -> Option<&[u8]> {
+        let id = id as usize;
+        if id >= self.segment_indices.len() {
+            return None;
+        }
+        let mut loaded = self.loaded.borrow_mut();
+        if loaded[id].is_none() {
+            match self.load(id) {
+                Ok(words) => loaded[id] = Some(words),
+                Err(e) => {
+                    *self.error.borrow_mut() = Some(e);
+                    return None;
+                }
+            }
+        }
+        let words: &[Word] = loaded[id].as_deref().unwrap();
+        // SAFETY: the boxed slice is not dropped or moved until `self` is, see `loaded`.
+        let words: &[Word] = unsafe { core::slice::from_raw_parts(words.as_ptr(), words.len()) };
+        Some(Word::words_to_bytes(words))
+    }
+
+    fn len(&self) -> usize {
+        self.segment_indices.len()
+    }
+}
+
+/// Reads the segment table of a message from a seekable stream, and returns a reader that
+/// loads the segments lazily. See [`SeekableSegments`].
+///
+/// `read` does not need to be buffered, because segments are read with one call each.
+pub fn read_message_from_seekable<R>(
+    read: R,
+    options: message::ReaderOptions,
+) -> Result<message::Reader<SeekableSegments<R>>>
+where
+    R: Read + Seek,
+{
+    Ok(message::Reader::new(SeekableSegments::new(read)?, options))
+}
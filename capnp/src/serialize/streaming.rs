@@ -0,0 +1,248 @@
+use alloc::vec::Vec;
+
+use crate::io::Write;
+use crate::message::{self, AllocationStrategy, Allocator, HeapAllocator};
+use crate::private::units::BYTES_PER_WORD;
+use crate::traits::{FromPointerBuilder, Owned, SetterInput};
+use crate::{any_pointer, Error, ErrorKind, Result};
+
+use super::BufferSegments;
+
+// Ends every message in the trailer framing.
+const TRAILER_MAGIC: [u8; 4] = *b"cpnT";
+
+// Each segment has an entry in the trailer: its offset in words (u64), its length in
+// words (u32), and four unused bytes.
+const ENTRY_BYTES: usize = 16;
+
+/// Builds a message whose segments can be written out, and their memory freed, while the
+/// rest of the message is still being built, so that a very large message never has to be
+/// in memory all at once.
+///
+/// Because segments are written out of order, the message uses a trailer framing instead of
+/// the standard one: the segments come first, in the order that they were flushed, followed
+/// by a table with the offset (in words, from the start of the message) and length of each
+/// segment, in order of segment id, followed by the number of segments and a magic number.
+/// All numbers are little-endian, and every entry in the table takes two words. Use
+/// [`read_message_with_trailer()`] to read the message.
+///
+/// The first segment, which holds the root, is never flushed before `finish()`, so a
+/// typical use allocates the objects that will be modified until the end, like the lists
+/// that the records of an export go into, right after the root, and makes the first segment
+/// big enough for them.
+pub struct StreamingBuilder<W, A = HeapAllocator>
+where
+    W: Write,
+    A: Allocator,
+{
+    message: message::Builder<A>,
+    write: W,
+
+    // Number of words written to `write` so far.
+    position: u64,
+
+    // Where each flushed segment was written, as (offset in words, length in words),
+    // by segment id.
+    flushed: Vec<Option<(u64, u32)>>,
+
+    // Set when a write fails. The writer may have taken part of the bytes, so the
+    // stream cannot be continued.
+    write_failed: bool,
+}
+
+impl<W> StreamingBuilder<W, HeapAllocator>
+where
+    W: Write,
+{
+    /// Creates a builder whose segments all have the same size (unless an object needs a
+    /// bigger one), so that they can be flushed regularly.
+    pub fn new_default(write: W) -> Self {
+        Self::new(
+            write,
+            HeapAllocator::new().allocation_strategy(AllocationStrategy::FixedSize),
+        )
+    }
+}
+
+impl<W, A> StreamingBuilder<W, A>
+where
+    W: Write,
+    A: Allocator,
+{
+    pub fn new(write: W, allocator: A) -> Self {
+        Self {
+            message: message::Builder::new(allocator),
+            write,
+            position: 0,
+            flushed: Vec::new(),
+            write_failed: false,
+        }
+    }
+
+    pub fn init_root<'a, T: FromPointerBuilder<'a>>(&'a mut self) -> T {
+        self.message.init_root()
+    }
+
+    pub fn get_root<'a, T: FromPointerBuilder<'a>>(&'a mut self) -> Result<T> {
+        self.message.get_root()
+    }
+
+    pub fn set_root<T: Owned>(&mut self, value: impl SetterInput<T>) -> Result<()> {
+        self.message.set_root(value)
+    }
+
+    /// Writes every segment other than the first one and the last one to the writer, and
+    /// frees its memory. The last segment is where objects are currently being allocated.
+    ///
+    /// If writing fails, the output is left incomplete, and this and `finish()` return
+    /// errors from then on.
+    ///
+    /// # Safety
+    /// Afterwards, nothing that was allocated before this call may be read or modified,
+    /// except for objects in the first segment. Objects in flushed segments are gone, and
+    /// accessing them is a use after free.
+    pub unsafe fn flush_completed(&mut self) -> Result<()> {
+        self.check_not_failed()?;
+        let count = self.message.segment_count();
+        for id in 1..count.saturating_sub(1) {
+            if self.is_flushed(id) {
+                continue;
+            }
+            self.write_segment(id)?;
+            unsafe {
+                self.message.release_segment(id as u32);
+            }
+        }
+        Ok(())
+    }
+
+    /// The number of bytes written to the writer so far.
+    pub fn bytes_written(&self) -> u64 {
+        self.position * BYTES_PER_WORD as u64
+    }
+
+    /// Writes the remaining segments and the trailer, and returns the writer.
+    pub fn finish(mut self) -> Result<W> {
+        self.check_not_failed()?;
+        // Make sure that there is a first segment, with a root pointer.
+        self.message.get_root::<any_pointer::Builder>()?;
+        let count = self.message.segment_count();
+        for id in 0..count {
+            if !self.is_flushed(id) {
+                self.write_segment(id)?;
+            }
+        }
+        let mut trailer = Vec::with_capacity(count * ENTRY_BYTES + BYTES_PER_WORD);
+        for entry in &self.flushed {
+            let (offset, len) = entry.expect("all segments were written");
+            trailer.extend_from_slice(&offset.to_le_bytes());
+            trailer.extend_from_slice(&len.to_le_bytes());
+            trailer.extend_from_slice(&[0; 4]);
+        }
+        trailer.extend_from_slice(&(count as u32).to_le_bytes());
+        trailer.extend_from_slice(&TRAILER_MAGIC);
+        self.write.write_all(&trailer)?;
+        Ok(self.write)
+    }
+
+    fn check_not_failed(&self) -> Result<()> {
+        if self.write_failed {
+            let mut error = Error::from_kind(ErrorKind::Failed);
+            write!(
+                error,
+                "an earlier write failed, so the output is incomplete"
+            );
+            return Err(error);
+        }
+        Ok(())
+    }
+
+    fn is_flushed(&self, id: usize) -> bool {
+        matches!(self.flushed.get(id), Some(Some(_)))
+    }
+
+    fn write_segment(&mut self, id: usize) -> Result<()> {
+        let segment = self
+            .message
+            .get_segment_for_output(id as u32)
+            .expect("segment is not released before it is written");
+        if let Err(e) = self.write.write_all(segment) {
+            self.write_failed = true;
+            return Err(e);
+        }
+        let len = (segment.len() / BYTES_PER_WORD) as u32;
+        if self.flushed.len() <= id {
+            self.flushed.resize(id + 1, None);
+        }
+        self.flushed[id] = Some((self.position, len));
+        self.position += u64::from(len);
+        Ok(())
+    }
+}
+
+fn invalid_trailer(reason: &str) -> Error {
+    let mut error = Error::from_kind(ErrorKind::InvalidMessageTrailer);
+    write!(error, "{reason}");
+    error
+}
+
+/// Reads a message written by a [`StreamingBuilder`] from `buffer`, without copying. The
+/// message must take up the whole buffer.
+///
+/// ALIGNMENT: If the "unaligned" feature is enabled, then there are no alignment requirements
+/// on `buffer`. Otherwise, `buffer` must be 8-byte aligned (attempts to read the message will
+/// trigger errors).
+pub fn read_message_with_trailer<T>(
+    buffer: T,
+    options: message::ReaderOptions,
+) -> Result<message::Reader<BufferSegments<T>>>
+where
+    T: core::ops::Deref<Target = [u8]>,
+{
+    let len = buffer.len();
+    if len < BYTES_PER_WORD || len % BYTES_PER_WORD != 0 {
+        return Err(invalid_trailer("message is not a whole number of words"));
+    }
+    let footer = &buffer[len - BYTES_PER_WORD..];
+    if footer[4..] != TRAILER_MAGIC {
+        return Err(invalid_trailer("missing magic number"));
+    }
+    let segment_count = u32::from_le_bytes(footer[..4].try_into().unwrap()) as usize;
+    if segment_count == 0 {
+        return Err(Error::from_kind(ErrorKind::InvalidNumberOfSegments(0)));
+    }
+    let table_start = segment_count
+        .checked_mul(ENTRY_BYTES)
+        .and_then(|table_len| (len - BYTES_PER_WORD).checked_sub(table_len))
+        .ok_or_else(|| invalid_trailer("segment table is longer than the message"))?;
+    let segments_words = (table_start / BYTES_PER_WORD) as u64;
+
+    let mut segment_indices = Vec::with_capacity(segment_count);
+    let mut total_words = 0u64;
+    for entry in buffer[table_start..len - BYTES_PER_WORD].chunks_exact(ENTRY_BYTES) {
+        let offset = u64::from_le_bytes(entry[..8].try_into().unwrap());
+        let len = u64::from(u32::from_le_bytes(entry[8..12].try_into().unwrap()));
+        if offset > segments_words || len > segments_words - offset {
+            return Err(invalid_trailer("segment is out of bounds"));
+        }
+        segment_indices.push((offset as usize, (offset + len) as usize));
+        total_words += len;
+    }
+
+    // As with the standard framing, don't accept a message that could not be traversed
+    // without hitting the traversal limit.
+    if let Some(limit) = options.traversal_limit_in_words {
+        if total_words > limit as u64 {
+            return Err(Error::from_kind(ErrorKind::MessageTooLarge(
+                total_words as usize,
+            )));
+        }
+    }
+
+    let segments = BufferSegments {
+        buffer,
+        segment_table_bytes_len: 0,
+        segment_indices,
+    };
+    Ok(message::Reader::new(segments, options))
+}
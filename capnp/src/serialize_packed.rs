@@ -254,6 +254,52 @@ where
     serialize::try_read_message(packed_read, options)
 }
 
+/// Iterator over a stream of back-to-back packed messages, such as a file that was written
+/// by calling [`write_message()`] repeatedly.
+///
+/// Iteration stops when the stream ends cleanly, that is, right after a message. If it ends
+/// in the middle of a message, the iterator yields an error of kind
+/// [`PrematureEndOfFile`](ErrorKind::PrematureEndOfFile) instead. After any error, the
+/// iterator yields nothing more.
+#[cfg(feature = "alloc")]
+pub struct MessageIter<R>
+where
+    R: BufRead,
+{
+    inner: serialize::MessageIter<PackedRead<R>>,
+}
+
+#[cfg(feature = "alloc")]
+impl<R> MessageIter<R>
+where
+    R: BufRead,
+{
+    pub fn new(read: R, options: message::ReaderOptions) -> Self {
+        Self {
+            inner: serialize::MessageIter::new(PackedRead { inner: read }, options),
+        }
+    }
+
+    pub fn into_inner(self) -> R {
+        self.inner.into_inner().inner
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<R> Iterator for MessageIter<R>
+where
+    R: BufRead,
+{
+    type Item = Result<crate::message::Reader<serialize::OwnedSegments>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<R> core::iter::FusedIterator for MessageIter<R> where R: BufRead {}
+
 /// Like read_message(), but does not allocate.
 /// Stores the message in `buffer`. Returns a `BufferNotLargeEnough`
 /// error if the buffer is not large enough.
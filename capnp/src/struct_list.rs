@@ -28,7 +28,7 @@ use crate::private::layout::{
     InlineComposite, ListBuilder, ListReader, PointerBuilder, PointerReader,
 };
 use crate::traits::{FromPointerBuilder, FromPointerReader, HasStructSize, IndexMove, ListIter};
-use crate::Result;
+use crate::{Error, ErrorKind, Result};
 
 #[derive(Copy, Clone)]
 pub struct Owned<T>
@@ -42,7 +42,7 @@ impl<T> introspect::Introspect for Owned<T>
 where
     T: introspect::Introspect + crate::traits::OwnedStruct,
 {
-    fn introspect() -> introspect::Type {
+    fn introspect() -> introspect::Type<'static> {
         introspect::Type::list_of(T::introspect())
     }
 }
@@ -223,6 +223,12 @@ where
             builder: builder.init_struct_list(size, T::Builder::STRUCT_SIZE),
         }
     }
+    fn try_init_pointer(builder: PointerBuilder<'a>, size: u32) -> Result<Builder<'a, T>> {
+        Ok(Builder {
+            marker: PhantomData,
+            builder: builder.try_init_struct_list(size, T::Builder::STRUCT_SIZE)?,
+        })
+    }
     fn get_from_pointer(
         builder: PointerBuilder<'a>,
         default: Option<&'a [crate::Word]>,
@@ -256,6 +262,150 @@ where
     }
 }
 
+/// A struct list builder that can grow and shrink after it has been created, unlike
+/// [`Builder`], whose length is fixed when it is initialized. Space is managed as for
+/// [`primitive_list::GrowableBuilder`](crate::primitive_list::GrowableBuilder).
+///
+/// A `GrowableBuilder` for a field is usually obtained from
+/// [`Orphanage::get_growable_struct_list()`](crate::orphan::Orphanage::get_growable_struct_list)
+/// and the orphan then adopted into the field.
+pub struct GrowableBuilder<'a, T>
+where
+    T: crate::traits::OwnedStruct,
+{
+    marker: PhantomData<T>,
+    pointer: PointerBuilder<'a>,
+    len: u32,
+
+    /// Words allocated for the list, including its tag.
+    capacity: u32,
+
+    /// Words per element, which for an existing list can be more than `STRUCT_SIZE`.
+    step: u32,
+}
+
+impl<'a, T> GrowableBuilder<'a, T>
+where
+    T: crate::traits::OwnedStruct,
+{
+    /// Creates a builder for the list that `pointer` points to, setting it to an empty list
+    /// if it is null.
+    pub fn new(mut pointer: PointerBuilder<'a>) -> Result<Self> {
+        let len = pointer
+            .reborrow()
+            .get_struct_list(T::Builder::STRUCT_SIZE, None)?
+            .len();
+        let mut capacity = 0;
+        pointer.resize_list(InlineComposite, T::Builder::STRUCT_SIZE, len, &mut capacity)?;
+        let step = pointer
+            .as_reader()
+            .get_list(InlineComposite, None)?
+            .get_step_size_in_bits()
+            / 64;
+        Ok(Self {
+            marker: PhantomData,
+            pointer,
+            len,
+            capacity,
+            step,
+        })
+    }
+
+    fn set_len(&mut self, len: u32) -> Result<()> {
+        self.pointer.resize_list(
+            InlineComposite,
+            T::Builder::STRUCT_SIZE,
+            len,
+            &mut self.capacity,
+        )?;
+        self.len = len;
+        Ok(())
+    }
+
+    pub fn len(&self) -> u32 {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The number of elements the list can hold without being moved.
+    pub fn capacity(&self) -> u32 {
+        match self.step {
+            0 => u32::MAX,
+            step => (self.capacity - 1) / step,
+        }
+    }
+
+    /// The length after adding `additional` elements, or an error if that overflows.
+    fn grown_len(&self, additional: usize) -> Result<u32> {
+        u32::try_from(additional)
+            .ok()
+            .and_then(|additional| self.len.checked_add(additional))
+            .ok_or_else(|| {
+                let mut error = Error::from_kind(ErrorKind::Failed);
+                write!(error, "list cannot grow by {additional} elements");
+                error
+            })
+    }
+
+    /// Makes sure that at least `additional` more elements can be added without moving the list.
+    pub fn reserve(&mut self, additional: u32) -> Result<()> {
+        let len = self.len;
+        self.set_len(self.grown_len(additional as usize)?)?;
+        self.set_len(len)
+    }
+
+    /// Appends a new element, which is zero-initialized, and returns a builder for it.
+    pub fn push(&mut self) -> Result<T::Builder<'_>> {
+        let index = self.len;
+        self.set_len(self.grown_len(1)?)?;
+        Ok(self.as_builder().get(index))
+    }
+
+    /// Shortens the list to `len` elements, zeroing the rest along with everything they
+    /// point to. Does nothing if the list is already shorter. The allocated space stays
+    /// the same.
+    pub fn truncate(&mut self, len: u32) -> Result<()> {
+        if len < self.len {
+            self.set_len(len)?;
+        }
+        Ok(())
+    }
+
+    /// Removes all elements, zeroing them.
+    pub fn clear(&mut self) -> Result<()> {
+        self.truncate(0)
+    }
+
+    /// Gets a fixed-length builder for the current elements.
+    pub fn as_builder(&mut self) -> Builder<'_, T> {
+        Builder {
+            marker: PhantomData,
+            builder: self
+                .pointer
+                .reborrow()
+                .get_struct_list(T::Builder::STRUCT_SIZE, None)
+                .expect("list was checked in new()"),
+        }
+    }
+
+    pub fn into_builder(self) -> Builder<'a, T> {
+        Builder {
+            marker: PhantomData,
+            builder: self
+                .pointer
+                .get_struct_list(T::Builder::STRUCT_SIZE, None)
+                .expect("list was checked in new()"),
+        }
+    }
+
+    pub fn into_reader(self) -> Reader<'a, T> {
+        self.into_builder().into_reader()
+    }
+}
+
 impl<'a, T> crate::traits::SetterInput<Owned<T>> for Reader<'a, T>
 where
     T: crate::traits::OwnedStruct,
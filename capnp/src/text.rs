@@ -26,7 +26,7 @@
 
 use core::str;
 
-use crate::Result;
+use crate::{Error, ErrorKind, Result};
 
 #[derive(Copy, Clone)]
 pub struct Owned(());
@@ -37,7 +37,7 @@ impl crate::traits::Owned for Owned {
 }
 
 impl crate::introspect::Introspect for Owned {
-    fn introspect() -> crate::introspect::Type {
+    fn introspect() -> crate::introspect::Type<'static> {
         crate::introspect::TypeVariant::Text.into()
     }
 }
@@ -294,10 +294,168 @@ impl<'a> core::fmt::Debug for Builder<'a> {
     }
 }
 
+/// A text builder that can grow after it has been created, unlike [`Builder`], whose length
+/// is fixed when it is initialized.
+///
+/// When an append does not fit in the space allocated so far, the text is extended in place
+/// if it is the last object in its segment, and otherwise moved to a new allocation in the
+/// same message with double the capacity. A move leaves the old space behind as zeroed
+/// garbage, as does any spare capacity; [`compact()`](crate::message::Builder::compact)
+/// reclaims both.
+///
+/// A `GrowableBuilder` for a field is usually obtained from
+/// [`Orphanage::get_growable_text()`](crate::orphan::Orphanage::get_growable_text) and the
+/// orphan then adopted into the field.
+pub struct GrowableBuilder<'a> {
+    pointer: crate::private::layout::PointerBuilder<'a>,
+
+    /// Start of the text.
+    ptr: *mut u8,
+
+    /// Does not include the trailing null byte.
+    len: u32,
+
+    /// Words allocated for the text, including the trailing null byte.
+    capacity: u32,
+}
+
+impl<'a> GrowableBuilder<'a> {
+    /// Creates a builder for the text that `pointer` points to, setting it to empty text
+    /// if it is null.
+    pub fn new(mut pointer: crate::private::layout::PointerBuilder<'a>) -> Result<Self> {
+        let len = pointer.reborrow().get_text(None)?.len() as u32;
+        let mut capacity = 0;
+        let ptr = pointer.resize_byte_list(len + 1, &mut capacity)?;
+        Ok(Self {
+            pointer,
+            ptr,
+            len,
+            capacity,
+        })
+    }
+
+    fn set_len(&mut self, len: u32) -> Result<()> {
+        self.ptr = self.pointer.resize_byte_list(len + 1, &mut self.capacity)?;
+        self.len = len;
+        // When shrinking, the new null terminator is the first byte that was cut off.
+        unsafe { *self.ptr.add(len as usize) = 0 };
+        Ok(())
+    }
+
+    /// The text's length, in bytes.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The number of bytes the text can hold without being moved.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.capacity as usize * crate::private::units::BYTES_PER_WORD - 1
+    }
+
+    /// The length after appending `additional` bytes, or an error if the text and its null
+    /// byte would not fit in a list, which holds fewer than 2^29 bytes.
+    fn grown_len(&self, additional: usize) -> Result<u32> {
+        u32::try_from(additional)
+            .ok()
+            .and_then(|additional| self.len.checked_add(additional))
+            .filter(|len| *len < (1 << 29) - 1)
+            .ok_or_else(|| {
+                let mut error = Error::from_kind(ErrorKind::Failed);
+                write!(
+                    error,
+                    "text cannot grow by {additional} bytes past 2^29 bytes"
+                );
+                error
+            })
+    }
+
+    /// Makes sure that at least `additional` more bytes can be appended without moving the text.
+    pub fn reserve(&mut self, additional: usize) -> Result<()> {
+        let len = self.len;
+        self.set_len(self.grown_len(additional)?)?;
+        self.set_len(len)
+    }
+
+    /// Appends `string` to the end of the text.
+    pub fn push_str(&mut self, string: &str) -> Result<()> {
+        let start = self.len as usize;
+        self.set_len(self.grown_len(string.len())?)?;
+        unsafe {
+            core::ptr::copy_nonoverlapping(string.as_ptr(), self.ptr.add(start), string.len());
+        }
+        Ok(())
+    }
+
+    /// Appends `ch` to the end of the text.
+    pub fn push(&mut self, ch: char) -> Result<()> {
+        self.push_str(ch.encode_utf8(&mut [0; 4]))
+    }
+
+    /// Shortens the text to `len` bytes, zeroing the rest. Does nothing if the text is
+    /// already shorter. The allocated space stays the same.
+    pub fn truncate(&mut self, len: usize) -> Result<()> {
+        if len < self.len() {
+            self.set_len(len as u32)?;
+        }
+        Ok(())
+    }
+
+    /// Sets the text to empty, zeroing its bytes.
+    pub fn clear(&mut self) -> Result<()> {
+        self.truncate(0)
+    }
+
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        unsafe { core::slice::from_raw_parts(self.ptr, self.len as usize) }
+    }
+
+    /// Converts to a `str`, returning a error if the data contains invalid utf-8.
+    #[inline]
+    pub fn to_str(&self) -> core::result::Result<&str, core::str::Utf8Error> {
+        str::from_utf8(self.as_bytes())
+    }
+
+    #[inline]
+    pub fn reborrow_as_reader(&self) -> Reader<'_> {
+        Reader(self.as_bytes())
+    }
+
+    #[inline]
+    pub fn into_reader(self) -> Reader<'a> {
+        Reader(unsafe { core::slice::from_raw_parts(self.ptr, self.len as usize) })
+    }
+}
+
+impl<'a> core::fmt::Write for GrowableBuilder<'a> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.push_str(s).map_err(|_| core::fmt::Error)
+    }
+}
+
+impl<'a> core::fmt::Debug for GrowableBuilder<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.reborrow_as_reader().fmt(f)
+    }
+}
+
 impl<'a> crate::traits::FromPointerBuilder<'a> for Builder<'a> {
     fn init_pointer(builder: crate::private::layout::PointerBuilder<'a>, size: u32) -> Builder<'a> {
         builder.init_text(size)
     }
+    fn try_init_pointer(
+        builder: crate::private::layout::PointerBuilder<'a>,
+        size: u32,
+    ) -> Result<Builder<'a>> {
+        builder.try_init_text(size)
+    }
     fn get_from_pointer(
         builder: crate::private::layout::PointerBuilder<'a>,
         default: Option<&'a [crate::Word]>,
@@ -313,8 +471,7 @@ impl<'a> crate::traits::SetterInput<Owned> for Reader<'a> {
         value: Reader<'a>,
         _canonicalize: bool,
     ) -> Result<()> {
-        pointer.set_text(value);
-        Ok(())
+        pointer.try_set_text(value)
     }
 }
 
@@ -327,8 +484,7 @@ impl<T: AsRef<str>> crate::traits::SetterInput<Owned> for T {
         value: T,
         _canonicalize: bool,
     ) -> Result<()> {
-        pointer.set_text(value.as_ref().into());
-        Ok(())
+        pointer.try_set_text(value.as_ref().into())
     }
 }
 
@@ -0,0 +1,651 @@
+//! Parser for the Cap'n Proto text format, i.e. the syntax of struct literals
+//! in schema files, as accepted by `capnp eval` and `capnp encode`.
+//!
+//! ```text
+//! ( name = "Alice",                  # text, with C-style escapes
+//!   id = 0x1f,                       # integers: decimal, hex, or octal
+//!   ratio = -1.5e3,                  # floats, including `inf` and `nan`
+//!   color = blue,                    # enumerants by name
+//!   blob = 0x"de ad be ef",          # data, as hex bytes or as a string
+//!   tags = ["a", "b"],               # lists
+//!   address = (city = "Paris"),      # structs and groups
+//!   empty = void )                   # void, also written as `()`
+//! ```
+//!
+//! Union members are set by assigning to them. Comments start with `#` and run to the end
+//! of the line. The outer parentheses may be omitted. The output of the `Debug`
+//! implementations of readers is valid input.
+
+use alloc::vec::Vec;
+
+use crate::introspect::{Type, TypeVariant};
+use crate::schema::EnumSchema;
+use crate::schema_capnp::field;
+use crate::{dynamic_list, dynamic_struct, dynamic_value};
+use crate::{Error, ErrorKind, Result};
+
+/// Maximum nesting depth of struct and list literals.
+const MAX_NESTING_DEPTH: usize = 64;
+
+/// Parses `input` and writes the fields it assigns into `builder`,
+/// which is usually a freshly initialized struct.
+pub fn parse<'a>(input: &str, builder: impl Into<dynamic_value::Builder<'a>>) -> Result<()> {
+    let dynamic_value::Builder::Struct(builder) = builder.into() else {
+        return Err(Error::from_kind(ErrorKind::NotAStruct));
+    };
+    let mut parser = Parser {
+        input,
+        pos: 0,
+        depth: 0,
+    };
+    let assignments = parser.parse_document()?;
+    Applier { input }.apply_struct(&assignments, builder)
+}
+
+/// A value literal, before it has been matched against a type.
+enum Value<'i> {
+    Void,
+    Bool(bool),
+    Int(i128),
+    Float(f64),
+    Identifier(&'i str),
+    String(Vec<u8>),
+    Data(Vec<u8>),
+    List(Vec<Spanned<'i>>),
+    Struct(Vec<Assignment<'i>>),
+}
+
+/// A value together with the byte offset at which it starts.
+struct Spanned<'i> {
+    value: Value<'i>,
+    pos: usize,
+}
+
+/// A `name = value` pair in a struct literal.
+struct Assignment<'i> {
+    name: &'i str,
+    name_pos: usize,
+    value: Spanned<'i>,
+}
+
+/// Formats an error at the given byte offset of `input` as `line:column: message`.
+fn error_at(input: &str, pos: usize, kind: ErrorKind, message: &str) -> Error {
+    let before = &input[..pos];
+    let line = before.matches('\n').count() + 1;
+    let column = before.chars().rev().take_while(|&c| c != '\n').count() + 1;
+    let mut error = Error::from_kind(kind);
+    write!(error, "{line}:{column}: {message}");
+    error
+}
+
+struct Parser<'i> {
+    input: &'i str,
+    pos: usize,
+    depth: usize,
+}
+
+impl<'i> Parser<'i> {
+    fn error(&self, message: &str) -> Error {
+        error_at(self.input, self.pos, ErrorKind::Failed, message)
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<()> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(self.error(&alloc::format!("expected '{c}'")))
+        }
+    }
+
+    /// Skips whitespace and comments.
+    fn skip_trivia(&mut self) {
+        loop {
+            match self.peek() {
+                Some(c) if c.is_whitespace() => self.pos += c.len_utf8(),
+                Some('#') => while !matches!(self.bump(), None | Some('\n')) {},
+                _ => return,
+            }
+        }
+    }
+
+    fn parse_document(&mut self) -> Result<Vec<Assignment<'i>>> {
+        self.skip_trivia();
+        let assignments = if self.eat('(') {
+            self.parse_assignments(Some(')'))?
+        } else {
+            self.parse_assignments(None)?
+        };
+        self.skip_trivia();
+        if self.pos < self.input.len() {
+            return Err(self.error("unexpected input after end of struct"));
+        }
+        Ok(assignments)
+    }
+
+    /// Parses comma-separated assignments up to and including `terminator`,
+    /// or up to the end of input if `terminator` is `None`.
+    fn parse_assignments(&mut self, terminator: Option<char>) -> Result<Vec<Assignment<'i>>> {
+        let mut assignments = Vec::new();
+        loop {
+            self.skip_trivia();
+            match (self.peek(), terminator) {
+                (None, None) => break,
+                (Some(c), Some(t)) if c == t => {
+                    self.pos += 1;
+                    break;
+                }
+                (None, Some(t)) => return Err(self.error(&alloc::format!("expected '{t}'"))),
+                _ => (),
+            }
+            let name_pos = self.pos;
+            let name = self.parse_identifier()?;
+            self.skip_trivia();
+            self.expect('=')?;
+            let value = self.parse_value()?;
+            assignments.push(Assignment {
+                name,
+                name_pos,
+                value,
+            });
+            self.skip_trivia();
+            if !self.eat(',') {
+                match (self.peek(), terminator) {
+                    (None, None) => break,
+                    (Some(c), Some(t)) if c == t => {
+                        self.pos += 1;
+                        break;
+                    }
+                    _ => return Err(self.error("expected ','")),
+                }
+            }
+        }
+        Ok(assignments)
+    }
+
+    fn parse_identifier(&mut self) -> Result<&'i str> {
+        let start = self.pos;
+        match self.peek() {
+            Some(c) if c.is_ascii_alphabetic() || c == '_' => (),
+            _ => return Err(self.error("expected identifier")),
+        }
+        while let Some(c) = self.peek() {
+            if c.is_ascii_alphanumeric() || c == '_' {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+        Ok(&self.input[start..self.pos])
+    }
+
+    fn enter(&mut self) -> Result<()> {
+        self.depth += 1;
+        if self.depth > MAX_NESTING_DEPTH {
+            Err(error_at(
+                self.input,
+                self.pos,
+                ErrorKind::NestingLimitExceeded,
+                "literal is too deeply nested",
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<Spanned<'i>> {
+        self.skip_trivia();
+        let pos = self.pos;
+        let value = match self.peek() {
+            None => return Err(self.error("expected value")),
+            Some('(') => {
+                self.enter()?;
+                self.pos += 1;
+                let assignments = self.parse_assignments(Some(')'))?;
+                self.depth -= 1;
+                Value::Struct(assignments)
+            }
+            Some('[') => {
+                self.enter()?;
+                self.pos += 1;
+                let elements = self.parse_list()?;
+                self.depth -= 1;
+                Value::List(elements)
+            }
+            Some('"') => Value::String(self.parse_string()?),
+            Some('0') if self.input[self.pos..].starts_with("0x\"") => {
+                self.pos += 2;
+                Value::Data(self.parse_hex_data()?)
+            }
+            Some('-') => {
+                self.pos += 1;
+                self.skip_trivia();
+                match self.peek() {
+                    Some(c) if c.is_ascii_digit() => match self.parse_number()? {
+                        Value::Int(i) => Value::Int(-i),
+                        Value::Float(f) => Value::Float(-f),
+                        _ => unreachable!(),
+                    },
+                    _ if matches!(self.parse_identifier(), Ok("inf" | "infinity")) => {
+                        Value::Float(f64::NEG_INFINITY)
+                    }
+                    _ => return Err(error_at(self.input, pos, ErrorKind::Failed, "invalid '-'")),
+                }
+            }
+            Some(c) if c.is_ascii_digit() => self.parse_number()?,
+            Some(c) if c.is_ascii_alphabetic() || c == '_' => match self.parse_identifier()? {
+                "void" => Value::Void,
+                "true" => Value::Bool(true),
+                "false" => Value::Bool(false),
+                "inf" | "infinity" => Value::Float(f64::INFINITY),
+                "nan" | "NaN" => Value::Float(f64::NAN),
+                identifier => Value::Identifier(identifier),
+            },
+            Some(_) => return Err(self.error("unexpected character")),
+        };
+        Ok(Spanned { value, pos })
+    }
+
+    fn parse_list(&mut self) -> Result<Vec<Spanned<'i>>> {
+        let mut elements = Vec::new();
+        loop {
+            self.skip_trivia();
+            if self.eat(']') {
+                break;
+            }
+            elements.push(self.parse_value()?);
+            self.skip_trivia();
+            if !self.eat(',') {
+                self.expect(']')?;
+                break;
+            }
+        }
+        Ok(elements)
+    }
+
+    fn parse_number(&mut self) -> Result<Value<'i>> {
+        let start = self.pos;
+        let rest = &self.input[start..];
+        let (radix, prefix_len) = if rest.starts_with("0x") || rest.starts_with("0X") {
+            (16, 2)
+        } else if rest.len() > 1 && rest.starts_with('0') && rest.as_bytes()[1].is_ascii_digit() {
+            (8, 1)
+        } else {
+            (10, 0)
+        };
+        self.pos += prefix_len;
+        let digits_start = self.pos;
+        let mut is_float = false;
+        while let Some(c) = self.peek() {
+            if c.is_digit(radix) {
+                self.pos += 1;
+            } else if radix == 10 && (c == '.' || c == 'e' || c == 'E') {
+                is_float = true;
+                self.pos += 1;
+                if (c == 'e' || c == 'E') && matches!(self.peek(), Some('+' | '-')) {
+                    self.pos += 1;
+                }
+            } else {
+                break;
+            }
+        }
+        let digits = &self.input[digits_start..self.pos];
+        let invalid = || error_at(self.input, start, ErrorKind::Failed, "invalid number");
+        if is_float {
+            digits
+                .parse::<f64>()
+                .map(Value::Float)
+                .map_err(|_| invalid())
+        } else {
+            i128::from_str_radix(digits, radix)
+                .map(Value::Int)
+                .map_err(|_| invalid())
+        }
+    }
+
+    fn parse_hex_data(&mut self) -> Result<Vec<u8>> {
+        let start = self.pos;
+        let text = self.parse_string()?;
+        let digits: Vec<u8> = text
+            .into_iter()
+            .filter(|b| !b.is_ascii_whitespace())
+            .collect();
+        if digits.len() % 2 != 0 {
+            return Err(error_at(
+                self.input,
+                start,
+                ErrorKind::Failed,
+                "odd number of hex digits",
+            ));
+        }
+        digits
+            .chunks(2)
+            .map(|pair| {
+                match (
+                    (pair[0] as char).to_digit(16),
+                    (pair[1] as char).to_digit(16),
+                ) {
+                    (Some(hi), Some(lo)) => Ok((hi * 16 + lo) as u8),
+                    _ => Err(error_at(
+                        self.input,
+                        start,
+                        ErrorKind::Failed,
+                        "invalid hex digit",
+                    )),
+                }
+            })
+            .collect()
+    }
+
+    // Escapes can produce any bytes, so whether a string is valid UTF-8 is checked only once it
+    // is known to be text rather than data.
+    fn parse_string(&mut self) -> Result<Vec<u8>> {
+        self.expect('"')?;
+        let mut out = Vec::new();
+        loop {
+            let Some(c) = self.bump() else {
+                return Err(self.error("unterminated string"));
+            };
+            match c {
+                '"' => break,
+                '\\' => self.parse_escape(&mut out)?,
+                '\n' => return Err(self.error("unterminated string")),
+                c => out.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes()),
+            }
+        }
+        Ok(out)
+    }
+
+    fn parse_escape(&mut self, out: &mut Vec<u8>) -> Result<()> {
+        let escape_pos = self.pos - 1;
+        let invalid = |p: &Self| {
+            error_at(
+                p.input,
+                escape_pos,
+                ErrorKind::Failed,
+                "invalid escape sequence",
+            )
+        };
+        let Some(c) = self.bump() else {
+            return Err(invalid(self));
+        };
+        let byte = match c {
+            'a' => 0x07,
+            'b' => 0x08,
+            'f' => 0x0c,
+            'n' => b'\n',
+            'r' => b'\r',
+            't' => b'\t',
+            'v' => 0x0b,
+            '\'' => b'\'',
+            '"' => b'"',
+            '\\' => b'\\',
+            '?' => b'?',
+            'x' => {
+                let digits = self.input[self.pos..]
+                    .bytes()
+                    .take(2)
+                    .take_while(u8::is_ascii_hexdigit)
+                    .count();
+                if digits == 0 {
+                    return Err(invalid(self));
+                }
+                let byte = u8::from_str_radix(&self.input[self.pos..self.pos + digits], 16)
+                    .map_err(|_| invalid(self))?;
+                self.pos += digits;
+                byte
+            }
+            'u' => {
+                // Rust-style `\u{...}`, as produced by `Debug`.
+                self.expect('{').map_err(|_| invalid(self))?;
+                let end = self.input[self.pos..]
+                    .find('}')
+                    .ok_or_else(|| invalid(self))?;
+                let digits = &self.input[self.pos..self.pos + end];
+                if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+                    return Err(invalid(self));
+                }
+                let code = u32::from_str_radix(digits, 16).map_err(|_| invalid(self))?;
+                let c = char::from_u32(code).ok_or_else(|| invalid(self))?;
+                self.pos += end + 1;
+                out.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+                return Ok(());
+            }
+            '0'..='7' => {
+                let digits = 1 + self.input[self.pos..]
+                    .bytes()
+                    .take(2)
+                    .take_while(|b| (b'0'..=b'7').contains(b))
+                    .count();
+                let start = self.pos - 1;
+                let byte = u8::from_str_radix(&self.input[start..start + digits], 8)
+                    .map_err(|_| invalid(self))?;
+                self.pos = start + digits;
+                byte
+            }
+            _ => return Err(invalid(self)),
+        };
+        out.push(byte);
+        Ok(())
+    }
+}
+
+/// Writes parsed values into builders, checking them against the schema.
+struct Applier<'i> {
+    input: &'i str,
+}
+
+impl<'i> Applier<'i> {
+    fn mismatch(&self, pos: usize, expected: &str) -> Error {
+        error_at(
+            self.input,
+            pos,
+            ErrorKind::TypeMismatch,
+            &alloc::format!("expected {expected}"),
+        )
+    }
+
+    fn text<'v>(&self, pos: usize, bytes: &'v [u8]) -> Result<&'v str> {
+        core::str::from_utf8(bytes).map_err(|_| {
+            error_at(
+                self.input,
+                pos,
+                ErrorKind::Failed,
+                "text is not valid UTF-8",
+            )
+        })
+    }
+
+    fn apply_struct(
+        &self,
+        assignments: &[Assignment],
+        mut builder: dynamic_struct::Builder,
+    ) -> Result<()> {
+        let schema = builder.get_schema();
+        for assignment in assignments {
+            let Some(field) = schema.find_field_by_name(assignment.name)? else {
+                return Err(error_at(
+                    self.input,
+                    assignment.name_pos,
+                    ErrorKind::FieldNotFound,
+                    assignment.name,
+                ));
+            };
+            self.apply_field(builder.reborrow(), field, &assignment.value)?;
+        }
+        Ok(())
+    }
+
+    fn apply_field<'a>(
+        &self,
+        mut builder: dynamic_struct::Builder<'a>,
+        field: crate::schema::Field<'a>,
+        value: &Spanned,
+    ) -> Result<()> {
+        let ty = field.get_type();
+        if let field::Group(_) = field.get_proto().which()? {
+            let Value::Struct(assignments) = &value.value else {
+                return Err(self.mismatch(value.pos, "struct literal"));
+            };
+            let group = builder.init(field)?;
+            return self.apply_struct(assignments, group.downcast());
+        }
+        match (ty.which(), &value.value) {
+            (TypeVariant::Struct(_), Value::Struct(assignments)) => {
+                let inner = builder.init(field)?;
+                self.apply_struct(assignments, inner.downcast())
+            }
+            (TypeVariant::Struct(_), _) => Err(self.mismatch(value.pos, "struct literal")),
+            (TypeVariant::List(element_type), Value::List(elements)) => {
+                let list = builder.initn(field, elements.len() as u32)?;
+                self.apply_list(list.downcast(), element_type, elements)
+            }
+            (TypeVariant::List(_), _) => Err(self.mismatch(value.pos, "list literal")),
+            (TypeVariant::Text, Value::String(s)) => {
+                let text = self.text(value.pos, s)?;
+                builder.set(field, dynamic_value::Reader::Text(text.into()))
+            }
+            (TypeVariant::Text, _) => Err(self.mismatch(value.pos, "text")),
+            (TypeVariant::Data, Value::Data(bytes) | Value::String(bytes)) => {
+                builder.set(field, dynamic_value::Reader::Data(bytes))
+            }
+            (TypeVariant::Data, _) => Err(self.mismatch(value.pos, "data")),
+            _ => builder.set(field, self.primitive(ty, value)?),
+        }
+    }
+
+    fn apply_list(
+        &self,
+        mut list: dynamic_list::Builder,
+        element_type: Type,
+        elements: &[Spanned],
+    ) -> Result<()> {
+        for (idx, element) in elements.iter().enumerate() {
+            let idx = idx as u32;
+            match (element_type.which(), &element.value) {
+                (TypeVariant::Struct(_), Value::Struct(assignments)) => {
+                    let inner = list.reborrow().get(idx)?;
+                    self.apply_struct(assignments, inner.downcast())?;
+                }
+                (TypeVariant::Struct(_), _) => {
+                    return Err(self.mismatch(element.pos, "struct literal"))
+                }
+                (TypeVariant::List(inner_type), Value::List(inner_elements)) => {
+                    let inner = list.reborrow().init(idx, inner_elements.len() as u32)?;
+                    self.apply_list(inner.downcast(), inner_type, inner_elements)?;
+                }
+                (TypeVariant::List(_), _) => return Err(self.mismatch(element.pos, "list literal")),
+                (TypeVariant::Text, Value::String(s)) => {
+                    let text = self.text(element.pos, s)?;
+                    list.set(idx, dynamic_value::Reader::Text(text.into()))?
+                }
+                (TypeVariant::Text, _) => return Err(self.mismatch(element.pos, "text")),
+                (TypeVariant::Data, Value::Data(bytes) | Value::String(bytes)) => {
+                    list.set(idx, dynamic_value::Reader::Data(bytes))?
+                }
+                (TypeVariant::Data, _) => return Err(self.mismatch(element.pos, "data")),
+                _ => list.set(idx, self.primitive(element_type, element)?)?,
+            }
+        }
+        Ok(())
+    }
+
+    fn integer<T: TryFrom<i128>>(&self, value: &Spanned) -> Result<T> {
+        match value.value {
+            Value::Int(i) => T::try_from(i).map_err(|_| {
+                error_at(
+                    self.input,
+                    value.pos,
+                    ErrorKind::Failed,
+                    "integer is out of range",
+                )
+            }),
+            _ => Err(self.mismatch(value.pos, "integer")),
+        }
+    }
+
+    fn float(&self, value: &Spanned) -> Result<f64> {
+        match value.value {
+            Value::Float(f) => Ok(f),
+            Value::Int(i) => Ok(i as f64),
+            _ => Err(self.mismatch(value.pos, "number")),
+        }
+    }
+
+    fn primitive<'s>(&self, ty: Type<'s>, value: &Spanned) -> Result<dynamic_value::Reader<'s>> {
+        Ok(match ty.which() {
+            TypeVariant::Void => match &value.value {
+                Value::Void => dynamic_value::Reader::Void,
+                Value::Struct(assignments) if assignments.is_empty() => dynamic_value::Reader::Void,
+                _ => return Err(self.mismatch(value.pos, "void")),
+            },
+            TypeVariant::Bool => match value.value {
+                Value::Bool(b) => dynamic_value::Reader::Bool(b),
+                _ => return Err(self.mismatch(value.pos, "boolean")),
+            },
+            TypeVariant::Int8 => dynamic_value::Reader::Int8(self.integer(value)?),
+            TypeVariant::Int16 => dynamic_value::Reader::Int16(self.integer(value)?),
+            TypeVariant::Int32 => dynamic_value::Reader::Int32(self.integer(value)?),
+            TypeVariant::Int64 => dynamic_value::Reader::Int64(self.integer(value)?),
+            TypeVariant::UInt8 => dynamic_value::Reader::UInt8(self.integer(value)?),
+            TypeVariant::UInt16 => dynamic_value::Reader::UInt16(self.integer(value)?),
+            TypeVariant::UInt32 => dynamic_value::Reader::UInt32(self.integer(value)?),
+            TypeVariant::UInt64 => dynamic_value::Reader::UInt64(self.integer(value)?),
+            TypeVariant::Float32 => dynamic_value::Reader::Float32(self.float(value)? as f32),
+            TypeVariant::Float64 => dynamic_value::Reader::Float64(self.float(value)?),
+            TypeVariant::Enum(raw) => {
+                let schema: EnumSchema = raw.into();
+                let ordinal = match value.value {
+                    Value::Identifier(name) => self.enumerant(schema, name, value.pos)?,
+                    Value::Int(_) => self.integer(value)?,
+                    _ => return Err(self.mismatch(value.pos, "enumerant")),
+                };
+                dynamic_value::Enum::new(ordinal, schema).into()
+            }
+            TypeVariant::AnyPointer | TypeVariant::Capability(_) => {
+                return Err(error_at(
+                    self.input,
+                    value.pos,
+                    ErrorKind::Unimplemented,
+                    "AnyPointer and capability fields cannot be parsed",
+                ))
+            }
+            TypeVariant::Text
+            | TypeVariant::Data
+            | TypeVariant::Struct(_)
+            | TypeVariant::List(_) => return Err(Error::from_kind(ErrorKind::TypeMismatch)),
+        })
+    }
+
+    fn enumerant(&self, schema: EnumSchema, name: &str, pos: usize) -> Result<u16> {
+        for enumerant in schema.get_enumerants()? {
+            if enumerant.get_proto().get_name()? == name {
+                return Ok(enumerant.get_ordinal());
+            }
+        }
+        Err(error_at(
+            self.input,
+            pos,
+            ErrorKind::Failed,
+            &alloc::format!("unknown enumerant {name}"),
+        ))
+    }
+}
@@ -34,7 +34,7 @@ impl crate::traits::Owned for Owned {
 }
 
 impl crate::introspect::Introspect for Owned {
-    fn introspect() -> crate::introspect::Type {
+    fn introspect() -> crate::introspect::Type<'static> {
         crate::introspect::Type::list_of(crate::introspect::TypeVariant::Text.into())
     }
 }
@@ -91,14 +91,22 @@ impl<'a> Reader<'a> {
     /// greater than or equal to `len()`.
     pub fn get(self, index: u32) -> Result<crate::text::Reader<'a>> {
         assert!(index < self.len());
-        self.reader.get_pointer_element(index).get_text(None)
+        self.reader
+            .get_pointer_element(index)
+            .get_text(None)
+            .map_err(|e| e.with_index(index))
     }
 
     /// Gets the `text::Reader` at position `index`. Returns `None` if `index`
     /// is greater than or equal to `len()`.
     pub fn try_get(self, index: u32) -> Option<Result<crate::text::Reader<'a>>> {
         if index < self.len() {
-            Some(self.reader.get_pointer_element(index).get_text(None))
+            Some(
+                self.reader
+                    .get_pointer_element(index)
+                    .get_text(None)
+                    .map_err(|e| e.with_index(index)),
+            )
         } else {
             None
         }
@@ -160,6 +168,11 @@ impl<'a> FromPointerBuilder<'a> for Builder<'a> {
             builder: builder.init_list(Pointer, size),
         }
     }
+    fn try_init_pointer(builder: PointerBuilder<'a>, size: u32) -> Result<Builder<'a>> {
+        Ok(Builder {
+            builder: builder.try_init_list(Pointer, size)?,
+        })
+    }
     fn get_from_pointer(
         builder: PointerBuilder<'a>,
         default: Option<&'a [crate::Word]>,
@@ -207,15 +220,15 @@ impl<'a, T: AsRef<str>> crate::traits::SetterInput<Owned> for &'a [T] {
         value: &'a [T],
         _canonicalize: bool,
     ) -> Result<()> {
-        let mut builder = pointer.init_list(
+        let mut builder = pointer.try_init_list(
             crate::private::layout::ElementSize::Pointer,
             value.len() as u32,
-        );
+        )?;
         for (idx, v) in value.iter().enumerate() {
             builder
                 .reborrow()
                 .get_pointer_element(idx as u32)
-                .set_text(v.as_ref().into());
+                .try_set_text(v.as_ref().into())?;
         }
         Ok(())
     }
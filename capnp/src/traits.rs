@@ -74,6 +74,13 @@ pub trait Pipelined {
 
 pub trait FromPointerBuilder<'a>: Sized {
     fn init_pointer(builder: PointerBuilder<'a>, length: u32) -> Self;
+
+    /// Like `init_pointer()`, but returns an error instead of panicking if the message's
+    /// allocator cannot provide the space. The default implementation calls `init_pointer()`.
+    fn try_init_pointer(builder: PointerBuilder<'a>, length: u32) -> Result<Self> {
+        Ok(Self::init_pointer(builder, length))
+    }
+
     fn get_from_pointer(
         builder: PointerBuilder<'a>,
         default: Option<&'a [crate::Word]>,
@@ -0,0 +1,185 @@
+//! Eager validation of whole messages, driven by dynamic reflection.
+//!
+//! Reading a message is lazy: a bad pointer is only noticed when a getter follows it, which
+//! may happen deep inside application code. [`message::Reader::validate()`] instead walks
+//! everything that is reachable from the root up front, and returns a [`Report`] of every
+//! problem that reading it would run into, each one with the [`Path`] of the field or list
+//! element where it was found, such as `root.items[3].name`.
+//!
+//! Problems include out-of-bounds and malformed pointers, pointers of the wrong kind for
+//! their field, text that is not NUL-terminated or is not valid UTF-8, and exceeding the
+//! nesting or traversal limit of the message's [`ReaderOptions`](message::ReaderOptions).
+//! The contents of `AnyPointer` fields are only checked for well-formed pointers, since
+//! their type is unknown. Unknown union members and enumerants are not problems, as they
+//! may come from a newer version of the schema.
+//!
+//! [`message::Reader::validate()`]: crate::message::Reader::validate
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+pub use crate::diff::{Path, PathElement};
+use crate::introspect::TypeVariant;
+use crate::schema::{Field, StructSchema};
+use crate::schema_capnp::field;
+use crate::{any_pointer, dynamic_list, dynamic_struct, dynamic_value};
+use crate::{Error, ErrorKind, Result};
+
+/// Something that is wrong with a message, and where.
+#[derive(Debug)]
+pub struct Problem {
+    pub path: Path,
+    pub error: Error,
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.error)
+    }
+}
+
+/// The problems found by validating a message. See the [module docs](self).
+#[derive(Debug, Default)]
+pub struct Report {
+    pub problems: Vec<Problem>,
+}
+
+impl Report {
+    /// Whether no problems were found.
+    pub fn is_ok(&self) -> bool {
+        self.problems.is_empty()
+    }
+
+    /// Turns the report into a single error, which has the kind of the first problem and
+    /// describes all of them.
+    pub fn into_result(self) -> Result<()> {
+        let Some(first) = self.problems.first() else {
+            return Ok(());
+        };
+        let mut error = Error::from_kind(first.error.kind);
+        let problems: Vec<String> = self.problems.iter().map(|p| p.to_string()).collect();
+        write!(error, "{}", problems.join("; "));
+        Err(error)
+    }
+}
+
+/// Writes one problem per line.
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for problem in &self.problems {
+            writeln!(f, "{problem}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Validates the message whose root is `root`, as a struct of type `schema`.
+pub(crate) fn validate_root(root: Result<any_pointer::Reader>, schema: StructSchema) -> Report {
+    let mut validator = Validator {
+        path: alloc::vec![PathElement::Field("root".into())],
+        ..Validator::default()
+    };
+    if let Some(root) = validator.check(root.and_then(|root| root.get_as_dynamic(schema))) {
+        validator.validate_struct(root);
+    }
+    Report {
+        problems: validator.problems,
+    }
+}
+
+#[derive(Default)]
+struct Validator {
+    path: Vec<PathElement>,
+    problems: Vec<Problem>,
+
+    // Set once the traversal limit is exceeded, after which every read would fail.
+    done: bool,
+}
+
+impl Validator {
+    fn report(&mut self, mut error: Error) {
+        // The path says the same, and more.
+        error.clear_context();
+        if error.kind == ErrorKind::ReadLimitExceeded {
+            self.done = true;
+        }
+        self.problems.push(Problem {
+            path: Path(self.path.clone()),
+            error,
+        });
+    }
+
+    fn check<T>(&mut self, result: Result<T>) -> Option<T> {
+        result.map_err(|e| self.report(e)).ok()
+    }
+
+    fn validate_struct(&mut self, reader: dynamic_struct::Reader) {
+        let Some(fields) = self.check(reader.get_schema().get_fields()) else {
+            return;
+        };
+        let Some(active) = self.check(reader.which()) else {
+            return;
+        };
+        for field in fields {
+            if self.done {
+                return;
+            }
+            if field.get_proto().get_discriminant_value() != field::NO_DISCRIMINANT
+                && active.map(|f| f.get_index()) != Some(field.get_index())
+            {
+                continue;
+            }
+            let Some(name) = self.check(field_name(field)) else {
+                continue;
+            };
+            self.path.push(name);
+            if let Some(value) = self.check(reader.get(field)) {
+                self.validate_value(value);
+            }
+            self.path.pop();
+        }
+    }
+
+    fn validate_value(&mut self, value: dynamic_value::Reader) {
+        match value {
+            dynamic_value::Reader::Struct(reader) => self.validate_struct(reader),
+            dynamic_value::Reader::List(list) => self.validate_list(list),
+            dynamic_value::Reader::Text(text) => {
+                if let Err(e) = text.to_str() {
+                    self.report(e.into());
+                }
+            }
+            dynamic_value::Reader::AnyPointer(pointer) => {
+                self.check(pointer.target_size());
+            }
+            _ => (),
+        }
+    }
+
+    fn validate_list(&mut self, list: dynamic_list::Reader) {
+        // The elements of other lists were checked when the list itself was read.
+        let element_type = list.element_type();
+        if !element_type.is_pointer_type()
+            || matches!(element_type.which(), TypeVariant::Capability(_))
+        {
+            return;
+        }
+        for index in 0..list.len() {
+            if self.done {
+                return;
+            }
+            self.path.push(PathElement::Index(index));
+            if let Some(value) = self.check(list.get(index)) {
+                self.validate_value(value);
+            }
+            self.path.pop();
+        }
+    }
+}
+
+fn field_name(field: Field) -> Result<PathElement> {
+    Ok(PathElement::Field(
+        field.get_proto().get_name()?.to_string()?,
+    ))
+}
@@ -0,0 +1,166 @@
+#![cfg(feature = "alloc")]
+
+use capnp::message::{self, BoundedAllocator, HeapAllocator, SingleSegmentAllocator};
+use capnp::schema_capnp::{node, value};
+use capnp::{list_list, primitive_list, text, ErrorKind};
+
+#[test]
+fn bounded_allocator_rejects_oversized_message() {
+    let allocator = BoundedAllocator::new(HeapAllocator::new().first_segment_words(16), 64);
+    let mut message = message::Builder::new(allocator);
+    let mut root = message.try_init_root::<node::Builder>().unwrap();
+    root.set_display_name("fits");
+
+    let err = message.set_root(&*"x".repeat(1000)).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::AllocationFailed);
+
+    let err = message
+        .try_initn_root::<primitive_list::Builder<u64>>(100)
+        .map(|_| ())
+        .unwrap_err();
+    assert_eq!(err.kind, ErrorKind::AllocationFailed);
+
+    // Smaller objects still fit.
+    let list = message
+        .try_initn_root::<primitive_list::Builder<u64>>(10)
+        .unwrap();
+    assert_eq!(list.len(), 10);
+    assert!(message.into_allocator().allocated_words() <= 64);
+}
+
+#[test]
+fn bounded_allocator_rejects_oversized_fields() {
+    let allocator = BoundedAllocator::new(HeapAllocator::new().first_segment_words(16), 64);
+    let mut message = message::Builder::new(allocator);
+    let mut root = message.try_init_root::<node::Builder>().unwrap();
+
+    let err = root
+        .reborrow()
+        .try_init_display_name(1000)
+        .map(|_| ())
+        .unwrap_err();
+    assert_eq!(err.kind, ErrorKind::AllocationFailed);
+    let err = root
+        .reborrow()
+        .try_init_nested_nodes(100)
+        .map(|_| ())
+        .unwrap_err();
+    assert_eq!(err.kind, ErrorKind::AllocationFailed);
+
+    let nested = root.reborrow().try_init_nested_nodes(2).unwrap();
+    assert_eq!(nested.len(), 2);
+
+    let mut lists = message
+        .try_initn_root::<list_list::Builder<primitive_list::Owned<u64>>>(2)
+        .unwrap();
+    let err = lists.reborrow().try_init(0, 100).map(|_| ()).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::AllocationFailed);
+    assert_eq!(lists.try_init(1, 3).unwrap().len(), 3);
+}
+
+#[test]
+fn bounded_allocator_fails_try_set() {
+    let allocator = BoundedAllocator::new(HeapAllocator::new().first_segment_words(16), 64);
+    let mut message = message::Builder::new(allocator);
+    let mut root = message.try_init_root::<node::Builder>().unwrap();
+    let err = root.try_set_display_name(&*"x".repeat(1000)).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::AllocationFailed);
+    root.try_set_display_name("fits").unwrap();
+    assert_eq!(
+        root.reborrow_as_reader().get_display_name().unwrap(),
+        "fits"
+    );
+
+    let mut value = message.try_init_root::<value::Builder>().unwrap();
+    let err = value.try_set_data(&[0; 1000]).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::AllocationFailed);
+    value.try_set_data(&[1, 2, 3]).unwrap();
+}
+
+#[test]
+fn bounded_allocator_limits_root() {
+    let allocator = BoundedAllocator::new(HeapAllocator::new(), 0);
+    let mut message = message::Builder::new(allocator);
+    let err = message
+        .try_init_root::<node::Builder>()
+        .map(|_| ())
+        .unwrap_err();
+    assert_eq!(err.kind, ErrorKind::AllocationFailed);
+    assert_eq!(
+        message.set_root("hello").unwrap_err().kind,
+        ErrorKind::AllocationFailed
+    );
+}
+
+#[test]
+#[should_panic]
+fn bounded_allocator_panics_on_infallible_init() {
+    let allocator = BoundedAllocator::new(HeapAllocator::new(), 32);
+    let mut message = message::Builder::new(allocator);
+    message.initn_root::<primitive_list::Builder<u64>>(1000);
+}
+
+#[test]
+fn single_segment_allocator_exhaustion() {
+    let mut buffer = [capnp::word(0, 0, 0, 0, 0, 0, 0, 0); 8];
+    let allocator = SingleSegmentAllocator::new(capnp::Word::words_to_bytes_mut(&mut buffer[..]));
+    let mut message = message::Builder::new(allocator);
+    message.set_root("short").unwrap();
+    let err = message.set_root(&*"x".repeat(100)).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::AllocationFailed);
+
+    let text: text::Reader = message.get_root_as_reader().unwrap();
+    assert_eq!(text, "");
+}
+
+// Hands out segments 50 words larger than asked for, and checks that each one is given back
+// with the size that it was allocated with.
+#[derive(Default)]
+struct OversizedAllocator {
+    live: Vec<(usize, u32)>,
+}
+
+unsafe impl message::Allocator for OversizedAllocator {
+    fn allocate_segment(&mut self, minimum_size: u32) -> (*mut u8, u32) {
+        let size = minimum_size + 50;
+        let segment = vec![capnp::word(0, 0, 0, 0, 0, 0, 0, 0); size as usize].into_boxed_slice();
+        let ptr = Box::into_raw(segment) as *mut u8;
+        self.live.push((ptr as usize, size));
+        (ptr, size)
+    }
+
+    unsafe fn deallocate_segment(&mut self, ptr: *mut u8, word_size: u32, _words_used: u32) {
+        let i = self
+            .live
+            .iter()
+            .position(|&(addr, _)| addr == ptr as usize)
+            .unwrap();
+        assert_eq!(self.live.swap_remove(i).1, word_size);
+        drop(Box::from_raw(core::ptr::slice_from_raw_parts_mut(
+            ptr as *mut capnp::Word,
+            word_size as usize,
+        )));
+    }
+}
+
+#[test]
+fn bounded_allocator_releases_truncated_segments_with_their_real_size() {
+    use capnp::message::Allocator;
+
+    let mut allocator = BoundedAllocator::new(OversizedAllocator::default(), 100);
+    let (first, first_size) = allocator.try_allocate_segment(40).unwrap();
+    assert_eq!(first_size, 90);
+    let (second, second_size) = allocator.try_allocate_segment(5).unwrap();
+    assert_eq!(second_size, 10);
+    unsafe { allocator.deallocate_segment(first, first_size, 0) };
+
+    // Both this segment and the second one are truncated while live.
+    let (third, third_size) = allocator.try_allocate_segment(60).unwrap();
+    assert_eq!(third_size, 90);
+    unsafe {
+        allocator.deallocate_segment(second, second_size, 0);
+        allocator.deallocate_segment(third, third_size, 0);
+    }
+    assert_eq!(allocator.allocated_words(), 0);
+    assert!(allocator.into_inner().live.is_empty());
+}
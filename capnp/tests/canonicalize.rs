@@ -498,3 +498,55 @@ fn far_pointer_to_same_segment() {
     let message = message::Reader::new(segment_array, Default::default());
     assert!(!message.is_canonical().unwrap());
 }
+
+#[cfg(feature = "alloc")]
+fn init_canonical_eq_node(mut node: capnp::schema_capnp::node::Builder) {
+    node.set_display_name("foo.capnp:Foo");
+    let mut nested = node.reborrow().init_nested_nodes(3);
+    for i in 0..3 {
+        nested.reborrow().get(i).set_name("nested");
+        nested.reborrow().get(i).set_id(i as u64 + 1);
+    }
+    node.init_struct().set_data_word_count(2);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn canonical_eq_ignores_layout() {
+    use capnp::canonical::{Canonical, CanonicalEq};
+    use capnp::schema_capnp::node;
+
+    let mut single = message::Builder::new_default();
+    init_canonical_eq_node(single.init_root());
+
+    // Tiny fixed-size segments force far pointers. Setting a field to its default
+    // leaves the data section different but the canonical form the same.
+    let mut split = message::Builder::new(
+        message::HeapAllocator::new()
+            .first_segment_words(1)
+            .allocation_strategy(message::AllocationStrategy::FixedSize),
+    );
+    init_canonical_eq_node(split.init_root());
+    split.get_root::<node::Builder>().unwrap().set_id(0);
+    assert!(split.get_segments_for_output().len() > 1);
+
+    let a: node::Reader = single.get_root_as_reader().unwrap();
+    let b: node::Reader = split.get_root_as_reader().unwrap();
+    assert!(a.canonical_eq(b).unwrap());
+
+    let dynamic_a: capnp::dynamic_value::Reader = a.into();
+    let dynamic_b: capnp::dynamic_value::Reader = b.into();
+    let dynamic_a: capnp::dynamic_struct::Reader = dynamic_a.downcast();
+    assert!(dynamic_a.canonical_eq(dynamic_b.downcast()).unwrap());
+
+    let mut set = std::collections::HashSet::new();
+    assert!(set.insert(Canonical::new(a).unwrap()));
+    assert!(!set.insert(Canonical::new(b).unwrap()));
+
+    let mut other = message::Builder::new_default();
+    init_canonical_eq_node(other.init_root());
+    other.get_root::<node::Builder>().unwrap().set_id(7);
+    let c: node::Reader = other.get_root_as_reader().unwrap();
+    assert!(!a.canonical_eq(c).unwrap());
+    assert!(set.insert(Canonical::new(c).unwrap()));
+}
@@ -0,0 +1,49 @@
+//! Helpers shared by the integration tests. Each test crate uses only some of them.
+#![allow(dead_code)]
+
+use std::path::PathBuf;
+
+use capnp::message;
+use capnp::schema_capnp::{node, ElementSize};
+
+pub const TEST_NODE_ID: u64 = 0xdeadbeef12345678;
+
+/// Fills in a struct node that exercises escapes in text, a slot with a default value,
+/// nested nodes, and an annotation holding data.
+pub fn init_test_node(mut node: node::Builder) {
+    node.set_id(TEST_NODE_ID);
+    node.set_display_name("foo.capnp:Bar");
+    node.set_scope_id(0x1234);
+    let mut nested = node.reborrow().init_nested_nodes(2);
+    nested.reborrow().get(0).set_name("Inner");
+    nested.reborrow().get(0).set_id(1);
+    nested.reborrow().get(1).set_name("Other");
+    nested.reborrow().get(1).set_id(2);
+    let mut annotation = node.reborrow().init_annotations(1).get(0);
+    annotation.set_id(1);
+    annotation.init_value().set_data(&[1, 2, 3, 255]);
+    let mut st = node.init_struct();
+    st.set_data_word_count(2);
+    st.set_preferred_list_encoding(ElementSize::InlineComposite);
+    let mut field = st.init_fields(1).get(0);
+    field.set_name("qux \"quoted\"\t\u{1}\n");
+    field.set_code_order(3);
+    let mut slot = field.init_slot();
+    slot.set_offset(1);
+    slot.reborrow().init_type().set_float64(());
+    slot.init_default_value().set_float64(-1.5e-3);
+}
+
+/// Builds a message whose root is a node with the given ID, named after it.
+pub fn node_message(id: u64) -> message::Builder<message::HeapAllocator> {
+    let mut message = message::Builder::new_default();
+    let mut root = message.init_root::<node::Builder>();
+    root.set_id(id);
+    root.set_display_name(&*format!("node {id}"));
+    message
+}
+
+/// A path in the temporary directory that no other test process uses.
+pub fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("capnp-test-{}-{name}", std::process::id()))
+}
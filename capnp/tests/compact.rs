@@ -0,0 +1,84 @@
+#![cfg(feature = "alloc")]
+
+use capnp::message::{self, HeapAllocator};
+use capnp::schema_capnp::node;
+use capnp::ErrorKind;
+
+fn churn(message: &mut message::Builder<HeapAllocator>) {
+    let mut root = message.init_root::<node::Builder>();
+    root.set_id(42);
+    for i in 0..20 {
+        root.set_display_name(format!("display name number {i}"));
+        root.reborrow().init_annotations(i + 1);
+    }
+}
+
+fn check_contents(root: node::Reader) {
+    assert_eq!(root.get_id(), 42);
+    assert_eq!(
+        root.get_display_name().unwrap().to_str().unwrap(),
+        "display name number 19"
+    );
+    assert_eq!(root.get_annotations().unwrap().len(), 20);
+}
+
+#[test]
+fn compact_in_place() {
+    let mut message = message::Builder::new(HeapAllocator::new().first_segment_words(16));
+    churn(&mut message);
+    assert!(message.get_segments_for_output().len() > 1);
+    let wasted = message.wasted_words().unwrap();
+    assert!(wasted > 0);
+
+    let reclaimed = message.compact().unwrap();
+    assert_eq!(reclaimed, wasted * 8);
+    assert_eq!(message.wasted_words().unwrap(), 0);
+    assert_eq!(message.get_segments_for_output().len(), 1);
+    check_contents(message.get_root_as_reader().unwrap());
+
+    // The message can still be modified afterwards.
+    let mut root = message.get_root::<node::Builder>().unwrap();
+    root.set_display_name("renamed");
+    assert_eq!(root.get_display_name().unwrap(), "renamed");
+}
+
+#[test]
+fn no_waste() {
+    let mut message = message::Builder::new_default();
+    assert_eq!(message.wasted_words().unwrap(), 0);
+    assert_eq!(message.compact().unwrap(), 0);
+
+    message.init_root::<node::Builder>().set_display_name("foo");
+    assert_eq!(message.wasted_words().unwrap(), 0);
+}
+
+#[test]
+fn compact_into() {
+    let mut message = message::Builder::new(HeapAllocator::new().first_segment_words(16));
+    churn(&mut message);
+    let wasted = message.wasted_words().unwrap();
+
+    let copy = message.compact_into(HeapAllocator::new()).unwrap();
+    assert_eq!(copy.get_segments_for_output().len(), 1);
+    assert_eq!(copy.wasted_words().unwrap(), 0);
+    check_contents(copy.get_root_as_reader().unwrap());
+
+    // The original is untouched.
+    assert_eq!(message.wasted_words().unwrap(), wasted);
+    check_contents(message.get_root_as_reader().unwrap());
+}
+
+#[test]
+fn orphans_invalidated() {
+    let mut message = message::Builder::new_default();
+    churn(&mut message);
+    let orphan = message
+        .get_root::<node::Builder>()
+        .unwrap()
+        .disown_display_name();
+    message.compact().unwrap();
+
+    let mut root = message.get_root::<node::Builder>().unwrap();
+    let err = root.adopt_display_name(orphan).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::OrphanBelongsToADifferentMessage);
+}
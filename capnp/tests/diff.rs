@@ -0,0 +1,219 @@
+#![cfg(feature = "alloc")]
+
+use capnp::diff::{self, Change, ChangeKind, Path, PathElement, Value};
+use capnp::message;
+use capnp::schema_capnp::node;
+
+fn init_struct_node(mut node: node::Builder, fields: &[&str]) {
+    node.set_id(0x1234);
+    node.set_display_name("foo.capnp:Foo");
+    let mut st = node.init_struct();
+    st.set_data_word_count(1);
+    let mut list = st.init_fields(fields.len() as u32);
+    for (i, name) in fields.iter().enumerate() {
+        let mut field = list.reborrow().get(i as u32);
+        field.set_name(name);
+        field.set_code_order(i as u16);
+        field.init_slot().init_type().set_uint32(());
+    }
+}
+
+fn path(elements: &[&str]) -> Path {
+    Path(
+        elements
+            .iter()
+            .map(|e| match e.parse() {
+                Ok(index) => PathElement::Index(index),
+                Err(_) => PathElement::Field(e.to_string()),
+            })
+            .collect(),
+    )
+}
+
+/// Checks that applying the diff from `a` to `b` to a copy of `a` yields `b`.
+fn check_round_trip(a: node::Reader, b: node::Reader) -> diff::Patch {
+    let patch = diff::diff(a, b).unwrap();
+    let mut message = message::Builder::new_default();
+    message.set_root(a).unwrap();
+    diff::apply(&patch, message.get_root::<node::Builder>().unwrap()).unwrap();
+    let applied: node::Reader = message.get_root_as_reader().unwrap();
+    assert_eq!(diff::diff(applied, b).unwrap(), diff::Patch::default());
+    patch
+}
+
+#[test]
+fn equal_messages() {
+    let mut a = message::Builder::new_default();
+    init_struct_node(a.init_root(), &["x", "y"]);
+    let a: node::Reader = a.get_root_as_reader().unwrap();
+    assert!(diff::diff(a, a).unwrap().is_empty());
+}
+
+#[test]
+fn scalars_and_lists() {
+    let mut a = message::Builder::new_default();
+    init_struct_node(a.init_root(), &["a", "b", "c", "d"]);
+    let mut b = message::Builder::new_default();
+    init_struct_node(b.init_root(), &["a", "x", "c", "d", "e"]);
+    {
+        let mut root = b.get_root::<node::Builder>().unwrap();
+        root.set_display_name("foo.capnp:Bar");
+        root.reborrow().init_annotations(1).get(0).set_id(7);
+        let node::Struct(st) = root.which().unwrap() else {
+            unreachable!()
+        };
+        let mut fields = st.get_fields().unwrap();
+        fields.reborrow().get(2).set_code_order(9);
+        fields.get(3).set_code_order(2);
+    }
+    let a: node::Reader = a.get_root_as_reader().unwrap();
+    let b: node::Reader = b.get_root_as_reader().unwrap();
+
+    let patch = check_round_trip(a, b);
+    let changes: Vec<String> = patch.changes.iter().map(|c| c.to_string()).collect();
+    assert_eq!(
+        changes,
+        [
+            r#"set displayName = "foo.capnp:Bar""#,
+            "init annotations with 1 elements",
+            "set annotations[0].id = 7",
+            r#"set struct.fields[1].name = "x""#,
+            "set struct.fields[2].codeOrder = 9",
+            "set struct.fields[3].codeOrder = 2",
+            "insert struct.fields[4]",
+            r#"set struct.fields[4].name = "e""#,
+            "set struct.fields[4].codeOrder = 4",
+            "init struct.fields[4].slot.type",
+            "switch union to struct.fields[4].slot.type.uint32 (from void)",
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn list_insertions_and_removals() {
+    let mut a = message::Builder::new_default();
+    init_struct_node(a.init_root(), &["a", "b", "c", "d", "e"]);
+    let mut b = message::Builder::new_default();
+    init_struct_node(b.init_root(), &["x", "a", "c", "e", "y"]);
+    for message in [&mut a, &mut b] {
+        // Make the fields differ only by name.
+        let root = message.get_root::<node::Builder>().unwrap();
+        let node::Struct(st) = root.which().unwrap() else {
+            unreachable!()
+        };
+        let mut fields = st.get_fields().unwrap();
+        for i in 0..fields.len() {
+            fields.reborrow().get(i).set_code_order(0);
+        }
+    }
+    let a: node::Reader = a.get_root_as_reader().unwrap();
+    let b: node::Reader = b.get_root_as_reader().unwrap();
+
+    let patch = check_round_trip(a, b);
+    let kinds: Vec<(Path, ChangeKind)> = patch
+        .changes
+        .into_iter()
+        .filter(|c| matches!(c.kind, ChangeKind::Insert | ChangeKind::Remove))
+        .map(|Change { path, kind }| (path, kind))
+        .collect();
+    assert_eq!(
+        kinds,
+        [
+            (path(&["struct", "fields", "0"]), ChangeKind::Insert),
+            (path(&["struct", "fields", "2"]), ChangeKind::Remove),
+            (path(&["struct", "fields", "3"]), ChangeKind::Remove),
+            (path(&["struct", "fields", "4"]), ChangeKind::Insert),
+        ]
+    );
+}
+
+#[test]
+fn list_edits_with_element_changes() {
+    let mut message = message::Builder::new_default();
+    init_struct_node(message.init_root(), &["a", "b", "c", "d"]);
+    let set_name = |p: &[&str], name: &str| Change {
+        path: path(p),
+        kind: ChangeKind::Set(Value::Text(name.into())),
+    };
+    let edit = |p: &[&str], kind| Change {
+        path: path(p),
+        kind,
+    };
+    let patch = diff::Patch {
+        changes: vec![
+            edit(&["struct", "fields", "0"], ChangeKind::Insert),
+            set_name(&["struct", "fields", "0", "name"], "x"),
+            set_name(&["struct", "fields", "2", "name"], "gone"),
+            edit(&["struct", "fields", "2"], ChangeKind::Remove),
+            set_name(&["struct", "fields", "2", "name"], "C"),
+            edit(&["struct", "fields", "4"], ChangeKind::Insert),
+            set_name(&["struct", "fields", "4", "name"], "y"),
+            set_name(&["displayName"], "foo.capnp:Bar"),
+        ],
+    };
+    diff::apply(&patch, message.get_root::<node::Builder>().unwrap()).unwrap();
+
+    let root: node::Reader = message.get_root_as_reader().unwrap();
+    assert_eq!(root.get_display_name().unwrap(), "foo.capnp:Bar");
+    let node::Struct(st) = root.which().unwrap() else {
+        panic!("expected a struct")
+    };
+    let names: Vec<&str> = st
+        .get_fields()
+        .unwrap()
+        .iter()
+        .map(|f| f.get_name().unwrap().to_str().unwrap())
+        .collect();
+    assert_eq!(names, ["x", "a", "C", "d", "y"]);
+}
+
+#[test]
+fn union_switch_and_clear() {
+    let mut a = message::Builder::new_default();
+    init_struct_node(a.init_root(), &["a"]);
+    a.get_root::<node::Builder>().unwrap().init_annotations(2);
+    let mut b = message::Builder::new_default();
+    {
+        let mut root = b.init_root::<node::Builder>();
+        root.set_id(0x1234);
+        root.set_display_name("foo.capnp:Foo");
+        let mut enumerants = root.init_enum().init_enumerants(1);
+        enumerants.reborrow().get(0).set_name("red");
+    }
+    let a: node::Reader = a.get_root_as_reader().unwrap();
+    let b: node::Reader = b.get_root_as_reader().unwrap();
+
+    let patch = check_round_trip(a, b);
+    assert_eq!(
+        patch.to_string(),
+        concat!(
+            "clear annotations\n",
+            "switch union to enum (from struct)\n",
+            "init enum.enumerants with 1 elements\n",
+            "set enum.enumerants[0].name = \"red\"\n",
+        )
+    );
+}
+
+#[test]
+fn bad_patches() {
+    let mut message = message::Builder::new_default();
+    init_struct_node(message.init_root(), &["a"]);
+    let patch = |path, kind| diff::Patch {
+        changes: vec![Change { path, kind }],
+    };
+    for bad in [
+        patch(path(&["nope"]), ChangeKind::Clear),
+        patch(path(&["struct", "fields", "1", "name"]), ChangeKind::Clear),
+        patch(path(&["struct", "fields", "3"]), ChangeKind::Insert),
+        patch(path(&["id"]), ChangeKind::Set(Value::Text("x".into()))),
+        patch(path(&["id", "0"]), ChangeKind::Remove),
+        patch(Path::default(), ChangeKind::Clear),
+    ] {
+        let root = message.get_root::<node::Builder>().unwrap();
+        assert!(diff::apply(&bad, root).is_err(), "{bad}");
+    }
+}
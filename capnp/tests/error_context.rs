@@ -0,0 +1,168 @@
+#![cfg(feature = "alloc")]
+
+use capnp::message::{self, ReaderOptions};
+use capnp::schema_capnp::node;
+use capnp::traits::HasTypeId;
+use capnp::{any_pointer, text_list, ErrorContext, ErrorKind, Word};
+
+fn words_of<A: message::Allocator>(message: &message::Builder<A>) -> Vec<Word> {
+    let segments = message.get_segments_for_output();
+    let mut words = Word::allocate_zeroed_vec(segments[0].len() / 8);
+    Word::words_to_bytes_mut(&mut words).copy_from_slice(segments[0]);
+    words
+}
+
+fn node_words() -> Vec<Word> {
+    let mut message = message::Builder::new_default();
+    let mut root = message.init_root::<node::Builder>();
+    root.reborrow().init_nested_nodes(2);
+    root.set_display_name("display");
+    words_of(&message)
+}
+
+#[test]
+fn generated_getters_add_field() {
+    let mut words = node_words();
+    // The display name was allocated last, so dropping the last word cuts it off.
+    words.pop();
+    let segments = [Word::words_to_bytes(&words)];
+    let message = message::Reader::new(message::SegmentArray::new(&segments), ReaderOptions::new());
+    let root = message.get_root::<node::Reader>().unwrap();
+
+    let err = root.get_display_name().map(|_| ()).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::MessageContainsOutOfBoundsPointer);
+    assert_eq!(
+        err.context(),
+        &[ErrorContext::Field {
+            type_id: node::Reader::TYPE_ID,
+            name: "displayName".into()
+        }]
+    );
+    assert_eq!(
+        err.to_string(),
+        format!(
+            "Message contains out-of-bounds pointer, at displayName of struct @{:#x}",
+            node::Reader::TYPE_ID
+        )
+    );
+}
+
+#[test]
+fn struct_list_elements_add_field() {
+    let mut message = message::Builder::new_default();
+    let mut root = message.init_root::<node::Builder>();
+    let mut nested = root.reborrow().init_nested_nodes(2);
+    nested.reborrow().get(0).set_name("zero");
+    nested.get(1).set_name("one");
+    let mut words = words_of(&message);
+    // The name of the second nested node was allocated last.
+    words.pop();
+
+    let segments = [Word::words_to_bytes(&words)];
+    let message = message::Reader::new(message::SegmentArray::new(&segments), ReaderOptions::new());
+    let nested = message
+        .get_root::<node::Reader>()
+        .unwrap()
+        .get_nested_nodes()
+        .unwrap();
+    assert_eq!(nested.get(0).get_name().unwrap(), "zero");
+    // Getting the element cannot fail, so only the getter of the element records context.
+    let err = nested.get(1).get_name().map(|_| ()).unwrap_err();
+    assert_eq!(
+        err.context(),
+        &[ErrorContext::Field {
+            type_id: node::nested_node::Reader::TYPE_ID,
+            name: "name".into()
+        }]
+    );
+
+    // Callers that walk a message record the rest of the path as they pass the error on.
+    let err = err
+        .with_index(1)
+        .with_field(node::Reader::TYPE_ID, "nestedNodes");
+    assert_eq!(
+        err.to_string(),
+        format!(
+            "Message contains out-of-bounds pointer, at nestedNodes[1].name of struct @{:#x}",
+            node::Reader::TYPE_ID
+        )
+    );
+}
+
+#[test]
+fn lists_add_index() {
+    let mut message = message::Builder::new_default();
+    let mut list = message
+        .init_root::<any_pointer::Builder>()
+        .initn_as::<text_list::Builder>(3);
+    list.set(0, "zero");
+    list.set(1, "one");
+    list.set(2, "two");
+    let mut words = words_of(&message);
+    // Make the last text run past the end of the segment.
+    let len = words.len();
+    words.truncate(len - 1);
+
+    let segments = [Word::words_to_bytes(&words)];
+    let message = message::Reader::new(message::SegmentArray::new(&segments), ReaderOptions::new());
+    let list = message.get_root::<text_list::Reader>().unwrap();
+    assert_eq!(list.get(1).unwrap(), "one");
+    let err = list.get(2).map(|_| ()).unwrap_err();
+    assert_eq!(err.context(), &[ErrorContext::Index(2)]);
+    assert!(err.to_string().ends_with(", at [2]"));
+    let errors: Vec<_> = list.iter().filter_map(|t| t.err()).collect();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].context(), &[ErrorContext::Index(2)]);
+}
+
+#[test]
+fn context_is_outermost_last() {
+    let err = capnp::Error::from_kind(ErrorKind::MessageContainsOutOfBoundsPointer)
+        .with_field(2, "name")
+        .with_index(3)
+        .with_field(1, "items");
+    assert_eq!(
+        err.context(),
+        &[
+            ErrorContext::Field {
+                type_id: 2,
+                name: "name".into()
+            },
+            ErrorContext::Index(3),
+            ErrorContext::Field {
+                type_id: 1,
+                name: "items".into()
+            },
+        ]
+    );
+    assert_eq!(
+        err.to_string(),
+        "Message contains out-of-bounds pointer, at items[3].name of struct @0x1"
+    );
+}
+
+#[test]
+fn dynamic_getters_add_field() {
+    let mut words = node_words();
+    words.pop();
+    let segments = [Word::words_to_bytes(&words)];
+    let message = message::Reader::new(message::SegmentArray::new(&segments), ReaderOptions::new());
+    let root: capnp::dynamic_value::Reader = message.get_root::<node::Reader>().unwrap().into();
+    let root = root.downcast::<capnp::dynamic_struct::Reader>();
+    let err = root.get_named("displayName").map(|_| ()).unwrap_err();
+    assert_eq!(
+        err.context(),
+        &[ErrorContext::Field {
+            type_id: node::Reader::TYPE_ID,
+            name: "displayName".into()
+        }]
+    );
+}
+
+#[test]
+fn context_does_not_grow_errors() {
+    // The context is boxed, so it costs an error no more than one pointer.
+    assert!(
+        core::mem::size_of::<capnp::Error>() <= core::mem::size_of::<(ErrorKind, String, usize)>()
+    );
+}
@@ -0,0 +1,127 @@
+#![cfg(feature = "alloc")]
+
+use capnp::field_mask::FieldMask;
+use capnp::message;
+use capnp::schema_capnp::{node, ElementSize};
+
+mod common;
+
+use common::{init_test_node, TEST_NODE_ID};
+
+#[test]
+fn project() {
+    let mut message = message::Builder::new_default();
+    init_test_node(message.init_root());
+    let source: node::Reader = message.get_root_as_reader().unwrap();
+
+    let mask = FieldMask::from_paths([
+        "displayName",
+        "nestedNodes[*].name",
+        "struct.fields[*].name",
+        "struct.dataWordCount",
+        "enum",
+    ])
+    .unwrap();
+    let mut projected = message::Builder::new_default();
+    mask.project(source, projected.init_root::<node::Builder>())
+        .unwrap();
+    let projected: node::Reader = projected.get_root_as_reader().unwrap();
+
+    assert_eq!(projected.get_id(), 0);
+    assert_eq!(projected.get_display_name().unwrap(), "foo.capnp:Bar");
+    assert!(!projected.has_annotations());
+    let nested = projected.get_nested_nodes().unwrap();
+    assert_eq!(nested.len(), 2);
+    assert_eq!(nested.get(1).get_name().unwrap(), "Other");
+    assert_eq!(nested.get(1).get_id(), 0);
+    let node::Struct(st) = projected.which().unwrap() else {
+        panic!("expected struct")
+    };
+    assert_eq!(st.get_data_word_count(), 2);
+    assert_eq!(
+        st.get_preferred_list_encoding().unwrap(),
+        ElementSize::Empty
+    );
+    let field = st.get_fields().unwrap().get(0);
+    assert_eq!(field.get_name().unwrap(), "qux \"quoted\"\t\u{1}\n");
+    assert_eq!(field.get_code_order(), 0);
+    assert!(!field.has_annotations());
+}
+
+#[test]
+fn retain() {
+    let mut message = message::Builder::new_default();
+    init_test_node(message.init_root());
+
+    let mask = FieldMask::from_paths(["id", "nestedNodes[*].id", "struct.fields"]).unwrap();
+    mask.retain(message.get_root::<node::Builder>().unwrap())
+        .unwrap();
+    let root: node::Reader = message.get_root_as_reader().unwrap();
+
+    assert_eq!(root.get_id(), TEST_NODE_ID);
+    assert!(!root.has_display_name());
+    assert!(!root.has_annotations());
+    let nested = root.get_nested_nodes().unwrap();
+    assert_eq!(nested.get(0).get_id(), 1);
+    assert!(!nested.get(0).has_name());
+    let node::Struct(st) = root.which().unwrap() else {
+        panic!("expected struct")
+    };
+    assert_eq!(st.get_data_word_count(), 0);
+    let field = st.get_fields().unwrap().get(0);
+    assert_eq!(field.get_name().unwrap(), "qux \"quoted\"\t\u{1}\n");
+    assert_eq!(field.get_code_order(), 3);
+}
+
+#[test]
+fn whole_subtrees() {
+    let mut message = message::Builder::new_default();
+    init_test_node(message.init_root());
+    let source: node::Reader = message.get_root_as_reader().unwrap();
+
+    // Selecting a group also selects everything below it.
+    let mask = FieldMask::from_paths(["struct.fields[*].name", "struct"]).unwrap();
+    assert_eq!(mask, FieldMask::from_paths(["struct"]).unwrap());
+    assert_eq!(
+        FieldMask::from_paths(["nestedNodes[*]"]).unwrap(),
+        FieldMask::from_paths(["nestedNodes[*]", "nestedNodes[*].id"]).unwrap()
+    );
+
+    let mut projected = message::Builder::new_default();
+    mask.project(source, projected.init_root::<node::Builder>())
+        .unwrap();
+    let projected: node::Reader = projected.get_root_as_reader().unwrap();
+    let node::Struct(st) = projected.which().unwrap() else {
+        panic!("expected struct")
+    };
+    assert_eq!(
+        st.get_preferred_list_encoding().unwrap(),
+        ElementSize::InlineComposite
+    );
+    assert_eq!(st.get_fields().unwrap().get(0).get_code_order(), 3);
+}
+
+#[test]
+fn errors() {
+    for path in ["", "a..b", "items[]", "items[*]x", ".a"] {
+        assert!(FieldMask::from_paths([path]).is_err(), "{path}");
+    }
+
+    for path in ["nope", "id.x", "annotations.id", "displayName[*]"] {
+        let mut message = message::Builder::new_default();
+        init_test_node(message.init_root());
+        let mask = FieldMask::from_paths([path]).unwrap();
+        let source: node::Reader = message.get_root_as_reader().unwrap();
+        let mut target = message::Builder::new_default();
+        assert!(
+            mask.project(source, target.init_root::<node::Builder>())
+                .is_err(),
+            "{path}"
+        );
+        assert!(
+            mask.retain(message.get_root::<node::Builder>().unwrap())
+                .is_err(),
+            "{path}"
+        );
+    }
+}
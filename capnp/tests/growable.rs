@@ -0,0 +1,314 @@
+#![cfg(feature = "alloc")]
+
+use capnp::message::{self, HeapAllocator};
+use capnp::schema_capnp::{annotation, node, value};
+use capnp::{data, primitive_list, struct_list, text};
+use std::fmt::Write as _;
+use std::io::Write as _;
+
+#[test]
+fn text_appends() {
+    let mut message = message::Builder::new(HeapAllocator::new().first_segment_words(8));
+    message.init_root::<node::Builder>();
+    let mut expected = String::new();
+    let mut orphanage = message.get_orphanage();
+    let mut orphan = orphanage.new_orphan::<text::Owned>();
+    for i in 0..100 {
+        // Allocate something else in between, so that the text cannot always grow in place.
+        if i % 10 == 0 {
+            let _ = orphanage.new_orphan_copy::<text::Owned>("filler").unwrap();
+        }
+        let mut builder = orphanage.get_growable_text(&mut orphan).unwrap();
+        writeln!(builder, "line {i}").unwrap();
+        writeln!(expected, "line {i}").unwrap();
+        assert_eq!(builder.to_str().unwrap(), expected);
+        assert!(builder.capacity() >= builder.len());
+    }
+
+    let mut root = message.get_root::<node::Builder>().unwrap();
+    root.adopt_display_name(orphan).unwrap();
+    let root = message.get_root_as_reader::<node::Reader>().unwrap();
+    assert_eq!(root.get_display_name().unwrap(), &expected[..]);
+
+    message.compact().unwrap();
+    let root = message.get_root_as_reader::<node::Reader>().unwrap();
+    assert_eq!(root.get_display_name().unwrap(), &expected[..]);
+}
+
+#[test]
+fn text_grows_in_place() {
+    let mut message = message::Builder::new_default();
+    message.init_root::<node::Builder>();
+    let mut orphanage = message.get_orphanage();
+    let mut orphan = orphanage.new_orphan::<text::Owned>();
+    let mut builder = orphanage.get_growable_text(&mut orphan).unwrap();
+    for _ in 0..50 {
+        builder.push_str("abc").unwrap();
+        builder.push('é').unwrap();
+    }
+    assert_eq!(builder.len(), 250);
+    message
+        .get_root::<node::Builder>()
+        .unwrap()
+        .adopt_display_name(orphan)
+        .unwrap();
+
+    // The text was always the last object, so it never moved. The only garbage
+    // is the word that held the orphan's pointer.
+    assert_eq!(message.wasted_words().unwrap(), 1);
+}
+
+#[test]
+fn text_truncate_and_reserve() {
+    let mut message = message::Builder::new_default();
+    message
+        .init_root::<node::Builder>()
+        .set_display_name("hello world");
+    let mut root = message.get_root::<node::Builder>().unwrap();
+    let mut orphan = root.disown_display_name();
+    let mut orphanage = message.get_orphanage();
+    let mut builder = orphanage.get_growable_text(&mut orphan).unwrap();
+    assert_eq!(builder.to_str().unwrap(), "hello world");
+
+    builder.reserve(100).unwrap();
+    assert!(builder.capacity() >= 111);
+    assert_eq!(builder.to_str().unwrap(), "hello world");
+
+    builder.truncate(5).unwrap();
+    builder.push_str("!").unwrap();
+    assert_eq!(builder.to_str().unwrap(), "hello!");
+    builder.truncate(50).unwrap();
+    assert_eq!(builder.len(), 6);
+
+    message
+        .get_root::<node::Builder>()
+        .unwrap()
+        .adopt_display_name(orphan)
+        .unwrap();
+    let root = message.get_root_as_reader::<node::Reader>().unwrap();
+    assert_eq!(root.get_display_name().unwrap(), "hello!");
+
+    let mut orphan = message
+        .get_root::<node::Builder>()
+        .unwrap()
+        .disown_display_name();
+    let mut orphanage = message.get_orphanage();
+    let mut builder = orphanage.get_growable_text(&mut orphan).unwrap();
+    builder.clear().unwrap();
+    assert!(builder.is_empty());
+    assert_eq!(orphanage.get_reader(&orphan).unwrap(), "");
+}
+
+#[test]
+fn data_appends() {
+    let mut message = message::Builder::new(HeapAllocator::new().first_segment_words(4));
+    let mut orphanage = message.get_orphanage();
+    let mut orphan = orphanage.new_orphan::<data::Owned>();
+    let mut expected = Vec::new();
+    for i in 0..200u8 {
+        if i % 16 == 0 {
+            let _ = orphanage
+                .new_orphan_copy::<data::Owned>(&[1; 9][..])
+                .unwrap();
+        }
+        let mut builder = orphanage.get_growable_data(&mut orphan).unwrap();
+        if i % 2 == 0 {
+            builder.push(i).unwrap();
+            expected.push(i);
+        } else {
+            builder.write_all(&[i, i]).unwrap();
+            expected.extend_from_slice(&[i, i]);
+        }
+        assert_eq!(builder.as_bytes(), &expected[..]);
+    }
+    assert_eq!(orphanage.get_reader(&orphan).unwrap(), &expected[..]);
+
+    let mut builder = orphanage.get_growable_data(&mut orphan).unwrap();
+    builder.as_bytes_mut()[0] = 0xff;
+    builder.truncate(3).unwrap();
+    assert_eq!(builder.into_reader(), &[0xff, 1, 1]);
+}
+
+#[test]
+fn growth_past_list_limit() {
+    let mut message = message::Builder::new_default();
+    message.init_root::<node::Builder>();
+    let mut orphanage = message.get_orphanage();
+
+    let mut orphan = orphanage.new_orphan::<text::Owned>();
+    let mut builder = orphanage.get_growable_text(&mut orphan).unwrap();
+    builder.push_str("abc").unwrap();
+    assert!(builder.reserve(usize::MAX).is_err());
+    assert!(builder.reserve((1 << 29) - 4).is_err());
+    assert_eq!(builder.to_str().unwrap(), "abc");
+
+    let mut orphan = orphanage.new_orphan::<data::Owned>();
+    let mut builder = orphanage.get_growable_data(&mut orphan).unwrap();
+    builder.push(1).unwrap();
+    assert!(builder.reserve(usize::MAX).is_err());
+    assert!(builder.reserve(u32::MAX as usize).is_err());
+    assert!(builder.reserve((1 << 29) - 1).is_err());
+    assert_eq!(builder.as_bytes(), &[1]);
+}
+
+#[test]
+fn wrong_type() {
+    let mut message = message::Builder::new_default();
+    let mut orphanage = message.get_orphanage();
+    let mut orphan = orphanage.new_orphan::<node::Owned>();
+    orphanage.get(&mut orphan).unwrap();
+    let mut orphan = orphan.into_any_pointer().into_typed::<text::Owned>();
+    assert!(orphanage.get_growable_text(&mut orphan).is_err());
+}
+
+#[test]
+fn primitive_list_appends() {
+    let mut message = message::Builder::new(HeapAllocator::new().first_segment_words(4));
+    let mut orphanage = message.get_orphanage();
+    let mut orphan = orphanage.new_orphan::<primitive_list::Owned<u32>>();
+    for i in 0..500 {
+        if i % 50 == 0 {
+            let _ = orphanage.new_orphan_copy::<text::Owned>("filler").unwrap();
+        }
+        let mut list = orphanage.get_growable_list(&mut orphan).unwrap();
+        list.push(i).unwrap();
+        assert_eq!(list.len(), i + 1);
+        assert!(list.capacity() >= list.len());
+    }
+    let mut list = orphanage.get_growable_list(&mut orphan).unwrap();
+    list.extend_from_slice(&[7, 8, 9]).unwrap();
+    let expected: Vec<u32> = (0..500).chain([7, 8, 9]).collect();
+    let reader = orphanage.get_reader(&orphan).unwrap();
+    assert_eq!(reader.iter().collect::<Vec<_>>(), expected);
+
+    let mut list = orphanage.get_growable_list(&mut orphan).unwrap();
+    list.truncate(3).unwrap();
+    list.as_builder().set(0, 100);
+    assert_eq!(list.into_reader().iter().collect::<Vec<_>>(), [100, 1, 2]);
+}
+
+#[test]
+fn bool_list_truncate() {
+    let mut message = message::Builder::new_default();
+    let mut orphanage = message.get_orphanage();
+    let mut orphan = orphanage
+        .new_orphan_copy::<primitive_list::Owned<bool>>(&[true; 20][..])
+        .unwrap();
+    let mut list = orphanage.get_growable_list(&mut orphan).unwrap();
+    list.truncate(5).unwrap();
+    list.push(false).unwrap();
+    list.push(true).unwrap();
+    let reader = orphanage.get_reader(&orphan).unwrap();
+    assert_eq!(
+        reader.iter().collect::<Vec<_>>(),
+        [true, true, true, true, true, false, true]
+    );
+
+    // The bits that were cut off were zeroed. The list follows the root pointer and the
+    // orphan's pointer.
+    let segments = message.get_segments_for_output();
+    assert_eq!(segments[0][16..24], [0b0101_1111, 0, 0, 0, 0, 0, 0, 0]);
+}
+
+fn set_annotation(mut annotation: annotation::Builder, id: u64) {
+    annotation.set_id(id);
+    annotation.init_value().set_text(format!("annotation {id}"));
+}
+
+fn check_annotations(list: struct_list::Reader<annotation::Owned>, ids: &[u64]) {
+    assert_eq!(list.len() as usize, ids.len());
+    for (annotation, &id) in list.iter().zip(ids) {
+        assert_eq!(annotation.get_id(), id);
+        let value::Text(text) = annotation.get_value().unwrap().which().unwrap() else {
+            panic!("expected text")
+        };
+        assert_eq!(text.unwrap(), &format!("annotation {id}")[..]);
+    }
+}
+
+#[test]
+fn struct_list_appends() {
+    let mut message = message::Builder::new(HeapAllocator::new().first_segment_words(16));
+    message.init_root::<node::Builder>();
+    let mut orphanage = message.get_orphanage();
+    let mut orphan = orphanage.new_orphan::<struct_list::Owned<annotation::Owned>>();
+    for i in 0..100 {
+        let mut list = orphanage.get_growable_struct_list(&mut orphan).unwrap();
+        set_annotation(list.push().unwrap(), i);
+    }
+    let ids: Vec<u64> = (0..100).collect();
+    check_annotations(orphanage.get_reader(&orphan).unwrap(), &ids);
+
+    message
+        .get_root::<node::Builder>()
+        .unwrap()
+        .adopt_annotations(orphan)
+        .unwrap();
+    let root = message.get_root_as_reader::<node::Reader>().unwrap();
+    check_annotations(root.get_annotations().unwrap(), &ids);
+
+    message.compact().unwrap();
+    let root = message.get_root_as_reader::<node::Reader>().unwrap();
+    check_annotations(root.get_annotations().unwrap(), &ids);
+}
+
+#[test]
+fn struct_list_shrinks_in_place() {
+    let mut message = message::Builder::new_default();
+    let mut root = message.init_root::<node::Builder>();
+    let mut annotations = root.reborrow().init_annotations(10);
+    for i in 0..10 {
+        set_annotation(annotations.reborrow().get(i), i as u64);
+    }
+    let size_before = message
+        .get_root_as_reader::<node::Reader>()
+        .unwrap()
+        .total_size()
+        .unwrap();
+
+    let mut orphan = message
+        .get_root::<node::Builder>()
+        .unwrap()
+        .disown_annotations();
+    let mut orphanage = message.get_orphanage();
+    let mut list = orphanage.get_growable_struct_list(&mut orphan).unwrap();
+    assert_eq!(list.len(), 10);
+    list.truncate(4).unwrap();
+    // A new element reuses the space of a removed one, which has been zeroed.
+    let element = list.push().unwrap().into_reader();
+    assert_eq!(element.get_id(), 0);
+    assert!(!element.has_value());
+    set_annotation(list.as_builder().get(4), 40);
+    message
+        .get_root::<node::Builder>()
+        .unwrap()
+        .adopt_annotations(orphan)
+        .unwrap();
+
+    let root = message.get_root_as_reader::<node::Reader>().unwrap();
+    check_annotations(root.get_annotations().unwrap(), &[0, 1, 2, 3, 40]);
+    assert!(root.total_size().unwrap().word_count < size_before.word_count);
+}
+
+#[test]
+fn list_growth_past_limit() {
+    let mut message = message::Builder::new_default();
+    message.init_root::<node::Builder>();
+    let mut orphanage = message.get_orphanage();
+
+    let mut orphan = orphanage.new_orphan::<primitive_list::Owned<u32>>();
+    let mut list = orphanage.get_growable_list(&mut orphan).unwrap();
+    list.push(1).unwrap();
+    assert!(list.reserve(u32::MAX).is_err());
+    assert!(list.reserve(1 << 29).is_err());
+    assert_eq!(list.len(), 1);
+
+    let mut orphan = orphanage.new_orphan::<struct_list::Owned<annotation::Owned>>();
+    let mut list = orphanage.get_growable_struct_list(&mut orphan).unwrap();
+    set_annotation(list.push().unwrap(), 1);
+    assert!(list.reserve(u32::MAX).is_err());
+    // Fewer than 2^29 elements, but more than 2^29 words.
+    assert!(list.reserve(1 << 28).is_err());
+    assert_eq!(list.len(), 1);
+    check_annotations(list.into_reader(), &[1]);
+}
@@ -0,0 +1,235 @@
+#![cfg(feature = "alloc")]
+
+use capnp::schema_capnp::{node, value, ElementSize};
+use capnp::{json, message};
+
+mod common;
+
+use common::{init_test_node, TEST_NODE_ID};
+
+#[test]
+fn encode_struct() {
+    let mut message = message::Builder::new_default();
+    init_test_node(message.init_root());
+    let root: node::Reader = message.get_root_as_reader().unwrap();
+    let encoded = json::to_json(root).unwrap();
+    assert_eq!(
+        encoded,
+        concat!(
+            r#"{"id":"16045690981402826360","displayName":"foo.capnp:Bar","displayNamePrefixLength":0,"#,
+            r#""scopeId":"4660","#,
+            r#""nestedNodes":[{"name":"Inner","id":"1"},{"name":"Other","id":"2"}],"#,
+            r#""annotations":[{"id":"1","value":{"data":"AQID/w=="}}],"#,
+            r#""struct":{"dataWordCount":2,"pointerCount":0,"preferredListEncoding":"inlineComposite","#,
+            r#""isGroup":false,"discriminantCount":0,"discriminantOffset":0,"#,
+            r#""fields":[{"name":"qux \"quoted\"\t\u0001\n","codeOrder":3,"discriminantValue":65535,"#,
+            r#""slot":{"offset":1,"type":{"float64":null},"defaultValue":{"float64":-0.0015},"hadExplicitDefault":false},"#,
+            r#""ordinal":{"implicit":null}}]},"isGeneric":false}"#
+        )
+    );
+}
+
+#[test]
+fn round_trip() {
+    let mut message = message::Builder::new_default();
+    init_test_node(message.init_root());
+    let root: node::Reader = message.get_root_as_reader().unwrap();
+    let encoded = json::to_json(root).unwrap();
+
+    let mut message2 = message::Builder::new_default();
+    json::from_json(&encoded, message2.init_root::<node::Builder>()).unwrap();
+    let root2: node::Reader = message2.get_root_as_reader().unwrap();
+    assert_eq!(json::to_json(root2).unwrap(), encoded);
+    assert_eq!(root2.get_id(), TEST_NODE_ID);
+    let node::Struct(st) = root2.which().unwrap() else {
+        panic!("expected struct")
+    };
+    assert_eq!(
+        st.get_preferred_list_encoding().unwrap(),
+        ElementSize::InlineComposite
+    );
+}
+
+#[test]
+fn decode_alternate_forms() {
+    let mut message = message::Builder::new_default();
+    json::from_json(
+        r#"{ "data": [104, 105] , "ignored": {"x": [1, 2.5e3]} }"#,
+        message.init_root::<value::Builder>(),
+    )
+    .unwrap();
+    let root: value::Reader = message.get_root_as_reader().unwrap();
+    let value::Data(d) = root.which().unwrap() else {
+        panic!("expected data")
+    };
+    assert_eq!(d.unwrap(), b"hi");
+
+    json::from_json(
+        r#"{"float64": "-Infinity"}"#,
+        message.init_root::<value::Builder>(),
+    )
+    .unwrap();
+    let root: value::Reader = message.get_root_as_reader().unwrap();
+    let value::Float64(f) = root.which().unwrap() else {
+        panic!("expected float64")
+    };
+    assert_eq!(f, f64::NEG_INFINITY);
+    assert_eq!(json::to_json(root).unwrap(), r#"{"float64":"-Infinity"}"#);
+
+    json::from_json(
+        r#"{"uint64": 1e3, "text": "é😀"}"#,
+        message.init_root::<value::Builder>(),
+    )
+    .unwrap();
+    let root: value::Reader = message.get_root_as_reader().unwrap();
+    assert_eq!(json::to_json(root).unwrap(), r#"{"text":"é😀"}"#);
+
+    // Integers above 2^53 are not rounded through a float.
+    json::from_json(
+        r#"{"uint64": 1.8446744073709551615e19}"#,
+        message.init_root::<value::Builder>(),
+    )
+    .unwrap();
+    let root: value::Reader = message.get_root_as_reader().unwrap();
+    let value::Uint64(n) = root.which().unwrap() else {
+        panic!("expected uint64")
+    };
+    assert_eq!(n, u64::MAX);
+    json::from_json(
+        r#"{"int64": -90071992547409930e-1}"#,
+        message.init_root::<value::Builder>(),
+    )
+    .unwrap();
+    let root: value::Reader = message.get_root_as_reader().unwrap();
+    let value::Int64(n) = root.which().unwrap() else {
+        panic!("expected int64")
+    };
+    assert_eq!(n, -9007199254740993);
+}
+
+#[test]
+fn decode_errors() {
+    let mut message = message::Builder::new_default();
+    for bad in [
+        r#"{"int8": 128}"#,
+        r#"{"int8": "x"}"#,
+        r#"{"bool": 1}"#,
+        r#"{"enum": [1]}"#,
+        r#"{"data": "%%%"}"#,
+        r#"{"text": "unterminated}"#,
+        r#"{"text": "a"} trailing"#,
+        r#"[1, 2]"#,
+        r#"{"text" "a"}"#,
+        r#"{"uint64": 18446744073709551616}"#,
+        r#"{"uint64": 1.8446744073709551616e19}"#,
+        r#"{"int64": -9223372036854775809}"#,
+        r#"{"int64": 9007199254740993.5}"#,
+        r#"{"int64": 1e400}"#,
+        r#"{"text": "\u+041"}"#,
+        r#"{"text": "\u-041"}"#,
+        r#"{"data": "AQ="}"#,
+        r#"{"data": "A==="}"#,
+        r#"{"data": "AQ==="}"#,
+        r#"{"data": "AR=="}"#,
+        r#"{"data": "AQF"}"#,
+        r#"{"float32": 1e39}"#,
+        r#"{"float32": -3.5e38}"#,
+    ] {
+        assert!(
+            json::from_json(bad, message.init_root::<value::Builder>()).is_err(),
+            "{bad}"
+        );
+    }
+
+    let deep = "[".repeat(100) + &"]".repeat(100);
+    let err = json::from_json(
+        &format!(r#"{{"int8": {deep}}}"#),
+        message.init_root::<value::Builder>(),
+    )
+    .unwrap_err();
+    assert_eq!(err.kind, capnp::ErrorKind::NestingLimitExceeded);
+}
+
+#[test]
+fn decode_data_encodings() {
+    let mut message = message::Builder::new_default();
+    for (text, bytes) in [
+        ("AQ==", &[1][..]),
+        ("AQ", &[1]),
+        ("AQI=", &[1, 2]),
+        ("AQID", &[1, 2, 3]),
+        ("-_8", &[0xfb, 0xff]),
+    ] {
+        json::from_json(
+            &format!(r#"{{"data": "{text}"}}"#),
+            message.init_root::<value::Builder>(),
+        )
+        .unwrap();
+        let root: value::Reader = message.get_root_as_reader().unwrap();
+        let value::Data(d) = root.which().unwrap() else {
+            panic!("expected data")
+        };
+        assert_eq!(d.unwrap(), bytes, "{text}");
+    }
+
+    json::from_json(
+        r#"{"float32": 3.4028235e38}"#,
+        message.init_root::<value::Builder>(),
+    )
+    .unwrap();
+    let root: value::Reader = message.get_root_as_reader().unwrap();
+    assert!(matches!(root.which().unwrap(), value::Float32(f) if f == f32::MAX));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn decode_hex_data() {
+    use capnp::any_pointer;
+    use capnp::schema_loader::SchemaLoader;
+
+    let mut schema_message = message::Builder::new_default();
+    {
+        let mut node = schema_message.init_root::<node::Builder>();
+        node.set_id(0xabcd_0000_0000_0001);
+        node.set_display_name("hex.capnp:Blob");
+        let mut st = node.init_struct();
+        st.set_pointer_count(1);
+        let mut field = st.init_fields(1).get(0);
+        field.set_name("bytes");
+        field
+            .reborrow()
+            .init_annotations(1)
+            .get(0)
+            .set_id(json::HEX_ANNOTATION_ID);
+        field.init_slot().init_type().set_data(());
+    }
+    let loader = SchemaLoader::new();
+    loader
+        .load(schema_message.get_root_as_reader().unwrap())
+        .unwrap();
+    let schema = loader.get_struct(0xabcd_0000_0000_0001).unwrap();
+
+    let mut message = message::Builder::new_default();
+    let root = message.init_root::<any_pointer::Builder>();
+    let blob = root.init_as_dynamic(schema).unwrap();
+    json::from_json(r#"{"bytes": "00fFa9"}"#, blob).unwrap();
+    let root: any_pointer::Reader = message.get_root_as_reader().unwrap();
+    let bytes: capnp::data::Reader = root
+        .get_as_dynamic(schema)
+        .unwrap()
+        .get_named("bytes")
+        .unwrap()
+        .downcast();
+    assert_eq!(bytes, &[0x00, 0xff, 0xa9]);
+
+    for bad in ["+1", "-1", "0", "0g", "é0"] {
+        let blob = message
+            .init_root::<any_pointer::Builder>()
+            .init_as_dynamic(schema)
+            .unwrap();
+        assert!(
+            json::from_json(&format!(r#"{{"bytes": "{bad}"}}"#), blob).is_err(),
+            "{bad}"
+        );
+    }
+}
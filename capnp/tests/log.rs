@@ -0,0 +1,190 @@
+#![cfg(all(feature = "alloc", feature = "std"))]
+
+use std::io::Cursor;
+
+use capnp::message::{self, ReaderOptions};
+use capnp::schema_capnp::node;
+use capnp::{log, ErrorKind};
+
+mod common;
+
+use common::{node_message, temp_path};
+
+fn write_log(count: u64, index_interval: u32) -> Vec<u8> {
+    let mut writer = log::Writer::with_index_interval(Vec::new(), index_interval).unwrap();
+    for id in 0..count {
+        assert_eq!(writer.append(&node_message(id)).unwrap(), id as usize);
+    }
+    writer.finish().unwrap()
+}
+
+fn id_of(message: &message::Reader<capnp::serialize::OwnedSegments>) -> u64 {
+    message.get_root::<node::Reader>().unwrap().get_id()
+}
+
+#[test]
+fn log_random_access_and_iteration() {
+    let bytes = write_log(10, 3);
+    let mut reader = log::Reader::new(Cursor::new(bytes)).unwrap();
+    assert_eq!(reader.len(), 10);
+    assert_eq!(reader.index_interval(), 3);
+    assert!(!reader.is_truncated());
+
+    for id in [7, 0, 9, 4] {
+        let message = reader.read(id, ReaderOptions::new()).unwrap();
+        assert_eq!(id_of(&message), id as u64);
+        let root = message.get_root::<node::Reader>().unwrap();
+        assert_eq!(root.get_display_name().unwrap(), &*format!("node {id}"));
+    }
+
+    let forwards: Vec<u64> = reader
+        .iter(ReaderOptions::new())
+        .map(|m| id_of(&m.unwrap()))
+        .collect();
+    assert_eq!(forwards, (0..10).collect::<Vec<_>>());
+    let backwards: Vec<u64> = reader
+        .iter(ReaderOptions::new())
+        .rev()
+        .map(|m| id_of(&m.unwrap()))
+        .collect();
+    assert_eq!(backwards, (0..10).rev().collect::<Vec<_>>());
+}
+
+#[test]
+fn log_without_final_index() {
+    let mut bytes = Vec::new();
+    let mut writer = log::Writer::with_index_interval(&mut bytes, 4).unwrap();
+    for id in 0..6 {
+        writer.append(&node_message(id)).unwrap();
+    }
+    // Not finishing the writer leaves two messages after the last index record.
+    drop(writer);
+    let mut reader = log::Reader::new(Cursor::new(bytes)).unwrap();
+    assert!(!reader.is_truncated());
+    assert_eq!(reader.len(), 6);
+    assert_eq!(id_of(&reader.read(5, ReaderOptions::new()).unwrap()), 5);
+    assert_eq!(id_of(&reader.read(1, ReaderOptions::new()).unwrap()), 1);
+
+    let empty = log::Writer::new(Vec::new()).unwrap().finish().unwrap();
+    let reader = log::Reader::new(Cursor::new(empty)).unwrap();
+    assert!(reader.is_empty());
+}
+
+#[test]
+fn log_recovers_from_truncated_tail() {
+    let mut bytes = write_log(10, 3);
+    let full_len = bytes.len();
+    bytes.truncate(full_len - 30);
+    let mut reader = log::Reader::new(Cursor::new(bytes.clone())).unwrap();
+    assert!(reader.is_truncated());
+    // The final index record is damaged, so the last message is still found by scanning.
+    assert_eq!(reader.len(), 10);
+    assert_eq!(id_of(&reader.read(9, ReaderOptions::new()).unwrap()), 9);
+
+    // Cut into the last message as well.
+    bytes.truncate(reader.valid_len() as usize - 100);
+    let mut reader = log::Reader::new(Cursor::new(bytes)).unwrap();
+    assert!(reader.is_truncated());
+    assert_eq!(reader.len(), 9);
+    assert_eq!(reader.iter(ReaderOptions::new()).count(), 9);
+}
+
+#[test]
+fn log_detects_corruption() {
+    let mut bytes = write_log(5, 2);
+
+    // Flip a bit in the display name of the message with id 3.
+    let needle = b"node 3";
+    let pos = bytes
+        .windows(needle.len())
+        .position(|w| w == needle)
+        .unwrap();
+    bytes[pos] ^= 1;
+    let mut reader = log::Reader::new(Cursor::new(bytes)).unwrap();
+    assert_eq!(reader.len(), 5);
+    reader.verify(2).unwrap();
+    assert_eq!(
+        reader.verify(3).unwrap_err().kind,
+        ErrorKind::ChecksumMismatch
+    );
+    let err = reader
+        .read(3, ReaderOptions::new())
+        .map(|_| ())
+        .unwrap_err();
+    assert_eq!(err.kind, ErrorKind::ChecksumMismatch);
+
+    let err = log::Reader::new(Cursor::new(vec![0; 64]))
+        .map(|_| ())
+        .unwrap_err();
+    assert_eq!(err.kind, ErrorKind::InvalidLogRecord);
+}
+
+#[test]
+fn log_file_append_after_crash() {
+    let path = temp_path("append");
+    let mut writer = log::Writer::create(&path).unwrap();
+    for id in 0..5 {
+        writer.append(&node_message(id)).unwrap();
+    }
+    writer.finish().unwrap();
+
+    // Simulate a crash in the middle of appending a record.
+    let mut bytes = std::fs::read(&path).unwrap();
+    let valid_len = bytes.len();
+    let mut torn = Vec::new();
+    log::Writer::new(&mut torn)
+        .unwrap()
+        .append(&node_message(99))
+        .unwrap();
+    bytes.extend_from_slice(&torn[16..torn.len() - 20]);
+    std::fs::write(&path, &bytes).unwrap();
+
+    let mut writer = log::Writer::open(&path).unwrap();
+    assert_eq!(writer.len(), 5);
+    assert_eq!(writer.append(&node_message(5)).unwrap(), 5);
+    writer.finish().unwrap();
+    assert!(std::fs::metadata(&path).unwrap().len() > valid_len as u64);
+
+    let mut reader = log::Reader::open(&path).unwrap();
+    assert!(!reader.is_truncated());
+    let ids: Vec<u64> = reader
+        .iter(ReaderOptions::new())
+        .map(|m| id_of(&m.unwrap()))
+        .collect();
+    assert_eq!(ids, vec![0, 1, 2, 3, 4, 5]);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn log_file_open_refuses_damage_before_the_end() {
+    let path = temp_path("damaged");
+    let mut writer = log::Writer::create(&path).unwrap();
+    for id in 0..5 {
+        writer.append(&node_message(id)).unwrap();
+    }
+    // Without a final index record, opening has to scan the records from the start.
+    writer.flush().unwrap();
+    drop(writer);
+
+    // Headers and trailers alternate, so this is the header of message 2.
+    let mut bytes = std::fs::read(&path).unwrap();
+    let header = bytes
+        .windows(4)
+        .enumerate()
+        .filter(|(_, w)| w == b"msg\0")
+        .nth(4)
+        .unwrap()
+        .0;
+    bytes[header] ^= 1;
+    std::fs::write(&path, &bytes).unwrap();
+
+    let reader = log::Reader::open(&path).unwrap();
+    assert!(reader.is_truncated());
+    assert_eq!(reader.len(), 2);
+    drop(reader);
+
+    let err = log::Writer::open(&path).map(|_| ()).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::InvalidLogRecord);
+    assert_eq!(std::fs::read(&path).unwrap(), bytes);
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,99 @@
+#![cfg(all(feature = "alloc", feature = "std"))]
+
+use capnp::message::{self, ReaderOptions, ReaderSegments};
+use capnp::schema_capnp::node;
+use capnp::{serialize, serialize_packed, ErrorKind};
+
+mod common;
+
+use common::node_message;
+
+fn id_of<S: ReaderSegments>(message: message::Reader<S>) -> u64 {
+    message.get_root::<node::Reader>().unwrap().get_id()
+}
+
+fn write_messages(packed: bool) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for id in 0..3 {
+        if packed {
+            serialize_packed::write_message(&mut bytes, &node_message(id)).unwrap();
+        } else {
+            serialize::write_message(&mut bytes, &node_message(id)).unwrap();
+        }
+    }
+    bytes
+}
+
+#[test]
+fn slice_message_iter() {
+    let bytes = write_messages(false);
+    let mut iter = serialize::SliceMessageIter::new(&bytes, ReaderOptions::new());
+    let ids: Vec<u64> = iter.by_ref().map(|m| id_of(m.unwrap())).collect();
+    assert_eq!(ids, vec![0, 1, 2]);
+    assert!(iter.remainder().is_empty());
+
+    let mut iter = serialize::SliceMessageIter::new(&[], ReaderOptions::new());
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn slice_message_iter_truncated() {
+    let bytes = write_messages(false);
+    for cut in [8, 4] {
+        let mut iter =
+            serialize::SliceMessageIter::new(&bytes[..bytes.len() - cut], ReaderOptions::new());
+        assert_eq!(id_of(iter.next().unwrap().unwrap()), 0);
+        assert_eq!(id_of(iter.next().unwrap().unwrap()), 1);
+        let err = iter.next().unwrap().map(|_| ()).unwrap_err();
+        assert_eq!(err.kind, ErrorKind::PrematureEndOfFile);
+        assert!(iter.next().is_none());
+    }
+
+    // A partial segment table.
+    let mut bytes = bytes;
+    bytes.extend_from_slice(&[0, 0, 0, 0]);
+    let results: Vec<_> = serialize::SliceMessageIter::new(&bytes, ReaderOptions::new()).collect();
+    assert_eq!(results.len(), 4);
+    assert_eq!(
+        results[3].as_ref().map(|_| ()).unwrap_err().kind,
+        ErrorKind::PrematureEndOfFile
+    );
+}
+
+#[test]
+fn message_iter() {
+    let bytes = write_messages(false);
+    let ids: Vec<u64> = serialize::MessageIter::new(&bytes[..], ReaderOptions::new())
+        .map(|m| id_of(m.unwrap()))
+        .collect();
+    assert_eq!(ids, vec![0, 1, 2]);
+
+    let mut iter = serialize::MessageIter::new(&bytes[..bytes.len() - 8], ReaderOptions::new());
+    assert_eq!(iter.by_ref().take(2).filter(|m| m.is_ok()).count(), 2);
+    let err = iter.next().unwrap().map(|_| ()).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::PrematureEndOfFile);
+    assert!(iter.next().is_none());
+
+    // Errors other than truncation are passed through.
+    let mut iter = serialize::MessageIter::new(&[0xff; 16][..], ReaderOptions::new());
+    let err = iter.next().unwrap().map(|_| ()).unwrap_err();
+    assert!(matches!(err.kind, ErrorKind::InvalidNumberOfSegments(_)));
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn packed_message_iter() {
+    let bytes = write_messages(true);
+    let ids: Vec<u64> = serialize_packed::MessageIter::new(&bytes[..], ReaderOptions::new())
+        .map(|m| id_of(m.unwrap()))
+        .collect();
+    assert_eq!(ids, vec![0, 1, 2]);
+
+    let mut iter =
+        serialize_packed::MessageIter::new(&bytes[..bytes.len() - 3], ReaderOptions::new());
+    assert!(iter.next().unwrap().is_ok());
+    assert!(iter.next().unwrap().is_ok());
+    let err = iter.next().unwrap().map(|_| ()).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::PrematureEndOfFile);
+    assert!(iter.next().is_none());
+}
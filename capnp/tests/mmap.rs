@@ -0,0 +1,89 @@
+#![cfg(all(feature = "mmap", target_os = "linux"))]
+
+use capnp::message::{self, AllocationStrategy, ReaderOptions};
+use capnp::mmap::FileAllocator;
+use capnp::schema_capnp::node;
+use capnp::{primitive_list, serialize, ErrorKind};
+
+mod common;
+
+use common::temp_path;
+
+#[test]
+fn read_message_from_mmap() {
+    let path = temp_path("read");
+    let mut message = message::Builder::new_default();
+    let mut root = message.init_root::<node::Builder>();
+    root.set_id(0x1234);
+    root.set_display_name("mapped");
+    let mut bytes = Vec::new();
+    serialize::write_message(&mut bytes, &message).unwrap();
+    std::fs::write(&path, &bytes).unwrap();
+
+    // Nothing writes to the file while it is mapped.
+    let reader = unsafe { serialize::read_message_from_mmap(&path, ReaderOptions::new()) }.unwrap();
+    let root = reader.get_root::<node::Reader>().unwrap();
+    assert_eq!(root.get_id(), 0x1234);
+    assert_eq!(root.get_display_name().unwrap(), "mapped");
+    drop(reader);
+
+    std::fs::write(&path, b"").unwrap();
+    let err = unsafe { serialize::read_message_from_mmap(&path, ReaderOptions::new()) }
+        .map(|_| ())
+        .unwrap_err();
+    assert_eq!(err.kind, ErrorKind::EmptyBuffer);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn file_allocator_round_trip() {
+    let path = temp_path("allocator");
+    let mut allocator = FileAllocator::create(&path)
+        .unwrap()
+        .first_segment_words(100)
+        .allocation_strategy(AllocationStrategy::FixedSize);
+    {
+        let mut message = message::Builder::new(&mut allocator);
+        let mut list = message.initn_root::<primitive_list::Builder<u64>>(1000);
+        for i in 0..1000 {
+            list.set(i, u64::from(i) * 7);
+        }
+    }
+    assert!(allocator.segment_count() > 1);
+    allocator.sync_all().unwrap();
+    let file_len = allocator.into_file().metadata().unwrap().len();
+
+    // Nothing writes to the file while it is mapped.
+    let reader = unsafe { serialize::read_message_from_mmap(&path, ReaderOptions::new()) }.unwrap();
+    let list = reader.get_root::<primitive_list::Reader<u64>>().unwrap();
+    assert_eq!(list.len(), 1000);
+    for i in 0..1000 {
+        assert_eq!(list.get(i), u64::from(i) * 7);
+    }
+    drop(reader);
+
+    // The file is also a valid stream, and has no trailing garbage.
+    let bytes = std::fs::read(&path).unwrap();
+    assert_eq!(bytes.len() as u64, file_len);
+    let mut slice = &bytes[..];
+    serialize::read_message(&mut slice, ReaderOptions::new()).unwrap();
+    assert!(slice.is_empty());
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn file_allocator_holds_one_message() {
+    let path = temp_path("finished");
+    let mut allocator = FileAllocator::create(&path).unwrap();
+    {
+        let mut message = message::Builder::new(&mut allocator);
+        message.set_root("first").unwrap();
+    }
+    let mut message = message::Builder::new(&mut allocator);
+    assert_eq!(
+        message.set_root("second").unwrap_err().kind,
+        ErrorKind::AllocationFailed
+    );
+    drop(message);
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,178 @@
+#![cfg(feature = "alloc")]
+
+use capnp::message;
+use capnp::schema_capnp::{field, node, value};
+use capnp::ErrorKind;
+
+#[test]
+fn move_between_fields() {
+    let mut message = message::Builder::new_default();
+    let mut root = message.init_root::<node::Builder>();
+    let mut annotations = root.reborrow().init_annotations(3);
+    for i in 0..3 {
+        annotations.reborrow().get(i).set_id(100 + i as u64);
+    }
+    root.reborrow().init_struct().init_fields(1);
+    let size_before = message
+        .get_root_as_reader::<node::Reader>()
+        .unwrap()
+        .total_size()
+        .unwrap();
+
+    let mut root = message.get_root::<node::Builder>().unwrap();
+    let orphan = root.disown_annotations();
+    assert!(!root.has_annotations());
+    let node::Struct(st) = root.which().unwrap() else {
+        panic!("expected struct")
+    };
+    let mut field = st.get_fields().unwrap().get(0);
+    field.adopt_annotations(orphan).unwrap();
+
+    let root: node::Reader = message.get_root_as_reader().unwrap();
+    assert!(!root.has_annotations());
+    let node::Struct(st) = root.which().unwrap() else {
+        panic!("expected struct")
+    };
+    let annotations = st.get_fields().unwrap().get(0).get_annotations().unwrap();
+    let ids: Vec<u64> = annotations.iter().map(|a| a.get_id()).collect();
+    assert_eq!(ids, [100, 101, 102]);
+
+    // The annotations were moved, not copied.
+    assert_eq!(
+        root.total_size().unwrap().word_count,
+        size_before.word_count
+    );
+}
+
+#[test]
+fn orphanage() {
+    let mut message = message::Builder::new_default();
+    let mut orphanage = message.get_orphanage();
+    let mut name = orphanage.new_orphan::<capnp::text::Owned>();
+    assert!(orphanage.is_null(&name).unwrap());
+    orphanage.init(&mut name, 3).unwrap().push_str("foo");
+    let mut nested = orphanage.new_orphan::<node::Owned>();
+    orphanage.get(&mut nested).unwrap().set_id(7);
+    assert_eq!(orphanage.get_reader(&nested).unwrap().get_id(), 7);
+    let copy = orphanage
+        .new_orphan_copy::<capnp::text::Owned>("bar")
+        .unwrap();
+
+    let mut root = message.init_root::<node::Builder>();
+    root.adopt_display_name(name).unwrap();
+    assert_eq!(root.reborrow().get_display_name().unwrap(), "foo");
+    root.adopt_display_name(copy).unwrap();
+    assert_eq!(root.reborrow().get_display_name().unwrap(), "bar");
+
+    let mut value = root.init_struct().init_fields(1).get(0).init_slot();
+    value
+        .reborrow()
+        .init_default_value()
+        .adopt_struct(nested.into_any_pointer())
+        .unwrap();
+    let value::Struct(st) = value
+        .into_reader()
+        .get_default_value()
+        .unwrap()
+        .which()
+        .unwrap()
+    else {
+        panic!("expected struct")
+    };
+    assert_eq!(st.get_as::<node::Reader>().unwrap().get_id(), 7);
+}
+
+#[test]
+fn different_message() {
+    let mut message1 = message::Builder::new_default();
+    message1
+        .init_root::<node::Builder>()
+        .set_display_name("foo");
+    let orphan = message1
+        .get_root::<node::Builder>()
+        .unwrap()
+        .disown_display_name();
+
+    let mut message2 = message::Builder::new_default();
+    let error = message2
+        .init_root::<node::Builder>()
+        .adopt_display_name(orphan)
+        .unwrap_err();
+    assert_eq!(error.kind, ErrorKind::OrphanBelongsToADifferentMessage);
+}
+
+#[test]
+fn dynamic() {
+    let mut message = message::Builder::new_default();
+    let mut root = message.init_root::<node::Builder>();
+    root.set_display_name("foo");
+    let mut value = root
+        .init_struct()
+        .init_fields(1)
+        .get(0)
+        .init_slot()
+        .init_default_value();
+    value.set_text("bar");
+
+    let mut root = message.get_root::<node::Builder>().unwrap();
+    let capnp::dynamic_value::Builder::Struct(mut dynamic) = root.reborrow().into() else {
+        panic!("expected struct")
+    };
+    let orphan = dynamic.disown_named("displayName").unwrap();
+    assert!(!dynamic.has_named("displayName").unwrap());
+    assert!(dynamic.disown_named("id").is_err());
+
+    let node::Struct(st) = root.which().unwrap() else {
+        panic!("expected struct")
+    };
+    let field = st.get_fields().unwrap().get(0);
+    let capnp::dynamic_value::Builder::Struct(mut dynamic_field) = field.into() else {
+        panic!("expected struct")
+    };
+    dynamic_field.adopt_named("name", orphan).unwrap();
+
+    let node::Struct(st) = message
+        .get_root::<node::Builder>()
+        .unwrap()
+        .which()
+        .unwrap()
+    else {
+        panic!("expected struct")
+    };
+    let mut field = st.get_fields().unwrap().get(0);
+    assert_eq!(field.reborrow().get_name().unwrap(), "foo");
+    let field::Slot(slot) = field.which().unwrap() else {
+        panic!("expected slot")
+    };
+    let capnp::dynamic_value::Builder::Struct(mut value) = slot.get_default_value().unwrap().into()
+    else {
+        panic!("expected struct")
+    };
+    assert!(value.disown_named("data").is_err());
+    let text = value.disown_named("text").unwrap();
+    value.adopt_named("data", text).unwrap();
+    // Text is stored with its NUL terminator.
+    assert!(matches!(
+        value.into_reader().get_named("data").unwrap(),
+        capnp::dynamic_value::Reader::Data(b"bar\0")
+    ));
+}
+
+#[test]
+fn disown_union_member() {
+    let mut message = message::Builder::new_default();
+    let mut root = message.init_root::<value::Builder>();
+    root.set_text("bar");
+
+    // `data` shares its pointer with `text`, which is the active member.
+    assert!(root.disown_data().is_err());
+    assert!(root.has_text());
+
+    let text = root.disown_text().unwrap();
+    root.adopt_data(text.into_any_pointer().into_typed())
+        .unwrap();
+    let value::Data(data) = root.into_reader().which().unwrap() else {
+        panic!("expected data")
+    };
+    assert_eq!(data.unwrap(), b"bar\0");
+}
@@ -0,0 +1,149 @@
+#![cfg(feature = "std")]
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
+use capnp::message::{self, AllocationStrategy, PooledAllocator, SegmentPool};
+use capnp::schema_capnp::node;
+use capnp::{primitive_list, text};
+
+#[test]
+fn pooled_allocator_reuses_segments() {
+    let pool = Rc::new(RefCell::new(SegmentPool::new()));
+    for i in 0..10 {
+        let mut message =
+            message::Builder::new(PooledAllocator::new(pool.clone()).first_segment_words(16));
+        let mut root = message.init_root::<node::Builder>();
+        root.set_display_name(&*format!("node {i}"));
+        root.set_id(i);
+
+        // Segments come back zeroed, so an uninitialized field reads as its default.
+        let root = message.get_root_as_reader::<node::Reader>().unwrap();
+        assert_eq!(root.get_id(), i);
+        assert_eq!(root.get_scope_id(), 0);
+        assert_eq!(root.get_display_name().unwrap(), &*format!("node {i}"));
+    }
+    let stats = pool.borrow().stats();
+    assert_eq!(stats.misses, 1);
+    assert_eq!(stats.hits, 9);
+    assert_eq!(stats.pooled_segments, 1);
+    assert_eq!(stats.pooled_words, 16);
+}
+
+#[test]
+fn pooled_allocator_grows_segments() {
+    let pool = Rc::new(RefCell::new(SegmentPool::new()));
+    for _ in 0..3 {
+        let mut message = message::Builder::new(
+            PooledAllocator::new(pool.clone())
+                .first_segment_words(8)
+                .allocation_strategy(AllocationStrategy::FixedSize),
+        );
+        let mut list = message.initn_root::<primitive_list::Builder<u64>>(20);
+        for i in 0..20 {
+            list.set(i, u64::from(i) * 3);
+        }
+        let list = message
+            .get_root_as_reader::<primitive_list::Reader<u64>>()
+            .unwrap();
+        assert_eq!(list.iter().sum::<u64>(), 570);
+    }
+    let stats = pool.borrow().stats();
+    assert_eq!(stats.misses, 2);
+    assert_eq!(stats.hits, 4);
+}
+
+#[test]
+fn segment_pool_limits() {
+    let pool = Rc::new(RefCell::new(SegmentPool::new().max_segment_words(64)));
+    {
+        let mut message =
+            message::Builder::new(PooledAllocator::new(pool.clone()).first_segment_words(128));
+        message.set_root("too big to keep").unwrap();
+    }
+    assert_eq!(pool.borrow().stats().pooled_segments, 0);
+
+    let pool = Rc::new(RefCell::new(SegmentPool::new().max_pooled_words(10)));
+    for _ in 0..2 {
+        let _ = message::Builder::new(PooledAllocator::new(pool.clone()).first_segment_words(8))
+            .set_root("x");
+    }
+    assert_eq!(pool.borrow().stats().pooled_words, 8);
+    pool.borrow_mut().clear();
+    assert_eq!(pool.borrow().stats().pooled_segments, 0);
+}
+
+#[test]
+fn pooled_allocator_across_threads() {
+    let pool = Arc::new(Mutex::new(SegmentPool::new()));
+    let threads: Vec<_> = (0..4)
+        .map(|t| {
+            let pool = pool.clone();
+            std::thread::spawn(move || {
+                for i in 0..100 {
+                    let mut message = message::Builder::new(PooledAllocator::new(pool.clone()));
+                    let value = format!("{t}:{i}");
+                    message.set_root(&*value).unwrap();
+                    let read: text::Reader = message.get_root_as_reader().unwrap();
+                    assert_eq!(read, &*value);
+                }
+            })
+        })
+        .collect();
+    for thread in threads {
+        thread.join().unwrap();
+    }
+    let stats = pool.lock().unwrap().stats();
+    assert_eq!(stats.hits + stats.misses, 400);
+    assert!(stats.misses <= 4);
+}
+
+#[test]
+fn builder_reset() {
+    let mut message = message::Builder::new_default();
+    let mut root = message.init_root::<node::Builder>();
+    root.set_id(7);
+    root.set_display_name("first");
+    let before = message.get_segments_for_output()[0].as_ptr();
+
+    message.reset();
+    assert_eq!(message.get_segments_for_output()[0].len(), 8);
+    let root = message.get_root_as_reader::<node::Reader>().unwrap();
+    assert_eq!(root.get_id(), 0);
+    assert!(!root.has_display_name());
+
+    let mut root = message.init_root::<node::Builder>();
+    root.set_display_name("second");
+    assert_eq!(message.get_segments_for_output()[0].as_ptr(), before);
+    let root = message.get_root_as_reader::<node::Reader>().unwrap();
+    assert_eq!(root.get_display_name().unwrap(), "second");
+}
+
+#[test]
+fn builder_reset_multi_segment() {
+    let mut message = message::Builder::new(
+        message::HeapAllocator::new()
+            .first_segment_words(4)
+            .allocation_strategy(AllocationStrategy::FixedSize),
+    );
+    message
+        .initn_root::<primitive_list::Builder<u64>>(10)
+        .set(9, 99);
+    assert!(message.get_segments_for_output().len() > 1);
+
+    message.reset();
+    assert_eq!(message.get_segments_for_output().len(), 1);
+    message.set_root("hi").unwrap();
+    assert_eq!(message.get_segments_for_output().len(), 1);
+    let read: text::Reader = message.get_root_as_reader().unwrap();
+    assert_eq!(read, "hi");
+
+    message
+        .initn_root::<primitive_list::Builder<u64>>(10)
+        .set(9, 99);
+    let list = message
+        .get_root_as_reader::<primitive_list::Reader<u64>>()
+        .unwrap();
+    assert_eq!(list.get(9), 99);
+}
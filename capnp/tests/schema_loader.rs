@@ -0,0 +1,481 @@
+#![cfg(all(feature = "alloc", feature = "std"))]
+
+use std::collections::HashSet;
+
+use capnp::capability::FromClientHook;
+use capnp::introspect::{Introspect, Type, TypeVariant};
+use capnp::private::capability::ClientHook;
+use capnp::schema::{EnumSchema, InterfaceSchema, StructSchema};
+use capnp::schema_capnp::node;
+use capnp::schema_loader::SchemaLoader;
+use capnp::{any_pointer, capability_list, dynamic_list, dynamic_value, message};
+
+mod common;
+
+use common::{init_test_node, TEST_NODE_ID};
+
+const FILE_ID: u64 = 0xd2b7_3a9c_5e1f_4000;
+const BOX_ID: u64 = 0xd2b7_3a9c_5e1f_4001;
+const HOLDER_ID: u64 = 0xd2b7_3a9c_5e1f_4002;
+const BASE_ID: u64 = 0xd2b7_3a9c_5e1f_4003;
+const CHILD_ID: u64 = 0xd2b7_3a9c_5e1f_4004;
+const PARAMS_ID: u64 = 0xd2b7_3a9c_5e1f_4005;
+const RESULTS_ID: u64 = 0xd2b7_3a9c_5e1f_4006;
+
+/// Collects the nodes of `ty` and of every type reachable from it.
+fn collect_nodes(
+    ty: Type<'static>,
+    seen: &mut HashSet<u64>,
+    nodes: &mut Vec<node::Reader<'static>>,
+) {
+    match ty.which() {
+        TypeVariant::Struct(st) => {
+            let schema: StructSchema = st.into();
+            if seen.insert(schema.get_proto().get_id()) {
+                nodes.push(schema.get_proto());
+                for field in schema.get_fields().unwrap() {
+                    collect_nodes(field.get_type(), seen, nodes);
+                }
+            }
+        }
+        TypeVariant::Enum(en) => {
+            let schema: EnumSchema = en.into();
+            if seen.insert(schema.get_proto().get_id()) {
+                nodes.push(schema.get_proto());
+            }
+        }
+        TypeVariant::List(element) => collect_nodes(element, seen, nodes),
+        _ => (),
+    }
+}
+
+/// Loads the nodes that schema.capnp's generated code carries for `node`.
+fn load_schema_capnp(loader: &SchemaLoader) {
+    let mut nodes = Vec::new();
+    collect_nodes(node::Owned::introspect(), &mut HashSet::new(), &mut nodes);
+    loader.load_all(nodes).unwrap();
+}
+
+fn node_id() -> u64 {
+    let TypeVariant::Struct(st) = node::Owned::introspect().which() else {
+        unreachable!()
+    };
+    StructSchema::from(st).get_proto().get_id()
+}
+
+#[test]
+fn read_like_generated_code() {
+    let loader = SchemaLoader::new();
+    load_schema_capnp(&loader);
+    let schema = loader.get_struct(node_id()).unwrap();
+
+    let mut message = message::Builder::new_default();
+    init_test_node(message.init_root());
+    let generated: node::Reader = message.get_root_as_reader().unwrap();
+    let root: any_pointer::Reader = message.get_root_as_reader().unwrap();
+    let loaded = root.get_as_dynamic(schema).unwrap();
+
+    assert_eq!(
+        format!("{:?}", dynamic_value::Reader::from(generated)),
+        format!("{loaded:?}")
+    );
+    assert_eq!(
+        loaded
+            .get_named("displayName")
+            .unwrap()
+            .downcast::<capnp::text::Reader>(),
+        "foo.capnp:Bar"
+    );
+
+    // Enums and annotations come along as well.
+    let list_encoding = loaded
+        .get_named("struct")
+        .unwrap()
+        .downcast::<capnp::dynamic_struct::Reader>()
+        .get_named("preferredListEncoding")
+        .unwrap()
+        .downcast::<dynamic_value::Enum>();
+    let enumerant = list_encoding.get_enumerant().unwrap().unwrap();
+    assert_eq!(enumerant.get_proto().get_name().unwrap(), "inlineComposite");
+}
+
+#[test]
+fn write_through_loaded_schema() {
+    let loader = SchemaLoader::new();
+    load_schema_capnp(&loader);
+    let schema = loader.get_struct(node_id()).unwrap();
+
+    let mut message = message::Builder::new_default();
+    {
+        let root: any_pointer::Builder = message.init_root();
+        let mut node = root.init_as_dynamic(schema).unwrap();
+        node.set_named("id", 77u64.into()).unwrap();
+        node.set_named("displayName", "x.capnp:Y".into()).unwrap();
+        let mut st = node
+            .init_named("struct")
+            .unwrap()
+            .downcast::<capnp::dynamic_struct::Builder>();
+        st.set_named("dataWordCount", 3u16.into()).unwrap();
+    }
+    let generated: node::Reader = message.get_root_as_reader().unwrap();
+    assert_eq!(generated.get_id(), 77);
+    assert_eq!(generated.get_display_name().unwrap(), "x.capnp:Y");
+    let node::Struct(st) = generated.which().unwrap() else {
+        panic!("expected a struct node")
+    };
+    assert_eq!(st.get_data_word_count(), 3);
+}
+
+/// Builds `struct Box(T) { value @0 :T; }` and `struct Holder { count @0 :UInt32;
+/// box @1 :Box(Text); }`.
+fn init_generic_nodes(mut nodes: capnp::struct_list::Builder<node::Owned>) {
+    {
+        let mut node = nodes.reborrow().get(0);
+        node.set_id(BOX_ID);
+        node.set_display_name("test.capnp:Box");
+        node.set_scope_id(FILE_ID);
+        node.set_is_generic(true);
+        node.reborrow().init_parameters(1).get(0).set_name("T");
+        let mut st = node.init_struct();
+        st.set_pointer_count(1);
+        let mut field = st.init_fields(1).get(0);
+        field.set_name("value");
+        let mut slot = field.init_slot();
+        slot.reborrow().init_default_value().init_any_pointer();
+        let mut parameter = slot.init_type().init_any_pointer().init_parameter();
+        parameter.set_scope_id(BOX_ID);
+        parameter.set_parameter_index(0);
+    }
+    {
+        let mut node = nodes.get(1);
+        node.set_id(HOLDER_ID);
+        node.set_display_name("test.capnp:Holder");
+        node.set_scope_id(FILE_ID);
+        let mut st = node.init_struct();
+        st.set_data_word_count(1);
+        st.set_pointer_count(1);
+        let mut fields = st.init_fields(2);
+        {
+            let mut field = fields.reborrow().get(0);
+            field.set_name("count");
+            let mut slot = field.init_slot();
+            slot.reborrow().init_default_value().set_uint32(0);
+            slot.init_type().set_uint32(());
+        }
+        {
+            let mut field = fields.get(1);
+            field.set_name("box");
+            field.set_code_order(1);
+            let mut slot = field.init_slot();
+            slot.reborrow().init_default_value().init_struct();
+            let mut ty = slot.init_type().init_struct();
+            ty.set_type_id(BOX_ID);
+            let mut scope = ty.init_brand().init_scopes(1).get(0);
+            scope.set_scope_id(BOX_ID);
+            scope.init_bind(1).get(0).init_type().set_text(());
+        }
+    }
+}
+
+#[test]
+fn generics() {
+    let mut schema_message = message::Builder::new_default();
+    init_generic_nodes(schema_message.initn_root(2));
+    let loader = SchemaLoader::new();
+    loader
+        .load_all(
+            schema_message
+                .get_root_as_reader::<capnp::struct_list::Reader<node::Owned>>()
+                .unwrap(),
+        )
+        .unwrap();
+
+    // Without a brand, the parameter is an AnyPointer.
+    let unbranded = loader.get_struct(BOX_ID).unwrap();
+    let value = unbranded.get_field_by_name("value").unwrap();
+    assert!(value.get_type() == TypeVariant::AnyPointer.into());
+
+    let holder = loader.get_struct(HOLDER_ID).unwrap();
+    let box_type = holder.get_field_by_name("box").unwrap().get_type();
+    let TypeVariant::Struct(branded) = box_type.which() else {
+        panic!("expected a struct")
+    };
+    let branded = StructSchema::from(branded);
+    let value = branded.get_field_by_name("value").unwrap();
+    assert!(value.get_type() == TypeVariant::Text.into());
+
+    // The same brand resolves to the same schema.
+    assert!(holder.get_field_by_name("box").unwrap().get_type() == box_type);
+
+    let mut message = message::Builder::new_default();
+    {
+        let root: any_pointer::Builder = message.init_root();
+        let mut h = root.init_as_dynamic(holder).unwrap();
+        h.set_named("count", 5u32.into()).unwrap();
+        let mut b = h
+            .init_named("box")
+            .unwrap()
+            .downcast::<capnp::dynamic_struct::Builder>();
+        b.set_named("value", "hello".into()).unwrap();
+    }
+    let root: any_pointer::Reader = message.get_root_as_reader().unwrap();
+    let h = root.get_as_dynamic(holder).unwrap();
+    assert_eq!(format!("{h:?}"), r#"(count = 5, box = (value = "hello"))"#);
+}
+
+#[test]
+fn errors() {
+    let loader = SchemaLoader::new();
+    assert!(loader.get_struct(HOLDER_ID).is_err());
+
+    let mut message = message::Builder::new_default();
+    init_test_node(message.init_root());
+    loader.load(message.get_root_as_reader().unwrap()).unwrap();
+    // Loading the same node again is fine.
+    loader.load(message.get_root_as_reader().unwrap()).unwrap();
+    assert!(loader.get_enum(TEST_NODE_ID).is_err());
+
+    // A different node with the same ID is not.
+    message
+        .get_root::<node::Builder>()
+        .unwrap()
+        .set_display_name("foo.capnp:Other");
+    assert!(loader.load(message.get_root_as_reader().unwrap()).is_err());
+
+    // Fields must lie within the struct.
+    let mut message = message::Builder::new_default();
+    let mut node = message.init_root::<node::Builder>();
+    node.set_id(0x1234_5679);
+    let mut st = node.init_struct();
+    st.set_data_word_count(1);
+    let mut slot = st.init_fields(1).get(0).init_slot();
+    slot.set_offset(2);
+    slot.init_type().set_uint32(());
+    assert!(loader.load(message.get_root_as_reader().unwrap()).is_err());
+
+    // ...and union members must have distinct discriminants.
+    let mut message = message::Builder::new_default();
+    let mut node = message.init_root::<node::Builder>();
+    node.set_id(0x1234_567a);
+    let mut st = node.init_struct();
+    st.set_data_word_count(1);
+    st.set_discriminant_count(2);
+    let mut fields = st.init_fields(2);
+    for i in 0..2 {
+        let mut field = fields.reborrow().get(i);
+        field.set_discriminant_value(0);
+        field.init_slot().init_type().set_void(());
+    }
+    assert!(loader.load(message.get_root_as_reader().unwrap()).is_err());
+}
+
+/// Builds `interface Base { ping @0 () -> (); }` and
+/// `interface Child extends(Base) { getName @0 (id :UInt32) -> (name :Text); }`,
+/// with `ping` using Holder's node for both its params and its results.
+fn init_interface_nodes(mut nodes: capnp::struct_list::Builder<node::Owned>) {
+    {
+        let mut node = nodes.reborrow().get(0);
+        node.set_id(BASE_ID);
+        node.set_display_name("test.capnp:Base");
+        node.set_scope_id(FILE_ID);
+        let mut method = node.init_interface().init_methods(1).get(0);
+        method.set_name("ping");
+        method.set_param_struct_type(HOLDER_ID);
+        method.set_result_struct_type(HOLDER_ID);
+    }
+    {
+        let mut node = nodes.reborrow().get(1);
+        node.set_id(CHILD_ID);
+        node.set_display_name("test.capnp:Child");
+        node.set_scope_id(FILE_ID);
+        let mut interface = node.init_interface();
+        interface
+            .reborrow()
+            .init_superclasses(1)
+            .get(0)
+            .set_id(BASE_ID);
+        let mut method = interface.init_methods(1).get(0);
+        method.set_name("getName");
+        method.set_param_struct_type(PARAMS_ID);
+        method.set_result_struct_type(RESULTS_ID);
+    }
+    {
+        let mut node = nodes.reborrow().get(2);
+        node.set_id(PARAMS_ID);
+        node.set_display_name("test.capnp:Child.getName$Params");
+        let mut st = node.init_struct();
+        st.set_data_word_count(1);
+        let mut slot = st.init_fields(1).get(0).init_slot();
+        slot.reborrow().init_default_value().set_uint32(0);
+        slot.init_type().set_uint32(());
+    }
+    {
+        let mut node = nodes.get(3);
+        node.set_id(RESULTS_ID);
+        node.set_display_name("test.capnp:Child.getName$Results");
+        let mut st = node.init_struct();
+        st.set_pointer_count(1);
+        let mut field = st.init_fields(1).get(0);
+        field.set_name("name");
+        let mut slot = field.init_slot();
+        slot.reborrow().init_default_value().set_text("");
+        slot.init_type().set_text(());
+    }
+}
+
+#[test]
+fn interfaces() {
+    let mut schema_message = message::Builder::new_default();
+    init_interface_nodes(schema_message.initn_root(4));
+    let loader = SchemaLoader::new();
+    loader
+        .load_all(
+            schema_message
+                .get_root_as_reader::<capnp::struct_list::Reader<node::Owned>>()
+                .unwrap(),
+        )
+        .unwrap();
+    assert!(loader.get_struct(CHILD_ID).is_err());
+
+    let child = loader.get_interface(CHILD_ID).unwrap();
+    let get_name = child.get_method_by_name("getName").unwrap();
+    assert_eq!(get_name.get_ordinal(), 0);
+    let results = get_name.get_result_type();
+    assert!(results.get_field_by_name("name").unwrap().get_type() == TypeVariant::Text.into());
+
+    // Methods of superclasses are found too. Holder has not been loaded,
+    // so the params of `ping` are empty for now...
+    let base = child.get_superclasses().unwrap().get(0);
+    assert_eq!(base.get_proto().get_id(), BASE_ID);
+    let ping = child.get_method_by_name("ping").unwrap();
+    assert_eq!(
+        ping.get_containing_interface().get_proto().get_id(),
+        BASE_ID
+    );
+    assert!(ping.get_param_type().get_fields().unwrap().is_empty());
+
+    // ...but schemas handed out later see it.
+    let mut message = message::Builder::new_default();
+    init_generic_nodes(message.initn_root(2));
+    loader
+        .load_all(
+            message
+                .get_root_as_reader::<capnp::struct_list::Reader<node::Owned>>()
+                .unwrap(),
+        )
+        .unwrap();
+    let base = loader.get_interface(BASE_ID).unwrap();
+    let child: InterfaceSchema = loader.get_interface(CHILD_ID).unwrap();
+    let ping = child.get_method_by_name("ping").unwrap();
+    assert!(ping.get_param_type().get_field_by_name("count").is_ok());
+    assert!(child.extends(base).unwrap());
+    assert!(!base.extends(child).unwrap());
+    assert!(child.find_method_by_id(BASE_ID, 0).unwrap().is_some());
+    assert!(child.find_method_by_id(BASE_ID, 1).unwrap().is_none());
+
+    // Interface types resolve to the same schemas.
+    let mut type_message = message::Builder::new_default();
+    let mut ty = type_message.init_root::<capnp::schema_capnp::type_::Builder>();
+    ty.reborrow().init_interface().set_type_id(CHILD_ID);
+    let ty = loader.get_type(ty.into_reader()).unwrap();
+    let TypeVariant::Capability(interface) = ty.which() else {
+        panic!("expected an interface")
+    };
+    assert!(InterfaceSchema::from(interface).extends(child).unwrap());
+}
+
+#[test]
+fn concurrent_loads() {
+    // Many loaders can come and go; each owns the schemas it hands out.
+    for _ in 0..4 {
+        let loader = SchemaLoader::new();
+        std::thread::scope(|scope| {
+            for _ in 0..8 {
+                scope.spawn(|| {
+                    let mut message = message::Builder::new_default();
+                    init_test_node(message.init_root());
+                    loader.load(message.get_root_as_reader().unwrap()).unwrap();
+                });
+            }
+        });
+        let schema = loader.get_struct(TEST_NODE_ID).unwrap();
+        assert_eq!(schema.get_fields().unwrap().len(), 1);
+    }
+}
+
+#[test]
+fn cyclic_superclasses() {
+    // `Base extends(Child)` on top of `Child extends(Base)`.
+    let mut schema_message = message::Builder::new_default();
+    {
+        let mut nodes = schema_message.initn_root::<capnp::struct_list::Builder<node::Owned>>(4);
+        init_interface_nodes(nodes.reborrow());
+        let mut interface = nodes.get(0).init_interface();
+        interface
+            .reborrow()
+            .init_superclasses(1)
+            .get(0)
+            .set_id(CHILD_ID);
+        interface.init_methods(1).get(0).set_name("ping");
+    }
+    let loader = SchemaLoader::new();
+    loader
+        .load_all(
+            schema_message
+                .get_root_as_reader::<capnp::struct_list::Reader<node::Owned>>()
+                .unwrap(),
+        )
+        .unwrap();
+
+    let child = loader.get_interface(CHILD_ID).unwrap();
+    let base = loader.get_interface(BASE_ID).unwrap();
+    // Searches that find what they look for before going around succeed...
+    assert!(child.find_method_by_name("getName").unwrap().is_some());
+    assert!(child.extends(base).unwrap());
+    // ...and the others fail rather than report that nothing was found.
+    assert!(child.find_method_by_name("missing").is_err());
+    assert!(child.find_method_by_id(0x1234, 0).is_err());
+}
+
+/// A client that does not describe its interface.
+struct UntypedClient {
+    hook: Box<dyn ClientHook>,
+}
+
+impl FromClientHook for UntypedClient {
+    fn new(hook: Box<dyn ClientHook>) -> Self {
+        Self { hook }
+    }
+
+    fn into_client_hook(self) -> Box<dyn ClientHook> {
+        self.hook
+    }
+
+    fn as_client_hook(&self) -> &dyn ClientHook {
+        &*self.hook
+    }
+}
+
+#[test]
+fn untyped_capability_lists() {
+    let TypeVariant::List(element) = capability_list::Owned::<UntypedClient>::introspect().which()
+    else {
+        panic!("expected a list type");
+    };
+    let TypeVariant::Capability(interface) = element.which() else {
+        panic!("expected a capability type");
+    };
+    let schema = InterfaceSchema::from(interface);
+    assert_eq!(schema.get_proto().get_display_name().unwrap(), "Capability");
+    assert!(schema.get_methods().unwrap().is_empty());
+    assert!(schema.get_superclasses().unwrap().is_empty());
+    assert!(schema.find_method_by_name("ping").unwrap().is_none());
+
+    let mut message = message::Builder::new_default();
+    let list = message.initn_root::<capability_list::Builder<UntypedClient>>(2);
+    let list: dynamic_list::Reader = dynamic_value::Reader::from(list.into_reader()).downcast();
+    assert!(matches!(
+        list.get(1).unwrap(),
+        dynamic_value::Reader::Capability(_)
+    ));
+}
@@ -0,0 +1,71 @@
+#![cfg(feature = "std")]
+
+use std::io::Cursor;
+
+use capnp::message::{self, AllocationStrategy, HeapAllocator, ReaderOptions, ReaderSegments};
+use capnp::{list_list, primitive_list, serialize, ErrorKind};
+
+fn build_message() -> Vec<u8> {
+    let mut message = message::Builder::new(
+        HeapAllocator::new()
+            .first_segment_words(32)
+            .allocation_strategy(AllocationStrategy::FixedSize),
+    );
+    let mut lists = message.initn_root::<list_list::Builder<primitive_list::Owned<u64>>>(20);
+    for i in 0..20 {
+        let mut list = lists.reborrow().init(i, 30);
+        for j in 0..30 {
+            list.set(j, u64::from(i * 100 + j));
+        }
+    }
+    assert!(message.get_segments_for_output().len() > 10);
+    serialize::write_message_to_words(&message)
+}
+
+#[test]
+fn seekable_segments_load_lazily() {
+    let segments = serialize::SeekableSegments::new(Cursor::new(build_message())).unwrap();
+    let reader = message::Reader::new(&segments, ReaderOptions::new());
+    let lists = reader
+        .get_root::<list_list::Reader<primitive_list::Owned<u64>>>()
+        .unwrap();
+    let list = lists.get(7).unwrap();
+    assert_eq!(list.get(29), 729);
+    assert!(segments.loaded_segment_count() <= 3);
+    assert!(segments.loaded_segment_count() < segments.len());
+
+    // Everything is still there when it is needed.
+    for i in 0..20 {
+        assert_eq!(lists.get(i).unwrap().get(5), u64::from(i * 100 + 5));
+    }
+    assert!(segments.take_error().is_none());
+}
+
+#[test]
+fn read_message_from_seekable_at_offset() {
+    let mut bytes = vec![0xaa; 24];
+    bytes.extend(build_message());
+    let mut cursor = Cursor::new(bytes);
+    cursor.set_position(24);
+    let reader = serialize::read_message_from_seekable(cursor, ReaderOptions::new()).unwrap();
+    let lists = reader
+        .get_root::<list_list::Reader<primitive_list::Owned<u64>>>()
+        .unwrap();
+    assert_eq!(lists.len(), 20);
+    assert_eq!(lists.get(19).unwrap().get(0), 1900);
+}
+
+#[test]
+fn seekable_segments_truncated() {
+    let mut bytes = build_message();
+    bytes.truncate(bytes.len() - 8);
+    let err = serialize::SeekableSegments::new(Cursor::new(bytes))
+        .map(|_| ())
+        .unwrap_err();
+    assert_eq!(err.kind, ErrorKind::PrematureEndOfFile);
+
+    let err = serialize::SeekableSegments::new(Cursor::new(vec![0xff, 0xff, 0, 0, 0, 0, 0, 0]))
+        .map(|_| ())
+        .unwrap_err();
+    assert!(matches!(err.kind, ErrorKind::InvalidNumberOfSegments(_)));
+}
@@ -0,0 +1,77 @@
+#![cfg(all(feature = "alloc", feature = "sync_reader"))]
+
+use capnp::message::{self, ReaderOptions, SharedMessage, TypedBuilder};
+use capnp::schema_capnp::node;
+use capnp::{serialize, ErrorKind};
+
+fn node_builder() -> TypedBuilder<node::Owned> {
+    let mut message = TypedBuilder::<node::Owned>::new_default();
+    let mut root = message.init_root();
+    root.set_id(42);
+    root.set_display_name("shared");
+    let mut nested = root.init_nested_nodes(100);
+    for i in 0..100 {
+        nested.reborrow().get(i).set_id(u64::from(i));
+    }
+    message
+}
+
+fn check(message: &SharedMessage<node::Owned>) {
+    let root = message.get().unwrap();
+    assert_eq!(root.get_id(), 42);
+    assert_eq!(root.get_display_name().unwrap(), "shared");
+    let ids: u64 = root
+        .get_nested_nodes()
+        .unwrap()
+        .iter()
+        .map(|n| n.get_id())
+        .sum();
+    assert_eq!(ids, 4950);
+}
+
+#[test]
+fn shared_message_across_threads() {
+    let shared = node_builder().freeze();
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let shared = shared.clone();
+            std::thread::spawn(move || {
+                // Far more reading than the default traversal limit allows.
+                for _ in 0..1000 {
+                    check(&shared);
+                }
+                shared
+            })
+        })
+        .collect();
+    for handle in handles {
+        assert!(handle.join().unwrap().ptr_eq(&shared));
+    }
+}
+
+#[test]
+fn shared_message_from_bytes() {
+    let builder = node_builder();
+    let mut bytes = Vec::new();
+    serialize::write_message(&mut bytes, builder.borrow_inner()).unwrap();
+    let shared = SharedMessage::<node::Owned>::from_bytes(&bytes, ReaderOptions::new()).unwrap();
+    check(&shared);
+
+    let mut options = ReaderOptions::new();
+    options.nesting_limit(1);
+    let reader = serialize::read_message(&bytes[..], options).unwrap();
+    let err = SharedMessage::<node::Owned>::from_reader(reader)
+        .map(|_| ())
+        .unwrap_err();
+    assert_eq!(err.kind, ErrorKind::MessageIsTooDeeplyNested);
+
+    // The nested nodes were allocated last, so dropping the last word cuts them off.
+    let segments = builder.borrow_inner().get_segments_for_output();
+    let truncated = [&segments[0][..segments[0].len() - 8]];
+    let mut bytes = Vec::new();
+    serialize::write_message_segments(&mut bytes, &message::SegmentArray::new(&truncated)).unwrap();
+    let err = SharedMessage::<node::Owned>::from_bytes(&bytes, ReaderOptions::new())
+        .map(|_| ())
+        .unwrap_err();
+    assert_eq!(err.kind, ErrorKind::MessageContainsOutOfBoundsPointer);
+}
@@ -0,0 +1,139 @@
+#![cfg(feature = "alloc")]
+
+use capnp::message::{AllocationStrategy, HeapAllocator, ReaderOptions};
+use capnp::schema_capnp::node;
+use capnp::serialize::{self, StreamingBuilder};
+use capnp::{ErrorKind, Word};
+
+const COUNT: u32 = 200;
+
+fn name(i: u32) -> String {
+    format!("{i:0>100}")
+}
+
+fn aligned(bytes: &[u8]) -> Vec<Word> {
+    let mut words = Word::allocate_zeroed_vec(bytes.len() / 8);
+    Word::words_to_bytes_mut(&mut words).copy_from_slice(bytes);
+    words
+}
+
+fn write_streamed() -> Vec<u8> {
+    let allocator = HeapAllocator::new()
+        .first_segment_words(512)
+        .allocation_strategy(AllocationStrategy::FixedSize);
+    let mut builder = StreamingBuilder::new(Vec::new(), allocator);
+    let mut root = builder.init_root::<node::Builder>();
+    root.set_id(7);
+    root.init_nested_nodes(COUNT);
+
+    let mut flushed_early = false;
+    for i in 0..COUNT {
+        let root = builder.get_root::<node::Builder>().unwrap();
+        let mut nested = root.get_nested_nodes().unwrap().get(i);
+        nested.set_id(u64::from(i));
+        nested.set_name(&*name(i));
+        if i % 20 == 19 {
+            // Only the first segment, which has the list, is used again.
+            unsafe {
+                builder.flush_completed().unwrap();
+            }
+            flushed_early |= builder.bytes_written() > 0;
+        }
+    }
+    assert!(flushed_early);
+    builder.finish().unwrap()
+}
+
+#[test]
+fn streamed_message_round_trip() {
+    let words = aligned(&write_streamed());
+    let message =
+        serialize::read_message_with_trailer(Word::words_to_bytes(&words), ReaderOptions::new())
+            .unwrap();
+    assert!(message.is_canonical().is_ok());
+    let root = message.get_root::<node::Reader>().unwrap();
+    assert_eq!(root.get_id(), 7);
+    let nested = root.get_nested_nodes().unwrap();
+    assert_eq!(nested.len(), COUNT);
+    for (i, nested) in nested.iter().enumerate() {
+        assert_eq!(nested.get_id(), i as u64);
+        assert_eq!(nested.get_name().unwrap(), &*name(i as u32));
+    }
+}
+
+#[test]
+fn streamed_empty_message() {
+    let bytes = StreamingBuilder::new_default(Vec::new()).finish().unwrap();
+    let words = aligned(&bytes);
+    let message =
+        serialize::read_message_with_trailer(Word::words_to_bytes(&words), ReaderOptions::new())
+            .unwrap();
+    assert!(message
+        .get_root::<capnp::any_pointer::Reader>()
+        .unwrap()
+        .is_null());
+}
+
+#[test]
+fn invalid_trailers() {
+    let bytes = write_streamed();
+    let read = |bytes: &[u8]| {
+        let words = aligned(bytes);
+        serialize::read_message_with_trailer(Word::words_to_bytes(&words), ReaderOptions::new())
+            .map(|_| ())
+            .unwrap_err()
+            .kind
+    };
+
+    let mut bad_magic = bytes.clone();
+    *bad_magic.last_mut().unwrap() ^= 1;
+    assert_eq!(read(&bad_magic), ErrorKind::InvalidMessageTrailer);
+
+    // Cutting off the start moves all of the segments out of bounds.
+    assert_eq!(read(&bytes[8..]), ErrorKind::InvalidMessageTrailer);
+    assert_eq!(
+        read(&bytes[bytes.len() - 8..]),
+        ErrorKind::InvalidMessageTrailer
+    );
+
+    let mut too_many = bytes.clone();
+    let n = too_many.len();
+    too_many[n - 8..n - 4].copy_from_slice(&u32::MAX.to_le_bytes());
+    assert_eq!(read(&too_many), ErrorKind::InvalidMessageTrailer);
+
+    let mut options = ReaderOptions::new();
+    options.traversal_limit_in_words(Some(100));
+    let words = aligned(&bytes);
+    let err = serialize::read_message_with_trailer(Word::words_to_bytes(&words), options)
+        .map(|_| ())
+        .unwrap_err();
+    assert!(matches!(err.kind, ErrorKind::MessageTooLarge(_)));
+}
+
+#[test]
+fn failed_write_stops_the_stream() {
+    let mut buffer = [0; 1000];
+    let allocator = HeapAllocator::new()
+        .first_segment_words(512)
+        .allocation_strategy(AllocationStrategy::FixedSize);
+    let mut builder = StreamingBuilder::new(&mut buffer[..], allocator);
+    builder
+        .init_root::<node::Builder>()
+        .init_nested_nodes(COUNT);
+
+    // The first segment that is flushed does not fit, and is written only partway.
+    let mut result = Ok(());
+    for i in 0..COUNT {
+        let root = builder.get_root::<node::Builder>().unwrap();
+        root.get_nested_nodes().unwrap().get(i).set_name(&*name(i));
+        result = unsafe { builder.flush_completed() };
+        if result.is_err() {
+            break;
+        }
+    }
+    assert!(result.is_err());
+    let err = unsafe { builder.flush_completed() }.unwrap_err();
+    assert_eq!(err.kind, ErrorKind::Failed);
+    let err = builder.finish().map(|_| ()).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::Failed);
+}
@@ -0,0 +1,159 @@
+#![cfg(feature = "alloc")]
+
+use capnp::schema_capnp::{node, value, ElementSize};
+use capnp::{message, text_format, ErrorKind};
+
+mod common;
+
+use common::init_test_node;
+
+#[test]
+fn round_trip_through_debug() {
+    let mut message = message::Builder::new_default();
+    init_test_node(message.init_root());
+    let root: node::Reader = message.get_root_as_reader().unwrap();
+
+    for printed in [format!("{root:?}"), format!("{root:#?}")] {
+        let mut message2 = message::Builder::new_default();
+        text_format::parse(&printed, message2.init_root::<node::Builder>()).unwrap();
+        let root2: node::Reader = message2.get_root_as_reader().unwrap();
+        assert_eq!(format!("{root2:?}"), format!("{root:?}"));
+    }
+}
+
+#[test]
+fn parse_literals() {
+    let mut message = message::Builder::new_default();
+    text_format::parse(
+        r#"
+        # A comment.
+        id = 0x10,               # hex
+        displayNamePrefixLength = 017,
+        struct = (
+          preferredListEncoding = eightBytes,
+          isGroup = true,
+          fields = [(name = "a\x41\101\n"), (name = "b", ordinal = (explicit = 3))],
+        ),
+        annotations = [(value = (data = 0x"de ad"))],
+        "#,
+        message.init_root::<node::Builder>(),
+    )
+    .unwrap();
+    let root: node::Reader = message.get_root_as_reader().unwrap();
+    assert_eq!(root.get_id(), 16);
+    assert_eq!(root.get_display_name_prefix_length(), 15);
+    let node::Struct(st) = root.which().unwrap() else {
+        panic!("expected struct")
+    };
+    assert_eq!(
+        st.get_preferred_list_encoding().unwrap(),
+        ElementSize::EightBytes
+    );
+    assert!(st.get_is_group());
+    let fields = st.get_fields().unwrap();
+    assert_eq!(fields.get(0).get_name().unwrap(), "aAA\n");
+    assert_eq!(fields.get(1).get_name().unwrap(), "b");
+    let value = root.get_annotations().unwrap().get(0).get_value().unwrap();
+    let value::Data(d) = value.which().unwrap() else {
+        panic!("expected data")
+    };
+    assert_eq!(d.unwrap(), &[0xde, 0xad]);
+
+    text_format::parse("(float32 = -inf)", message.init_root::<value::Builder>()).unwrap();
+    let root: value::Reader = message.get_root_as_reader().unwrap();
+    assert!(matches!(root.which().unwrap(), value::Float32(f) if f == f32::NEG_INFINITY));
+
+    text_format::parse("(void = ())", message.init_root::<value::Builder>()).unwrap();
+    let root: value::Reader = message.get_root_as_reader().unwrap();
+    assert!(matches!(root.which().unwrap(), value::Void(())));
+}
+
+#[test]
+fn parse_byte_escapes() {
+    let mut message = message::Builder::new_default();
+    text_format::parse(
+        r#"(data = "\xff\377a\u{e9}")"#,
+        message.init_root::<value::Builder>(),
+    )
+    .unwrap();
+    let root: value::Reader = message.get_root_as_reader().unwrap();
+    let value::Data(d) = root.which().unwrap() else {
+        panic!("expected data")
+    };
+    assert_eq!(d.unwrap(), &[0xff, 0xff, b'a', 0xc3, 0xa9]);
+
+    // Bytes that spell out valid UTF-8 are fine in text, others are not.
+    text_format::parse(
+        r#"(text = "\xc3\xa9")"#,
+        message.init_root::<value::Builder>(),
+    )
+    .unwrap();
+    let root: value::Reader = message.get_root_as_reader().unwrap();
+    let value::Text(t) = root.which().unwrap() else {
+        panic!("expected text")
+    };
+    assert_eq!(t.unwrap(), "\u{e9}");
+    let err = text_format::parse(r#"(text = "\xff")"#, message.init_root::<value::Builder>())
+        .unwrap_err();
+    assert_eq!(err.extra, "1:9: text is not valid UTF-8");
+
+    for input in [
+        r#"(data = "\x")"#,
+        r#"(data = "\400")"#,
+        r#"(data = "\u{+41}")"#,
+    ] {
+        let err = text_format::parse(input, message.init_root::<value::Builder>()).unwrap_err();
+        assert!(err.extra.ends_with("invalid escape sequence"), "{input}");
+    }
+}
+
+#[test]
+fn error_locations() {
+    let mut message = message::Builder::new_default();
+    let cases = [
+        (
+            "(id = 1,\n  bogus = 2)",
+            ErrorKind::FieldNotFound,
+            "2:3: bogus",
+        ),
+        (
+            "(id = \"x\")",
+            ErrorKind::TypeMismatch,
+            "1:7: expected integer",
+        ),
+        (
+            "(id = -1)",
+            ErrorKind::Failed,
+            "1:7: integer is out of range",
+        ),
+        (
+            "(id = 1 scopeId = 2)",
+            ErrorKind::Failed,
+            "1:9: expected ','",
+        ),
+        (
+            "(displayName = \"abc)",
+            ErrorKind::Failed,
+            "1:21: unterminated string",
+        ),
+        (
+            "(struct = (preferredListEncoding = huge))",
+            ErrorKind::Failed,
+            "1:36: unknown enumerant huge",
+        ),
+        (
+            "(id = 1) x",
+            ErrorKind::Failed,
+            "1:10: unexpected input after end of struct",
+        ),
+    ];
+    for (input, kind, extra) in cases {
+        let err = text_format::parse(input, message.init_root::<node::Builder>()).unwrap_err();
+        assert_eq!(err.kind, kind, "{input}");
+        assert_eq!(err.extra, extra, "{input}");
+    }
+
+    let deep = "(annotations = [(value = (struct = ".repeat(40);
+    let err = text_format::parse(&deep, message.init_root::<node::Builder>()).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::NestingLimitExceeded);
+}
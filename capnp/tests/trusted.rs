@@ -0,0 +1,97 @@
+#![cfg(feature = "alloc")]
+
+use capnp::message::{self, ReaderOptions};
+use capnp::schema_capnp::node;
+use capnp::{serialize, ErrorKind, Word};
+
+fn node_words() -> Vec<Word> {
+    let mut message = message::Builder::new_default();
+    let mut root = message.init_root::<node::Builder>();
+    let mut nested = root.reborrow().init_nested_nodes(4);
+    for i in 0..4 {
+        nested.reborrow().get(i).set_name("nested");
+    }
+    root.set_display_name("display");
+    let segments = message.get_segments_for_output();
+    let mut words = Word::allocate_zeroed_vec(segments[0].len() / 8);
+    Word::words_to_bytes_mut(&mut words).copy_from_slice(segments[0]);
+    words
+}
+
+fn read_names<S: message::ReaderSegments>(message: &message::Reader<S>) -> capnp::Result<usize> {
+    let root = message.get_root::<node::Reader>()?;
+    let mut total = root.get_display_name()?.len();
+    for nested in root.get_nested_nodes()? {
+        total += nested.get_name()?.len();
+    }
+    Ok(total)
+}
+
+#[test]
+fn trusted_reader_skips_traversal_limit() {
+    let words = node_words();
+    let segments = [Word::words_to_bytes(&words)];
+    let mut options = ReaderOptions::new();
+    options.traversal_limit_in_words(Some(words.len()));
+
+    let message = message::Reader::new(message::SegmentArray::new(&segments), options);
+    assert!(!message.is_trusted());
+    assert_eq!(read_names(&message).unwrap(), 31);
+    let err = read_names(&message).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::ReadLimitExceeded);
+
+    let message =
+        unsafe { message::Reader::new_trusted(message::SegmentArray::new(&segments), options) };
+    assert!(message.is_trusted());
+    for _ in 0..3 {
+        assert_eq!(read_names(&message).unwrap(), 31);
+    }
+}
+
+#[test]
+fn into_trusted_checks_pointers() {
+    let words = node_words();
+    let segments = [Word::words_to_bytes(&words)];
+    let message = message::Reader::new(message::SegmentArray::new(&segments), ReaderOptions::new())
+        .into_trusted()
+        .unwrap();
+    assert!(message.is_trusted());
+    assert_eq!(read_names(&message).unwrap(), 31);
+
+    // The display name was allocated last, so dropping the last word cuts it off.
+    let segments = [Word::words_to_bytes(&words[..words.len() - 1])];
+    let err = message::Reader::new(message::SegmentArray::new(&segments), ReaderOptions::new())
+        .into_trusted()
+        .map(|_| ())
+        .unwrap_err();
+    assert_eq!(err.kind, ErrorKind::MessageContainsOutOfBoundsPointer);
+}
+
+#[test]
+fn into_trusted_serialized_messages() {
+    let words = node_words();
+    let segments = [Word::words_to_bytes(&words)];
+    let flat = serialize::write_message_segments_to_words(&message::SegmentArray::new(&segments));
+    let mut words = Word::allocate_zeroed_vec(flat.len() / 8);
+    Word::words_to_bytes_mut(&mut words).copy_from_slice(&flat);
+    let bytes = Word::words_to_bytes(&words);
+
+    let message = serialize::read_message(bytes, ReaderOptions::new())
+        .unwrap()
+        .into_trusted()
+        .unwrap();
+    assert_eq!(read_names(&message).unwrap(), 31);
+
+    let segments = serialize::BufferSegments::new(bytes, ReaderOptions::new()).unwrap();
+    let message = message::Reader::new(segments, ReaderOptions::new())
+        .into_trusted()
+        .unwrap();
+    assert_eq!(read_names(&message).unwrap(), 31);
+
+    let message =
+        serialize::read_message_from_flat_slice_no_alloc(&mut &bytes[..], ReaderOptions::new())
+            .unwrap()
+            .into_trusted()
+            .unwrap();
+    assert_eq!(read_names(&message).unwrap(), 31);
+}
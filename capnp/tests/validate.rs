@@ -0,0 +1,100 @@
+#![cfg(feature = "alloc")]
+
+use capnp::message::{self, ReaderOptions};
+use capnp::schema_capnp::node;
+use capnp::validate::Report;
+use capnp::{ErrorKind, Word};
+
+fn node_message() -> message::Builder<message::HeapAllocator> {
+    let mut message = message::Builder::new_default();
+    let mut root = message.init_root::<node::Builder>();
+    root.set_id(7);
+    let mut nested = root.reborrow().init_nested_nodes(3);
+    for (i, name) in ["alpha", "beta", "gamma"].into_iter().enumerate() {
+        nested.reborrow().get(i as u32).set_name(name);
+    }
+    root.set_display_name("display");
+    message
+}
+
+fn words_of(message: &message::Builder<message::HeapAllocator>) -> Vec<Word> {
+    let segments = message.get_segments_for_output();
+    assert_eq!(segments.len(), 1);
+    let mut words = Word::allocate_zeroed_vec(segments[0].len() / 8);
+    Word::words_to_bytes_mut(&mut words).copy_from_slice(segments[0]);
+    words
+}
+
+fn replace(words: &mut [Word], needle: &[u8], byte: u8) {
+    let bytes = Word::words_to_bytes_mut(words);
+    let pos = bytes
+        .windows(needle.len())
+        .position(|w| w == needle)
+        .unwrap();
+    bytes[pos] = byte;
+}
+
+fn validate(words: &[Word], options: ReaderOptions) -> Report {
+    let segments = [Word::words_to_bytes(words)];
+    message::Reader::new(message::SegmentArray::new(&segments), options).validate::<node::Owned>()
+}
+
+#[test]
+fn valid_message() {
+    let words = words_of(&node_message());
+    let report = validate(&words, ReaderOptions::new());
+    assert!(report.is_ok(), "{report}");
+    report.into_result().unwrap();
+}
+
+#[test]
+fn problems_have_paths() {
+    let mut words = words_of(&node_message());
+    replace(&mut words, b"beta", 0xff);
+    replace(&mut words, b"display", 0xfe);
+    let report = validate(&words, ReaderOptions::new());
+    let paths: Vec<String> = report.problems.iter().map(|p| p.path.to_string()).collect();
+    assert_eq!(paths, vec!["root.displayName", "root.nestedNodes[1].name"]);
+
+    let err = report.into_result().unwrap_err();
+    assert!(matches!(err.kind, ErrorKind::TextContainsNonUtf8Data(_)));
+    assert!(err.to_string().contains("root.nestedNodes[1].name"));
+}
+
+#[test]
+fn out_of_bounds_pointer() {
+    let mut words = words_of(&node_message());
+    // The display name was allocated last, so dropping the last word cuts it off.
+    words.pop();
+    let report = validate(&words, ReaderOptions::new());
+    assert_eq!(report.problems.len(), 1);
+    assert_eq!(report.problems[0].path.to_string(), "root.displayName");
+    assert_eq!(
+        report.problems[0].error.kind,
+        ErrorKind::MessageContainsOutOfBoundsPointer
+    );
+}
+
+#[test]
+fn validation_does_not_consume_traversal_limit() {
+    let words = words_of(&node_message());
+    let mut options = ReaderOptions::new();
+    options.traversal_limit_in_words(Some(words.len()));
+    let segments = [Word::words_to_bytes(&words)];
+    let message = message::Reader::new(message::SegmentArray::new(&segments), options);
+    for _ in 0..3 {
+        let report = message.validate::<node::Owned>();
+        assert!(report.is_ok(), "{report}");
+    }
+    let root = message.get_root::<node::Reader>().unwrap();
+    assert_eq!(root.get_display_name().unwrap(), "display");
+
+    // A limit that is too small for the whole message is reported.
+    let mut options = ReaderOptions::new();
+    options.traversal_limit_in_words(Some(4));
+    let report = validate(&words, options);
+    assert_eq!(
+        report.problems.last().unwrap().error.kind,
+        ErrorKind::ReadLimitExceeded
+    );
+}
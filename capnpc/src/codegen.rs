@@ -38,6 +38,7 @@ pub struct CodeGenerationCommand {
     raw_code_generator_request_path: Option<PathBuf>,
     capnp_root: String,
     crates_provide_map: HashMap<u64, String>,
+    compatibility_baseline_path: Option<PathBuf>,
 }
 
 impl Default for CodeGenerationCommand {
@@ -48,6 +49,7 @@ impl Default for CodeGenerationCommand {
             raw_code_generator_request_path: None,
             capnp_root: "::capnp".into(),
             crates_provide_map: HashMap::new(),
+            compatibility_baseline_path: None,
         }
     }
 }
@@ -107,6 +109,17 @@ impl CodeGenerationCommand {
         self
     }
 
+    /// Sets the path of a raw code generator request to check the schema against.
+    ///
+    /// See [`crate::CompilerCommand::compatibility_baseline`] for more details.
+    pub fn compatibility_baseline<P>(&mut self, path: P) -> &mut Self
+    where
+        P: AsRef<Path>,
+    {
+        self.compatibility_baseline_path = Some(path.as_ref().to_path_buf());
+        self
+    }
+
     /// Generates Rust code according to a `schema_capnp::code_generator_request` read from `inp`.
     pub fn run<T>(&mut self, inp: T) -> ::capnp::Result<()>
     where
@@ -119,6 +132,10 @@ impl CodeGenerationCommand {
 
         let ctx = GeneratorContext::new_from_code_generation_command(self, &message)?;
 
+        if let Some(baseline_path) = &self.compatibility_baseline_path {
+            check_compatibility(baseline_path, ctx.request)?;
+        }
+
         for requested_file in ctx.request.get_requested_files()? {
             let id = requested_file.get_id();
             let mut filepath = self.output_directory.to_path_buf();
@@ -183,6 +200,37 @@ impl CodeGenerationCommand {
     }
 }
 
+fn check_compatibility(
+    baseline_path: &Path,
+    request: schema_capnp::code_generator_request::Reader,
+) -> ::capnp::Result<()> {
+    let baseline_file = ::std::fs::File::open(baseline_path).map_err(|e| {
+        Error::failed(format!(
+            "could not open compatibility baseline {}: {e}",
+            baseline_path.display()
+        ))
+    })?;
+    let baseline = capnp::serialize::read_message(
+        ::std::io::BufReader::new(baseline_file),
+        capnp::message::ReaderOptions::new(),
+    )?;
+    let incompatibilities = crate::compatibility::check_requests(
+        baseline.get_root::<schema_capnp::code_generator_request::Reader>()?,
+        request,
+    )?;
+    if incompatibilities.is_empty() {
+        return Ok(());
+    }
+    let mut message = format!(
+        "schema has changed incompatibly since {}:",
+        baseline_path.display()
+    );
+    for incompatibility in incompatibilities {
+        message.push_str(&format!("\n  {incompatibility}"));
+    }
+    Err(Error::failed(message))
+}
+
 pub struct GeneratorContext<'a> {
     pub request: schema_capnp::code_generator_request::Reader<'a>,
     pub node_map: collections::hash_map::HashMap<u64, schema_capnp::node::Reader<'a>>,
@@ -771,6 +819,12 @@ pub fn getter_text(
                 }
             }
 
+            // Records where errors that are returned by a reader getter happened.
+            let field_context = format!(
+                ".map_err(|e| e.with_field(_private::TYPE_ID, \"{}\"))",
+                field.get_name()?.to_str()?
+            );
+
             let getter_fragment = match (raw_type.which()?, default) {
                 (type_::Void(()), value::Void(())) => {
                     if is_fn {
@@ -833,7 +887,7 @@ pub fn getter_text(
 
                     if is_reader {
                         fmt!(ctx,
-                            "{capnp}::traits::FromPointerReader::get_from_pointer(&self.{member}.get_pointer_field({offset}), {default})")
+                            "{capnp}::traits::FromPointerReader::get_from_pointer(&self.{member}.get_pointer_field({offset}), {default}){field_context}")
                     } else {
                         fmt!(ctx,"{capnp}::traits::FromPointerBuilder::get_from_pointer(self.{member}.get_pointer_field({offset}), {default})")
                     }
@@ -846,7 +900,7 @@ pub fn getter_text(
                     if !raw_type.is_parameter()? {
                         fmt!(ctx,"{capnp}::any_pointer::{module_string}::new(self.{member}.get_pointer_field({offset}))")
                     } else if is_reader {
-                        fmt!(ctx,"{capnp}::traits::FromPointerReader::get_from_pointer(&self.{member}.get_pointer_field({offset}), ::core::option::Option::None)")
+                        fmt!(ctx,"{capnp}::traits::FromPointerReader::get_from_pointer(&self.{member}.get_pointer_field({offset}), ::core::option::Option::None){field_context}")
                     } else {
                         fmt!(ctx,"{capnp}::traits::FromPointerBuilder::get_from_pointer(self.{member}.get_pointer_field({offset}), ::core::option::Option::None)")
                     }
@@ -988,8 +1042,12 @@ fn generate_setter(
 
     let mut setter_interior = Vec::new();
     let mut setter_param = "value".to_string();
+    let mut try_setter_interior = Vec::new();
+    let mut has_try_setter = false;
     let mut initter_interior = Vec::new();
     let mut initter_mut = false;
+    let mut try_initter_interior = Vec::new();
+    let mut has_try_initter = false;
     let mut initn_interior = Vec::new();
     let mut initter_params = Vec::new();
 
@@ -999,11 +1057,16 @@ fn generate_setter(
             "self.builder.set_data_field::<u16>({}, {});",
             discriminant_offset as usize, discriminant_value as usize
         )));
+        try_setter_interior.push(Line(format!(
+            "self.builder.set_data_field::<u16>({}, {});",
+            discriminant_offset as usize, discriminant_value as usize
+        )));
         let init_discrim = Line(format!(
             "self.builder.set_data_field::<u16>({}, {});",
             discriminant_offset as usize, discriminant_value as usize
         ));
         initter_interior.push(init_discrim.clone());
+        try_initter_interior.push(init_discrim.clone());
         initn_interior.push(init_discrim);
     }
 
@@ -1073,13 +1136,22 @@ fn generate_setter(
                     (Some(tstr), None)
                 }
                 type_::Text(()) => {
-                    // The text::Reader impl of SetterInput never fails, so we can unwrap().
+                    // Setting text fails only if the allocator runs out of space, which
+                    // try_set_*() reports instead of panicking.
                     setter_interior.push(Line(fmt!(ctx,
                         "{capnp}::traits::SetterInput::set_pointer_builder(self.builder.reborrow().get_pointer_field({offset}), value, false).unwrap()"
                     )));
+                    try_setter_interior.push(Line(fmt!(ctx,
+                        "{capnp}::traits::SetterInput::set_pointer_builder(self.builder.reborrow().get_pointer_field({offset}), value, false)"
+                    )));
+                    has_try_setter = true;
                     initter_interior.push(Line(format!(
                         "self.builder.get_pointer_field({offset}).init_text(size)"
                     )));
+                    try_initter_interior.push(Line(format!(
+                        "self.builder.get_pointer_field({offset}).try_init_text(size)"
+                    )));
+                    has_try_initter = true;
                     initter_params.push("size: u32");
                     (
                         Some(fmt!(
@@ -1093,9 +1165,17 @@ fn generate_setter(
                     setter_interior.push(Line(format!(
                         "self.builder.reborrow().get_pointer_field({offset}).set_data(value);"
                     )));
+                    try_setter_interior.push(Line(format!(
+                        "self.builder.reborrow().get_pointer_field({offset}).try_set_data(value)"
+                    )));
+                    has_try_setter = true;
                     initter_interior.push(Line(format!(
                         "self.builder.get_pointer_field({offset}).init_data(size)"
                     )));
+                    try_initter_interior.push(Line(format!(
+                        "self.builder.get_pointer_field({offset}).try_init_data(size)"
+                    )));
+                    has_try_initter = true;
                     initter_params.push("size: u32");
                     (
                         Some(fmt!(ctx, "{capnp}::data::Reader<'_>")),
@@ -1111,6 +1191,9 @@ fn generate_setter(
                     initter_params.push("size: u32");
                     initter_interior.push(
                         Line(fmt!(ctx,"{capnp}::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field({offset}), size)")));
+                    try_initter_interior.push(
+                        Line(fmt!(ctx,"{capnp}::traits::FromPointerBuilder::try_init_pointer(self.builder.get_pointer_field({offset}), size)")));
+                    has_try_initter = true;
 
                     let mr = match et.which()? {
                         type_::Void(())
@@ -1169,6 +1252,9 @@ fn generate_setter(
                     return_result = true;
                     initter_interior.push(
                       Line(fmt!(ctx,"{capnp}::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field({offset}), 0)")));
+                    try_initter_interior.push(
+                      Line(fmt!(ctx,"{capnp}::traits::FromPointerBuilder::try_init_pointer(self.builder.get_pointer_field({offset}), 0)")));
+                    has_try_initter = true;
                     setter_interior.push(
                         Line(fmt!(ctx,"{capnp}::traits::SetterInput::set_pointer_builder(self.builder.reborrow().get_pointer_field({offset}), value, false)")));
 
@@ -1186,6 +1272,8 @@ fn generate_setter(
                 type_::AnyPointer(_) => {
                     if typ.is_parameter()? {
                         initter_interior.push(Line(fmt!(ctx,"{capnp}::any_pointer::Builder::new(self.builder.get_pointer_field({offset})).init_as()")));
+                        try_initter_interior.push(Line(fmt!(ctx,"{capnp}::any_pointer::Builder::new(self.builder.get_pointer_field({offset})).try_init_as()")));
+                        has_try_initter = true;
                         setter_interior.push(Line(fmt!(ctx,"{capnp}::traits::SetterInput::set_pointer_builder(self.builder.reborrow().get_pointer_field({offset}), value, false)")));
                         return_result = true;
 
@@ -1232,6 +1320,16 @@ fn generate_setter(
         )));
         result.push(indent(setter_interior));
         result.push(line("}"));
+        if has_try_setter {
+            // Like set_*(), but returns an error if the allocator runs out of space.
+            result.push(line("#[inline]"));
+            result.push(Line(fmt!(
+                ctx,
+                "pub fn try_set_{styled_name}(&mut self, {setter_param}: {reader_type}) -> {capnp}::Result<()> {{"
+            )));
+            result.push(indent(try_setter_interior));
+            result.push(line("}"));
+        }
     }
     if let Some(builder_type) = maybe_builder_type {
         result.push(line("#[inline]"));
@@ -1242,6 +1340,78 @@ fn generate_setter(
         )));
         result.push(indent(initter_interior));
         result.push(line("}"));
+        if has_try_initter {
+            // Like init_*(), but returns an error if the allocator runs out of space.
+            result.push(line("#[inline]"));
+            result.push(Line(fmt!(
+                ctx,
+                "pub fn try_init_{styled_name}(self, {args}) -> {capnp}::Result<{builder_type}> {{"
+            )));
+            result.push(indent(try_initter_interior));
+            result.push(line("}"));
+        }
+    }
+    if let field::Slot(reg_field) = field.which()? {
+        let typ = reg_field.get_type()?;
+        if typ.is_pointer()? {
+            let offset = reg_field.get_offset() as usize;
+            let owned_type = typ.type_string(ctx, Leaf::Owned)?;
+            result.push(line("#[inline]"));
+            if discriminant_value == field::NO_DISCRIMINANT {
+                result.push(Line(fmt!(
+                    ctx,
+                    "pub fn disown_{styled_name}(&mut self) -> {capnp}::orphan::Orphan<{owned_type}> {{"
+                )));
+                result.push(indent(Line(fmt!(
+                    ctx,
+                    "{capnp}::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field({offset}))"
+                ))));
+            } else {
+                // Disowning an inactive member would detach whatever the active one points to.
+                result.push(Line(fmt!(
+                    ctx,
+                    "pub fn disown_{styled_name}(&mut self) -> {capnp}::Result<{capnp}::orphan::Orphan<{owned_type}>> {{"
+                )));
+                result.push(indent(vec![
+                    Line(format!(
+                        "if self.builder.get_data_field::<u16>({}) != {} {{",
+                        discriminant_offset as usize, discriminant_value as usize
+                    )),
+                    indent(vec![
+                        Line(fmt!(ctx, "let mut error = {capnp}::Error::from_kind({capnp}::ErrorKind::Failed);")),
+                        Line(format!(
+                            "::core::write!(error, \"cannot disown {}, which is not the active member of its union\");",
+                            field.get_name()?.to_str()?
+                        )),
+                        line("return ::core::result::Result::Err(error);"),
+                    ]),
+                    line("}"),
+                    Line(fmt!(
+                        ctx,
+                        "::core::result::Result::Ok({capnp}::orphan::Orphan::disown(self.builder.reborrow().get_pointer_field({offset})))"
+                    )),
+                ]));
+            }
+            result.push(line("}"));
+
+            let mut adopter_interior = Vec::new();
+            if discriminant_value != field::NO_DISCRIMINANT {
+                adopter_interior.push(Line(format!(
+                    "self.builder.set_data_field::<u16>({}, {});",
+                    discriminant_offset as usize, discriminant_value as usize
+                )));
+            }
+            adopter_interior.push(Line(format!(
+                "orphan.adopt_into(self.builder.reborrow().get_pointer_field({offset}))"
+            )));
+            result.push(line("#[inline]"));
+            result.push(Line(fmt!(
+                ctx,
+                "pub fn adopt_{styled_name}(&mut self, orphan: {capnp}::orphan::Orphan<{owned_type}>) -> {capnp}::Result<()> {{"
+            )));
+            result.push(indent(adopter_interior));
+            result.push(line("}"));
+        }
     }
     Ok(Branch(result))
 }
@@ -1680,7 +1850,7 @@ fn generate_get_field_types(
         Ok(Branch(vec![
             Line(fmt!(
                 ctx,
-                "pub fn get_field_types(index: u16) -> {capnp}::introspect::Type {{"
+                "pub fn get_field_types(index: u16) -> {capnp}::introspect::Type<'static> {{"
             )),
             indent(body),
             Line("}".into()),
@@ -1690,7 +1860,109 @@ fn generate_get_field_types(
         Ok(Branch(vec![
             Line(fmt!(
                 ctx,
-                "pub fn get_field_types<{0}>(index: u16) -> {capnp}::introspect::Type {1} {{",
+                "pub fn get_field_types<{0}>(index: u16) -> {capnp}::introspect::Type<'static> {1} {{",
+                params.params,
+                params.where_clause
+            )),
+            indent(body),
+            Line("}".into()),
+        ]))
+    }
+}
+
+fn generate_get_method_types(
+    ctx: &GeneratorContext,
+    node_reader: schema_capnp::node::Reader,
+    mut branches: Vec<FormattedText>,
+) -> ::capnp::Result<FormattedText> {
+    let body = if branches.is_empty() {
+        Line("panic!(\"invalid method index {}\", index)".into())
+    } else {
+        branches.push(Line(
+            "_ => panic!(\"invalid method index {}\", index),".into(),
+        ));
+        Branch(vec![
+            Line("match index {".into()),
+            indent(branches),
+            Line("}".into()),
+        ])
+    };
+    if !node_reader.get_is_generic() {
+        Ok(Branch(vec![
+            Line(fmt!(
+                ctx,
+                "pub fn get_method_types(index: u16) -> ({capnp}::introspect::Type<'static>, {capnp}::introspect::Type<'static>) {{"
+            )),
+            indent(body),
+            Line("}".into()),
+        ]))
+    } else {
+        let params = node_reader.parameters_texts(ctx);
+        Ok(Branch(vec![
+            Line(fmt!(
+                ctx,
+                "pub fn get_method_types<{0}>(index: u16) -> ({capnp}::introspect::Type<'static>, {capnp}::introspect::Type<'static>) {1} {{",
+                params.params,
+                params.where_clause
+            )),
+            indent(body),
+            Line("}".into()),
+        ]))
+    }
+}
+
+fn generate_get_superclass_types(
+    ctx: &GeneratorContext,
+    node_reader: schema_capnp::node::Reader,
+) -> ::capnp::Result<FormattedText> {
+    let interface = match node_reader.which()? {
+        schema_capnp::node::Interface(interface) => interface,
+        _ => return Err(Error::failed("not an interface".into())),
+    };
+    let mut branches = vec![];
+    for (index, superclass) in interface.get_superclasses()?.iter().enumerate() {
+        let type_id = superclass.get_id();
+        let typ = do_branding(
+            ctx,
+            type_id,
+            superclass.get_brand()?,
+            Leaf::Owned,
+            &ctx.get_qualified_module(type_id),
+        )?;
+        branches.push(Line(fmt!(
+            ctx,
+            "{} => <{} as {capnp}::introspect::Introspect>::introspect(),",
+            index,
+            typ
+        )));
+    }
+    let body = if branches.is_empty() {
+        Line("panic!(\"invalid superclass index {}\", index)".into())
+    } else {
+        branches.push(Line(
+            "_ => panic!(\"invalid superclass index {}\", index),".into(),
+        ));
+        Branch(vec![
+            Line("match index {".into()),
+            indent(branches),
+            Line("}".into()),
+        ])
+    };
+    if !node_reader.get_is_generic() {
+        Ok(Branch(vec![
+            Line(fmt!(
+                ctx,
+                "pub fn get_superclass_types(index: u32) -> {capnp}::introspect::Type<'static> {{"
+            )),
+            indent(body),
+            Line("}".into()),
+        ]))
+    } else {
+        let params = node_reader.parameters_texts(ctx);
+        Ok(Branch(vec![
+            Line(fmt!(
+                ctx,
+                "pub fn get_superclass_types<{0}>(index: u32) -> {capnp}::introspect::Type<'static> {1} {{",
                 params.params,
                 params.where_clause
             )),
@@ -1767,6 +2039,18 @@ fn generate_get_annotation_types(
                 }
             }
         }
+        node::Interface(i) => {
+            for (midx, method) in i.get_methods()?.iter().enumerate() {
+                for (idx, annotation) in method.get_annotations()?.iter().enumerate() {
+                    branches.push(annotation_branch(
+                        ctx,
+                        annotation,
+                        Some(midx as u16),
+                        idx as u32,
+                    )?);
+                }
+            }
+        }
         _ => (),
     }
 
@@ -1785,7 +2069,7 @@ fn generate_get_annotation_types(
 
     if !node_reader.get_is_generic() {
         Ok(Branch(vec![
-            Line(fmt!(ctx,"pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> {capnp}::introspect::Type {{")),
+            Line(fmt!(ctx,"pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> {capnp}::introspect::Type<'static> {{")),
             indent(body),
             Line("}".into()),
         ]))
@@ -1793,7 +2077,7 @@ fn generate_get_annotation_types(
         let params = node_reader.parameters_texts(ctx);
         Ok(Branch(vec![
             Line(fmt!(ctx,
-                "pub fn get_annotation_types<{0}>(child_index: Option<u16>, index: u32) -> {capnp}::introspect::Type {1} {{",
+                "pub fn get_annotation_types<{0}>(child_index: Option<u16>, index: u32) -> {capnp}::introspect::Type<'static> {1} {{",
                 params.params, params.where_clause
             )),
             indent(body),
@@ -1873,6 +2157,30 @@ fn generate_members_by_name(
     Ok(Branch(vec![Line(members_by_name_string)]))
 }
 
+fn generate_methods_by_name(
+    node_reader: schema_capnp::node::Reader,
+) -> ::capnp::Result<FormattedText> {
+    let interface = match node_reader.which()? {
+        schema_capnp::node::Interface(interface) => interface,
+        _ => return Err(Error::failed("not an interface".into())),
+    };
+
+    let mut methods_by_name = Vec::new();
+    for (index, method) in interface.get_methods()?.iter().enumerate() {
+        methods_by_name.push((method.get_name()?.to_str()?, index));
+    }
+    methods_by_name.sort_by_key(|k| k.0);
+
+    let indices: Vec<String> = methods_by_name
+        .iter()
+        .map(|(_, index)| format!("{index}"))
+        .collect();
+    Ok(Line(format!(
+        "pub static METHODS_BY_NAME : &[u16] = &[{}];",
+        indices.join(",")
+    )))
+}
+
 // We need this to work around the fact that Rust does not allow typedefs
 // with unused type parameters.
 fn get_ty_params_of_brand(
@@ -2154,6 +2462,9 @@ fn generate_node(
                         Line(fmt!(ctx,"fn init_pointer(builder: {capnp}::private::layout::PointerBuilder<'a>, _size: u32) -> Self {{")),
                         indent(Line(fmt!(ctx,"builder.init_struct(<Self as {capnp}::traits::HasStructSize>::STRUCT_SIZE).into()"))),
                         line("}"),
+                        Line(fmt!(ctx,"fn try_init_pointer(builder: {capnp}::private::layout::PointerBuilder<'a>, _size: u32) -> {capnp}::Result<Self> {{")),
+                        indent(Line(fmt!(ctx,"::core::result::Result::Ok(builder.try_init_struct(<Self as {capnp}::traits::HasStructSize>::STRUCT_SIZE)?.into())"))),
+                        line("}"),
                         Line(fmt!(ctx,"fn get_from_pointer(builder: {capnp}::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [{capnp}::Word]>) -> {capnp}::Result<Self> {{")),
                         indent(Line(fmt!(ctx,"::core::result::Result::Ok(builder.get_struct(<Self as {capnp}::traits::HasStructSize>::STRUCT_SIZE, default)?.into())"))),
                         line("}")
@@ -2167,7 +2478,7 @@ fn generate_node(
                     Branch(vec![
                         Line("#[derive(Copy, Clone)]".into()),
                         line("pub struct Owned(());"),
-                        Line(fmt!(ctx,"impl {capnp}::introspect::Introspect for Owned {{ fn introspect() -> {capnp}::introspect::Type {{ {capnp}::introspect::TypeVariant::Struct({capnp}::introspect::RawBrandedStructSchema {{ generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }}).into() }} }}")),
+                        Line(fmt!(ctx,"impl {capnp}::introspect::Introspect for Owned {{ fn introspect() -> {capnp}::introspect::Type<'static> {{ {capnp}::introspect::TypeVariant::Struct({capnp}::introspect::RawBrandedStructSchema {{ generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }}.into()).into() }} }}")),
                         Line(fmt!(ctx, "impl {capnp}::traits::Owned for Owned {{ type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }}")),
                         Line(fmt!(ctx,"impl {capnp}::traits::OwnedStruct for Owned {{ type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }}")),
                         Line(fmt!(ctx,"impl {capnp}::traits::Pipelined for Owned {{ type Pipeline = Pipeline; }}"))
@@ -2178,7 +2489,7 @@ fn generate_node(
                         Line(format!("pub struct Owned<{}> {{", params.params)),
                             indent(Line(params.phantom_data_type.clone())),
                         line("}"),
-                        Line(fmt!(ctx,"impl <{0}> {capnp}::introspect::Introspect for Owned <{0}> {1} {{ fn introspect() -> {capnp}::introspect::Type {{ {capnp}::introspect::TypeVariant::Struct({capnp}::introspect::RawBrandedStructSchema {{ generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<{0}>, annotation_types: _private::get_annotation_types::<{0}> }}).into() }} }}",
+                        Line(fmt!(ctx,"impl <{0}> {capnp}::introspect::Introspect for Owned <{0}> {1} {{ fn introspect() -> {capnp}::introspect::Type<'static> {{ {capnp}::introspect::TypeVariant::Struct({capnp}::introspect::RawBrandedStructSchema {{ generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<{0}>, annotation_types: _private::get_annotation_types::<{0}> }}.into()).into() }} }}",
                             params.params, params.where_clause)),
                         Line(fmt!(ctx,"impl <{0}> {capnp}::traits::Owned for Owned <{0}> {1} {{ type Reader<'a> = Reader<'a, {0}>; type Builder<'a> = Builder<'a, {0}>; }}",
                             params.params, params.where_clause)),
@@ -2434,7 +2745,7 @@ fn generate_node(
                     "impl {capnp}::introspect::Introspect for {last_name} {{"
                 )),
                 indent(Line(fmt!(ctx,
-                    "fn introspect() -> {capnp}::introspect::Type {{ {capnp}::introspect::TypeVariant::Enum({capnp}::introspect::RawEnumSchema {{ encoded_node: &{0}::ENCODED_NODE, annotation_types: {0}::get_annotation_types }}).into() }}", name_as_mod))),
+                    "fn introspect() -> {capnp}::introspect::Type<'static> {{ {capnp}::introspect::TypeVariant::Enum({capnp}::introspect::RawEnumSchema {{ encoded_node: &{0}::ENCODED_NODE, annotation_types: {0}::get_annotation_types }}.into()).into() }}", name_as_mod))),
                 Line("}".into()),
             ]));
 
@@ -2523,8 +2834,28 @@ fn generate_node(
                 format_u64(node_id)
             )));
 
+            private_mod_interior.push(crate::pointer_constants::node_word_array_declaration(
+                ctx,
+                "ENCODED_NODE",
+                *node_reader,
+                crate::pointer_constants::WordArrayDeclarationOptions { public: true },
+            )?);
+
+            // `static` instead of `const` so that this has a fixed memory address
+            // and we can check equality of `RawInterfaceSchema` values by comparing pointers.
+            private_mod_interior.push(Branch(vec![
+                Line(fmt!(ctx,"pub static RAW_SCHEMA: {capnp}::introspect::RawInterfaceSchema = {capnp}::introspect::RawInterfaceSchema {{")),
+                indent(vec![
+                    Line("encoded_node: &ENCODED_NODE,".into()),
+                    Line("methods_by_name: METHODS_BY_NAME,".into()),
+                ]),
+                Line("};".into()),
+            ]));
+            private_mod_interior.push(generate_methods_by_name(*node_reader)?);
+
             mod_interior.push(line("#![allow(unused_variables)]"));
 
+            let mut method_type_branches = Vec::new();
             let methods = interface.get_methods()?;
             for (ordinal, method) in methods.into_iter().enumerate() {
                 let name = method.get_name()?.to_str()?;
@@ -2573,6 +2904,11 @@ fn generate_node(
                     &result_scopes.join("::"),
                 )?;
 
+                method_type_branches.push(Line(fmt!(
+                    ctx,
+                    "{ordinal} => (<{param_type} as {capnp}::introspect::Introspect>::introspect(), <{result_type} as {capnp}::introspect::Introspect>::introspect()),"
+                )));
+
                 dispatch_arms.push(
                     Line(fmt!(ctx,
                         "{ordinal} => server.{}({capnp}::private::capability::internal_get_typed_params(params), {capnp}::private::capability::internal_get_typed_results(results)),",
@@ -2616,6 +2952,14 @@ fn generate_node(
                 method.get_annotations()?;
             }
 
+            private_mod_interior.push(generate_get_method_types(
+                ctx,
+                *node_reader,
+                method_type_branches,
+            )?);
+            private_mod_interior.push(generate_get_superclass_types(ctx, *node_reader)?);
+            private_mod_interior.push(generate_get_annotation_types(ctx, *node_reader)?);
+
             let mut base_dispatch_arms = Vec::new();
 
             let server_base = {
@@ -2682,13 +3026,23 @@ fn generate_node(
                     indent(Line(fmt!(ctx,"fn as_client_hook(&self) -> &dyn ({capnp}::private::capability::ClientHook) {{"))),
                     indent(indent(line("&*self.client.hook"))),
                     indent(line("}")),
+                    // Generic clients do not know how their parameters are bound, since
+                    // they are not constrained here, so they keep the default.
+                    if is_generic {
+                        Branch(Vec::new())
+                    } else {
+                        indent(Branch(vec![
+                            Line(fmt!(ctx,"fn introspect_interface() -> {capnp}::introspect::Type<'static> {{")),
+                            indent(Line(fmt!(ctx,"<Self as {capnp}::introspect::Introspect>::introspect()"))),
+                            line("}")]))
+                    },
                     line("}")]));
 
             mod_interior.push(if !is_generic {
                 Branch(vec![
                     Line("#[derive(Copy, Clone)]".into()),
                     line("pub struct Owned(());"),
-                    Line(fmt!(ctx,"impl {capnp}::introspect::Introspect for Owned {{ fn introspect() -> {capnp}::introspect::Type {{ {capnp}::introspect::TypeVariant::Capability.into() }} }}")),
+                    Line(fmt!(ctx,"impl {capnp}::introspect::Introspect for Owned {{ fn introspect() -> {capnp}::introspect::Type<'static> {{ {capnp}::introspect::TypeVariant::Capability({capnp}::introspect::RawBrandedInterfaceSchema {{ generic: &_private::RAW_SCHEMA, method_types: _private::get_method_types, superclass_types: _private::get_superclass_types, annotation_types: _private::get_annotation_types }}.into()).into() }} }}")),
                     line("impl ::capnp::traits::Owned for Owned { type Reader<'a> = Client; type Builder<'a> = Client; }"),
                     Line(fmt!(ctx,"impl {capnp}::traits::Pipelined for Owned {{ type Pipeline = Client; }}"))])
             } else {
@@ -2698,7 +3052,7 @@ fn generate_node(
                     indent(Line(params.phantom_data_type.clone())),
                     line("}"),
                     Line(fmt!(ctx,
-                              "impl <{0}> {capnp}::introspect::Introspect for Owned <{0}> {1} {{ fn introspect() -> {capnp}::introspect::Type {{ {capnp}::introspect::TypeVariant::Capability.into() }} }}",
+                              "impl <{0}> {capnp}::introspect::Introspect for Owned <{0}> {1} {{ fn introspect() -> {capnp}::introspect::Type<'static> {{ {capnp}::introspect::TypeVariant::Capability({capnp}::introspect::RawBrandedInterfaceSchema {{ generic: &_private::RAW_SCHEMA, method_types: _private::get_method_types::<{0}>, superclass_types: _private::get_superclass_types::<{0}>, annotation_types: _private::get_annotation_types::<{0}> }}.into()).into() }} }}",
                               params.params, params.where_clause)),
                     Line(fmt!(ctx,
                         "impl <{0}> {capnp}::traits::Owned for Owned <{0}> {1} {{ type Reader<'a> = Client<{0}>; type Builder<'a> = Client<{0}>; }}",
@@ -2743,6 +3097,10 @@ fn generate_node(
                 ),
                 line("}")]));
 
+            mod_interior.push(Line(fmt!(ctx,
+                "impl <{0}> {capnp}::introspect::Introspect for Client<{0}> {1} {{ fn introspect() -> {capnp}::introspect::Type<'static> {{ <Owned<{0}> as {capnp}::introspect::Introspect>::introspect() }} }}",
+                params.params, params.where_clause)));
+
             mod_interior.push(Branch(vec![
                 Line(fmt!(ctx,
                     "impl {bracketed_params} {capnp}::traits::HasTypeId for Client{bracketed_params} {{"
@@ -2999,9 +3357,9 @@ fn generate_node(
             let ty = annotation_reader.get_type()?;
             if !is_generic {
                 interior.push(Line(fmt!(ctx,
-                    "pub fn get_type() -> {capnp}::introspect::Type {{ <{} as {capnp}::introspect::Introspect>::introspect() }}", ty.type_string(ctx, Leaf::Owned)?)));
+                    "pub fn get_type() -> {capnp}::introspect::Type<'static> {{ <{} as {capnp}::introspect::Introspect>::introspect() }}", ty.type_string(ctx, Leaf::Owned)?)));
             } else {
-                interior.push(Line(fmt!(ctx,"pub fn get_type<{0}>() -> {capnp}::introspect::Type {1} {{ <{2} as {capnp}::introspect::Introspect>::introspect() }}", params.params, params.where_clause, ty.type_string(ctx, Leaf::Owned)?)));
+                interior.push(Line(fmt!(ctx,"pub fn get_type<{0}>() -> {capnp}::introspect::Type<'static> {1} {{ <{2} as {capnp}::introspect::Introspect>::introspect() }}", params.params, params.where_clause, ty.type_string(ctx, Leaf::Owned)?)));
             }
             output.push(Branch(vec![
                 Line(format!("pub mod {} {{", last_name)),
@@ -0,0 +1,821 @@
+//! Detection of backwards-incompatible schema changes.
+//!
+//! [`check()`] compares an old and a new set of `schema.capnp` nodes, such as the `nodes`
+//! of two `CodeGeneratorRequest`s, and reports changes that would break existing readers
+//! and writers of messages, or existing RPC peers. Nodes are matched by ID and their
+//! members by ordinal, so renaming things is allowed but renumbering them is not.
+//!
+//! To run the check as part of code generation, see
+//! [`CodeGenerationCommand::compatibility_baseline()`](crate::codegen::CodeGenerationCommand::compatibility_baseline).
+
+use std::collections::HashMap;
+use std::fmt;
+
+use capnp::schema_capnp::{code_generator_request, field, node, type_};
+
+/// The kind of change found by [`check()`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum IncompatibilityKind {
+    /// Two different nodes of the new schema have the same ID.
+    IdCollision,
+    /// A node has the same name as before, but a different ID.
+    IdChanged,
+    /// A node changed kind, e.g. from a struct to an enum.
+    NodeKindChanged,
+    /// A struct has fewer data words or pointers than before.
+    StructShrunk,
+    /// A field was removed.
+    FieldRemoved,
+    /// A field moved to a different ordinal.
+    FieldRenumbered,
+    /// A field has a different type.
+    FieldTypeChanged,
+    /// A field is stored at a different offset, or changed between a slot and a group.
+    FieldOffsetChanged,
+    /// A field's discriminant value changed, or a union's discriminant moved.
+    UnionDiscriminantChanged,
+    /// An enumerant was removed.
+    EnumerantRemoved,
+    /// A method was removed.
+    MethodRemoved,
+    /// A method's params or results are a different struct type.
+    MethodTypeChanged,
+}
+
+/// A backwards-incompatible change found by [`check()`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Incompatibility {
+    pub kind: IncompatibilityKind,
+
+    /// ID of the node where the change was found.
+    pub node_id: u64,
+
+    /// Display name of the node, followed by the name of the member that changed, if any.
+    pub location: String,
+
+    /// What changed.
+    pub description: String,
+}
+
+impl fmt::Display for Incompatibility {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.location, self.description)
+    }
+}
+
+/// Compares the nodes of two code generator requests. See [`check()`].
+pub fn check_requests(
+    old: code_generator_request::Reader,
+    new: code_generator_request::Reader,
+) -> capnp::Result<Vec<Incompatibility>> {
+    check(old.get_nodes()?, new.get_nodes()?)
+}
+
+/// Compares the `old` and `new` versions of a schema and returns the changes that
+/// are not backwards compatible.
+///
+/// Nodes of `old` that do not appear in `new` are ignored, so `new` may be a
+/// superset of `old` and vice versa.
+pub fn check<'a, 'b>(
+    old: impl IntoIterator<Item = node::Reader<'a>>,
+    new: impl IntoIterator<Item = node::Reader<'b>>,
+) -> capnp::Result<Vec<Incompatibility>> {
+    let mut checker = Checker {
+        old_nodes: HashMap::new(),
+        new_nodes: HashMap::new(),
+        result: Vec::new(),
+    };
+
+    let mut new_ids_by_name = HashMap::new();
+    for node in new {
+        let name = node.get_display_name()?.to_str()?;
+        if let Some(other) = checker.new_nodes.get(&node.get_id()) {
+            let other_name = other.get_display_name()?.to_str()?;
+            if other_name != name {
+                checker.report(
+                    IncompatibilityKind::IdCollision,
+                    node,
+                    None,
+                    format!("ID @{:#x} is also used by {other_name}", node.get_id()),
+                )?;
+            }
+            continue;
+        }
+        checker.new_nodes.insert(node.get_id(), node);
+        new_ids_by_name.insert(name, node.get_id());
+    }
+
+    let mut old_nodes = Vec::new();
+    for node in old {
+        checker.old_nodes.insert(node.get_id(), node);
+        old_nodes.push(node);
+    }
+
+    for old_node in old_nodes {
+        if let node::Struct(st) = old_node.which()? {
+            if st.get_is_group() {
+                // Checked along with the struct that contains the group.
+                continue;
+            }
+        }
+        match checker.new_nodes.get(&old_node.get_id()) {
+            Some(&new_node) => checker.check_node(old_node, new_node)?,
+            None => {
+                let name = old_node.get_display_name()?.to_str()?;
+                if let Some(new_id) = new_ids_by_name.get(name) {
+                    checker.report(
+                        IncompatibilityKind::IdChanged,
+                        old_node,
+                        None,
+                        format!("ID changed from @{:#x} to @{new_id:#x}", old_node.get_id()),
+                    )?;
+                }
+            }
+        }
+    }
+    Ok(checker.result)
+}
+
+struct Checker<'a, 'b> {
+    old_nodes: HashMap<u64, node::Reader<'a>>,
+    new_nodes: HashMap<u64, node::Reader<'b>>,
+    result: Vec<Incompatibility>,
+}
+
+impl<'a, 'b> Checker<'a, 'b> {
+    fn report(
+        &mut self,
+        kind: IncompatibilityKind,
+        node: node::Reader,
+        member: Option<&str>,
+        description: String,
+    ) -> capnp::Result<()> {
+        let mut location = node.get_display_name()?.to_string()?;
+        if let Some(member) = member {
+            location.push('.');
+            location.push_str(member);
+        }
+        self.result.push(Incompatibility {
+            kind,
+            node_id: node.get_id(),
+            location,
+            description,
+        });
+        Ok(())
+    }
+
+    fn check_node(&mut self, old: node::Reader<'a>, new: node::Reader<'b>) -> capnp::Result<()> {
+        match (old.which()?, new.which()?) {
+            (node::Struct(old_struct), node::Struct(new_struct)) => {
+                self.check_struct(old, old_struct, new_struct)
+            }
+            (node::Enum(old_enum), node::Enum(new_enum)) => {
+                let new_len = new_enum.get_enumerants()?.len();
+                for enumerant in old_enum.get_enumerants()?.iter().skip(new_len as usize) {
+                    self.report(
+                        IncompatibilityKind::EnumerantRemoved,
+                        old,
+                        Some(enumerant.get_name()?.to_str()?),
+                        "enumerant was removed".into(),
+                    )?;
+                }
+                Ok(())
+            }
+            (node::Interface(old_interface), node::Interface(new_interface)) => {
+                self.check_interface(old, old_interface, new_interface)
+            }
+            (old_which, new_which) => {
+                let (old_kind, new_kind) = (node_kind(&old_which), node_kind(&new_which));
+                if old_kind != new_kind {
+                    self.report(
+                        IncompatibilityKind::NodeKindChanged,
+                        old,
+                        None,
+                        format!("changed from {old_kind} to {new_kind}"),
+                    )?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn check_struct(
+        &mut self,
+        node: node::Reader<'a>,
+        old: node::struct_::Reader<'a>,
+        new: node::struct_::Reader<'b>,
+    ) -> capnp::Result<()> {
+        if new.get_data_word_count() < old.get_data_word_count()
+            || new.get_pointer_count() < old.get_pointer_count()
+        {
+            self.report(
+                IncompatibilityKind::StructShrunk,
+                node,
+                None,
+                format!(
+                    "size changed from {} data words and {} pointers to {} data words and {} pointers",
+                    old.get_data_word_count(),
+                    old.get_pointer_count(),
+                    new.get_data_word_count(),
+                    new.get_pointer_count()
+                ),
+            )?;
+        }
+        if old.get_discriminant_count() > 0
+            && new.get_discriminant_offset() != old.get_discriminant_offset()
+        {
+            self.report(
+                IncompatibilityKind::UnionDiscriminantChanged,
+                node,
+                None,
+                format!(
+                    "union discriminant moved from offset {} to {}",
+                    old.get_discriminant_offset(),
+                    new.get_discriminant_offset()
+                ),
+            )?;
+        }
+
+        let new_fields = new.get_fields()?;
+        let mut new_by_ordinal = HashMap::new();
+        let mut new_by_name = HashMap::new();
+        for field in new_fields {
+            if let Some(ordinal) = member_ordinal(field, &self.new_nodes)? {
+                new_by_ordinal.insert(ordinal, field);
+            }
+            new_by_name.insert(field.get_name()?.to_str()?, field);
+        }
+
+        for old_field in old.get_fields()? {
+            let name = old_field.get_name()?.to_str()?;
+            let same_name = new_by_name.get(name).copied();
+            let new_field = match old_field.get_ordinal().which()? {
+                field::ordinal::Explicit(ordinal) => {
+                    if let Some(field::ordinal::Explicit(new_ordinal)) =
+                        same_name.map(|f| f.get_ordinal().which()).transpose()?
+                    {
+                        if new_ordinal != ordinal {
+                            self.report(
+                                IncompatibilityKind::FieldRenumbered,
+                                node,
+                                Some(name),
+                                format!("ordinal changed from @{ordinal} to @{new_ordinal}"),
+                            )?;
+                            continue;
+                        }
+                    }
+                    new_by_ordinal.get(&ordinal).copied()
+                }
+                field::ordinal::Implicit(()) => match member_ordinal(old_field, &self.old_nodes)? {
+                    Some(ordinal) => new_by_ordinal.get(&ordinal).copied(),
+                    None => same_name,
+                },
+            };
+            let Some(new_field) = new_field else {
+                self.report(
+                    IncompatibilityKind::FieldRemoved,
+                    node,
+                    Some(name),
+                    "field was removed".into(),
+                )?;
+                continue;
+            };
+            self.check_field(node, name, old_field, new_field)?;
+        }
+        Ok(())
+    }
+
+    fn check_field(
+        &mut self,
+        node: node::Reader<'a>,
+        name: &str,
+        old: field::Reader<'a>,
+        new: field::Reader<'b>,
+    ) -> capnp::Result<()> {
+        let old_discriminant = old.get_discriminant_value();
+        let new_discriminant = new.get_discriminant_value();
+        if old_discriminant != field::NO_DISCRIMINANT && new_discriminant != old_discriminant {
+            let description = if new_discriminant == field::NO_DISCRIMINANT {
+                "field is no longer a member of the union".into()
+            } else {
+                format!("discriminant value changed from {old_discriminant} to {new_discriminant}")
+            };
+            self.report(
+                IncompatibilityKind::UnionDiscriminantChanged,
+                node,
+                Some(name),
+                description,
+            )?;
+        }
+
+        match (old.which()?, new.which()?) {
+            (field::Slot(old_slot), field::Slot(new_slot)) => {
+                let (old_type, new_type) = (old_slot.get_type()?, new_slot.get_type()?);
+                if !same_type(old_type, new_type)? {
+                    let description = format!(
+                        "type changed from {} to {}",
+                        type_name(old_type, &self.old_nodes)?,
+                        type_name(new_type, &self.new_nodes)?
+                    );
+                    self.report(
+                        IncompatibilityKind::FieldTypeChanged,
+                        node,
+                        Some(name),
+                        description,
+                    )?;
+                } else if old_slot.get_offset() != new_slot.get_offset() {
+                    self.report(
+                        IncompatibilityKind::FieldOffsetChanged,
+                        node,
+                        Some(name),
+                        format!(
+                            "offset changed from {} to {}",
+                            old_slot.get_offset(),
+                            new_slot.get_offset()
+                        ),
+                    )?;
+                }
+            }
+            (field::Group(old_group), field::Group(new_group)) => {
+                let old_node = self.old_nodes.get(&old_group.get_type_id()).copied();
+                let new_node = self.new_nodes.get(&new_group.get_type_id()).copied();
+                if let (Some(old_node), Some(new_node)) = (old_node, new_node) {
+                    self.check_node(old_node, new_node)?;
+                }
+            }
+            (field::Slot(_), field::Group(_)) => self.report(
+                IncompatibilityKind::FieldOffsetChanged,
+                node,
+                Some(name),
+                "changed from a slot to a group".into(),
+            )?,
+            (field::Group(_), field::Slot(_)) => self.report(
+                IncompatibilityKind::FieldOffsetChanged,
+                node,
+                Some(name),
+                "changed from a group to a slot".into(),
+            )?,
+        }
+        Ok(())
+    }
+
+    fn check_interface(
+        &mut self,
+        node: node::Reader<'a>,
+        old: node::interface::Reader<'a>,
+        new: node::interface::Reader<'b>,
+    ) -> capnp::Result<()> {
+        let new_methods = new.get_methods()?;
+        for (ordinal, old_method) in old.get_methods()?.iter().enumerate() {
+            let name = old_method.get_name()?.to_str()?;
+            if ordinal >= new_methods.len() as usize {
+                self.report(
+                    IncompatibilityKind::MethodRemoved,
+                    node,
+                    Some(name),
+                    "method was removed".into(),
+                )?;
+                continue;
+            }
+            let new_method = new_methods.get(ordinal as u32);
+            for (what, old_id, new_id) in [
+                (
+                    "params",
+                    old_method.get_param_struct_type(),
+                    new_method.get_param_struct_type(),
+                ),
+                (
+                    "results",
+                    old_method.get_result_struct_type(),
+                    new_method.get_result_struct_type(),
+                ),
+            ] {
+                if old_id != new_id {
+                    self.check_method_struct(node, name, what, old_id, new_id)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks params or results whose struct ID changed. This is fine as long as both
+    /// are compatible implicitly-declared structs, whose IDs are derived from method names.
+    fn check_method_struct(
+        &mut self,
+        node: node::Reader<'a>,
+        method_name: &str,
+        what: &str,
+        old_id: u64,
+        new_id: u64,
+    ) -> capnp::Result<()> {
+        let old_struct = self.old_nodes.get(&old_id).copied();
+        let new_struct = self.new_nodes.get(&new_id).copied();
+        if let (Some(old_struct), Some(new_struct)) = (old_struct, new_struct) {
+            if old_struct.get_scope_id() == 0 && new_struct.get_scope_id() == 0 {
+                return self.check_node(old_struct, new_struct);
+            }
+        }
+        self.report(
+            IncompatibilityKind::MethodTypeChanged,
+            node,
+            Some(method_name),
+            format!(
+                "{what} changed from {} to {}",
+                node_name(old_id, &self.old_nodes)?,
+                node_name(new_id, &self.new_nodes)?
+            ),
+        )
+    }
+}
+
+fn node_kind<A, B, C, D, E>(which: &node::Which<A, B, C, D, E>) -> &'static str {
+    match which {
+        node::File(()) => "file",
+        node::Struct(_) => "struct",
+        node::Enum(_) => "enum",
+        node::Interface(_) => "interface",
+        node::Const(_) => "const",
+        node::Annotation(_) => "annotation",
+    }
+}
+
+/// The ordinal that identifies `field` among the members of its struct. Groups and unions
+/// have no ordinal of their own, so they are identified by the smallest ordinal among their
+/// members, which does not change when they are renamed.
+fn member_ordinal(
+    field: field::Reader,
+    nodes: &HashMap<u64, node::Reader>,
+) -> capnp::Result<Option<u16>> {
+    match (field.get_ordinal().which()?, field.which()?) {
+        (field::ordinal::Explicit(ordinal), _) => Ok(Some(ordinal)),
+        (field::ordinal::Implicit(()), field::Group(group)) => {
+            let Some(node::Struct(st)) = nodes
+                .get(&group.get_type_id())
+                .map(|node| node.which())
+                .transpose()?
+            else {
+                return Ok(None);
+            };
+            let mut result = None;
+            for member in st.get_fields()? {
+                if let Some(ordinal) = member_ordinal(member, nodes)? {
+                    result = Some(result.map_or(ordinal, |r: u16| r.min(ordinal)));
+                }
+            }
+            Ok(result)
+        }
+        (field::ordinal::Implicit(()), field::Slot(_)) => Ok(None),
+    }
+}
+
+fn is_pointer(ty: type_::Reader) -> capnp::Result<bool> {
+    Ok(matches!(
+        ty.which()?,
+        type_::Text(())
+            | type_::Data(())
+            | type_::List(_)
+            | type_::Struct(_)
+            | type_::Interface(_)
+            | type_::AnyPointer(_)
+    ))
+}
+
+/// Whether `old` and `new` have the same encoding. Any pointer type may be
+/// replaced by `AnyPointer` and vice versa.
+fn same_type(old: type_::Reader, new: type_::Reader) -> capnp::Result<bool> {
+    Ok(match (old.which()?, new.which()?) {
+        (type_::List(old), type_::List(new)) => {
+            same_type(old.get_element_type()?, new.get_element_type()?)?
+        }
+        (type_::Enum(old), type_::Enum(new)) => old.get_type_id() == new.get_type_id(),
+        (type_::Struct(old), type_::Struct(new)) => old.get_type_id() == new.get_type_id(),
+        (type_::Interface(old), type_::Interface(new)) => old.get_type_id() == new.get_type_id(),
+        (type_::AnyPointer(_), _) => is_pointer(new)?,
+        (_, type_::AnyPointer(_)) => is_pointer(old)?,
+        _ => type_name(old, &HashMap::new())? == type_name(new, &HashMap::new())?,
+    })
+}
+
+fn node_name(id: u64, nodes: &HashMap<u64, node::Reader>) -> capnp::Result<String> {
+    match nodes.get(&id) {
+        Some(node) => Ok(node.get_display_name()?.to_string()?),
+        None => Ok(format!("@{id:#x}")),
+    }
+}
+
+fn type_name(ty: type_::Reader, nodes: &HashMap<u64, node::Reader>) -> capnp::Result<String> {
+    Ok(match ty.which()? {
+        type_::Void(()) => "Void".into(),
+        type_::Bool(()) => "Bool".into(),
+        type_::Int8(()) => "Int8".into(),
+        type_::Int16(()) => "Int16".into(),
+        type_::Int32(()) => "Int32".into(),
+        type_::Int64(()) => "Int64".into(),
+        type_::Uint8(()) => "UInt8".into(),
+        type_::Uint16(()) => "UInt16".into(),
+        type_::Uint32(()) => "UInt32".into(),
+        type_::Uint64(()) => "UInt64".into(),
+        type_::Float32(()) => "Float32".into(),
+        type_::Float64(()) => "Float64".into(),
+        type_::Text(()) => "Text".into(),
+        type_::Data(()) => "Data".into(),
+        type_::List(list) => format!("List({})", type_name(list.get_element_type()?, nodes)?),
+        type_::Enum(e) => node_name(e.get_type_id(), nodes)?,
+        type_::Struct(s) => node_name(s.get_type_id(), nodes)?,
+        type_::Interface(i) => node_name(i.get_type_id(), nodes)?,
+        type_::AnyPointer(_) => "AnyPointer".into(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check_requests, IncompatibilityKind};
+    use capnp::message;
+    use capnp::schema_capnp::{code_generator_request, field, node};
+
+    const FILE: u64 = 0xf11e;
+    const FOO: u64 = 0xf00;
+    const COLOR: u64 = 0xc010;
+    const SERVICE: u64 = 0x5e5;
+
+    /// A field with an explicit ordinal: (name, ordinal, offset, type).
+    type Field<'a> = (&'a str, u16, u32, &'a str);
+
+    fn init_struct(
+        mut node: node::Builder,
+        id: u64,
+        name: &str,
+        data_words: u16,
+        pointers: u16,
+        fields: &[Field],
+    ) {
+        node.set_id(id);
+        node.set_display_name(name);
+        node.set_scope_id(FILE);
+        let mut st = node.init_struct();
+        st.set_data_word_count(data_words);
+        st.set_pointer_count(pointers);
+        let mut list = st.init_fields(fields.len() as u32);
+        for (i, &(name, ordinal, offset, ty)) in fields.iter().enumerate() {
+            let mut field = list.reborrow().get(i as u32);
+            field.set_name(name);
+            field.set_code_order(i as u16);
+            field.set_discriminant_value(field::NO_DISCRIMINANT);
+            field.reborrow().init_ordinal().set_explicit(ordinal);
+            let mut slot = field.init_slot();
+            slot.set_offset(offset);
+            let mut t = slot.init_type();
+            match ty {
+                "UInt32" => t.set_uint32(()),
+                "Float32" => t.set_float32(()),
+                "Text" => t.set_text(()),
+                "AnyPointer" => {
+                    t.init_any_pointer().init_unconstrained().set_any_kind(());
+                }
+                _ => t.init_struct().set_type_id(FOO),
+            }
+        }
+    }
+
+    fn init_enum(mut node: node::Builder, id: u64, names: &[&str]) {
+        node.set_id(id);
+        node.set_display_name("test.capnp:Color");
+        let mut enumerants = node.init_enum().init_enumerants(names.len() as u32);
+        for (i, name) in names.iter().enumerate() {
+            enumerants.reborrow().get(i as u32).set_name(name);
+        }
+    }
+
+    fn init_interface(mut node: node::Builder, methods: &[(&str, u64)]) {
+        node.set_id(SERVICE);
+        node.set_display_name("test.capnp:Service");
+        let mut list = node.init_interface().init_methods(methods.len() as u32);
+        for (i, &(name, params)) in methods.iter().enumerate() {
+            let mut method = list.reborrow().get(i as u32);
+            method.set_name(name);
+            method.set_param_struct_type(params);
+            method.set_result_struct_type(FOO);
+        }
+    }
+
+    fn request(
+        count: u32,
+        build: impl FnOnce(capnp::struct_list::Builder<node::Owned>),
+    ) -> message::Builder<message::HeapAllocator> {
+        let mut message = message::Builder::new_default();
+        build(
+            message
+                .init_root::<code_generator_request::Builder>()
+                .init_nodes(count),
+        );
+        message
+    }
+
+    fn kinds(
+        old: &message::Builder<message::HeapAllocator>,
+        new: &message::Builder<message::HeapAllocator>,
+    ) -> Vec<(IncompatibilityKind, String)> {
+        check_requests(
+            old.get_root_as_reader().unwrap(),
+            new.get_root_as_reader().unwrap(),
+        )
+        .unwrap()
+        .into_iter()
+        .map(|i| (i.kind, i.location))
+        .collect()
+    }
+
+    #[test]
+    fn compatible_changes() {
+        let old = request(2, |mut nodes| {
+            init_struct(
+                nodes.reborrow().get(0),
+                FOO,
+                "test.capnp:Foo",
+                1,
+                1,
+                &[("a", 0, 0, "UInt32"), ("b", 1, 0, "Text")],
+            );
+            init_enum(nodes.get(1), COLOR, &["red", "green"]);
+        });
+        let new = request(2, |mut nodes| {
+            init_struct(
+                nodes.reborrow().get(0),
+                FOO,
+                "test.capnp:Bar",
+                2,
+                2,
+                &[
+                    ("renamed", 0, 0, "UInt32"),
+                    ("b", 1, 0, "AnyPointer"),
+                    ("c", 2, 1, "Text"),
+                ],
+            );
+            init_enum(nodes.get(1), COLOR, &["red", "green", "blue"]);
+        });
+        assert_eq!(kinds(&old, &new), []);
+    }
+
+    #[test]
+    fn struct_changes() {
+        let old = request(1, |nodes| {
+            init_struct(
+                nodes.get(0),
+                FOO,
+                "test.capnp:Foo",
+                1,
+                2,
+                &[
+                    ("a", 0, 0, "UInt32"),
+                    ("b", 1, 1, "UInt32"),
+                    ("c", 2, 0, "Text"),
+                    ("d", 3, 1, "Text"),
+                    ("e", 4, 0, "Text"),
+                ],
+            );
+        });
+        let new = request(1, |nodes| {
+            init_struct(
+                nodes.get(0),
+                FOO,
+                "test.capnp:Foo",
+                1,
+                1,
+                &[
+                    ("a", 0, 0, "Float32"),
+                    ("b", 1, 2, "UInt32"),
+                    ("d", 2, 0, "Text"),
+                    ("c", 3, 1, "Text"),
+                ],
+            );
+        });
+        assert_eq!(
+            kinds(&old, &new),
+            [
+                (IncompatibilityKind::StructShrunk, "test.capnp:Foo".into()),
+                (
+                    IncompatibilityKind::FieldTypeChanged,
+                    "test.capnp:Foo.a".into()
+                ),
+                (
+                    IncompatibilityKind::FieldOffsetChanged,
+                    "test.capnp:Foo.b".into()
+                ),
+                (
+                    IncompatibilityKind::FieldRenumbered,
+                    "test.capnp:Foo.c".into()
+                ),
+                (
+                    IncompatibilityKind::FieldRenumbered,
+                    "test.capnp:Foo.d".into()
+                ),
+                (IncompatibilityKind::FieldRemoved, "test.capnp:Foo.e".into()),
+            ]
+        );
+    }
+
+    /// Builds `struct Foo { a @0 :UInt32; <group_name> :group { x @1 :UInt32; } }`,
+    /// with `x` at `offset`.
+    fn init_with_group(
+        mut nodes: capnp::struct_list::Builder<node::Owned>,
+        group_id: u64,
+        group_name: &str,
+        offset: u32,
+    ) {
+        init_struct(
+            nodes.reborrow().get(1),
+            group_id,
+            &format!("test.capnp:Foo.{group_name}"),
+            1,
+            0,
+            &[("x", 1, offset, "UInt32")],
+        );
+        let node::Struct(mut group) = nodes.reborrow().get(1).which().unwrap() else {
+            unreachable!()
+        };
+        group.set_is_group(true);
+        let mut node = nodes.get(0);
+        node.set_id(FOO);
+        node.set_display_name("test.capnp:Foo");
+        node.set_scope_id(FILE);
+        let mut st = node.init_struct();
+        st.set_data_word_count(1);
+        let mut fields = st.init_fields(2);
+        let mut a = fields.reborrow().get(0);
+        a.set_name("a");
+        a.set_discriminant_value(field::NO_DISCRIMINANT);
+        a.reborrow().init_ordinal().set_explicit(0);
+        a.init_slot().init_type().set_uint32(());
+        let mut group = fields.get(1);
+        group.set_name(group_name);
+        group.set_code_order(1);
+        group.set_discriminant_value(field::NO_DISCRIMINANT);
+        group.reborrow().init_ordinal().set_implicit(());
+        group.init_group().set_type_id(group_id);
+    }
+
+    #[test]
+    fn group_changes() {
+        let old = request(2, |nodes| init_with_group(nodes, 0x9001, "g", 1));
+        // Renaming a group gives it a new ID, but it is still found by its members.
+        let renamed = request(2, |nodes| init_with_group(nodes, 0x9002, "h", 1));
+        assert_eq!(kinds(&old, &renamed), []);
+
+        let moved = request(2, |nodes| init_with_group(nodes, 0x9002, "h", 0));
+        assert_eq!(
+            kinds(&old, &moved),
+            [(
+                IncompatibilityKind::FieldOffsetChanged,
+                "test.capnp:Foo.g.x".into()
+            )]
+        );
+    }
+
+    #[test]
+    fn other_changes() {
+        const PARAMS: u64 = 0x9a9a;
+        let old = request(4, |mut nodes| {
+            init_struct(nodes.reborrow().get(0), FOO, "test.capnp:Foo", 0, 0, &[]);
+            init_enum(nodes.reborrow().get(1), COLOR, &["red", "green"]);
+            init_interface(
+                nodes.reborrow().get(2),
+                &[("get", PARAMS), ("put", PARAMS), ("delete", PARAMS)],
+            );
+            init_struct(nodes.get(3), PARAMS, "test.capnp:Params", 0, 0, &[]);
+        });
+        let new = request(5, |mut nodes| {
+            init_struct(nodes.reborrow().get(0), FOO, "test.capnp:Foo", 0, 0, &[]);
+            init_enum(nodes.reborrow().get(1), COLOR, &["red"]);
+            init_interface(nodes.reborrow().get(2), &[("get", PARAMS), ("put", FOO)]);
+            init_struct(
+                nodes.reborrow().get(3),
+                0x1234,
+                "test.capnp:Params",
+                0,
+                0,
+                &[],
+            );
+            init_struct(nodes.get(4), COLOR, "test.capnp:Other", 0, 0, &[]);
+        });
+        assert_eq!(
+            kinds(&old, &new),
+            [
+                (IncompatibilityKind::IdCollision, "test.capnp:Other".into()),
+                (
+                    IncompatibilityKind::EnumerantRemoved,
+                    "test.capnp:Color.green".into()
+                ),
+                (
+                    IncompatibilityKind::MethodTypeChanged,
+                    "test.capnp:Service.put".into()
+                ),
+                (
+                    IncompatibilityKind::MethodRemoved,
+                    "test.capnp:Service.delete".into()
+                ),
+                (IncompatibilityKind::IdChanged, "test.capnp:Params".into()),
+            ]
+        );
+    }
+}
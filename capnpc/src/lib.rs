@@ -69,6 +69,7 @@
 
 pub mod codegen;
 pub mod codegen_types;
+pub mod compatibility;
 mod pointer_constants;
 
 use std::{
@@ -89,10 +90,9 @@ pub(crate) fn convert_io_err(err: std::io::Error) -> capnp::Error {
         | io::ErrorKind::NotConnected => capnp::ErrorKind::Disconnected,
         _ => capnp::ErrorKind::Failed,
     };
-    capnp::Error {
-        extra: format!("{err}"),
-        kind,
-    }
+    let mut error = capnp::Error::from_kind(kind);
+    error.extra = format!("{err}");
+    error
 }
 
 fn run_command(
@@ -123,6 +123,7 @@ pub struct CompilerCommand {
     default_parent_module: Vec<String>,
     raw_code_generator_request_path: Option<PathBuf>,
     crate_provides_map: HashMap<u64, String>,
+    compatibility_baseline_path: Option<PathBuf>,
 }
 
 impl CompilerCommand {
@@ -297,6 +298,20 @@ impl CompilerCommand {
         self
     }
 
+    /// Checks the schema against a baseline before generating code, failing if any
+    /// backwards-incompatible changes are found. `path` should point to a raw code generator
+    /// request from an earlier version of the schema, as written by
+    /// [`raw_code_generator_request_path()`](Self::raw_code_generator_request_path).
+    ///
+    /// See [`compatibility`] for the kinds of changes that are detected.
+    pub fn compatibility_baseline<P>(&mut self, path: P) -> &mut Self
+    where
+        P: AsRef<Path>,
+    {
+        self.compatibility_baseline_path = Some(path.as_ref().to_path_buf());
+        self
+    }
+
     /// Runs the command.
     /// Returns an error if `OUT_DIR` or a custom output directory was not set, or if `capnp compile` fails.
     pub fn run(&mut self) -> ::capnp::Result<()> {
@@ -386,6 +401,9 @@ impl CompilerCommand {
             code_generation_command
                 .raw_code_generator_request_path(raw_code_generator_request_path.clone());
         }
+        if let Some(compatibility_baseline_path) = &self.compatibility_baseline_path {
+            code_generation_command.compatibility_baseline(compatibility_baseline_path.clone());
+        }
 
         run_command(command, code_generation_command).map_err(|error| {
             ::capnp::Error::failed(format!(
@@ -2,6 +2,8 @@ fn main() {
     capnpc::CompilerCommand::new()
         .crate_provides("external_crate", [0xe6f94f52f7be8fe2])
         .file("test.capnp")
+        .file("json.capnp")
+        .file("test-json.capnp")
         .file("in-submodule.capnp")
         .file("in-other-submodule.capnp")
         .file("schema/test-in-dir.capnp")
@@ -408,3 +408,66 @@ fn test_get_named_missing() {
     assert!(root.get_named("zzzzzzz").is_err());
     assert!(root.has_named("zzzzzzz").is_err());
 }
+
+#[test]
+fn test_interface_schema() -> ::capnp::Result<()> {
+    use crate::test_capnp::{
+        generic_base, generic_extend, test_capability_list, test_extends, test_interface,
+    };
+    use capnp::introspect::{Introspect, TypeVariant};
+    use capnp::schema::{InterfaceSchema, StructSchema};
+
+    fn interface_schema<T: Introspect>() -> InterfaceSchema {
+        let TypeVariant::Capability(interface) = T::introspect().which() else {
+            panic!("expected an interface")
+        };
+        interface.into()
+    }
+
+    let extends = interface_schema::<test_extends::Owned>();
+    assert_eq!(3, extends.get_methods()?.len());
+    let corge = extends.get_method_by_name("corge")?;
+    assert_eq!(1, corge.get_ordinal());
+    assert!(corge
+        .get_param_type()
+        .get_proto()
+        .get_display_name()?
+        .to_str()?
+        .ends_with("TestBigStruct"));
+
+    // Methods of superclasses are found too.
+    let foo = extends.get_method_by_name("foo")?;
+    assert_eq!(0, foo.get_ordinal());
+    assert_eq!(
+        test_interface::_private::TYPE_ID,
+        foo.get_containing_interface().get_proto().get_id()
+    );
+    let params = foo.get_param_type();
+    assert!(params.get_field_by_name("j")?.get_type() == TypeVariant::Bool.into());
+    let results = foo.get_result_type();
+    assert!(results.get_field_by_name("x")?.get_type() == TypeVariant::Text.into());
+    assert!(extends.find_method_by_name("nonexistent")?.is_none());
+    assert!(extends
+        .find_method_by_id(test_interface::_private::TYPE_ID, 3)?
+        .is_some());
+
+    assert_eq!(1, extends.get_superclasses()?.len());
+    let base = interface_schema::<test_interface::Owned>();
+    assert!(extends.extends(base)?);
+    assert!(!base.extends(extends)?);
+
+    // Superclasses carry their brands.
+    let generic = interface_schema::<generic_extend::Owned>();
+    let superclass = generic.get_superclasses()?.get(0);
+    assert!(superclass.extends(interface_schema::<generic_base::Owned<capnp::data::Owned>>())?);
+
+    // Capability fields and lists carry the schema as well.
+    let TypeVariant::Struct(list_holder) = test_capability_list::Owned::introspect().which() else {
+        panic!("expected a struct")
+    };
+    let list_type = StructSchema::from(list_holder)
+        .get_field_by_name("foo")?
+        .get_type();
+    assert!(list_type == capnp::introspect::Type::list_of(test_interface::Owned::introspect()));
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use crate::test_json_capnp::{person, shape, Color};
+use capnp::{json, message};
+
+const PERSON_JSON: &str = concat!(
+    r#"{"full_name":"Ada","favoriteColor":"light-blue","key":"abcd","blob":"AQID","#,
+    r#""home_street":"Main","home_city":"Paris","#,
+    r#""shapes":[{"kind":"radius","radius":1.5},{"kind":"square","square":2},{"kind":"empty"}],"#,
+    r#""pet":{"pet":"dogName","value":"Rex"}}"#
+);
+
+fn init_person(mut person: person::Builder<'_>) {
+    person.set_full_name("Ada");
+    person.set_favorite_color(Color::LightBlue);
+    person.set_key(&[0xab, 0xcd]);
+    person.set_blob(&[1, 2, 3]);
+    {
+        let mut home = person.reborrow().init_home();
+        home.set_street("Main");
+        home.set_city("Paris");
+    }
+    {
+        let mut shapes = person.reborrow().init_shapes(3);
+        shapes.reborrow().get(0).set_circle(1.5);
+        shapes.reborrow().get(1).set_square(2.0);
+        shapes.reborrow().get(2).set_empty(());
+    }
+    person.init_pet().set_dog_name("Rex");
+}
+
+#[test]
+fn encode_with_annotations() {
+    let mut message = message::Builder::new_default();
+    init_person(message.init_root());
+    let person: person::Reader<'_> = message.get_root_as_reader().unwrap();
+    assert_eq!(json::to_json(person).unwrap(), PERSON_JSON);
+}
+
+#[test]
+fn decode_with_annotations() {
+    let mut message = message::Builder::new_default();
+    json::from_json(PERSON_JSON, message.init_root::<person::Builder<'_>>()).unwrap();
+    let person: person::Reader<'_> = message.get_root_as_reader().unwrap();
+    assert_eq!(person.get_full_name().unwrap(), "Ada");
+    assert_eq!(person.get_favorite_color().unwrap(), Color::LightBlue);
+    assert_eq!(person.get_key().unwrap(), &[0xab, 0xcd]);
+    assert_eq!(person.get_blob().unwrap(), &[1, 2, 3]);
+    assert_eq!(person.get_home().unwrap().get_city().unwrap(), "Paris");
+
+    let shapes = person.get_shapes().unwrap();
+    assert_eq!(shapes.len(), 3);
+    assert!(matches!(shapes.get(0).which().unwrap(), shape::Circle(r) if r == 1.5));
+    assert!(matches!(shapes.get(1).which().unwrap(), shape::Square(s) if s == 2.0));
+    assert!(matches!(shapes.get(2).which().unwrap(), shape::Empty(())));
+
+    match person.get_pet().which().unwrap() {
+        person::pet::DogName(name) => assert_eq!(name.unwrap(), "Rex"),
+        _ => panic!("expected dogName"),
+    }
+
+    assert_eq!(json::to_json(person).unwrap(), PERSON_JSON);
+}
+
+#[test]
+fn decode_discriminator_without_value() {
+    let mut message = message::Builder::new_default();
+    json::from_json(
+        r#"{"pet": {"pet": "none"}, "shapes": [{"kind": "square"}]}"#,
+        message.init_root::<person::Builder<'_>>(),
+    )
+    .unwrap();
+    let person: person::Reader<'_> = message.get_root_as_reader().unwrap();
+    assert!(matches!(
+        person.get_pet().which().unwrap(),
+        person::pet::None(())
+    ));
+    assert!(matches!(
+        person.get_shapes().unwrap().get(0).which().unwrap(),
+        shape::Square(s) if s == 0.0
+    ));
+}
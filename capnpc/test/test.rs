@@ -27,6 +27,14 @@ pub mod test_capnp {
     include!(concat!(env!("OUT_DIR"), "/test_capnp.rs"));
 }
 
+pub mod json_capnp {
+    include!(concat!(env!("OUT_DIR"), "/json_capnp.rs"));
+}
+
+pub mod test_json_capnp {
+    include!(concat!(env!("OUT_DIR"), "/test_json_capnp.rs"));
+}
+
 pub mod foo {
     pub mod bar {
         pub mod in_submodule_capnp {
@@ -84,6 +92,9 @@ mod test_util;
 #[cfg(test)]
 mod dynamic;
 
+#[cfg(test)]
+mod json;
+
 #[cfg(test)]
 mod tests {
     use crate::test_util::{init_test_message, CheckTestMessage};
@@ -174,7 +174,7 @@ impl<R: Rng> Filler<R> {
             }
 
             TypeVariant::AnyPointer => Ok(()),
-            TypeVariant::Capability => Ok(()),
+            TypeVariant::Capability(_) => Ok(()),
         }
     }
 
@@ -215,7 +215,7 @@ impl<R: Rng> Filler<R> {
                 self.fill_list(recursion_depth + 1, builder.get(index)?.downcast())
             }
             TypeVariant::AnyPointer => Ok(()),
-            TypeVariant::Capability => Ok(()),
+            TypeVariant::Capability(_) => Ok(()),
         }
     }
 